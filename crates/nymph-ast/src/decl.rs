@@ -34,6 +34,7 @@ pub enum Declaration {
 	ExternalLet(Option<Visibility>, EcoString, LetDeclaration),
 	/// `effect Database`
 	Effect {
+		attributes: Vec<Spanned<Attribute>>,
 		visibility: Option<Visibility>,
 		name: Ident,
 	},
@@ -54,15 +55,19 @@ pub enum Declaration {
 	/// A product type. Its body splits into flat instance/namespace [`ImplMember`]s
 	/// and nested interface [`StructImpl`]s.
 	Struct {
+		attributes: Vec<Spanned<Attribute>>,
 		visibility: Option<Visibility>,
 		name: Ident,
 		generics: Vec<Spanned<GenericParam>>,
 		fields: Vec<Spanned<StructField>>,
 		members: Vec<Spanned<ImplMember>>,
 		impls: Vec<Spanned<StructImpl>>,
+		/// Interfaces named by a `derives` clause.
+		derives: Vec<Ident>,
 	},
 	/// A sum type.
 	Enum {
+		attributes: Vec<Spanned<Attribute>>,
 		visibility: Option<Visibility>,
 		name: Ident,
 		generics: Vec<Spanned<GenericParam>>,
@@ -72,15 +77,19 @@ pub enum Declaration {
 		variants: Vec<Spanned<EnumVariant>>,
 		members: Vec<Spanned<ImplMember>>,
 		impls: Vec<Spanned<StructImpl>>,
+		/// Interfaces named by a `derives` clause.
+		derives: Vec<Ident>,
 	},
 	/// A `namespace` of type-level (static) members.
 	Namespace {
+		attributes: Vec<Spanned<Attribute>>,
 		visibility: Option<Visibility>,
 		name: Ident,
 		members: Vec<Spanned<ImplMember>>,
 	},
 	/// An interface (trait), possibly with super-interfaces and default members.
 	Interface {
+		attributes: Vec<Spanned<Attribute>>,
 		visibility: Option<Visibility>,
 		name: Ident,
 		generics: Vec<Spanned<GenericParam>>,
@@ -89,6 +98,7 @@ pub enum Declaration {
 	},
 	/// An inherent impl: `impl<T> Option<T> { ... }`.
 	Impl {
+		attributes: Vec<Spanned<Attribute>>,
 		visibility: Option<Visibility>,
 		generics: Vec<Spanned<GenericParam>>,
 		type_: Spanned<Type>,
@@ -96,12 +106,135 @@ pub enum Declaration {
 	},
 	/// An interface impl: `impl<T> Unwrap<Output = T> for Option<T> { ... }`.
 	ImplFor {
+		attributes: Vec<Spanned<Attribute>>,
 		visibility: Option<Visibility>,
 		generics: Vec<Spanned<GenericParam>>,
 		type_: Spanned<Type>,
 		for_interface: (Ident, Vec<Spanned<GenericArg>>),
 		members: Vec<Spanned<ImplMember>>,
 	},
+	/// `test "adds numbers" { ... }`, `async test "reads a file" { ... }`
+	///
+	/// Checked and lowered as a private, parameterless function whose
+	/// signature the parser synthesizes under a name no source can spell (see
+	/// [`test_function_name`]), so every later phase treats it as a `func`.
+	Test {
+		name: Spanned<EcoString>,
+		meta: FuncDeclaration,
+		body: Expr,
+	},
+}
+
+impl Declaration {
+	/// The attributes written before this declaration. Functions, lets, type
+	/// aliases and tests keep theirs on the shared declaration metadata.
+	#[must_use]
+	pub fn attributes(&self) -> &[Spanned<Attribute>] {
+		match self {
+			Self::Import { .. } => &[],
+			Self::Let { meta, .. } | Self::ExternalLet(_, _, meta) => &meta.attributes,
+			Self::Func { meta, .. } | Self::ExternalFunc(_, _, meta) | Self::Test { meta, .. } => {
+				&meta.attributes
+			}
+			Self::TypeAlias { meta, .. } => &meta.attributes,
+			Self::Effect { attributes, .. }
+			| Self::Struct { attributes, .. }
+			| Self::Enum { attributes, .. }
+			| Self::Namespace { attributes, .. }
+			| Self::Interface { attributes, .. }
+			| Self::Impl { attributes, .. }
+			| Self::ImplFor { attributes, .. } => attributes,
+		}
+	}
+
+	/// Mutable access to [`Declaration::attributes`], or `None` for an import,
+	/// which cannot carry attributes.
+	pub fn attributes_mut(&mut self) -> Option<&mut Vec<Spanned<Attribute>>> {
+		match self {
+			Self::Import { .. } => None,
+			Self::Let { meta, .. } | Self::ExternalLet(_, _, meta) => Some(&mut meta.attributes),
+			Self::Func { meta, .. } | Self::ExternalFunc(_, _, meta) | Self::Test { meta, .. } => {
+				Some(&mut meta.attributes)
+			}
+			Self::TypeAlias { meta, .. } => Some(&mut meta.attributes),
+			Self::Effect { attributes, .. }
+			| Self::Struct { attributes, .. }
+			| Self::Enum { attributes, .. }
+			| Self::Namespace { attributes, .. }
+			| Self::Interface { attributes, .. }
+			| Self::Impl { attributes, .. }
+			| Self::ImplFor { attributes, .. } => Some(attributes),
+		}
+	}
+}
+
+/// `@inline`, `@allow(unused-variable)`, `@deprecated("use parse_all")`: metadata
+/// written before a declaration, member, field or variant. The parser records
+/// every attribute; each consumer decides which names it understands.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, salsa::SalsaValue)]
+pub struct Attribute {
+	pub name: Ident,
+	pub arguments: Vec<Spanned<AttributeArgument>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, salsa::SalsaValue)]
+pub enum AttributeArgument {
+	/// A plain string literal: `"use parse_all"`.
+	String(EcoString),
+	/// A name, hyphen-joined like a lint name: `inline`, `unused-variable`.
+	Name(EcoString),
+}
+
+impl AttributeArgument {
+	/// The argument's text, without quotes for a string.
+	#[must_use]
+	pub fn text(&self) -> &str {
+		match self {
+			Self::String(text) | Self::Name(text) => text,
+		}
+	}
+}
+
+/// The first attribute called `name`.
+#[must_use]
+pub fn find_attribute<'a>(
+	attributes: &'a [Spanned<Attribute>],
+	name: &str,
+) -> Option<&'a Attribute> {
+	attributes
+		.iter()
+		.map(|attribute| &attribute.0)
+		.find(|attribute| attribute.name.0 == name)
+}
+
+/// The synthesized function name of the `index`th test declared in a module.
+/// `$` never appears in a source identifier, so it cannot collide with one.
+#[must_use]
+pub fn test_function_name(index: usize) -> EcoString {
+	ecow::eco_format!("test${index}")
+}
+
+/// Whether `name` is a function name synthesized by [`test_function_name`].
+#[must_use]
+pub fn is_test_function_name(name: &str) -> bool {
+	name
+		.strip_prefix("test$")
+		.is_some_and(|index| !index.is_empty() && index.bytes().all(|byte| byte.is_ascii_digit()))
+}
+
+/// The marker recorded for `external("module", "symbol")`, which binds a
+/// declaration to a module and export of the application's choosing instead of
+/// a linkage-registry entry. A quote never appears in an identifier marker, so
+/// the two forms cannot collide.
+#[must_use]
+pub fn host_binding_marker(module: &str, symbol: &str) -> EcoString {
+	ecow::eco_format!("\"{module}\".{symbol}")
+}
+
+/// The module and symbol of a marker minted by [`host_binding_marker`].
+#[must_use]
+pub fn host_binding(marker: &str) -> Option<(&str, &str)> {
+	marker.strip_prefix('"')?.rsplit_once("\".")
 }
 
 #[derive(Debug, Copy, Eq, Clone, PartialEq, Hash, salsa::SalsaValue)]
@@ -125,12 +258,14 @@ pub enum ImportRoot {
 
 #[derive(Debug, Clone, PartialEq, salsa::SalsaValue)]
 pub struct TypeAliasDeclaration {
+	pub attributes: Vec<Spanned<Attribute>>,
 	pub name: Ident,
 	pub generics: Vec<Spanned<GenericParam>>,
 }
 
 #[derive(Debug, Clone, PartialEq, salsa::SalsaValue)]
 pub struct LetDeclaration {
+	pub attributes: Vec<Spanned<Attribute>>,
 	pub kind: LetKind,
 	pub name: Spanned<Pattern>,
 	pub type_: Option<Spanned<Type>>,
@@ -162,6 +297,7 @@ impl LetDeclaration {
 
 #[derive(Debug, Clone, PartialEq, salsa::SalsaValue)]
 pub struct FuncDeclaration {
+	pub attributes: Vec<Spanned<Attribute>>,
 	pub name: Ident,
 	pub kind: FuncKind,
 	/// Whether this callable constructs a cold task recipe.
@@ -189,6 +325,7 @@ pub struct FuncParam {
 
 #[derive(Debug, Clone, PartialEq, salsa::SalsaValue)]
 pub struct StructField {
+	pub attributes: Vec<Spanned<Attribute>>,
 	pub visibility: Option<Visibility>,
 	pub name: Ident,
 	pub type_: Spanned<Type>,
@@ -220,6 +357,16 @@ pub enum ImplMember {
 	ExternalFunc(Option<Visibility>, EcoString, FuncDeclaration),
 }
 
+impl ImplMember {
+	#[must_use]
+	pub fn attributes(&self) -> &[Spanned<Attribute>] {
+		match self {
+			Self::Let { meta, .. } | Self::ExternalLet(_, _, meta) => &meta.attributes,
+			Self::Func { meta, .. } | Self::ExternalFunc(_, _, meta) => &meta.attributes,
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, salsa::SalsaValue)]
 pub enum InterfaceMember {
 	Element(Box<Spanned<InterfaceElement>>),
@@ -244,8 +391,19 @@ pub enum InterfaceElement {
 	},
 }
 
+impl InterfaceElement {
+	#[must_use]
+	pub fn attributes(&self) -> &[Spanned<Attribute>] {
+		match self {
+			Self::Let { meta, .. } => &meta.attributes,
+			Self::Func { meta, .. } => &meta.attributes,
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, salsa::SalsaValue)]
 pub struct EnumVariant {
+	pub attributes: Vec<Spanned<Attribute>>,
 	pub name: Ident,
 	pub fields: Vec<Spanned<StructField>>,
 }
@@ -616,6 +616,7 @@ mod tests {
 					Spanned::new(
 						Statement::Let {
 							meta: LetDeclaration {
+								attributes: Vec::new(),
 								kind: LetKind::Instance,
 								name: Spanned::new(Pattern::Placeholder, SPAN),
 								type_: None,
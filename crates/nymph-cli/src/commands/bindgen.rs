@@ -0,0 +1,93 @@
+//! `nymph bindgen`: generate host bindings from a TypeScript declaration file.
+
+use std::path::{Path, PathBuf};
+
+use crate::NymphCommand;
+use crate::project_support::ManifestSelection;
+
+/// Generate a `.nym` module of `external` bindings from a `.d.ts` file.
+/// Exports without a Nymph equivalent are listed at the end of the module
+/// and reported on stderr.
+#[derive(clap::Args)]
+pub(crate) struct BindgenCommand {
+	/// The TypeScript declaration file to read.
+	#[arg(value_name = "FILE")]
+	file: PathBuf,
+
+	/// The module specifier the bindings import from (defaults to the file
+	/// name without its `.d.ts` extension, or the directory name for an
+	/// `index.d.ts`).
+	#[arg(long, value_name = "SPECIFIER")]
+	module: Option<String>,
+
+	/// Write the module to this path instead of stdout.
+	#[arg(short, long, value_name = "PATH")]
+	output: Option<PathBuf>,
+}
+
+impl NymphCommand for BindgenCommand {
+	fn run(&self, _manifest: &ManifestSelection) -> i32 {
+		let source = match std::fs::read_to_string(&self.file) {
+			Ok(source) => source,
+			Err(error) => {
+				eprintln!("error: could not read {}: {error}", self.file.display());
+				return 1;
+			}
+		};
+		let Some(module) = self.module.clone().or_else(|| default_module(&self.file)) else {
+			eprintln!(
+				"error: could not infer a module specifier from {}; pass --module",
+				self.file.display()
+			);
+			return 1;
+		};
+		let file_name = self.file.file_name().map_or_else(
+			|| self.file.display().to_string(),
+			|name| name.to_string_lossy().into_owned(),
+		);
+		let bindings = match nymph_compiler::generate_bindings(&source, &module, &file_name) {
+			Ok(bindings) => bindings,
+			Err(errors) => {
+				for error in errors {
+					eprintln!("error: {}: {error}", self.file.display());
+				}
+				return 1;
+			}
+		};
+		for unmapped in &bindings.unmapped {
+			eprintln!(
+				"warning: {}:{}: `{}` is not bound: {}",
+				self.file.display(),
+				unmapped.line,
+				unmapped.name,
+				unmapped.reason
+			);
+		}
+		match &self.output {
+			Some(output) => {
+				if let Err(error) = std::fs::write(output, &bindings.source) {
+					eprintln!("error: could not write {}: {error}", output.display());
+					return 1;
+				}
+			}
+			None => print!("{}", bindings.source),
+		}
+		0
+	}
+}
+
+/// `left-pad` for `left-pad.d.ts`, and the package directory's name for its
+/// `index.d.ts`.
+fn default_module(file: &Path) -> Option<String> {
+	let name = file.file_name()?.to_str()?;
+	let stem = [".d.ts", ".d.mts", ".d.cts", ".ts"]
+		.iter()
+		.find_map(|extension| name.strip_suffix(extension))
+		.unwrap_or(name);
+	if stem != "index" {
+		return Some(stem.to_string());
+	}
+	let directory = std::path::absolute(file).ok()?;
+	let directory = directory.parent()?.file_name()?.to_str()?;
+	Some(directory.to_string())
+}
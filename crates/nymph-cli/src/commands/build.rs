@@ -2,21 +2,86 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::NymphCommand;
-use crate::project_support::{ManifestSelection, ProjectOperation};
+use crate::message_format::{MessageFormat, Report};
+use crate::project_support::{
+	ManifestSelection, ProjectOperation, load_workspace, resolve_operations,
+};
 
-/// compile a Nymph source file to a JavaScript module.
+/// compile a Nymph source file to a JavaScript module. From a workspace root,
+/// every member's entry is built next to its source.
 #[derive(clap::Args)]
 pub(crate) struct BuildCommand {
 	/// Path to the `.nym` source file to build (defaults to project build.entry).
 	file: Option<PathBuf>,
 
 	/// Output path for the emitted JavaScript (defaults to `<input>.mjs`)
-	#[arg(short, long, value_name = "FILE")]
+	#[arg(short, long, value_name = "FILE", conflicts_with = "out_dir")]
 	output: Option<PathBuf>,
 
+	/// What to emit: one bundled module, or one ES module per Nymph module.
+	#[arg(long, value_enum, default_value_t, value_name = "LAYOUT")]
+	emit: EmitKind,
+
+	/// Directory for `--emit modules` output (defaults to `dist` beside the
+	/// input).
+	#[arg(long, value_name = "DIR")]
+	out_dir: Option<PathBuf>,
+
 	/// Build with the release compiler profile.
 	#[arg(long)]
 	release: bool,
+
+	/// How to report diagnostics: rendered to stderr, or as JSON lines or a
+	/// SARIF log on stdout.
+	#[arg(long, value_enum, default_value_t, value_name = "FORMAT")]
+	message_format: MessageFormat,
+
+	/// Build again whenever a source file or manifest changes.
+	#[arg(long)]
+	watch: bool,
+
+	/// Also write TypeScript declarations (`.d.mts`) for the entry's public
+	/// API beside its output, and export that API under its Nymph names.
+	#[arg(long)]
+	declarations: bool,
+
+	/// Write a Node program instead of an importable module: the output
+	/// starts with a shebang, runs `main` like `nymph run`, and is marked
+	/// executable.
+	#[arg(long, conflicts_with = "declarations")]
+	executable: bool,
+
+	/// Also write a source map back to the `.nym` sources, next to the output
+	/// or inline in it.
+	#[arg(
+		long,
+		value_enum,
+		value_name = "KIND",
+		num_args = 0..=1,
+		require_equals = true,
+		default_missing_value = "external"
+	)]
+	source_map: Option<SourceMapKind>,
+}
+
+/// What `nymph build --emit` writes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+enum EmitKind {
+	/// Every module linked into one file.
+	#[default]
+	Bundle,
+	/// One ES module per Nymph module and runtime module, under `--out-dir`,
+	/// importing each other by relative path.
+	Modules,
+}
+
+/// Where `nymph build --source-map` puts the map.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+enum SourceMapKind {
+	/// A `<output>.map` file beside the output.
+	External,
+	/// A data URL at the end of the output.
+	Inline,
 }
 
 impl NymphCommand for BuildCommand {
@@ -26,23 +91,257 @@ impl NymphCommand for BuildCommand {
 		} else {
 			nymph_compiler::BuildProfile::Development
 		};
-		let operation = match ProjectOperation::resolve(self.file.as_deref(), manifest, profile) {
-			Some(operation) => operation,
-			None => return 1,
+		if self.out_dir.is_some() && self.emit != EmitKind::Modules {
+			eprintln!("error: --out-dir is only used with --emit modules");
+			return 1;
+		}
+		if self.executable && self.emit == EmitKind::Modules {
+			eprintln!(
+				"error: --executable writes one bundled program; it cannot be used with --emit modules"
+			);
+			return 1;
+		}
+		if (self.output.is_some() || self.out_dir.is_some()) && self.file.is_none() {
+			match load_workspace(manifest) {
+				Ok(Some(_)) => {
+					let flag = if self.output.is_some() {
+						"--output"
+					} else {
+						"--out-dir"
+					};
+					eprintln!(
+						"error: {flag} names one output; build a single workspace member with --manifest"
+					);
+					return 1;
+				}
+				Ok(None) => {}
+				Err(error) => {
+					eprintln!("error: {error}");
+					return 1;
+				}
+			}
+		}
+		let layout = match self.emit {
+			EmitKind::Bundle => nymph_compiler::OutputLayout::Bundle,
+			EmitKind::Modules => nymph_compiler::OutputLayout::Modules,
+		};
+		let resolve = || {
+			resolve_operations(self.file.as_deref(), manifest, profile, self.message_format).map(
+				|operations| {
+					operations
+						.into_iter()
+						.map(|operation| {
+							operation.map(|operation| {
+								operation
+									.with_source_maps(self.source_map.is_some())
+									.with_output_layout(layout)
+									.with_declarations(self.declarations)
+							})
+						})
+						.collect()
+				},
+			)
+		};
+		if self.watch {
+			return crate::watch::watch(resolve, |operations, _| self.build_all(operations) == 0);
+		}
+		match resolve() {
+			Ok(operations) => self.build_all(&operations),
+			Err(error) => {
+				eprintln!("error: {error}");
+				1
+			}
+		}
+	}
+}
+
+impl BuildCommand {
+	fn build_all(&self, operations: &[Option<ProjectOperation>]) -> i32 {
+		let mut report = Report::default();
+		let code = operations
+			.iter()
+			.map(|operation| match operation {
+				Some(operation) => self.build(operation, &mut report),
+				None => 1,
+			})
+			.fold(0, i32::max);
+		report.finish(self.message_format, code == 0);
+		code
+	}
+
+	fn build(&self, operation: &ProjectOperation, report: &mut Report) -> i32 {
+		let compiled = if self.executable {
+			operation.compile_entry()
+		} else {
+			operation.compile_selected_mode()
+		};
+		report.merge(operation.take_report());
+		let Some(compiled) = compiled else {
+			return 1;
 		};
+		if self.emit == EmitKind::Modules {
+			let out_dir = self.out_dir.clone().unwrap_or_else(|| {
+				operation
+					.target_file()
+					.parent()
+					.unwrap_or_else(|| Path::new("."))
+					.join("dist")
+			});
+			let entry = compiled
+				.modules
+				.first()
+				.map(|module| out_dir.join(&module.path));
+			let written = compiled
+				.modules
+				.iter()
+				.map(|module| {
+					let path = out_dir.join(&module.path);
+					if let Some(dir) = path.parent()
+						&& let Err(err) = std::fs::create_dir_all(dir)
+					{
+						eprintln!("error: could not create {}: {err}", dir.display());
+						return 1;
+					}
+					self.write_module(&path, &module.js, module.source_map.as_deref())
+				})
+				.fold(0, i32::max);
+			return match entry {
+				Some(entry) => written.max(write_declarations(&entry, &compiled)),
+				None => written,
+			};
+		}
 		let output_path = self
 			.output
 			.clone()
 			.unwrap_or_else(|| operation.target_file().with_extension("mjs"));
-		match operation.compile_selected_mode() {
-			Some(compiled) => match write_output_atomically(&output_path, &compiled.js) {
-				Ok(()) => 0,
-				Err(err) => {
-					eprintln!("error: could not write {}: {err}", output_path.display());
-					1
+		if self.executable {
+			return self.write_executable(&output_path, &compiled);
+		}
+		self
+			.write_module(&output_path, &compiled.js, compiled.source_map.as_deref())
+			.max(write_declarations(&output_path, &compiled))
+	}
+
+	/// Write `compiled` as a program Node runs directly: a shebang, the
+	/// bundle, and the launcher `nymph run` appends after it.
+	fn write_executable(
+		&self,
+		output_path: &Path,
+		compiled: &nymph_compiler::CompiledProject,
+	) -> i32 {
+		let root = compiled
+			.entry_root
+			.as_ref()
+			.expect("entry compilation provides a validated root adapter");
+		let mut js = String::from("#!/usr/bin/env node\n");
+		// Node only honors source maps when asked to, which `nymph run` does
+		// on its command line; a shebang cannot pass the flag portably.
+		if self.source_map.is_some() {
+			js.push_str("process.setSourceMapsEnabled(true);\n");
+		}
+		let preamble = js.lines().count();
+		js.push_str(&super::run::root_launcher(
+			&compiled.js,
+			&compiled.entry_main,
+			root,
+			"main",
+		));
+		// The launcher follows the bundle, so only the preamble moves it.
+		let map = compiled
+			.source_map
+			.as_deref()
+			.map(|map| shift_source_map(map, preamble));
+		let code = self.write_module(output_path, &js, map.as_deref());
+		if code != 0 {
+			return code;
+		}
+		match mark_executable(output_path) {
+			Ok(()) => 0,
+			Err(err) => {
+				eprintln!(
+					"error: could not make {} executable: {err}",
+					output_path.display()
+				);
+				1
+			}
+		}
+	}
+
+	/// Write `js` to `output_path`, with `map` beside it or inline when a
+	/// source map was asked for.
+	fn write_module(&self, output_path: &Path, js: &str, map: Option<&str>) -> i32 {
+		let mut js = js.to_string();
+		if let (Some(kind), Some(map)) = (self.source_map, map) {
+			let file_name = output_path
+				.file_name()
+				.map_or_else(|| "out".to_string(), |n| n.to_string_lossy().into_owned());
+			let mut map = oxc_sourcemap::SourceMap::from_json_string(map)
+				.expect("the compiler emits valid source maps");
+			map.set_file(&file_name);
+			let url = match kind {
+				SourceMapKind::External => {
+					let map_path = output_path.with_file_name(format!("{file_name}.map"));
+					if let Err(err) = write_output_atomically(&map_path, &map.to_json_string()) {
+						eprintln!("error: could not write {}: {err}", map_path.display());
+						return 1;
+					}
+					format!("{file_name}.map")
 				}
-			},
-			None => 1,
+				SourceMapKind::Inline => map.to_data_url(),
+			};
+			js.push_str(&format!("//# sourceMappingURL={url}\n"));
+		}
+		match write_output_atomically(output_path, &js) {
+			Ok(()) => 0,
+			Err(err) => {
+				eprintln!("error: could not write {}: {err}", output_path.display());
+				1
+			}
+		}
+	}
+}
+
+/// Move every mapping in the source map `map` down by `lines` generated
+/// lines.
+fn shift_source_map(map: &str, lines: usize) -> String {
+	let mut map: serde_json::Value =
+		serde_json::from_str(map).expect("the compiler emits valid source maps");
+	if let Some(mappings) = map.get_mut("mappings")
+		&& let Some(text) = mappings.as_str()
+	{
+		*mappings = serde_json::Value::String(format!("{}{text}", ";".repeat(lines)));
+	}
+	map.to_string()
+}
+
+/// Let the owner, group, and others run `path`, as `chmod +x` does.
+#[cfg(unix)]
+fn mark_executable(path: &Path) -> std::io::Result<()> {
+	use std::os::unix::fs::PermissionsExt;
+
+	let mut permissions = std::fs::metadata(path)?.permissions();
+	permissions.set_mode(permissions.mode() | 0o111);
+	std::fs::set_permissions(path, permissions)
+}
+
+/// Other platforms start a `.mjs` file through its association with Node.
+#[cfg(not(unix))]
+fn mark_executable(_path: &Path) -> std::io::Result<()> {
+	Ok(())
+}
+
+/// Write `compiled`'s declarations, if it has any, beside the entry module
+/// at `entry` (`main.mjs` gets `main.d.mts`).
+fn write_declarations(entry: &Path, compiled: &nymph_compiler::CompiledProject) -> i32 {
+	let Some(declarations) = &compiled.declarations else {
+		return 0;
+	};
+	let path = entry.with_extension("d.mts");
+	match write_output_atomically(&path, declarations) {
+		Ok(()) => 0,
+		Err(err) => {
+			eprintln!("error: could not write {}: {err}", path.display());
+			1
 		}
 	}
 }
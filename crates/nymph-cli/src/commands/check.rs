@@ -1,17 +1,9 @@
 use std::path::PathBuf;
 
 use crate::NymphCommand;
-use crate::project_support::{ManifestSelection, ProjectOperation};
+use crate::message_format::{MessageFormat, Report};
+use crate::project_support::{ManifestSelection, ProjectOperation, resolve_operations};
 
-/// `nymph check [file]` — parse and type-check a Nymph source file. Prints
-/// "ok" and exits 0 when there are no diagnostics; otherwise renders every
-/// diagnostic (severity, `file:line:col`, message, source excerpt) to stderr
-/// and exits nonzero only if at least one diagnostic is an error (warnings
-/// alone still exit 0).
-///
-/// With no file, the nearest project's manifest `build.entry` is used. In a
-/// project, that manifest-selected module is checked in entry mode and other
-/// explicit files are checked as libraries. Loose files are libraries.
 #[derive(clap::Args)]
 pub(crate) struct CheckCommand {
 	/// Path to the `.nym` source file to check (defaults to project build.entry).
@@ -20,6 +12,15 @@ pub(crate) struct CheckCommand {
 	/// Check with the release compiler profile.
 	#[arg(long)]
 	release: bool,
+
+	/// How to report diagnostics: rendered to stderr, or as JSON lines or a
+	/// SARIF log on stdout.
+	#[arg(long, value_enum, default_value_t, value_name = "FORMAT")]
+	message_format: MessageFormat,
+
+	/// Check again whenever a source file or manifest changes.
+	#[arg(long)]
+	watch: bool,
 }
 
 impl NymphCommand for CheckCommand {
@@ -29,16 +30,43 @@ impl NymphCommand for CheckCommand {
 		} else {
 			nymph_compiler::BuildProfile::Development
 		};
-		let operation = match ProjectOperation::resolve(self.file.as_deref(), manifest, profile) {
-			Some(operation) => operation,
-			None => return 1,
-		};
-		let diagnostics = operation.check_selected_mode();
-		if diagnostics.is_empty() {
+		let resolve =
+			|| resolve_operations(self.file.as_deref(), manifest, profile, self.message_format);
+		if self.watch {
+			return crate::watch::watch(resolve, |operations, _| self.check(operations) == 0);
+		}
+		match resolve() {
+			Ok(operations) => self.check(&operations),
+			Err(error) => {
+				eprintln!("error: {error}");
+				1
+			}
+		}
+	}
+}
+
+impl CheckCommand {
+	fn check(&self, operations: &[Option<ProjectOperation>]) -> i32 {
+		let mut clean = true;
+		let mut failed = false;
+		let mut report = Report::default();
+		for operation in operations {
+			let Some(operation) = operation else {
+				failed = true;
+				continue;
+			};
+			let diagnostics = operation.check_selected_mode();
+			if !diagnostics.is_empty() {
+				clean = false;
+				operation.emit(&diagnostics);
+				failed |= diagnostics.iter().any(|d| d.diag.is_error());
+			}
+			report.merge(operation.take_report());
+		}
+		if self.message_format == MessageFormat::Human && clean && !failed {
 			println!("ok");
-			return 0;
 		}
-		eprint!("{}", operation.render(&diagnostics));
-		i32::from(diagnostics.iter().any(|d| d.diag.is_error()))
+		report.finish(self.message_format, !failed);
+		i32::from(failed)
 	}
 }
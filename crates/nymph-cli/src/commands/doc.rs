@@ -3,11 +3,11 @@ use std::process::Command;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::NymphCommand;
-use crate::project_support::{ManifestSelection, load_project, render_project_diagnostics};
+use crate::project_support::{ManifestSelection, ProjectOperation, load_project, load_workspace};
 
 #[derive(clap::Args)]
 pub(crate) struct DocCommand {
-	/// Directory for the generated site (defaults to target/nymph/doc in the project root).
+	/// Directory for the generated site (defaults to target/nymph/doc in the project or workspace root).
 	#[arg(short, long, value_name = "DIR")]
 	output: Option<PathBuf>,
 
@@ -28,57 +28,27 @@ impl NymphCommand for DocCommand {
 
 impl DocCommand {
 	fn run_with_opener(&self, manifest: &ManifestSelection, opener: &dyn Opener) -> i32 {
-		let project = match load_project(manifest) {
-			Ok(project) => project,
+		match load_workspace(manifest) {
+			Ok(Some(workspace)) => return self.run_workspace(&workspace),
+			Ok(None) => {}
 			Err(error) => {
 				eprintln!("error: {error}");
 				return 1;
 			}
-		};
-		let entry = match project.entry_module() {
-			Ok(entry) => entry,
+		}
+		let project = match load_project(manifest) {
+			Ok(project) => project,
 			Err(error) => {
-				eprintln!(
-					"error: invalid build entry for manifest {}: {error}",
-					project.manifest_path().display()
-				);
-				return 1;
-			}
-		};
-		let source_root = project.source_root();
-		let load = nymph_project::fs_loader(source_root.clone());
-		let documentation = match nymph_compiler::document_project(
-			entry.as_str(),
-			&load,
-			nymph_compiler::DocOptions {
-				document_private_items: self.document_private_items,
-			},
-		) {
-			Ok(documentation) => documentation,
-			Err(diagnostics) => {
-				eprint!(
-					"{}",
-					render_project_diagnostics(&diagnostics, &source_root, &load)
-				);
+				eprintln!("error: {error}");
 				return 1;
 			}
 		};
-		if !documentation.diagnostics.is_empty() {
-			eprint!(
-				"{}",
-				render_project_diagnostics(&documentation.diagnostics, &source_root, &load)
-			);
-		}
 		let output = self
 			.output
 			.clone()
-			.unwrap_or_else(|| project.root().join("target/nymph/doc"));
-		let published = match publish(&output, &documentation.render_html()) {
-			Ok(published) => published,
-			Err(error) => {
-				eprintln!("error: could not publish {}: {error}", output.display());
-				return 1;
-			}
+			.unwrap_or_else(|| default_output(&project));
+		let Some(published) = self.document(&project, &output) else {
+			return 1;
 		};
 		let index = published.join("index.html");
 		let display_index = output.join("index.html");
@@ -98,6 +68,74 @@ impl DocCommand {
 		println!("generated documentation at {}", display_index.display());
 		0
 	}
+
+	/// Document every workspace member into its own `<output>/<name>` site.
+	fn run_workspace(&self, workspace: &nymph_project::WorkspaceRoot) -> i32 {
+		if self.open {
+			eprintln!(
+				"error: --open needs a single package; document one workspace member with --manifest"
+			);
+			return 1;
+		}
+		let output = self
+			.output
+			.clone()
+			.unwrap_or_else(|| workspace.root().join(DOC_DIR));
+		let mut status = 0;
+		for member in workspace.members() {
+			let output = output.join(&member.manifest().package.name);
+			match self.document(member, &output) {
+				Some(_) => println!(
+					"generated documentation at {}",
+					output.join("index.html").display()
+				),
+				None => status = 1,
+			}
+		}
+		status
+	}
+
+	/// Generate and publish one project's site, reporting any failure.
+	fn document(&self, project: &nymph_project::Project, output: &Path) -> Option<PathBuf> {
+		let operation = ProjectOperation::resolve(
+			None,
+			&ManifestSelection::Explicit(project.manifest_path().to_path_buf()),
+			nymph_compiler::BuildProfile::Development,
+		)?;
+		let documentation = match operation.document(nymph_compiler::DocOptions {
+			document_private_items: self.document_private_items,
+		}) {
+			Ok(documentation) => documentation,
+			Err(diagnostics) => {
+				eprint!("{}", operation.render(&diagnostics));
+				return None;
+			}
+		};
+		if !documentation.diagnostics.is_empty() {
+			eprint!("{}", operation.render(&documentation.diagnostics));
+		}
+		match publish(output, &documentation.render_html()) {
+			Ok(published) => Some(published),
+			Err(error) => {
+				eprintln!("error: could not publish {}: {error}", output.display());
+				None
+			}
+		}
+	}
+}
+
+const DOC_DIR: &str = "target/nymph/doc";
+
+/// `target/nymph/doc` of a standalone project; a workspace member's site lives
+/// under its name in the workspace root's shared `target/`.
+fn default_output(project: &nymph_project::Project) -> PathBuf {
+	match project.workspace_manifest() {
+		Some(_) => project
+			.workspace_root()
+			.join(DOC_DIR)
+			.join(&project.manifest().package.name),
+		None => project.root().join(DOC_DIR),
+	}
 }
 
 trait Opener {
@@ -0,0 +1,392 @@
+//! `nymph fix`: apply the machine-applicable edits diagnostics carry.
+//!
+//! Each round checks the target and keeps every edit group whose replacements
+//! overlap no group kept before it, then applies the kept groups together. A
+//! group is all-or-nothing, so a fix spanning several modules lands in all of
+//! them or in none. The edited text is checked again in memory, and rounds
+//! repeat until no edit applies; only then is each changed file written.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use nymph_diagnostics::{EditGroup, SourceEdit, SourceId, SourceSnapshot, TextReplacement};
+
+use crate::NymphCommand;
+use crate::project_support::{
+	ManifestSelection, ProjectOperation, atomic_write, load_workspace, member_selections,
+};
+
+/// Rounds after which fixes that keep producing new fixes are abandoned.
+const MAX_ROUNDS: usize = 16;
+
+/// Unchanged lines shown around each change in a `--dry-run` diff.
+const DIFF_CONTEXT: usize = 3;
+
+/// Apply every machine-applicable fix the checker suggests. From a workspace
+/// root, every member is fixed.
+#[derive(clap::Args)]
+pub(crate) struct FixCommand {
+	/// Path to the `.nym` source file to fix (defaults to project build.entry).
+	file: Option<PathBuf>,
+
+	/// Check with the release compiler profile, which reports release-only
+	/// lints such as `echo-in-release`.
+	#[arg(long)]
+	release: bool,
+
+	/// Print the fixes as a unified diff instead of writing them.
+	#[arg(long)]
+	dry_run: bool,
+}
+
+impl NymphCommand for FixCommand {
+	fn run(&self, manifest: &ManifestSelection) -> i32 {
+		let profile = if self.release {
+			nymph_compiler::BuildProfile::Release
+		} else {
+			nymph_compiler::BuildProfile::Development
+		};
+		let selections = match self.file {
+			Some(_) => vec![manifest.clone()],
+			None => match load_workspace(manifest) {
+				Ok(Some(workspace)) => member_selections(&workspace),
+				Ok(None) => vec![manifest.clone()],
+				Err(error) => {
+					eprintln!("error: {error}");
+					return 1;
+				}
+			},
+		};
+		let mut failed = false;
+		for selection in &selections {
+			match ProjectOperation::resolve(self.file.as_deref(), selection, profile) {
+				Some(operation) => failed |= !self.fix(&operation),
+				None => failed = true,
+			}
+		}
+		i32::from(failed)
+	}
+}
+
+/// A root-package module, by its package-relative module key.
+type ModuleKey = String;
+
+impl FixCommand {
+	/// Fix one target, reporting what is left afterwards. Returns whether the
+	/// target ends up free of errors and its fixes were written.
+	fn fix(&self, operation: &ProjectOperation) -> bool {
+		let mut original = BTreeMap::<ModuleKey, String>::new();
+		let mut fixed = BTreeMap::<ModuleKey, (String, usize)>::new();
+		let mut diagnostics = operation.check_selected_mode();
+		for round in 0.. {
+			let groups = applicable_groups(operation, &diagnostics);
+			if groups.is_empty() {
+				break;
+			}
+			if round == MAX_ROUNDS {
+				eprintln!("warning: fixes were still changing the source after {MAX_ROUNDS} rounds");
+				break;
+			}
+			let edited = match apply_groups(operation, &groups) {
+				Ok(edited) => edited,
+				Err(error) => {
+					eprintln!("error: could not apply fixes: {error:?}");
+					return false;
+				}
+			};
+			for (module, text) in edited {
+				let fixes = groups
+					.iter()
+					.filter(|group| {
+						group
+							.sources()
+							.iter()
+							.any(|source| root_module(source.source()) == Some(module.as_str()))
+					})
+					.count();
+				if let Some(before) = operation.analyzed_source(0, &module) {
+					original.entry(module.clone()).or_insert(before);
+				}
+				operation.override_source(0, &module, text.clone());
+				let entry = fixed.entry(module).or_insert((String::new(), 0));
+				*entry = (text, entry.1 + fixes);
+			}
+			diagnostics = operation.check_selected_mode();
+		}
+
+		let mut written = true;
+		for (module, (text, fixes)) in &fixed {
+			let Some(path) = operation.module_path(0, module) else {
+				continue;
+			};
+			let before = original.get(module).map_or("", String::as_str);
+			if self.dry_run {
+				print!(
+					"{}",
+					unified_diff(&path.display().to_string(), before, text)
+				);
+				continue;
+			}
+			match atomic_write(&path, text.as_bytes()) {
+				Ok(()) => {
+					let plural = if *fixes == 1 { "" } else { "es" };
+					eprintln!("fixed {} ({fixes} fix{plural})", path.display());
+				}
+				Err(error) => {
+					eprintln!("error: could not write {}: {error}", path.display());
+					written = false;
+				}
+			}
+		}
+		if !diagnostics.is_empty() {
+			operation.emit(&diagnostics);
+		}
+		written && !diagnostics.iter().any(|d| d.diag.is_error())
+	}
+}
+
+/// The root-package module key an edit source names, if it names one.
+fn root_module(source: &SourceId) -> Option<&str> {
+	match nymph_compiler::package_module(source.module())? {
+		(0, module) => Some(module),
+		_ => None,
+	}
+}
+
+/// Every edit group to apply this round, in diagnostic order: a group is kept
+/// when it edits only root-package modules this check read, and none of its
+/// replacements overlaps a replacement of a group kept before it.
+fn applicable_groups(
+	operation: &ProjectOperation,
+	diagnostics: &[nymph_compiler::ProjectDiagnostic],
+) -> Vec<EditGroup> {
+	let mut claimed = BTreeMap::<SourceId, Vec<nymph_ast::Span>>::new();
+	let mut groups = Vec::new();
+	for group in diagnostics.iter().flat_map(|d| d.diag.edits()) {
+		let editable = group.sources().iter().all(|source| {
+			root_module(source.source())
+				.is_some_and(|module| operation.analyzed_source(0, module).is_some())
+		});
+		let overlaps = group.sources().iter().any(|source| {
+			claimed.get(source.source()).is_some_and(|spans| {
+				source
+					.replacements()
+					.iter()
+					.any(|replacement| spans.iter().any(|span| overlap(*span, replacement.span())))
+			})
+		});
+		if !editable || overlaps {
+			continue;
+		}
+		for source in group.sources() {
+			claimed
+				.entry(source.source().clone())
+				.or_default()
+				.extend(source.replacements().iter().map(TextReplacement::span));
+		}
+		groups.push(group.clone());
+	}
+	groups
+}
+
+/// Whether two replacements touch the same text; two insertions at one offset
+/// overlap too, since their order would be ambiguous.
+fn overlap(left: nymph_ast::Span, right: nymph_ast::Span) -> bool {
+	left.start == right.start || (left.start < right.end && right.start < left.end)
+}
+
+/// Apply `groups` as one edit and return each edited module's new text.
+fn apply_groups(
+	operation: &ProjectOperation,
+	groups: &[EditGroup],
+) -> Result<Vec<(ModuleKey, String)>, nymph_diagnostics::EditError> {
+	let mut merged = BTreeMap::<SourceId, (nymph_diagnostics::SourceVersion, Vec<_>)>::new();
+	for source in groups.iter().flat_map(EditGroup::sources) {
+		merged
+			.entry(source.source().clone())
+			.or_insert_with(|| (source.version(), Vec::new()))
+			.1
+			.extend_from_slice(source.replacements());
+	}
+	// Each snapshot is the exact text the check analyzed, so it is current at
+	// the version the edits were produced against.
+	let texts = merged
+		.iter()
+		.map(|(source, (version, _))| {
+			let text = root_module(source)
+				.and_then(|module| operation.analyzed_source(0, module))
+				.unwrap_or_default();
+			(source.clone(), *version, text)
+		})
+		.collect::<Vec<_>>();
+	let snapshots = texts
+		.iter()
+		.map(|(source, version, text)| SourceSnapshot::new(source.clone(), *version, text))
+		.collect::<Vec<_>>();
+	let sources = merged
+		.into_iter()
+		.map(|(source, (version, replacements))| SourceEdit::new(source, version, replacements))
+		.collect::<Result<Vec<_>, _>>()?;
+	let edited = EditGroup::new("nymph fix", sources)?.apply(&snapshots)?;
+	Ok(
+		edited
+			.into_iter()
+			.filter_map(|edited| Some((root_module(&edited.source)?.to_string(), edited.text)))
+			.collect(),
+	)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DiffLine<'a> {
+	Same(&'a str),
+	Removed(&'a str),
+	Added(&'a str),
+}
+
+/// A unified diff from `before` to `after` under the header `path`, or nothing
+/// when the two are equal.
+fn unified_diff(path: &str, before: &str, after: &str) -> String {
+	let lines = diff_lines(before, after);
+	let is_change = |line: &DiffLine<'_>| !matches!(line, DiffLine::Same(_));
+	let mut out = String::new();
+	let mut next = 0;
+	while let Some(first) = (next..lines.len()).find(|&index| is_change(&lines[index])) {
+		// Changes separated by at most twice the context share one hunk.
+		let mut last = first;
+		while let Some(following) = (last + 1..lines.len()).find(|&index| is_change(&lines[index]))
+			&& following - last <= 2 * DIFF_CONTEXT + 1
+		{
+			last = following;
+		}
+		let start = first.saturating_sub(DIFF_CONTEXT);
+		let end = (last + 1 + DIFF_CONTEXT).min(lines.len());
+		let counts = |range: std::ops::Range<usize>| {
+			lines[range]
+				.iter()
+				.fold((0, 0), |(old, new), line| match line {
+					DiffLine::Same(_) => (old + 1, new + 1),
+					DiffLine::Removed(_) => (old + 1, new),
+					DiffLine::Added(_) => (old, new + 1),
+				})
+		};
+		let (old_before, new_before) = counts(0..start);
+		let (old_len, new_len) = counts(start..end);
+		if out.is_empty() {
+			out.push_str(&format!("--- {path}\n+++ {path}\n"));
+		}
+		out.push_str(&format!(
+			"@@ -{} +{} @@\n",
+			hunk_range(old_before, old_len),
+			hunk_range(new_before, new_len)
+		));
+		for line in &lines[start..end] {
+			let (marker, text) = match line {
+				DiffLine::Same(text) => (' ', text),
+				DiffLine::Removed(text) => ('-', text),
+				DiffLine::Added(text) => ('+', text),
+			};
+			out.push(marker);
+			out.push_str(text);
+			if !text.ends_with('\n') {
+				out.push_str("\n\\ No newline at end of file\n");
+			}
+		}
+		next = end;
+	}
+	out
+}
+
+/// A hunk header range: 1-based, except that an empty range names the line
+/// before it.
+fn hunk_range(before: usize, len: usize) -> String {
+	let start = if len == 0 { before } else { before + 1 };
+	if len == 1 {
+		start.to_string()
+	} else {
+		format!("{start},{len}")
+	}
+}
+
+/// A line-level longest-common-subsequence diff. The common prefix and
+/// suffix are matched first, so the table covers only the changed middle.
+fn diff_lines<'a>(before: &'a str, after: &'a str) -> Vec<DiffLine<'a>> {
+	let old = before.split_inclusive('\n').collect::<Vec<_>>();
+	let new = after.split_inclusive('\n').collect::<Vec<_>>();
+	let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+	let suffix = old[prefix..]
+		.iter()
+		.rev()
+		.zip(new[prefix..].iter().rev())
+		.take_while(|(a, b)| a == b)
+		.count();
+	let old_middle = &old[prefix..old.len() - suffix];
+	let new_middle = &new[prefix..new.len() - suffix];
+
+	// `common[i][j]`: the LCS length of `old_middle[i..]` and `new_middle[j..]`.
+	let mut common = vec![vec![0usize; new_middle.len() + 1]; old_middle.len() + 1];
+	for i in (0..old_middle.len()).rev() {
+		for j in (0..new_middle.len()).rev() {
+			common[i][j] = if old_middle[i] == new_middle[j] {
+				common[i + 1][j + 1] + 1
+			} else {
+				common[i + 1][j].max(common[i][j + 1])
+			};
+		}
+	}
+
+	let mut lines = old[..prefix]
+		.iter()
+		.map(|line| DiffLine::Same(line))
+		.collect::<Vec<_>>();
+	let (mut i, mut j) = (0, 0);
+	while i < old_middle.len() || j < new_middle.len() {
+		if i < old_middle.len() && j < new_middle.len() && old_middle[i] == new_middle[j] {
+			lines.push(DiffLine::Same(old_middle[i]));
+			i += 1;
+			j += 1;
+		} else if j < new_middle.len() && (i == old_middle.len() || common[i][j + 1] > common[i + 1][j])
+		{
+			lines.push(DiffLine::Added(new_middle[j]));
+			j += 1;
+		} else {
+			lines.push(DiffLine::Removed(old_middle[i]));
+			i += 1;
+		}
+	}
+	lines.extend(
+		old[old.len() - suffix..]
+			.iter()
+			.map(|line| DiffLine::Same(line)),
+	);
+	lines
+}
+
+#[cfg(test)]
+mod tests {
+	use super::unified_diff;
+
+	#[test]
+	fn unified_diff_merges_nearby_changes_and_marks_a_missing_final_newline() {
+		let before = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\nn\no\np";
+		let after = "a\nB\nc\nd\ne\nf\ng\nH\ni\nj\nk\nl\nm\nn\no\nP";
+		assert_eq!(
+			unified_diff("src/main.nym", before, after),
+			"--- src/main.nym\n+++ src/main.nym\n\
+			 @@ -1,11 +1,11 @@\n a\n-b\n+B\n c\n d\n e\n f\n g\n-h\n+H\n i\n j\n k\n\
+			 @@ -13,4 +13,4 @@\n m\n n\n o\n-p\n\\ No newline at end of file\n+P\n\\ No newline at end of file\n"
+		);
+	}
+
+	#[test]
+	fn unified_diff_of_pure_insertions_and_deletions() {
+		assert_eq!(unified_diff("f", "x\n", "x\n"), "");
+		assert_eq!(
+			unified_diff("f", "", "x\n"),
+			"--- f\n+++ f\n@@ -0,0 +1 @@\n+x\n"
+		);
+		assert_eq!(
+			unified_diff("f", "x\ny\n", "y\n"),
+			"--- f\n+++ f\n@@ -1,2 +1 @@\n-x\n y\n"
+		);
+	}
+}
@@ -60,23 +60,33 @@ impl NymphCommand for FormatCommand {
 }
 
 fn select_files(files: &[PathBuf], manifest: &ManifestSelection) -> anyhow::Result<Vec<PathBuf>> {
-	let project = match manifest {
-		ManifestSelection::Explicit(path) => Some(nymph_project::Project::load(path)?),
+	let authoritative_roots = match manifest {
+		ManifestSelection::Explicit(path) => Some(match nymph_project::WorkspaceRoot::load(path)? {
+			Some(workspace) => member_source_roots(&workspace),
+			None => vec![nymph_project::Project::load(path)?.source_root()],
+		}),
 		ManifestSelection::Discover if files.is_empty() => {
-			let cwd = std::env::current_dir()?;
-			Some(nymph_project::discover(&cwd).map_err(|error| {
-				anyhow::anyhow!("no project source root to format: {error}; pass one or more .nym files")
-			})?)
+			let cwd = nymph_project::normalize_path(std::env::current_dir()?)?;
+			Some(match nymph_project::WorkspaceRoot::discover(&cwd)? {
+				Some(workspace) => member_source_roots(&workspace),
+				None => vec![
+					nymph_project::discover(&cwd)
+						.map_err(|error| {
+							anyhow::anyhow!(
+								"no project source root to format: {error}; pass one or more .nym files"
+							)
+						})?
+						.source_root(),
+				],
+			})
 		}
 		ManifestSelection::Discover => None,
 	};
-	let authoritative_root = project.as_ref().map(nymph_project::Project::source_root);
 	let mut selected = BTreeSet::new();
 	if files.is_empty() {
-		collect_sources(
-			authoritative_root.as_ref().expect("project selected"),
-			&mut selected,
-		)?;
+		for root in authoritative_roots.as_ref().expect("project selected") {
+			collect_sources(root, &mut selected)?;
+		}
 	} else {
 		for file in files {
 			let file = nymph_project::normalize_path(file)?;
@@ -86,8 +96,8 @@ fn select_files(files: &[PathBuf], manifest: &ManifestSelection) -> anyhow::Resu
 					file.display()
 				);
 			}
-			if let Some(root) = &authoritative_root {
-				ensure_within(&file, root)?;
+			if let Some(roots) = &authoritative_roots {
+				ensure_within_any(&file, roots)?;
 			} else {
 				match nymph_project::discover(file.parent().unwrap_or(Path::new("."))) {
 					Ok(discovered) => ensure_within(&file, &discovered.source_root())?,
@@ -107,6 +117,31 @@ fn select_files(files: &[PathBuf], manifest: &ManifestSelection) -> anyhow::Resu
 	Ok(selected.into_iter().collect())
 }
 
+fn member_source_roots(workspace: &nymph_project::WorkspaceRoot) -> Vec<PathBuf> {
+	workspace
+		.members()
+		.iter()
+		.map(nymph_project::Project::source_root)
+		.collect()
+}
+
+/// Like [`ensure_within`], for the source roots of every workspace member.
+fn ensure_within_any(file: &Path, roots: &[PathBuf]) -> anyhow::Result<()> {
+	if let [root] = roots {
+		return ensure_within(file, root);
+	}
+	let canonical = fs::canonicalize(file)?;
+	for root in roots {
+		if canonical.starts_with(fs::canonicalize(root)?) {
+			return Ok(());
+		}
+	}
+	anyhow::bail!(
+		"source file {} is outside the source root of every workspace member",
+		file.display()
+	)
+}
+
 fn ensure_within(file: &Path, root: &Path) -> anyhow::Result<()> {
 	let file = fs::canonicalize(file)?;
 	let root = fs::canonicalize(root)?;
@@ -120,7 +155,7 @@ fn ensure_within(file: &Path, root: &Path) -> anyhow::Result<()> {
 	Ok(())
 }
 
-fn collect_sources(dir: &Path, selected: &mut BTreeSet<PathBuf>) -> anyhow::Result<()> {
+pub(crate) fn collect_sources(dir: &Path, selected: &mut BTreeSet<PathBuf>) -> anyhow::Result<()> {
 	for entry in fs::read_dir(dir)? {
 		let entry = entry?;
 		let ty = entry.file_type()?;
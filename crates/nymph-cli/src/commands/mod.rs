@@ -1,7 +1,10 @@
+pub(crate) mod bindgen;
 pub(crate) mod build;
 pub(crate) mod check;
 pub(crate) mod doc;
+pub(crate) mod fix;
 pub(crate) mod format;
 pub(crate) mod new;
 pub(crate) mod repl;
 pub(crate) mod run;
+pub(crate) mod test;
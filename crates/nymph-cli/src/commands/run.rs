@@ -1,19 +1,24 @@
 use std::path::PathBuf;
 use std::process::Command;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 
 use crate::NymphCommand;
 use crate::compile_guard::{CompileOutcome, compile_guarded, unsupported_feature_message};
 use crate::project_support::{ManifestSelection, ProjectOperation};
+use crate::watch::Watcher;
 
 /// `nymph run [file]` — compile a Nymph source file and execute it under
 /// `node`, forwarding stdout/stderr live and propagating node's exit status.
 /// With no file, the nearest project's manifest `build.entry` is used.
+/// Everything after `--` is passed to the program as its arguments.
 ///
 /// The program's entry point is its top-level `main`: a parameterless,
 /// non-generic function with a validated synchronous or task root result. `run`
 /// appends a separate Node launcher after the emitted module (the module itself
-/// stays a self-contained ES module with no self-executing code). The run file
+/// stays a self-contained ES module with no self-executing code). Node runs
+/// with `--enable-source-maps` and an inline map, so stack traces point into
+/// the `.nym` sources. The run file
 /// is always compiled in *entry mode*: a missing
 /// or mis-shaped `main` — missing entirely,
 /// generic, taking parameters, or returning an unsupported root type — is
@@ -46,6 +51,16 @@ pub(crate) struct RunCommand {
 	/// Run with the release compiler profile.
 	#[arg(long)]
 	release: bool,
+
+	/// Run again whenever a source file or manifest changes, stopping the
+	/// previous run first if it is still going.
+	#[arg(long, conflicts_with = "expr")]
+	watch: bool,
+
+	/// Arguments for the program, after `--`; `std/env`'s `args()` returns
+	/// them.
+	#[arg(last = true, value_name = "ARGS")]
+	args: Vec<String>,
 }
 
 impl NymphCommand for RunCommand {
@@ -59,25 +74,68 @@ impl NymphCommand for RunCommand {
 			return run_inline_expr(expr, profile);
 		}
 
+		if self.watch {
+			let resolve = || {
+				Ok(vec![
+					ProjectOperation::resolve(self.file.as_deref(), manifest, profile)
+						.map(|operation| operation.with_source_maps(true)),
+				])
+			};
+			return crate::watch::watch(resolve, |operations, watcher| {
+				let compiled = operations[0]
+					.as_ref()
+					.and_then(ProjectOperation::compile_entry);
+				compiled.is_some_and(|compiled| {
+					execute_until_changed(&node_launcher(&compiled), &self.args, watcher)
+				})
+			});
+		}
+
 		let operation = match ProjectOperation::resolve(self.file.as_deref(), manifest, profile) {
-			Some(operation) => operation,
+			Some(operation) => operation.with_source_maps(true),
 			None => return 1,
 		};
 		match operation.compile_entry() {
-			Some(compiled) => execute(&node_launcher(&compiled)),
+			Some(compiled) => execute(&node_launcher(&compiled), &self.args),
 			None => 1,
 		}
 	}
 }
 
 fn node_launcher(compiled: &nymph_compiler::CompiledProject) -> String {
+	let mut launcher = root_launcher(
+		&compiled.js,
+		&compiled.entry_main,
+		compiled
+			.entry_root
+			.as_ref()
+			.expect("entry compilation provides a validated root adapter"),
+		"main",
+	);
+	// The launcher follows the bundle, so the bundle's map still lines up.
+	if let Some(map) = &compiled.source_map {
+		let map = oxc_sourcemap::SourceMap::from_json_string(map)
+			.expect("the compiler emits valid source maps");
+		launcher.push_str(&format!("//# sourceMappingURL={}\n", map.to_data_url()));
+	}
+	launcher
+}
+
+/// Append a launcher to the bundled `js` that runs the parameterless
+/// `function` as its own root execution and maps its outcome to the process
+/// exit status: `0` for a successful root value, `1` for `None`/`Error`, `101`
+/// for a defect, and `130`/`143` for cancellation. `subject` names the
+/// function in failure messages. `nymph test` runs each test through this too,
+/// and `nymph build --executable` embeds it in the program it writes.
+pub(crate) fn root_launcher(
+	js: &str,
+	function: &str,
+	root: &nymph_compiler::CompiledEntryRoot,
+	subject: &str,
+) -> String {
 	use nymph_compiler::CompiledEntryRoot;
 
-	let (kind, binding, task) = match compiled
-		.entry_root
-		.as_ref()
-		.expect("entry compilation provides a validated root adapter")
-	{
+	let (kind, binding, task) = match root {
 		CompiledEntryRoot::Void => ("void", None, false),
 		CompiledEntryRoot::Option { binding } => ("option", Some(binding.as_str()), false),
 		CompiledEntryRoot::Result { binding } => ("result", Some(binding.as_str()), false),
@@ -87,7 +145,7 @@ fn node_launcher(compiled: &nymph_compiler::CompiledProject) -> String {
 	};
 	let binding = binding.unwrap_or("undefined");
 	format!(
-		r#"{}
+		r#"{js}
 const __nymphRootKind = "{kind}";
 const __nymphRootEnum = {binding};
 let __nymphRootExecution;
@@ -103,7 +161,7 @@ const __nymphSigint = () => __nymphSignal(130);
 const __nymphSigterm = () => __nymphSignal(143);
 process.on("SIGINT", __nymphSigint);
 process.on("SIGTERM", __nymphSigterm);
-__nymphRootExecution = nymphStartRoot(() => {}(), {task});
+__nymphRootExecution = nymphStartRoot(() => {function}(), {task});
 if (__nymphSignalStatus !== undefined) __nymphRootExecution.cancel();
 const __nymphOutcome = await __nymphRootExecution.outcome;
 process.off("SIGINT", __nymphSigint);
@@ -127,10 +185,10 @@ if (__nymphOutcome.tag === "cancelled") {{
 		const tag = value?.[Symbol.for("nymph.tag")];
 		if (__nymphRootKind === "option") {{
 			if (tag === __nymphRootEnum.None[Symbol.for("nymph.tag")]) {{
-				process.stderr.write("error: main returned None\n");
+				process.stderr.write("error: {subject} returned None\n");
 				process.exitCode = 1;
 			}} else if (tag !== __nymphRootEnum.Some[Symbol.for("nymph.tag")]) {{
-				throw new TypeError("main produced an invalid Option root value");
+				throw new TypeError("{subject} produced an invalid Option root value");
 			}}
 		}} else if (__nymphRootKind === "result") {{
 			if (tag === __nymphRootEnum.Error[Symbol.for("nymph.tag")]) {{
@@ -138,15 +196,14 @@ if (__nymphOutcome.tag === "cancelled") {{
 				process.stderr.write(`error: ${{rendered.v}}\n`);
 				process.exitCode = 1;
 			}} else if (tag !== __nymphRootEnum.Ok[Symbol.for("nymph.tag")]) {{
-				throw new TypeError("main produced an invalid Result root value");
+				throw new TypeError("{subject} produced an invalid Result root value");
 			}}
 		}}
 	}} catch (defect) {{
 		__nymphWriteDefect(defect);
 	}}
 }}
-"#,
-		compiled.js, compiled.entry_main
+"#
 	)
 }
 
@@ -176,12 +233,15 @@ fn run_inline_expr(expr: &str, profile: nymph_compiler::BuildProfile) -> i32 {
 		}
 	};
 
-	execute(&format!("{js}\nconsole.log(__nymph_repl_display().v);\n"))
+	execute(
+		&format!("{js}\nconsole.log(__nymph_repl_display().v);\n"),
+		&[],
+	)
 }
 
-/// Write `js` to a unique temp `.mjs` and run it under `node`, forwarding
-/// stdio live and returning node's exit code.
-fn execute(js: &str) -> i32 {
+/// Write `js` to a unique temp `.mjs` and run it under `node` with `args`,
+/// forwarding stdio live and returning node's exit code.
+fn execute(js: &str, args: &[String]) -> i32 {
 	// `.mjs` makes Node treat the temp file as an ES module, matching the
 	// pattern in `crates/nymph-codegen/tests/run_node.rs`. The pid + a
 	// monotonic counter keep the path unique across concurrent invocations.
@@ -201,7 +261,7 @@ fn execute(js: &str) -> i32 {
 	// Spawn with inherited stdio so output streams live. On Unix, forward the
 	// first termination signal for Node to clean up, then force-stop it if a
 	// second signal arrives.
-	let status = run_node(&temp_path);
+	let status = run_node(&temp_path, args);
 	let _ = std::fs::remove_file(&temp_path);
 
 	match status {
@@ -213,8 +273,81 @@ fn execute(js: &str) -> i32 {
 	}
 }
 
+/// Run `js` like [`execute`] until it exits or a watched file changes,
+/// whichever comes first, returning whether it exited successfully. A run a
+/// change interrupts is asked to stop, then killed if it does not stop in
+/// time.
+fn execute_until_changed(js: &str, args: &[String], watcher: &Watcher) -> bool {
+	static COUNTER: AtomicU64 = AtomicU64::new(0);
+	let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+	let temp_path = std::env::temp_dir().join(format!(
+		"nymph_cli_watch_{}_{unique}.mjs",
+		std::process::id()
+	));
+	if let Err(err) = std::fs::write(&temp_path, js) {
+		eprintln!(
+			"error: could not write temp file {}: {err}",
+			temp_path.display()
+		);
+		return false;
+	}
+	let status = Command::new("node")
+		.arg("--enable-source-maps")
+		.arg(&temp_path)
+		.args(args)
+		.spawn()
+		.and_then(|mut child| {
+			loop {
+				if let Some(status) = child.try_wait()? {
+					return Ok(status);
+				}
+				if watcher.changed_within(POLL) {
+					return stop(&mut child);
+				}
+			}
+		});
+	let _ = std::fs::remove_file(&temp_path);
+	match status {
+		Ok(status) => status.success(),
+		Err(err) => {
+			eprintln!("error: could not run node: {err}");
+			false
+		}
+	}
+}
+
+/// How often a watched run checks whether Node exited.
+const POLL: Duration = Duration::from_millis(100);
+
+/// How long a run interrupted by a change has to stop on its own.
+#[cfg(unix)]
+const STOP_GRACE: Duration = Duration::from_secs(2);
+
+/// Ask `child` to stop, so its root is cancelled cooperatively, and kill it
+/// if it is still running after `STOP_GRACE`. Without Unix signals it is
+/// killed straight away.
+fn stop(child: &mut std::process::Child) -> std::io::Result<std::process::ExitStatus> {
+	#[cfg(unix)]
+	{
+		use rustix::process::{Pid, Signal, kill_process};
+		use std::time::Instant;
+
+		let pid = Pid::from_raw(child.id() as i32).expect("Node child has a positive process ID");
+		let _ = kill_process(pid, Signal::TERM);
+		let deadline = Instant::now() + STOP_GRACE;
+		while Instant::now() < deadline {
+			if let Some(status) = child.try_wait()? {
+				return Ok(status);
+			}
+			std::thread::sleep(POLL);
+		}
+	}
+	child.kill()?;
+	child.wait()
+}
+
 #[cfg(unix)]
-fn run_node(path: &std::path::Path) -> std::io::Result<i32> {
+fn run_node(path: &std::path::Path, args: &[String]) -> std::io::Result<i32> {
 	use rustix::process::{Pid, Signal, kill_process};
 	use signal_hook::consts::signal::{SIGINT, SIGTERM};
 	use signal_hook::iterator::Signals;
@@ -227,7 +360,12 @@ fn run_node(path: &std::path::Path) -> std::io::Result<i32> {
 	// Isolate Node from the terminal's foreground process group. Otherwise a
 	// terminal signal would reach Node directly and then arrive a second time
 	// through this forwarder, skipping cooperative cleanup.
-	let mut child = Command::new("node").arg(path).process_group(0).spawn()?;
+	let mut child = Command::new("node")
+		.arg("--enable-source-maps")
+		.arg(path)
+		.args(args)
+		.process_group(0)
+		.spawn()?;
 	let pid = Pid::from_raw(child.id() as i32).expect("Node child has a positive process ID");
 	let forwarded_status = Arc::new(AtomicI32::new(0));
 	let thread_status = Arc::clone(&forwarded_status);
@@ -262,9 +400,11 @@ fn run_node(path: &std::path::Path) -> std::io::Result<i32> {
 }
 
 #[cfg(not(unix))]
-fn run_node(path: &std::path::Path) -> std::io::Result<i32> {
+fn run_node(path: &std::path::Path, args: &[String]) -> std::io::Result<i32> {
 	Command::new("node")
+		.arg("--enable-source-maps")
 		.arg(path)
+		.args(args)
 		.status()
 		.map(|status| status.code().unwrap_or(1))
 }
@@ -294,7 +434,11 @@ function nymphProtocolDisplayStep() {{}}
 			),
 			entry_main: "main".to_string(),
 			entry_root: Some(nymph_compiler::CompiledEntryRoot::TaskVoid),
+			tests: Vec::new(),
 			entry_tag: 0,
+			source_map: None,
+			modules: Vec::new(),
+			declarations: None,
 		};
 		let script = node_launcher(&compiled);
 		let path = std::env::temp_dir().join(format!(
@@ -355,7 +499,11 @@ function nymphProtocolDisplayStep() {}
 			.to_string(),
 			entry_main: "main".to_string(),
 			entry_root: Some(nymph_compiler::CompiledEntryRoot::TaskVoid),
+			tests: Vec::new(),
 			entry_tag: 0,
+			source_map: None,
+			modules: Vec::new(),
+			declarations: None,
 		};
 		let output = Command::new("node")
 			.arg("--input-type=module")
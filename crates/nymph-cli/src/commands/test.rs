@@ -0,0 +1,200 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::NymphCommand;
+use crate::commands::format::collect_sources;
+use crate::commands::run::root_launcher;
+use crate::message_format::MessageFormat;
+use crate::project_support::{ManifestSelection, ProjectOperation, resolve_operations};
+
+/// `nymph test [filter]` — run every `test` declared in the project's source
+/// root (every member's, from a workspace root).
+///
+/// Only modules that declare a test are compiled, each as its own library
+/// bundle. Every test then runs in a fresh `node` process as its own root
+/// execution, under the same outcome model as `nymph run`: a defect, a
+/// cancellation, or a `None`/`Error` result fails the test. Output the test
+/// itself writes is shown only when it fails. The command exits nonzero if
+/// any test fails or any module fails to compile.
+#[derive(clap::Args)]
+pub(crate) struct TestCommand {
+	/// Only run tests whose `module::name` contains this string.
+	filter: Option<String>,
+
+	/// Test with the release compiler profile.
+	#[arg(long)]
+	release: bool,
+
+	/// Run the tests again whenever a source file or manifest changes.
+	#[arg(long)]
+	watch: bool,
+}
+
+/// One executed test's display name and captured failure report.
+struct Failure {
+	test: String,
+	output: String,
+}
+
+impl NymphCommand for TestCommand {
+	fn run(&self, manifest: &ManifestSelection) -> i32 {
+		let profile = if self.release {
+			nymph_compiler::BuildProfile::Release
+		} else {
+			nymph_compiler::BuildProfile::Development
+		};
+		let resolve = || resolve_operations(None, manifest, profile, MessageFormat::Human);
+		if self.watch {
+			return crate::watch::watch(resolve, |operations, _| self.run_all(operations) == 0);
+		}
+		match resolve() {
+			Ok(operations) => self.run_all(&operations),
+			Err(error) => {
+				eprintln!("error: {error}");
+				1
+			}
+		}
+	}
+}
+
+impl TestCommand {
+	fn run_all(&self, operations: &[Option<ProjectOperation>]) -> i32 {
+		let mut failed = false;
+		for operation in operations {
+			failed |= !operation
+				.as_ref()
+				.is_some_and(|operation| self.run_project(operation));
+		}
+		i32::from(failed)
+	}
+
+	/// Run one project's tests, returning whether every selected test passed.
+	fn run_project(&self, operation: &ProjectOperation) -> bool {
+		let modules = match test_modules(operation.source_root()) {
+			Ok(modules) => modules,
+			Err(error) => {
+				eprintln!("error: {error}");
+				return false;
+			}
+		};
+		let keys = modules.iter().map(String::as_str).collect::<Vec<_>>();
+		let Some(compiled) = operation.compile_tests(&keys) else {
+			return false;
+		};
+		let selected = compiled
+			.iter()
+			.flat_map(|(module, project)| {
+				project
+					.tests
+					.iter()
+					.map(move |test| (format!("{module}::{}", test.name), project, test))
+			})
+			.collect::<Vec<_>>();
+		let total = selected.len();
+		let selected = selected
+			.into_iter()
+			.filter(|(name, _, _)| {
+				self
+					.filter
+					.as_ref()
+					.is_none_or(|filter| name.contains(filter))
+			})
+			.collect::<Vec<_>>();
+		let filtered_out = total - selected.len();
+
+		println!(
+			"running {} test{}",
+			selected.len(),
+			if selected.len() == 1 { "" } else { "s" }
+		);
+		let mut passed = 0;
+		let mut failures = Vec::new();
+		for (name, project, test) in selected {
+			match run_test(project, test) {
+				Ok(None) => {
+					println!("test {name} ... ok");
+					passed += 1;
+				}
+				Ok(Some(output)) => {
+					println!("test {name} ... FAILED");
+					failures.push(Failure { test: name, output });
+				}
+				Err(error) => {
+					println!("test {name} ... FAILED");
+					failures.push(Failure {
+						test: name,
+						output: format!("error: could not run node: {error}\n"),
+					});
+				}
+			}
+		}
+
+		if !failures.is_empty() {
+			println!("\nfailures:");
+			for failure in &failures {
+				println!("\n---- {} ----", failure.test);
+				print!("{}", failure.output);
+			}
+			println!("\nfailures:");
+			for failure in &failures {
+				println!("    {}", failure.test);
+			}
+		}
+		println!(
+			"\ntest result: {}. {passed} passed; {} failed; {filtered_out} filtered out",
+			if failures.is_empty() { "ok" } else { "FAILED" },
+			failures.len()
+		);
+		failures.is_empty()
+	}
+}
+
+/// The module key of every source under `src_root` that declares a test, in
+/// path order. Like `nymph format`, `target` and `dependencies` directories
+/// are skipped.
+fn test_modules(src_root: &Path) -> anyhow::Result<Vec<String>> {
+	let mut files = BTreeSet::new();
+	collect_sources(src_root, &mut files)?;
+	let mut modules = Vec::new();
+	for file in files {
+		let source = fs::read_to_string(&file)?;
+		if nymph_compiler::declares_tests(&source, &file.display().to_string()) {
+			let module = nymph_project::module_from_file(src_root, &file)?;
+			modules.push(module.as_str().to_string());
+		}
+	}
+	Ok(modules)
+}
+
+/// Run one test in its own `node` process. `Ok(None)` is a pass; a failure
+/// carries everything the process wrote, stdout first.
+fn run_test(
+	project: &nymph_compiler::CompiledProject,
+	test: &nymph_compiler::CompiledTest,
+) -> std::io::Result<Option<String>> {
+	static COUNTER: AtomicU64 = AtomicU64::new(0);
+	let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+	let path = std::env::temp_dir().join(format!(
+		"nymph_cli_test_{}_{unique}.mjs",
+		std::process::id()
+	));
+	fs::write(
+		&path,
+		root_launcher(&project.js, &test.binding, &test.root, "test"),
+	)?;
+	let output = Command::new("node").arg(&path).output();
+	let _ = fs::remove_file(&path);
+	let output = output?;
+	if output.status.success() {
+		return Ok(None);
+	}
+	let mut report = String::from_utf8_lossy(&output.stdout).into_owned();
+	report.push_str(&String::from_utf8_lossy(&output.stderr));
+	if report.is_empty() {
+		report = format!("error: test exited with {}\n", output.status);
+	}
+	Ok(Some(report))
+}
@@ -72,6 +72,9 @@ pub(crate) fn compile_guarded(
 			&nymph_compiler::CompilerOptions {
 				profile,
 				lints: Default::default(),
+				source_maps: false,
+				layout: nymph_compiler::OutputLayout::Bundle,
+				declarations: false,
 			},
 		)
 	}));
@@ -5,13 +5,17 @@ use std::path::PathBuf;
 use clap::Parser;
 
 use crate::commands::{
-	build::BuildCommand, check::CheckCommand, doc::DocCommand, format::FormatCommand,
-	new::NewCommand, repl::ReplCommand, run::RunCommand,
+	bindgen::BindgenCommand, build::BuildCommand, check::CheckCommand, doc::DocCommand,
+	fix::FixCommand, format::FormatCommand, new::NewCommand, repl::ReplCommand, run::RunCommand,
+	test::TestCommand,
 };
 
 mod commands;
 mod compile_guard;
+mod message_format;
 mod project_support;
+mod sarif;
+mod watch;
 
 pub(crate) trait NymphCommand {
 	/// Run the command and return the process exit code.
@@ -33,25 +37,31 @@ pub(crate) struct NymphCli {
 
 #[derive(clap::Subcommand)]
 enum NymphCommands {
+	Bindgen(BindgenCommand),
 	Build(BuildCommand),
 	Check(CheckCommand),
 	Doc(DocCommand),
+	Fix(FixCommand),
 	Format(FormatCommand),
 	New(NewCommand),
 	Repl(ReplCommand),
 	Run(RunCommand),
+	Test(TestCommand),
 }
 
 impl NymphCommands {
 	fn run(&self, manifest: &project_support::ManifestSelection) -> i32 {
 		match self {
+			NymphCommands::Bindgen(cmd) => cmd.run(manifest),
 			NymphCommands::Build(cmd) => cmd.run(manifest),
 			NymphCommands::Check(cmd) => cmd.run(manifest),
 			NymphCommands::Doc(cmd) => cmd.run(manifest),
+			NymphCommands::Fix(cmd) => cmd.run(manifest),
 			NymphCommands::Format(cmd) => cmd.run(manifest),
 			NymphCommands::New(cmd) => cmd.run(manifest),
 			NymphCommands::Repl(cmd) => cmd.run(manifest),
 			NymphCommands::Run(cmd) => cmd.run(manifest),
+			NymphCommands::Test(cmd) => cmd.run(manifest),
 		}
 	}
 }
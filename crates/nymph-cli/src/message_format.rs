@@ -0,0 +1,279 @@
+//! Machine-readable diagnostic output for `check` and `build`.
+//!
+//! `--message-format json` prints one JSON object per line to stdout: a
+//! `"diagnostic"` record for every [`nymph_diagnostics::Diagnostic`] the
+//! command reports, then exactly one `"summary"` record once the command is
+//! done. Human rendering stays on stderr; the JSON stream is never mixed with
+//! it, so a consumer reads stdout line by line without scraping text.
+//!
+//! `--message-format sarif` holds the same records back and prints one SARIF
+//! log built from all of them when the command finishes (see [`crate::sarif`]).
+
+use nymph_diagnostics::{Applicability, Diagnostic, EditGroup, Severity};
+use serde_json::{Value, json};
+
+/// How a command reports its diagnostics.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum MessageFormat {
+	/// Rendered reports on stderr.
+	#[default]
+	Human,
+	/// One JSON object per line on stdout, ending with a summary record.
+	Json,
+	/// A single SARIF 2.1.0 log on stdout.
+	Sarif,
+}
+
+/// Everything a command reported in a deferred or summarized format, gathered
+/// from each of its project operations.
+#[derive(Debug, Default)]
+pub(crate) struct Report {
+	tally: DiagnosticTally,
+	/// `"diagnostic"` records held back until the command finishes.
+	deferred: Vec<Value>,
+}
+
+impl Report {
+	pub fn count(&mut self, diagnostic: &Diagnostic) {
+		self.tally.record(diagnostic);
+	}
+
+	/// Hold `record` back until [`Self::finish`].
+	pub fn defer(&mut self, record: Value) {
+		self.deferred.push(record);
+	}
+
+	pub fn merge(&mut self, other: Self) {
+		self.tally = self.tally.merge(other.tally);
+		self.deferred.extend(other.deferred);
+	}
+
+	/// Print the output `format` closes a command with: the JSON summary
+	/// record, or the SARIF log of every deferred record. Human output has
+	/// nothing to close.
+	pub fn finish(self, format: MessageFormat, success: bool) {
+		match format {
+			MessageFormat::Human => {}
+			MessageFormat::Json => println!("{}", summary_record(self.tally, success)),
+			MessageFormat::Sarif => println!("{:#}", crate::sarif::log(&self.deferred)),
+		}
+	}
+}
+
+/// Diagnostic counts accumulated over a whole command.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct DiagnosticTally {
+	errors: usize,
+	warnings: usize,
+}
+
+impl DiagnosticTally {
+	fn record(&mut self, diagnostic: &Diagnostic) {
+		match diagnostic.severity {
+			Severity::Error => self.errors += 1,
+			Severity::Warning => self.warnings += 1,
+			Severity::Info | Severity::Hint => {}
+		}
+	}
+
+	#[must_use]
+	fn merge(self, other: Self) -> Self {
+		Self {
+			errors: self.errors + other.errors,
+			warnings: self.warnings + other.warnings,
+		}
+	}
+}
+
+/// One source a diagnostic or edit refers to: the path shown to users and the
+/// text its byte spans index.
+pub(crate) struct SourceFile<'a> {
+	pub path: &'a str,
+	pub text: &'a str,
+}
+
+/// The `"diagnostic"` record for `diagnostic`, reported in `module` and
+/// located in `file`. `edit_file` names the file of each edit source.
+pub(crate) fn diagnostic_record(
+	module: &str,
+	file: &SourceFile<'_>,
+	diagnostic: &Diagnostic,
+	edit_file: impl Fn(&str) -> Option<String>,
+) -> Value {
+	json!({
+		"type": "diagnostic",
+		"code": diagnostic.code.as_str(),
+		"severity": severity_name(diagnostic.severity),
+		"message": diagnostic.message.as_str(),
+		"file": file.path,
+		"module": module,
+		"span": span_record(file.text, diagnostic.span),
+		"labels": diagnostic
+			.labels
+			.iter()
+			.map(|label| json!({
+				"message": label.message.as_str(),
+				"span": span_record(file.text, label.span),
+			}))
+			.collect::<Vec<_>>(),
+		"notes": diagnostic.notes.iter().map(|note| note.as_str()).collect::<Vec<_>>(),
+		"help": diagnostic.help.as_deref(),
+		"edits": diagnostic
+			.edits()
+			.iter()
+			.map(|edit| edit_record(edit, &edit_file))
+			.collect::<Vec<_>>(),
+	})
+}
+
+/// The closing `"summary"` record. `success` mirrors the exit status, which
+/// also covers failures that are not diagnostics (a missing manifest, an
+/// unwritable output).
+fn summary_record(tally: DiagnosticTally, success: bool) -> Value {
+	json!({
+		"type": "summary",
+		"success": success,
+		"errors": tally.errors,
+		"warnings": tally.warnings,
+	})
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+	match severity {
+		Severity::Error => "error",
+		Severity::Warning => "warning",
+		Severity::Info => "info",
+		Severity::Hint => "hint",
+	}
+}
+
+/// Byte offsets plus 1-based line and column positions. Columns count Unicode
+/// scalar values, so a multi-byte character advances the column by one.
+fn span_record(text: &str, span: nymph_ast::Span) -> Value {
+	let (line, column) = position(text, span.start);
+	let (end_line, end_column) = position(text, span.end);
+	json!({
+		"start": span.start,
+		"end": span.end,
+		"line": line,
+		"column": column,
+		"end_line": end_line,
+		"end_column": end_column,
+	})
+}
+
+fn position(text: &str, offset: usize) -> (usize, usize) {
+	let mut offset = offset.min(text.len());
+	while !text.is_char_boundary(offset) {
+		offset -= 1;
+	}
+	let before = &text[..offset];
+	let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+	(
+		before.matches('\n').count() + 1,
+		before[line_start..].chars().count() + 1,
+	)
+}
+
+fn edit_record(edit: &EditGroup, edit_file: &impl Fn(&str) -> Option<String>) -> Value {
+	let applicability = match edit.applicability() {
+		Applicability::MachineApplicable => "machine-applicable",
+	};
+	json!({
+		"title": edit.title(),
+		"applicability": applicability,
+		"sources": edit
+			.sources()
+			.iter()
+			.map(|source| json!({
+				"project": source.source().project(),
+				"module": source.source().module(),
+				"file": edit_file(source.source().module()),
+				"version": source.version().0,
+				"replacements": source
+					.replacements()
+					.iter()
+					.map(|replacement| json!({
+						"start": replacement.span().start,
+						"end": replacement.span().end,
+						"replacement": replacement.replacement(),
+					}))
+					.collect::<Vec<_>>(),
+			}))
+			.collect::<Vec<_>>(),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use nymph_ast::Span;
+	use nymph_diagnostics::{
+		Diagnostic, EditGroup, Label, SourceEdit, SourceId, SourceVersion, TextReplacement,
+	};
+	use serde_json::json;
+
+	use super::{SourceFile, diagnostic_record};
+
+	#[test]
+	fn records_carry_positions_labels_and_edits() {
+		let text = "let a = 1\nlet bé = true\n";
+		let source = SourceId::new("app", "main");
+		let edit = EditGroup::new(
+			"Rename `bé`",
+			vec![
+				SourceEdit::new(
+					source,
+					SourceVersion(0),
+					vec![TextReplacement::new(Span::new(14, 17), "b")],
+				)
+				.unwrap(),
+			],
+		)
+		.unwrap();
+		let diagnostic = Diagnostic::warning("W0001".into(), "unused binding", Span::new(14, 17))
+			.with_label(Label::new(Span::new(4, 5), "shadowed here"))
+			.with_note("bindings starting with `_` are exempt")
+			.with_help("remove it")
+			.with_edit(edit);
+
+		let record = diagnostic_record(
+			"main",
+			&SourceFile {
+				path: "src/main.nym",
+				text,
+			},
+			&diagnostic,
+			|module| Some(format!("src/{module}.nym")),
+		);
+
+		assert_eq!(
+			record,
+			json!({
+				"type": "diagnostic",
+				"code": "W0001",
+				"severity": "warning",
+				"message": "unused binding",
+				"file": "src/main.nym",
+				"module": "main",
+				"span": {"start": 14, "end": 17, "line": 2, "column": 5, "end_line": 2, "end_column": 7},
+				"labels": [{
+					"message": "shadowed here",
+					"span": {"start": 4, "end": 5, "line": 1, "column": 5, "end_line": 1, "end_column": 6},
+				}],
+				"notes": ["bindings starting with `_` are exempt"],
+				"help": "remove it",
+				"edits": [{
+					"title": "Rename `bé`",
+					"applicability": "machine-applicable",
+					"sources": [{
+						"project": "app",
+						"module": "main",
+						"file": "src/main.nym",
+						"version": 0,
+						"replacements": [{"start": 14, "end": 17, "replacement": "b"}],
+					}],
+				}],
+			})
+		);
+	}
+}
@@ -1,5 +1,5 @@
 //! Filesystem-backed target resolution and multi-module project support for
-//! the CLI (`build`, `run`, `check`).
+//! the CLI (`build`, `run`, `check`, `test`).
 //!
 //! A target falls back to loose mode only when discovery reports that no
 //! `nymph.toml` exists. A found but unusable manifest is authoritative. The
@@ -7,9 +7,16 @@
 //! owns only the commands' shared selection policy.
 
 use std::path::{Path, PathBuf};
-use std::{cell::RefCell, collections::BTreeMap, fs::OpenOptions, io::Write as _, rc::Rc};
+use std::{
+	cell::RefCell,
+	collections::{BTreeMap, BTreeSet},
+	fs::OpenOptions,
+	io::Write as _,
+	rc::Rc,
+};
 
 use crate::compile_guard::{guarded, unsupported_feature_message};
+use crate::message_format::{MessageFormat, Report, SourceFile, diagnostic_record};
 
 /// Select whether target resolution discovers the nearest conventional
 /// manifest or loads one explicit path authoritatively.
@@ -39,6 +46,61 @@ pub(crate) fn load_project(manifest: &ManifestSelection) -> anyhow::Result<nymph
 	}
 }
 
+/// Load the workspace declared by the selected manifest, for commands that
+/// cover every member when run from a workspace root. `None` means the
+/// selected manifest declares no `[workspace]` (or none was found), and the
+/// command applies to a single project as usual.
+pub(crate) fn load_workspace(
+	manifest: &ManifestSelection,
+) -> anyhow::Result<Option<nymph_project::WorkspaceRoot>> {
+	match manifest {
+		ManifestSelection::Discover => {
+			let current_dir = nymph_project::normalize_path(std::env::current_dir()?)?;
+			Ok(nymph_project::WorkspaceRoot::discover(&current_dir)?)
+		}
+		ManifestSelection::Explicit(path) => Ok(nymph_project::WorkspaceRoot::load(path)?),
+	}
+}
+
+/// The explicit selection of each workspace member, in member order.
+pub(crate) fn member_selections(
+	workspace: &nymph_project::WorkspaceRoot,
+) -> Vec<ManifestSelection> {
+	workspace
+		.members()
+		.iter()
+		.map(|member| ManifestSelection::Explicit(member.manifest_path().to_path_buf()))
+		.collect()
+}
+
+/// Resolve every operation a source-target command covers: the selected
+/// project alone for an explicit `file`, and each member from a workspace
+/// root. A selection that fails to resolve is `None` and already reported;
+/// `Err` means the workspace itself could not be loaded.
+pub(crate) fn resolve_operations(
+	file: Option<&Path>,
+	manifest: &ManifestSelection,
+	profile: nymph_compiler::BuildProfile,
+	format: MessageFormat,
+) -> anyhow::Result<Vec<Option<ProjectOperation>>> {
+	let selections = match file {
+		Some(_) => vec![manifest.clone()],
+		None => match load_workspace(manifest)? {
+			Some(workspace) => member_selections(&workspace),
+			None => vec![manifest.clone()],
+		},
+	};
+	Ok(
+		selections
+			.iter()
+			.map(|selection| {
+				ProjectOperation::resolve(file, selection, profile)
+					.map(|operation| operation.with_message_format(format))
+			})
+			.collect(),
+	)
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum TargetIntent {
 	Entry,
@@ -75,16 +137,39 @@ pub(crate) struct ResolvedTarget {
 	pub entry_key: String,
 	pub intent: TargetIntent,
 	pub options: nymph_compiler::CompilerOptions,
+	/// Import aliases of the root package's direct dependencies, by index into
+	/// `dependencies` plus one (index `0` is the root package itself).
+	pub root_dependencies: BTreeMap<String, usize>,
+	/// Resolved dependency packages; empty for loose files.
+	pub dependencies: Vec<nymph_project::GraphPackage>,
+	/// The project and workspace manifests the target was resolved from;
+	/// empty for loose files.
+	pub manifests: Vec<PathBuf>,
 }
 
 type SourceLoader = dyn Fn(&str) -> Option<String>;
 
-/// Shared target, source loader, compiler dispatch, and diagnostic context for
-/// a single `build`, `check`, or `run` operation.
+/// One package's filesystem source root and its recorded loader.
+struct PackageLoad {
+	src_root: PathBuf,
+	load: Box<SourceLoader>,
+	dependencies: BTreeMap<String, usize>,
+}
+
+/// Shared target, source loaders, compiler dispatch, and diagnostic context for
+/// a single `build`, `check`, or `run` operation. Index `0` of `packages` is
+/// the root package; dependency packages follow in package-graph order.
 pub(crate) struct ProjectOperation {
 	target: ResolvedTarget,
-	load: Box<SourceLoader>,
-	analyzed_sources: Rc<RefCell<BTreeMap<String, String>>>,
+	packages: Vec<PackageLoad>,
+	analyzed_sources: Rc<RefCell<BTreeMap<(usize, String), String>>>,
+	/// In-memory module texts read instead of the files, by package index and
+	/// module key.
+	source_overrides: Rc<RefCell<BTreeMap<(usize, String), String>>>,
+	message_format: MessageFormat,
+	report: RefCell<Report>,
+	/// The compiler session kept across `--watch` cycles, if any.
+	session: RefCell<Option<nymph_compiler::ProjectSession>>,
 }
 
 impl ProjectOperation {
@@ -99,47 +184,216 @@ impl ProjectOperation {
 				eprintln!("error: {error}");
 			})
 			.ok()?;
-		let fs_load = nymph_project::fs_loader(target.src_root.clone());
-		let analyzed_sources = Rc::new(RefCell::new(BTreeMap::<String, String>::new()));
-		let observed = analyzed_sources.clone();
-		let load = Box::new(move |module: &str| {
-			if let Some(source) = observed.borrow().get(module) {
-				return Some(source.clone());
-			}
-			let source = fs_load(module)?;
-			observed
-				.borrow_mut()
-				.entry(module.to_string())
-				.or_insert_with(|| source.clone());
-			Some(source)
-		});
+		let analyzed_sources = Rc::new(RefCell::new(BTreeMap::<(usize, String), String>::new()));
+		let source_overrides = Rc::new(RefCell::new(BTreeMap::<(usize, String), String>::new()));
+		let roots = std::iter::once((target.src_root.clone(), target.root_dependencies.clone())).chain(
+			target
+				.dependencies
+				.iter()
+				.map(|package| (package.source_root.clone(), package.dependencies.clone())),
+		);
+		let packages = roots
+			.enumerate()
+			.map(|(index, (src_root, dependencies))| {
+				let fs_load = nymph_project::fs_loader(src_root.clone());
+				let observed = analyzed_sources.clone();
+				let overrides = source_overrides.clone();
+				let load: Box<SourceLoader> = Box::new(move |module: &str| {
+					let key = (index, module.to_string());
+					if let Some(source) = observed.borrow().get(&key) {
+						return Some(source.clone());
+					}
+					let overridden = overrides.borrow().get(&key).cloned();
+					let source = overridden.or_else(|| fs_load(module))?;
+					observed
+						.borrow_mut()
+						.entry(key)
+						.or_insert_with(|| source.clone());
+					Some(source)
+				});
+				PackageLoad {
+					src_root,
+					load,
+					dependencies,
+				}
+			})
+			.collect();
 		Some(Self {
 			target,
-			load,
+			packages,
 			analyzed_sources,
+			source_overrides,
+			message_format: MessageFormat::Human,
+			report: RefCell::default(),
+			session: RefCell::default(),
 		})
 	}
 
+	/// Report this operation's diagnostics in `format`.
+	#[must_use]
+	pub fn with_message_format(mut self, format: MessageFormat) -> Self {
+		self.message_format = format;
+		self
+	}
+
+	/// Also produce a source map for every compiled bundle when `enabled`.
+	#[must_use]
+	pub fn with_source_maps(mut self, enabled: bool) -> Self {
+		self.target.options.source_maps = enabled;
+		self
+	}
+
+	/// Compile to `layout` instead of one bundle.
+	#[must_use]
+	pub fn with_output_layout(mut self, layout: nymph_compiler::OutputLayout) -> Self {
+		self.target.options.layout = layout;
+		self
+	}
+
+	/// Also declare the entry's public API for TypeScript when `enabled`.
+	#[must_use]
+	pub fn with_declarations(mut self, enabled: bool) -> Self {
+		self.target.options.declarations = enabled;
+		self
+	}
+
+	/// Keep one compiler session across checks and compilations, updated
+	/// through [`Self::reload`] instead of reading every source again.
+	#[must_use]
+	pub fn with_live_session(self) -> Self {
+		let source_uris = self.source_uris();
+		let session = nymph_compiler::ProjectSession::new(
+			&self.package_sources(&source_uris),
+			&self.target.options,
+		);
+		*self.session.borrow_mut() = Some(session);
+		self
+	}
+
+	/// Read the `.nym` files among `paths` again in the live session, if
+	/// they belong to one of the operation's packages. Other paths are
+	/// ignored.
+	pub fn reload(&self, paths: &BTreeSet<PathBuf>) {
+		let mut session = self.session.borrow_mut();
+		let Some(session) = session.as_mut() else {
+			return;
+		};
+		let modules = paths
+			.iter()
+			.flat_map(|path| {
+				self
+					.packages
+					.iter()
+					.enumerate()
+					.filter_map(move |(index, package)| {
+						let module = nymph_project::module_from_file(&package.src_root, path).ok()?;
+						Some((index, module.as_str().to_string()))
+					})
+			})
+			.collect::<Vec<_>>();
+		if modules.is_empty() {
+			return;
+		}
+		let mut analyzed = self.analyzed_sources.borrow_mut();
+		for module in &modules {
+			analyzed.remove(module);
+		}
+		drop(analyzed);
+		let source_uris = self.source_uris();
+		session.reload(modules, &self.package_sources(&source_uris));
+	}
+
+	/// The directories whose changes can change this operation's result,
+	/// each with whether its subdirectories are included: every package's
+	/// source root, and the directories holding the manifests. A loose file's
+	/// directory is watched without its subdirectories, since it can be any
+	/// directory at all.
+	pub fn watched_paths(&self) -> Vec<(PathBuf, bool)> {
+		let recursive = !self.target.manifests.is_empty();
+		let source_roots = self
+			.packages
+			.iter()
+			.map(|package| (package.src_root.clone(), recursive));
+		let manifests = self
+			.target
+			.manifests
+			.iter()
+			.filter_map(|manifest| Some((manifest.parent()?.to_path_buf(), false)));
+		source_roots.chain(manifests).collect()
+	}
+
+	/// Run `query` in the live session after acquiring everything `roots`
+	/// import, or `None` without a live session.
+	fn live<T>(
+		&self,
+		roots: &[&str],
+		query: impl FnOnce(&nymph_compiler::ProjectSession) -> T,
+	) -> Option<T> {
+		let mut session = self.session.borrow_mut();
+		let session = session.as_mut()?;
+		let source_uris = self.source_uris();
+		session.set_roots(roots, &self.package_sources(&source_uris));
+		Some(query(session))
+	}
+
+	fn package_sources<'a>(
+		&'a self,
+		source_uris: &'a [Box<SourceLoader>],
+	) -> Vec<nymph_compiler::PackageSources<'a>> {
+		self
+			.packages
+			.iter()
+			.enumerate()
+			.map(|(index, package)| nymph_compiler::PackageSources {
+				load: &package.load,
+				source_uri: source_uris.get(index).map(|uri| uri as &SourceLoader),
+				dependencies: package.dependencies.clone(),
+			})
+			.collect()
+	}
+
 	pub fn target_file(&self) -> &Path {
 		&self.target.file
 	}
 
 	pub fn check_selected_mode(&self) -> Vec<nymph_compiler::ProjectDiagnostic> {
+		let entry = self.target.entry_key.as_str();
+		let live = self.live(&[entry], |session| match self.target.intent {
+			TargetIntent::Entry => session.check(entry),
+			TargetIntent::Library => session.check_library(entry),
+		});
+		if let Some(diagnostics) = live {
+			return diagnostics;
+		}
 		self.analyzed_sources.borrow_mut().clear();
+		let packages = self.package_sources(&[]);
 		match self.target.intent {
-			TargetIntent::Entry => nymph_compiler::check_project_with_embedded_std_and_options(
+			TargetIntent::Entry => nymph_compiler::check_project_packages_with_embedded_std_and_options(
 				&self.target.entry_key,
-				&self.load,
-				&self.target.options,
-			),
-			TargetIntent::Library => nymph_compiler::check_project_library_with_embedded_std_and_options(
-				&self.target.entry_key,
-				&self.load,
+				&packages,
 				&self.target.options,
 			),
+			TargetIntent::Library => {
+				nymph_compiler::check_project_library_packages_with_embedded_std_and_options(
+					&self.target.entry_key,
+					&packages,
+					&self.target.options,
+				)
+			}
 		}
 	}
 
+	/// Document the selected project's root package; dependencies are checked
+	/// but not documented.
+	pub fn document(
+		&self,
+		options: nymph_compiler::DocOptions,
+	) -> Result<nymph_compiler::DocProject, Vec<nymph_compiler::ProjectDiagnostic>> {
+		self.analyzed_sources.borrow_mut().clear();
+		let packages = self.package_sources(&[]);
+		nymph_compiler::document_project_packages(&self.target.entry_key, &packages, options)
+	}
+
 	pub fn compile_selected_mode(&self) -> Option<nymph_compiler::CompiledProject> {
 		self.compile(self.target.intent)
 	}
@@ -149,42 +403,159 @@ impl ProjectOperation {
 	}
 
 	pub fn render(&self, diagnostics: &[nymph_compiler::ProjectDiagnostic]) -> String {
-		render_project_diagnostics(diagnostics, &self.target.src_root, &self.load)
+		render_package_diagnostics(diagnostics, &self.package_renders())
+	}
+
+	/// Report `diagnostics` in the operation's message format: rendered to
+	/// stderr, as JSON records on stdout, or held back for a SARIF log. Every
+	/// diagnostic is counted in [`Self::take_report`].
+	pub fn emit(&self, diagnostics: &[nymph_compiler::ProjectDiagnostic]) {
+		if self.message_format == MessageFormat::Human {
+			eprint!("{}", self.render(diagnostics));
+		}
+		let packages = self.package_renders();
+		let mut report = self.report.borrow_mut();
+		for diagnostic in diagnostics {
+			report.count(&diagnostic.diag);
+			match self.message_format {
+				MessageFormat::Human => {}
+				MessageFormat::Json => println!("{}", json_diagnostic(diagnostic, &packages)),
+				MessageFormat::Sarif => report.defer(json_diagnostic(diagnostic, &packages)),
+			}
+		}
+	}
+
+	/// What this operation has emitted so far, for the command to finish.
+	pub fn take_report(&self) -> Report {
+		self.report.take()
+	}
+
+	fn package_renders(&self) -> Vec<PackageRender<'_>> {
+		self
+			.packages
+			.iter()
+			.map(|package| {
+				(
+					package.src_root.as_path(),
+					&package.load as &dyn Fn(&str) -> Option<String>,
+				)
+			})
+			.collect()
 	}
 
 	fn compile(&self, intent: TargetIntent) -> Option<nymph_compiler::CompiledProject> {
-		let source_root = &self.target.src_root;
-		let source_uri = |module: &str| {
-			url::Url::from_file_path(
-				nymph_compiler::ModulePath::new(module)
-					.ok()?
-					.source_file(source_root),
-			)
-			.ok()
-			.map(String::from)
-		};
-		let result = guarded(|| match intent {
+		let entry = self.target.entry_key.as_str();
+		let live = self.live(&[entry], |session| {
+			guarded(|| match intent {
+				TargetIntent::Entry => session.compile(entry),
+				TargetIntent::Library => session.compile_library(entry),
+			})
+		});
+		if let Some(result) = live {
+			return self.report(result);
+		}
+		let source_uris = self.source_uris();
+		let packages = self.package_sources(&source_uris);
+		self.report(guarded(|| match intent {
 			TargetIntent::Entry => {
-				nymph_compiler::compile_project_with_embedded_std_options_and_source_uris(
+				nymph_compiler::compile_project_packages_with_embedded_std_and_options(
 					&self.target.entry_key,
-					&self.load,
+					&packages,
 					&self.target.options,
-					&source_uri,
 				)
 			}
 			TargetIntent::Library => {
-				nymph_compiler::compile_project_library_with_embedded_std_options_and_source_uris(
+				nymph_compiler::compile_project_library_packages_with_embedded_std_and_options(
 					&self.target.entry_key,
-					&self.load,
+					&packages,
 					&self.target.options,
-					&source_uri,
 				)
 			}
-		});
+		}))
+	}
+
+	/// Compile each of the root package's `modules` as a test library. See
+	/// [`nymph_compiler::compile_project_tests_packages_with_embedded_std_and_options`].
+	pub fn compile_tests(
+		&self,
+		modules: &[&str],
+	) -> Option<Vec<(String, nymph_compiler::CompiledProject)>> {
+		if let Some(result) = self.live(modules, |session| {
+			guarded(|| session.compile_tests(modules))
+		}) {
+			return self.report(result);
+		}
+		let source_uris = self.source_uris();
+		let packages = self.package_sources(&source_uris);
+		self.report(guarded(|| {
+			nymph_compiler::compile_project_tests_packages_with_embedded_std_and_options(
+				modules,
+				&packages,
+				&self.target.options,
+			)
+		}))
+	}
+
+	/// The root package's source root.
+	pub fn source_root(&self) -> &Path {
+		&self.target.src_root
+	}
+
+	/// The text the last check or compilation read for `module` of the
+	/// package at index `package`.
+	pub fn analyzed_source(&self, package: usize, module: &str) -> Option<String> {
+		self
+			.analyzed_sources
+			.borrow()
+			.get(&(package, module.to_string()))
+			.cloned()
+	}
+
+	/// Read `text` for `module` of the package at index `package` from now on,
+	/// instead of its file.
+	pub fn override_source(&self, package: usize, module: &str, text: String) {
+		self
+			.source_overrides
+			.borrow_mut()
+			.insert((package, module.to_string()), text);
+	}
+
+	/// The file of `module` in the package at index `package`.
+	pub fn module_path(&self, package: usize, module: &str) -> Option<PathBuf> {
+		let module = nymph_compiler::ModulePath::new(module).ok()?;
+		let package = self.packages.get(package)?;
+		Some(nymph_project::file_for_module(&package.src_root, &module))
+	}
+
+	fn source_uris(&self) -> Vec<Box<SourceLoader>> {
+		self
+			.packages
+			.iter()
+			.map(|package| {
+				let source_root = package.src_root.clone();
+				Box::new(move |module: &str| {
+					url::Url::from_file_path(
+						nymph_compiler::ModulePath::new(module)
+							.ok()?
+							.source_file(&source_root),
+					)
+					.ok()
+					.map(String::from)
+				}) as Box<SourceLoader>
+			})
+			.collect()
+	}
+
+	/// Emit a guarded compilation's diagnostics, or render its backend panic
+	/// to stderr.
+	fn report<T>(
+		&self,
+		result: Result<Result<T, Vec<nymph_compiler::ProjectDiagnostic>>, String>,
+	) -> Option<T> {
 		match result {
 			Ok(Ok(compiled)) => Some(compiled),
 			Ok(Err(diagnostics)) => {
-				eprint!("{}", self.render(&diagnostics));
+				self.emit(&diagnostics);
 				None
 			}
 			Err(payload) => {
@@ -227,7 +598,10 @@ pub(crate) fn resolve(
 		Some(project) => {
 			let options = nymph_compiler::CompilerOptions {
 				profile,
-				lints: project.manifest().lints.clone(),
+				lints: project.lints(),
+				source_maps: false,
+				layout: nymph_compiler::OutputLayout::Bundle,
+				declarations: false,
 			};
 			let src_root = project.source_root();
 			let entry_module = project.entry_module().map_err(|error| {
@@ -258,12 +632,20 @@ pub(crate) fn resolve(
 			} else {
 				TargetIntent::Library
 			};
+			let graph = project.resolve_packages()?;
+			update_lockfile(&project, &graph)?;
+			let manifests = std::iter::once(project.manifest_path().to_path_buf())
+				.chain(project.workspace_manifest().map(Path::to_path_buf))
+				.collect();
 			Ok(ResolvedTarget {
 				file,
 				src_root,
 				entry_key: module.as_str().to_string(),
 				intent,
 				options,
+				root_dependencies: graph.root().dependencies.clone(),
+				dependencies: graph.dependencies().to_vec(),
+				manifests,
 			})
 		}
 		None => {
@@ -288,12 +670,47 @@ pub(crate) fn resolve(
 				options: nymph_compiler::CompilerOptions {
 					profile,
 					lints: Default::default(),
+					source_maps: false,
+					layout: nymph_compiler::OutputLayout::Bundle,
+					declarations: false,
 				},
+				root_dependencies: BTreeMap::new(),
+				dependencies: Vec::new(),
+				manifests: Vec::new(),
 			})
 		}
 	}
 }
 
+/// Record the registry selections of `graph` in the project's lock file. The
+/// file is rewritten only when its contents change, and a project that locks
+/// nothing gets no lock file unless it already has one. Workspace members
+/// share one lock, which records the selections of every member.
+fn update_lockfile(
+	project: &nymph_project::Project,
+	graph: &nymph_project::PackageGraph,
+) -> anyhow::Result<()> {
+	let path = project.lock_path();
+	let workspace = project
+		.workspace_manifest()
+		.map(nymph_project::WorkspaceRoot::load)
+		.transpose()?
+		.flatten();
+	let lock = match workspace {
+		Some(workspace) => workspace.lockfile()?,
+		None => graph.lockfile(),
+	};
+	let existing = std::fs::read_to_string(&path).ok();
+	if existing.is_none() && lock.packages.is_empty() {
+		return Ok(());
+	}
+	let contents = lock.to_toml()?;
+	if existing.as_deref() != Some(contents.as_str()) {
+		std::fs::write(&path, contents)?;
+	}
+	Ok(())
+}
+
 fn ensure_source_file(file: &Path) -> anyhow::Result<()> {
 	if file.is_file() {
 		Ok(())
@@ -383,24 +800,18 @@ fn replace_file(from: &Path, to: &Path) -> std::io::Result<()> {
 	Ok(())
 }
 
-/// Render a batch of project diagnostics, each against its own module's
-/// filesystem path and source (re-read through `load`).
-pub(crate) fn render_project_diagnostics(
+/// A package's source root and module loader, for diagnostic rendering.
+pub(crate) type PackageRender<'a> = (&'a Path, &'a dyn Fn(&str) -> Option<String>);
+
+/// Render diagnostics from a multi-package project, resolving each module
+/// against the source root and loader of the package that owns it.
+pub(crate) fn render_package_diagnostics(
 	diags: &[nymph_compiler::ProjectDiagnostic],
-	src_root: &Path,
-	load: &dyn Fn(&str) -> Option<String>,
+	packages: &[PackageRender<'_>],
 ) -> String {
 	let mut out = String::new();
 	for d in diags {
-		let source = load(&d.module).unwrap_or_default();
-		let filename = nymph_compiler::ModulePath::new(&d.module).map_or_else(
-			|_| format!("{}.nym", d.module),
-			|module| {
-				nymph_project::file_for_module(src_root, &module)
-					.display()
-					.to_string()
-			},
-		);
+		let (filename, source) = diagnostic_source(d, packages);
 		out.push_str(&nymph_diagnostics::render(
 			&filename,
 			&source,
@@ -409,3 +820,50 @@ pub(crate) fn render_project_diagnostics(
 	}
 	out
 }
+
+/// The `--message-format json` record of one project diagnostic. An edit
+/// source's module is a diagnostic module key, located the same way.
+fn json_diagnostic(
+	d: &nymph_compiler::ProjectDiagnostic,
+	packages: &[PackageRender<'_>],
+) -> serde_json::Value {
+	let (filename, source) = diagnostic_source(d, packages);
+	diagnostic_record(
+		&d.module,
+		&SourceFile {
+			path: &filename,
+			text: &source,
+		},
+		&d.diag,
+		|module| {
+			let (index, module) = nymph_compiler::package_module(module)?;
+			module_file(packages.get(index)?.0, module)
+		},
+	)
+}
+
+/// The display path and current text of the module `d` is reported in.
+fn diagnostic_source(
+	d: &nymph_compiler::ProjectDiagnostic,
+	packages: &[PackageRender<'_>],
+) -> (String, String) {
+	let owner = d
+		.package_module()
+		.and_then(|(index, module)| Some((packages.get(index)?, module)));
+	let source = owner
+		.and_then(|((_, load), module)| load(module))
+		.unwrap_or_default();
+	let filename = owner
+		.and_then(|((src_root, _), module)| module_file(src_root, module))
+		.unwrap_or_else(|| format!("{}.nym", d.module));
+	(filename, source)
+}
+
+fn module_file(src_root: &Path, module: &str) -> Option<String> {
+	let module = nymph_compiler::ModulePath::new(module).ok()?;
+	Some(
+		nymph_project::file_for_module(src_root, &module)
+			.display()
+			.to_string(),
+	)
+}
@@ -0,0 +1,304 @@
+//! SARIF 2.1.0 export of `--message-format json` diagnostic records.
+//!
+//! Each record becomes one `result`. Its rule is the diagnostic's code: a typed
+//! catalog code as its four stable digits, described by the compiler's error
+//! catalog, or a lint name, described by its lint entry. Labels become related
+//! locations and machine-applicable edits become fixes. Columns count Unicode
+//! code points, which is SARIF's default `columnKind`.
+
+use serde_json::{Value, json};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// The SARIF log of `records`, one run of the `nymph` tool.
+pub(crate) fn log(records: &[Value]) -> Value {
+	let mut rules = Vec::<(String, Value)>::new();
+	let results = records
+		.iter()
+		.map(|record| {
+			let code = record["code"].as_str().unwrap_or_default();
+			let id = rule_id(code);
+			let index = match rules.iter().position(|(known, _)| *known == id) {
+				Some(index) => index,
+				None => {
+					rules.push((id.clone(), rule(&id, code)));
+					rules.len() - 1
+				}
+			};
+			result(record, &id, index)
+		})
+		.collect::<Vec<_>>();
+	json!({
+		"$schema": SCHEMA,
+		"version": "2.1.0",
+		"runs": [{
+			"tool": {
+				"driver": {
+					"name": "nymph",
+					"version": env!("CARGO_PKG_VERSION"),
+					"informationUri": env!("CARGO_PKG_HOMEPAGE"),
+					"rules": rules.into_iter().map(|(_, rule)| rule).collect::<Vec<_>>(),
+				},
+			},
+			"columnKind": "unicodeCodePoints",
+			"results": results,
+		}],
+	})
+}
+
+/// A catalog code padded to its four stable digits; lint names and other
+/// codes are used as they are.
+fn rule_id(code: &str) -> String {
+	match catalog_entry(code) {
+		Some(entry) => format!("{:04}", entry.code),
+		None => code.to_string(),
+	}
+}
+
+fn catalog_entry(code: &str) -> Option<&'static nymph_compiler::CatalogEntry> {
+	let code = code.parse::<u16>().ok()?;
+	nymph_compiler::error_catalog().find(|entry| entry.code == code)
+}
+
+fn rule(id: &str, code: &str) -> Value {
+	if let Some(entry) = catalog_entry(code) {
+		let mut rule = json!({ "id": id, "name": entry.name });
+		if !entry.summary.is_empty() {
+			rule["shortDescription"] = json!({ "text": entry.summary });
+		}
+		return rule;
+	}
+	if let Some(lint) = nymph_compiler::LINTS.iter().find(|lint| lint.name == code) {
+		let level = match lint.default_level {
+			nymph_compiler::LintLevel::Allow => "none",
+			nymph_compiler::LintLevel::Warn => "warning",
+			nymph_compiler::LintLevel::Deny => "error",
+		};
+		return json!({
+			"id": id,
+			"name": lint.name,
+			"shortDescription": { "text": lint.summary },
+			"defaultConfiguration": { "level": level },
+		});
+	}
+	json!({ "id": id })
+}
+
+fn result(record: &Value, id: &str, rule_index: usize) -> Value {
+	let level = match record["severity"].as_str() {
+		Some("error") => "error",
+		Some("warning") => "warning",
+		_ => "note",
+	};
+	let file = record["file"].as_str().unwrap_or_default();
+	let mut text = record["message"].as_str().unwrap_or_default().to_string();
+	for note in record["notes"].as_array().into_iter().flatten() {
+		text.push_str("\nnote: ");
+		text.push_str(note.as_str().unwrap_or_default());
+	}
+	if let Some(help) = record["help"].as_str() {
+		text.push_str("\nhelp: ");
+		text.push_str(help);
+	}
+	json!({
+		"ruleId": id,
+		"ruleIndex": rule_index,
+		"level": level,
+		"message": { "text": text },
+		"locations": [{ "physicalLocation": physical_location(file, &record["span"]) }],
+		"relatedLocations": record["labels"]
+			.as_array()
+			.into_iter()
+			.flatten()
+			.enumerate()
+			.map(|(index, label)| json!({
+				"id": index,
+				"physicalLocation": physical_location(file, &label["span"]),
+				"message": { "text": label["message"] },
+			}))
+			.collect::<Vec<_>>(),
+		"fixes": record["edits"]
+			.as_array()
+			.into_iter()
+			.flatten()
+			.map(fix)
+			.collect::<Vec<_>>(),
+	})
+}
+
+fn physical_location(file: &str, span: &Value) -> Value {
+	let start = span["start"].as_u64().unwrap_or_default();
+	let end = span["end"].as_u64().unwrap_or_default();
+	json!({
+		"artifactLocation": artifact_location(file),
+		"region": {
+			"startLine": span["line"],
+			"startColumn": span["column"],
+			"endLine": span["end_line"],
+			"endColumn": span["end_column"],
+			"byteOffset": start,
+			"byteLength": end.saturating_sub(start),
+		},
+	})
+}
+
+fn fix(edit: &Value) -> Value {
+	json!({
+		"description": { "text": edit["title"] },
+		"artifactChanges": edit["sources"]
+			.as_array()
+			.into_iter()
+			.flatten()
+			.map(|source| json!({
+				"artifactLocation": artifact_location(
+					source["file"].as_str().or(source["module"].as_str()).unwrap_or_default(),
+				),
+				"replacements": source["replacements"]
+					.as_array()
+					.into_iter()
+					.flatten()
+					.map(|replacement| {
+						let start = replacement["start"].as_u64().unwrap_or_default();
+						let end = replacement["end"].as_u64().unwrap_or_default();
+						json!({
+							"deletedRegion": {
+								"byteOffset": start,
+								"byteLength": end.saturating_sub(start),
+							},
+							"insertedContent": { "text": replacement["replacement"] },
+						})
+					})
+					.collect::<Vec<_>>(),
+			}))
+			.collect::<Vec<_>>(),
+	})
+}
+
+/// An absolute path as a `file:` URI; anything else, such as a standard-library
+/// module's virtual path, as a relative URI reference.
+fn artifact_location(file: &str) -> Value {
+	let uri = url::Url::from_file_path(file).map_or_else(|()| file.to_string(), String::from);
+	json!({ "uri": uri })
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use super::log;
+
+	#[test]
+	fn records_map_to_results_with_catalog_rules_related_locations_and_fixes() {
+		let span = |start: usize, end: usize, column: usize| {
+			json!({
+				"start": start, "end": end,
+				"line": 1, "column": column, "end_line": 1, "end_column": column + end - start,
+			})
+		};
+		let records = [
+			json!({
+				"type": "diagnostic",
+				"code": "2027",
+				"severity": "error",
+				"message": "mismatched types: expected `int`, found `boolean`",
+				"file": "/work/src/main.nym",
+				"module": "main",
+				"span": span(16, 20, 17),
+				"labels": [{ "message": "expected because of this", "span": span(10, 13, 11) }],
+				"notes": [],
+				"help": null,
+				"edits": [{
+					"title": "Use an integer",
+					"applicability": "machine-applicable",
+					"sources": [{
+						"project": "app",
+						"module": "main",
+						"file": "/work/src/main.nym",
+						"version": 0,
+						"replacements": [{ "start": 16, "end": 20, "replacement": "1" }],
+					}],
+				}],
+			}),
+			json!({
+				"type": "diagnostic",
+				"code": "echo-in-release",
+				"severity": "warning",
+				"message": "`echo` is erased from release builds",
+				"file": "/work/src/main.nym",
+				"module": "main",
+				"span": span(16, 20, 17),
+				"labels": [],
+				"notes": [],
+				"help": "use `println` or telemetry for intentional release output",
+				"edits": [],
+			}),
+		];
+
+		let log = log(&records);
+		let run = &log["runs"][0];
+		assert_eq!(log["version"], "2.1.0");
+		assert_eq!(run["tool"]["driver"]["name"], "nymph");
+		assert_eq!(
+			run["tool"]["driver"]["rules"],
+			json!([
+				{
+					"id": "2027",
+					"name": "MismatchedTypes",
+					"shortDescription": { "text": "Two types that were required to match did not." },
+				},
+				{
+					"id": "echo-in-release",
+					"name": "echo-in-release",
+					"shortDescription": {
+						"text": "An `echo` observation in a release build, where it is erased.",
+					},
+					"defaultConfiguration": { "level": "warning" },
+				},
+			])
+		);
+		assert_eq!(
+			run["results"][0],
+			json!({
+				"ruleId": "2027",
+				"ruleIndex": 0,
+				"level": "error",
+				"message": { "text": "mismatched types: expected `int`, found `boolean`" },
+				"locations": [{
+					"physicalLocation": {
+						"artifactLocation": { "uri": "file:///work/src/main.nym" },
+						"region": {
+							"startLine": 1, "startColumn": 17, "endLine": 1, "endColumn": 21,
+							"byteOffset": 16, "byteLength": 4,
+						},
+					},
+				}],
+				"relatedLocations": [{
+					"id": 0,
+					"physicalLocation": {
+						"artifactLocation": { "uri": "file:///work/src/main.nym" },
+						"region": {
+							"startLine": 1, "startColumn": 11, "endLine": 1, "endColumn": 14,
+							"byteOffset": 10, "byteLength": 3,
+						},
+					},
+					"message": { "text": "expected because of this" },
+				}],
+				"fixes": [{
+					"description": { "text": "Use an integer" },
+					"artifactChanges": [{
+						"artifactLocation": { "uri": "file:///work/src/main.nym" },
+						"replacements": [{
+							"deletedRegion": { "byteOffset": 16, "byteLength": 4 },
+							"insertedContent": { "text": "1" },
+						}],
+					}],
+				}],
+			})
+		);
+		assert_eq!(run["results"][1]["ruleIndex"], 1);
+		assert_eq!(
+			run["results"][1]["message"]["text"],
+			"`echo` is erased from release builds\nhelp: use `println` or telemetry for intentional release output"
+		);
+	}
+}
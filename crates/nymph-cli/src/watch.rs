@@ -0,0 +1,461 @@
+//! `--watch`: rerun a command whenever a source file or manifest changes.
+//!
+//! Each project operation keeps one live [`nymph_compiler::ProjectSession`]
+//! across cycles. A changed `.nym` file is reloaded into the sessions that
+//! hold it, so the next cycle recomputes only the queries it affects. A
+//! changed `nymph.toml` can move the source root or change the dependency
+//! graph, so every operation is resolved again with a fresh session.
+//!
+//! Linux is notified through inotify; other platforms rescan the watched
+//! trees a few times a second.
+
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::io::IsTerminal as _;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use crate::project_support::ProjectOperation;
+
+/// How long the watched files must stay quiet before a cycle starts, so that
+/// saving several files at once triggers one cycle.
+const SETTLE: Duration = Duration::from_millis(50);
+
+/// What the notifier reports: one changed file, or that changes were lost
+/// and anything may have changed.
+enum Change {
+	File(PathBuf),
+	Lost,
+}
+
+/// Every change since the previous cycle.
+#[derive(Default)]
+pub(crate) struct Changes {
+	files: BTreeSet<PathBuf>,
+	lost: bool,
+}
+
+/// Run `cycle` over the operations `resolve` selects, then again after each
+/// change to their sources or manifests, until the process is interrupted.
+/// `None` entries are selections that failed to resolve; `resolve` has
+/// already reported them. Each resolved operation keeps a live session.
+pub(crate) fn watch(
+	resolve: impl Fn() -> anyhow::Result<Vec<Option<ProjectOperation>>>,
+	mut cycle: impl FnMut(&[Option<ProjectOperation>], &Watcher) -> bool,
+) -> i32 {
+	let watcher = match Watcher::new() {
+		Ok(watcher) => watcher,
+		Err(error) => {
+			eprintln!("error: could not watch for changes: {error}");
+			return 1;
+		}
+	};
+	let resolve = || match resolve() {
+		Ok(operations) => operations
+			.into_iter()
+			.map(|operation| operation.map(ProjectOperation::with_live_session))
+			.collect(),
+		Err(error) => {
+			eprintln!("error: {error}");
+			Vec::new()
+		}
+	};
+	let mut operations = resolve();
+	let mut watched = BTreeSet::new();
+	loop {
+		for operation in operations.iter().flatten() {
+			for (path, recursive) in operation.watched_paths() {
+				if watched.insert((path.clone(), recursive))
+					&& let Err(error) = watcher.add(&path, recursive)
+				{
+					eprintln!("warning: could not watch {}: {error}", path.display());
+				}
+			}
+		}
+		if watched.is_empty() {
+			eprintln!("error: nothing to watch");
+			return 1;
+		}
+
+		let started = Instant::now();
+		let success = cycle(&operations, &watcher);
+		eprintln!(
+			"[watch] {} in {:.2?}; waiting for changes",
+			if success { "finished" } else { "failed" },
+			started.elapsed()
+		);
+
+		let Some(changes) = watcher.wait() else {
+			eprintln!("error: stopped receiving file changes");
+			return 1;
+		};
+		if std::io::stderr().is_terminal() {
+			eprint!("\x1b[2J\x1b[3J\x1b[H");
+		}
+		let started = Instant::now();
+		if changes.lost || changes.files.iter().any(|file| is_manifest(file)) {
+			operations = resolve();
+		} else {
+			for operation in operations.iter().flatten() {
+				operation.reload(&changes.files);
+			}
+		}
+		eprintln!(
+			"[watch] {} in {:.2?}",
+			describe(&changes),
+			started.elapsed()
+		);
+	}
+}
+
+fn describe(changes: &Changes) -> String {
+	if changes.lost {
+		return "reloaded everything".to_string();
+	}
+	match changes.files.len() {
+		1 => format!(
+			"reloaded {}",
+			changes.files.first().expect("one file").display()
+		),
+		count => format!("reloaded {count} files"),
+	}
+}
+
+fn is_manifest(path: &Path) -> bool {
+	path.file_name() == Some(OsStr::new("nymph.toml"))
+}
+
+/// Whether a change to `path` can change a command's result.
+fn is_watched_file(path: &Path) -> bool {
+	is_manifest(path) || path.extension() == Some(OsStr::new("nym"))
+}
+
+/// Directories a recursive watch skips, like `nymph format` does.
+fn is_skipped_directory(name: &OsStr) -> bool {
+	name == "target" || name == "dependencies"
+}
+
+/// The changes reported so far, gathered until they settle.
+pub(crate) struct Watcher {
+	notifier: notifier::Notifier,
+	receiver: mpsc::Receiver<Change>,
+	pending: RefCell<Option<Changes>>,
+}
+
+impl Watcher {
+	fn new() -> std::io::Result<Self> {
+		let (sender, receiver) = mpsc::channel();
+		Ok(Self {
+			notifier: notifier::Notifier::start(sender)?,
+			receiver,
+			pending: RefCell::default(),
+		})
+	}
+
+	/// Watch the directory `path`, and every directory below it when
+	/// `recursive`.
+	fn add(&self, path: &Path, recursive: bool) -> std::io::Result<()> {
+		self.notifier.add(path, recursive)
+	}
+
+	/// Wait up to `timeout` for a change. A change is kept for the next
+	/// [`Self::wait`], which returns it without blocking.
+	pub(crate) fn changed_within(&self, timeout: Duration) -> bool {
+		if self.pending.borrow().is_some() {
+			return true;
+		}
+		match self.receiver.recv_timeout(timeout) {
+			Ok(change) => {
+				self.gather(change);
+				true
+			}
+			Err(_) => false,
+		}
+	}
+
+	/// Block until something changes, then return every change once they
+	/// settle. `None` means the notifier stopped.
+	fn wait(&self) -> Option<Changes> {
+		if self.pending.borrow().is_none() {
+			let change = self.receiver.recv().ok()?;
+			self.gather(change);
+		}
+		self.pending.take()
+	}
+
+	fn gather(&self, first: Change) {
+		let mut pending = self.pending.borrow_mut();
+		let changes = pending.get_or_insert_default();
+		let mut next = Some(first);
+		while let Some(change) = next {
+			match change {
+				Change::File(file) => {
+					changes.files.insert(file);
+				}
+				Change::Lost => changes.lost = true,
+			}
+			next = self.receiver.recv_timeout(SETTLE).ok();
+		}
+	}
+}
+
+#[cfg(target_os = "linux")]
+mod notifier {
+	use std::collections::BTreeMap;
+	use std::ffi::OsStr;
+	use std::mem::MaybeUninit;
+	use std::os::unix::ffi::OsStrExt as _;
+	use std::path::{Path, PathBuf};
+	use std::sync::{Arc, Mutex, mpsc};
+
+	use rustix::fd::OwnedFd;
+	use rustix::fs::inotify::{self, ReadFlags, WatchFlags};
+
+	use super::{Change, is_skipped_directory, is_watched_file};
+
+	/// Each watched directory by watch descriptor, with whether directories
+	/// created below it are watched too.
+	type Watches = Arc<Mutex<BTreeMap<i32, (PathBuf, bool)>>>;
+
+	/// An inotify instance and the thread forwarding its events.
+	pub(super) struct Notifier {
+		inotify: Arc<OwnedFd>,
+		watches: Watches,
+	}
+
+	impl Notifier {
+		pub(super) fn start(sender: mpsc::Sender<Change>) -> std::io::Result<Self> {
+			let inotify = Arc::new(inotify::init(inotify::CreateFlags::CLOEXEC)?);
+			let notifier = Self {
+				inotify,
+				watches: Watches::default(),
+			};
+			let forwarder = Self {
+				inotify: Arc::clone(&notifier.inotify),
+				watches: Arc::clone(&notifier.watches),
+			};
+			std::thread::spawn(move || forwarder.forward(&sender));
+			Ok(notifier)
+		}
+
+		pub(super) fn add(&self, path: &Path, recursive: bool) -> std::io::Result<()> {
+			let flags = WatchFlags::CREATE
+				| WatchFlags::DELETE
+				| WatchFlags::CLOSE_WRITE
+				| WatchFlags::MOVED_FROM
+				| WatchFlags::MOVED_TO
+				| WatchFlags::ONLYDIR;
+			let descriptor = inotify::add_watch(&*self.inotify, path, flags)?;
+			// Watching a directory again returns its existing descriptor.
+			self
+				.watches
+				.lock()
+				.expect("watch table lock")
+				.entry(descriptor)
+				.or_insert_with(|| (path.to_path_buf(), false))
+				.1 |= recursive;
+			if recursive {
+				for entry in std::fs::read_dir(path)? {
+					let entry = entry?;
+					if entry.file_type()?.is_dir() && !is_skipped_directory(&entry.file_name()) {
+						self.add(&entry.path(), true)?;
+					}
+				}
+			}
+			Ok(())
+		}
+
+		fn forward(&self, sender: &mpsc::Sender<Change>) {
+			let mut buffer = [MaybeUninit::uninit(); 4096];
+			let mut reader = inotify::Reader::new(&*self.inotify, &mut buffer);
+			loop {
+				let (descriptor, flags, name) = match reader.next() {
+					Ok(event) => (
+						event.wd(),
+						event.events(),
+						event
+							.file_name()
+							.map(|name| OsStr::from_bytes(name.to_bytes()).to_os_string()),
+					),
+					Err(rustix::io::Errno::INTR) => continue,
+					Err(_) => return,
+				};
+				let changes = if flags.contains(ReadFlags::QUEUE_OVERFLOW) {
+					vec![Change::Lost]
+				} else {
+					self.changes(descriptor, flags, name.as_deref())
+				};
+				for change in changes {
+					if sender.send(change).is_err() {
+						return;
+					}
+				}
+			}
+		}
+
+		/// The changes one event reports.
+		fn changes(&self, descriptor: i32, flags: ReadFlags, name: Option<&OsStr>) -> Vec<Change> {
+			let mut watches = self.watches.lock().expect("watch table lock");
+			if flags.contains(ReadFlags::IGNORED) {
+				watches.remove(&descriptor);
+				return Vec::new();
+			}
+			let Some((directory, recursive)) = watches.get(&descriptor).cloned() else {
+				return Vec::new();
+			};
+			drop(watches);
+			let Some(name) = name else {
+				return Vec::new();
+			};
+			let path = directory.join(name);
+			if !flags.contains(ReadFlags::ISDIR) {
+				return if is_watched_file(&path) {
+					vec![Change::File(path)]
+				} else {
+					Vec::new()
+				};
+			}
+			if !recursive || is_skipped_directory(name) {
+				return Vec::new();
+			}
+			if flags.contains(ReadFlags::MOVED_FROM) {
+				// The sources below it are gone without events of their own.
+				self.forget(&path);
+				return vec![Change::Lost];
+			}
+			if !flags.intersects(ReadFlags::CREATE | ReadFlags::MOVED_TO) {
+				return Vec::new();
+			}
+			// Files can land in a new directory before it is watched, so
+			// report every source it already holds.
+			if self.add(&path, true).is_err() {
+				return vec![Change::Lost];
+			}
+			let mut files = Vec::new();
+			watched_files(&path, &mut files);
+			files.into_iter().map(Change::File).collect()
+		}
+
+		/// Stop watching `directory` and everything below it.
+		fn forget(&self, directory: &Path) {
+			let mut watches = self.watches.lock().expect("watch table lock");
+			watches.retain(|descriptor, (path, _)| {
+				let below = path.starts_with(directory);
+				if below {
+					let _ = inotify::remove_watch(&*self.inotify, *descriptor);
+				}
+				!below
+			});
+		}
+	}
+
+	fn watched_files(directory: &Path, files: &mut Vec<PathBuf>) {
+		let Ok(entries) = std::fs::read_dir(directory) else {
+			return;
+		};
+		for entry in entries.flatten() {
+			let path = entry.path();
+			match entry.file_type() {
+				Ok(kind) if kind.is_dir() => {
+					if !is_skipped_directory(&entry.file_name()) {
+						watched_files(&path, files);
+					}
+				}
+				Ok(_) if is_watched_file(&path) => files.push(path),
+				_ => {}
+			}
+		}
+	}
+}
+
+#[cfg(not(target_os = "linux"))]
+mod notifier {
+	use std::collections::BTreeMap;
+	use std::path::{Path, PathBuf};
+	use std::sync::{Arc, Mutex, mpsc};
+	use std::time::{Duration, SystemTime};
+
+	use super::{Change, is_skipped_directory, is_watched_file};
+
+	/// How often the watched trees are rescanned.
+	const INTERVAL: Duration = Duration::from_millis(250);
+
+	/// The last seen modification time and length of every watched file.
+	type Snapshot = BTreeMap<PathBuf, (Option<SystemTime>, u64)>;
+
+	#[derive(Default)]
+	struct State {
+		roots: Vec<(PathBuf, bool)>,
+		seen: Snapshot,
+	}
+
+	/// A thread that rescans every watched directory and reports the
+	/// watched files whose modification time or length changed.
+	pub(super) struct Notifier {
+		state: Arc<Mutex<State>>,
+	}
+
+	impl Notifier {
+		pub(super) fn start(sender: mpsc::Sender<Change>) -> std::io::Result<Self> {
+			let state = Arc::new(Mutex::new(State::default()));
+			let scanned = Arc::clone(&state);
+			std::thread::spawn(move || {
+				loop {
+					std::thread::sleep(INTERVAL);
+					let mut state = scanned.lock().expect("watch state lock");
+					let mut now = Snapshot::new();
+					for (root, recursive) in &state.roots {
+						scan(root, *recursive, &mut now);
+					}
+					let changed = state
+						.seen
+						.iter()
+						.filter(|(path, stamp)| now.get(*path) != Some(stamp))
+						.map(|(path, _)| path)
+						.chain(now.keys().filter(|path| !state.seen.contains_key(*path)))
+						.cloned()
+						.collect::<Vec<_>>();
+					state.seen = now;
+					drop(state);
+					for path in changed {
+						if sender.send(Change::File(path)).is_err() {
+							return;
+						}
+					}
+				}
+			});
+			Ok(Self { state })
+		}
+
+		pub(super) fn add(&self, path: &Path, recursive: bool) -> std::io::Result<()> {
+			std::fs::read_dir(path)?;
+			let mut state = self.state.lock().expect("watch state lock");
+			let mut seen = std::mem::take(&mut state.seen);
+			scan(path, recursive, &mut seen);
+			state.seen = seen;
+			state.roots.push((path.to_path_buf(), recursive));
+			Ok(())
+		}
+	}
+
+	fn scan(directory: &Path, recursive: bool, snapshot: &mut Snapshot) {
+		let Ok(entries) = std::fs::read_dir(directory) else {
+			return;
+		};
+		for entry in entries.flatten() {
+			let Ok(metadata) = entry.metadata() else {
+				continue;
+			};
+			let path = entry.path();
+			if metadata.is_dir() {
+				if recursive && !is_skipped_directory(&entry.file_name()) {
+					scan(&path, true, snapshot);
+				}
+			} else if is_watched_file(&path) {
+				snapshot.insert(path, (metadata.modified().ok(), metadata.len()));
+			}
+		}
+	}
+}
@@ -1,6 +1,6 @@
 //! Integration tests: spawn the real `nymph` binary and assert on its
 //! observable behavior (exit code, stdout, stderr) for `check`, `build`,
-//! `run`, and command-line parsing.
+//! `run`, `test`, and command-line parsing.
 
 use std::io::Write;
 use std::process::{Command, Stdio};
@@ -75,6 +75,7 @@ fn nymph_in(args: &[&str], current_dir: impl AsRef<std::path::Path>) -> Output {
 		.current_dir(current_dir)
 		.env("NO_COLOR", "1")
 		.env_remove("FORCE_COLOR")
+		.env_remove("NYMPH_REGISTRY")
 		.output()
 		.expect("spawn nymph");
 	Output {
@@ -159,7 +160,7 @@ fn deterministic_examples_have_exact_output_and_status() {
 		("todo-cli", "[x] #1 write the compiler\n"),
 		(
 			"word-frequency",
-			"most common words:\n  4  the\n  2  fox\n  1  quick\n  1  brown\n  1  lazy\n",
+			"most common of 6 words:\n  4  the\n  2  fox\n  1  quick\n  1  brown\n  1  lazy\n",
 		),
 	] {
 		let manifest = example_manifest(name);
@@ -706,6 +707,363 @@ fn check_attributes_imported_module_diagnostics_to_the_source_path() {
 	std::fs::remove_dir_all(root).unwrap();
 }
 
+fn write_package(root: &std::path::Path, name: &str, dependencies: &str, source: &str) {
+	let dir = root.join(name);
+	std::fs::create_dir_all(dir.join("src")).unwrap();
+	std::fs::write(
+		dir.join("nymph.toml"),
+		format!(
+			"[package]\nname='{name}'\nversion='1.0.0'\n[build]\nentry='lib.nym'\n[dependencies]\n{dependencies}"
+		),
+	)
+	.unwrap();
+	std::fs::write(dir.join("src/lib.nym"), source).unwrap();
+}
+
+#[test]
+fn path_dependencies_resolve_transitively_for_every_command() {
+	let root = unique_temp_path("nymph_cli_path_dependencies", "dir");
+	std::fs::create_dir_all(root.join("app/src")).unwrap();
+	std::fs::write(
+		root.join("app/nymph.toml"),
+		"[package]\nname='app'\nversion='1.0.0'\n[dependencies]\nutilities = { path = '../utilities' }\n",
+	)
+	.unwrap();
+	std::fs::write(
+		root.join("app/src/main.nym"),
+		"import std/io with (println)\nimport utilities/lib with (greeting)\nfunc main(): void = println(greeting())\n",
+	)
+	.unwrap();
+	write_package(
+		&root,
+		"utilities",
+		"text-tools = { path = '../text-tools' }",
+		"import text_tools/lib with (name)\npublic func greeting(): string = \"hello, ${name()}\"\n",
+	);
+	write_package(
+		&root,
+		"text-tools",
+		"",
+		"public func name(): string = \"packages\"\n",
+	);
+
+	for command in ["check", "build"] {
+		let out = nymph_in(&[command], root.join("app"));
+		assert!(out.status.success(), "{command}: {}", out.stderr);
+	}
+	let out = nymph_in(&["run"], root.join("app"));
+	assert!(out.status.success(), "{}", out.stderr);
+	assert_eq!(out.stdout, "hello, packages\n");
+
+	std::fs::write(
+		root.join("text-tools/src/lib.nym"),
+		"public func name(): string = 1\n",
+	)
+	.unwrap();
+	let out = nymph_in(&["check"], root.join("app"));
+	assert_eq!(out.status.code(), Some(1), "{}", out.stderr);
+	let dependency_file = root.join("text-tools/src/lib.nym");
+	assert!(
+		out
+			.stderr
+			.contains(&format!("{}:1:", dependency_file.display())),
+		"dependency diagnostics point to the dependency's source: {}",
+		out.stderr
+	);
+
+	std::fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn dependency_cycles_and_name_mismatches_are_selection_errors() {
+	let root = unique_temp_path("nymph_cli_dependency_errors", "dir");
+	write_package(&root, "app", "left = { path = '../left' }", "");
+	write_package(&root, "left", "right = { path = '../right' }", "");
+	write_package(&root, "right", "left = { path = '../left' }", "");
+
+	let out = nymph_in(&["check"], root.join("app"));
+	assert_eq!(out.status.code(), Some(1));
+	assert!(
+		out
+			.stderr
+			.contains("dependency cycle: left -> right -> left"),
+		"{}",
+		out.stderr
+	);
+
+	write_package(&root, "app", "utilities = { path = '../left' }", "");
+	let out = nymph_in(&["check"], root.join("app"));
+	assert_eq!(out.status.code(), Some(1));
+	assert!(
+		out.stderr.contains("resolves to package `left`"),
+		"{}",
+		out.stderr
+	);
+
+	std::fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn registry_dependencies_are_selected_locked_and_reused() {
+	let root = unique_temp_path("nymph_cli_registry_dependencies", "dir");
+	let publish = |version: &str, greeting: &str| {
+		let dir = root.join("registry/greeting").join(version);
+		std::fs::create_dir_all(dir.join("src")).unwrap();
+		std::fs::write(
+			dir.join("nymph.toml"),
+			format!("[package]\nname='greeting'\nversion='{version}'\n[build]\nentry='lib.nym'\n"),
+		)
+		.unwrap();
+		std::fs::write(
+			dir.join("src/lib.nym"),
+			format!("public func greeting(): string = \"{greeting}\"\n"),
+		)
+		.unwrap();
+	};
+	publish("1.0.0", "old");
+	publish("1.3.0", "new");
+	publish("2.0.0", "next");
+	std::fs::create_dir_all(root.join("app/src")).unwrap();
+	std::fs::write(
+		root.join("app/nymph.toml"),
+		"[package]\nname='app'\nversion='1.0.0'\n[registry]\npath='../registry'\n[dependencies]\ngreeting='^1'\n",
+	)
+	.unwrap();
+	std::fs::write(
+		root.join("app/src/main.nym"),
+		"import std/io with (println)\nimport greeting/lib with (greeting)\nfunc main(): void = println(greeting())\n",
+	)
+	.unwrap();
+
+	let out = nymph_in(&["run"], root.join("app"));
+	assert!(out.status.success(), "{}", out.stderr);
+	assert_eq!(out.stdout, "new\n");
+	let lock = std::fs::read_to_string(root.join("app/nymph.lock")).unwrap();
+	assert!(
+		lock.contains("name = \"greeting\"\nversion = \"1.3.0\"\nsource = \"registry\""),
+		"{lock}"
+	);
+
+	// A newer compatible release does not move a locked build.
+	publish("1.4.0", "newer");
+	let out = nymph_in(&["run"], root.join("app"));
+	assert_eq!(out.stdout, "new\n", "{}", out.stderr);
+	assert_eq!(
+		std::fs::read_to_string(root.join("app/nymph.lock")).unwrap(),
+		lock
+	);
+
+	std::fs::write(
+		root.join("app/nymph.toml"),
+		"[package]\nname='app'\nversion='1.0.0'\n[dependencies]\ngreeting='^3'\n",
+	)
+	.unwrap();
+	let out = nymph_in(&["check"], root.join("app"));
+	assert_eq!(out.status.code(), Some(1));
+	assert!(out.stderr.contains("requires a registry"), "{}", out.stderr);
+	let out = Command::new(env!("CARGO_BIN_EXE_nymph"))
+		.arg("check")
+		.current_dir(root.join("app"))
+		.env("NYMPH_REGISTRY", root.join("registry"))
+		.output()
+		.unwrap();
+	assert!(
+		String::from_utf8_lossy(&out.stderr).contains("no version of `greeting`"),
+		"{}",
+		String::from_utf8_lossy(&out.stderr)
+	);
+
+	std::fs::remove_dir_all(root).unwrap();
+}
+
+fn git_in(repository: &std::path::Path, args: &[&str]) -> String {
+	let out = Command::new("git")
+		.arg("-C")
+		.arg(repository)
+		.args([
+			"-c",
+			"user.name=nymph",
+			"-c",
+			"user.email=nymph@example.com",
+		])
+		.args(args)
+		.output()
+		.expect("spawn git");
+	assert!(out.status.success(), "{out:?}");
+	String::from_utf8(out.stdout).unwrap().trim().to_string()
+}
+
+#[test]
+fn git_dependencies_are_checked_out_into_target_and_pinned() {
+	let root = unique_temp_path("nymph_cli_git_dependencies", "dir");
+	write_package(&root, "tools", "", "public func word(): string = \"one\"\n");
+	let repository = root.join("tools");
+	git_in(
+		&root,
+		&["init", "--quiet", "--initial-branch=main", "tools"],
+	);
+	git_in(&repository, &["add", "--all"]);
+	git_in(&repository, &["commit", "--quiet", "-m", "one"]);
+	git_in(&repository, &["tag", "v1"]);
+	let release = |word: &str| {
+		std::fs::write(
+			repository.join("src/lib.nym"),
+			format!("public func word(): string = \"{word}\"\n"),
+		)
+		.unwrap();
+		git_in(&repository, &["commit", "--quiet", "--all", "-m", word]);
+		git_in(&repository, &["rev-parse", "HEAD"])
+	};
+	let two = release("two");
+
+	std::fs::create_dir_all(root.join("app/src")).unwrap();
+	let manifest = |reference: &str| {
+		std::fs::write(
+			root.join("app/nymph.toml"),
+			format!(
+				"[package]\nname='app'\nversion='1.0.0'\n[dependencies]\ntools = {{ git = '../tools'{reference} }}\n"
+			),
+		)
+		.unwrap();
+	};
+	manifest(", branch = 'main'");
+	std::fs::write(
+		root.join("app/src/main.nym"),
+		"import std/io with (println)\nimport tools/lib with (word)\nfunc main(): void = println(word())\n",
+	)
+	.unwrap();
+
+	let out = nymph_in(&["run"], root.join("app"));
+	assert!(out.status.success(), "{}", out.stderr);
+	assert_eq!(out.stdout, "two\n");
+	let lock = std::fs::read_to_string(root.join("app/nymph.lock")).unwrap();
+	assert!(
+		lock.contains(&format!("source = \"git+../tools?branch=main#{two}\"")),
+		"{lock}"
+	);
+	assert!(
+		root
+			.join("app/target/nymph/git/checkouts/tools")
+			.join(&two)
+			.join("nymph.toml")
+			.is_file()
+	);
+
+	// The lock keeps the build on its commit after the branch moves on.
+	release("three");
+	let out = nymph_in(&["run"], root.join("app"));
+	assert_eq!(out.stdout, "two\n", "{}", out.stderr);
+
+	// Changing the reference re-resolves and rewrites the pin.
+	manifest(", tag = 'v1'");
+	let out = nymph_in(&["run"], root.join("app"));
+	assert_eq!(out.stdout, "one\n", "{}", out.stderr);
+	assert!(
+		std::fs::read_to_string(root.join("app/nymph.lock"))
+			.unwrap()
+			.contains("?tag=v1#")
+	);
+
+	manifest(", tag = 'missing'");
+	let out = nymph_in(&["check"], root.join("app"));
+	assert_eq!(out.status.code(), Some(1));
+	assert!(
+		out.stderr.contains("tag `missing` does not name a commit"),
+		"{}",
+		out.stderr
+	);
+
+	std::fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn workspace_roots_check_build_format_and_document_every_member() {
+	let root = unique_temp_path("nymph_cli_workspace", "dir");
+	std::fs::create_dir_all(&root).unwrap();
+	std::fs::write(
+		root.join("nymph.toml"),
+		"[workspace]\nmembers = ['app', 'text']\n[workspace.lints]\necho-in-release = 'deny'\n",
+	)
+	.unwrap();
+	write_package(
+		&root,
+		"app",
+		"text = { path = '../text' }",
+		"import std/io with (println)\nimport text/lib with (name)\nfunc main(): void = println(name())\n",
+	);
+	write_package(
+		&root,
+		"text",
+		"",
+		"public func name(): string = echo \"text\"\nfunc main(): void = {}\n",
+	);
+
+	let out = nymph_in(&["check"], &root);
+	assert!(out.status.success(), "{}", out.stderr);
+	assert_eq!(out.stdout, "ok\n");
+	let out = nymph_in(&["check", "--release"], &root);
+	assert_eq!(out.status.code(), Some(1), "{}", out.stderr);
+	assert!(
+		out
+			.stderr
+			.contains(&format!("{}:1:", root.join("text/src/lib.nym").display())),
+		"inherited workspace lints apply to members: {}",
+		out.stderr
+	);
+	let text_manifest = root.join("text/nymph.toml");
+	let mut manifest = std::fs::read_to_string(&text_manifest).unwrap();
+	manifest.push_str("[lints]\necho-in-release = 'allow'\n");
+	std::fs::write(&text_manifest, manifest).unwrap();
+	let out = nymph_in(&["check", "--release"], &root);
+	assert!(
+		out.status.success(),
+		"member lints override: {}",
+		out.stderr
+	);
+
+	let out = nymph_in(&["build"], &root);
+	assert!(out.status.success(), "{}", out.stderr);
+	assert!(root.join("app/src/lib.mjs").is_file());
+	assert!(root.join("text/src/lib.mjs").is_file());
+	let out = nymph_in(&["build", "--output", "out.mjs"], &root);
+	assert_eq!(out.status.code(), Some(1));
+
+	std::fs::write(
+		root.join("text/src/lib.nym"),
+		"public func name(): string =   \"text\"\nfunc main(): void = {}\n",
+	)
+	.unwrap();
+	let out = nymph_in(&["format", "--check"], &root);
+	assert_eq!(out.status.code(), Some(1), "{}", out.stderr);
+	assert!(out.stderr.contains("text/src/lib.nym"), "{}", out.stderr);
+
+	let out = nymph_in(&["doc"], &root);
+	assert!(out.status.success(), "{}", out.stderr);
+	for member in ["app", "text"] {
+		assert!(
+			root
+				.join("target/nymph/doc")
+				.join(member)
+				.join("index.html")
+				.is_file()
+		);
+	}
+
+	let out = nymph_in(&["run"], &root);
+	assert_eq!(out.status.code(), Some(1));
+	assert!(
+		out
+			.stderr
+			.contains("declares a workspace but no package; select one of its members"),
+		"{}",
+		out.stderr
+	);
+	let out = nymph_in(&["check"], root.join("text"));
+	assert!(out.status.success(), "a member alone: {}", out.stderr);
+
+	std::fs::remove_dir_all(root).unwrap();
+}
+
 #[test]
 fn check_explicit_project_and_loose_targets_use_std_without_emitting_js() {
 	let root = write_project(
@@ -888,6 +1246,252 @@ fn check_reports_a_type_error_with_location_and_message() {
 	);
 }
 
+#[test]
+fn check_and_build_report_json_diagnostics_and_a_summary() {
+	let broken = write_source("func f(): int = true");
+	let echoing = write_source("func f(): int = echo 1");
+	let json_lines = |out: &Output| {
+		out
+			.stdout
+			.lines()
+			.map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+			.collect::<Vec<_>>()
+	};
+
+	for command in ["check", "build"] {
+		let out = nymph(&[
+			command,
+			"--message-format",
+			"json",
+			broken.to_str().unwrap(),
+		]);
+		assert_eq!(out.status.code(), Some(1), "{command}: {}", out.stderr);
+		assert_eq!(out.stderr, "", "{command}: JSON mode keeps stderr free");
+		assert_eq!(
+			json_lines(&out),
+			[
+				serde_json::json!({
+					"type": "diagnostic",
+					"code": "2027",
+					"severity": "error",
+					"message": "mismatched types: expected `int`, found `boolean`",
+					"file": broken.to_str().unwrap(),
+					"module": broken.file_stem().unwrap().to_str().unwrap(),
+					"span": {"start": 16, "end": 20, "line": 1, "column": 17, "end_line": 1, "end_column": 21},
+					"labels": [],
+					"notes": [],
+					"help": null,
+					"edits": [],
+				}),
+				serde_json::json!({"type": "summary", "success": false, "errors": 1, "warnings": 0}),
+			],
+			"{command}"
+		);
+		assert!(!broken.with_extension("mjs").exists());
+	}
+
+	let out = nymph(&[
+		"check",
+		"--release",
+		"--message-format",
+		"json",
+		echoing.to_str().unwrap(),
+	]);
+	assert_eq!(out.status.code(), Some(0), "{}", out.stderr);
+	let records = json_lines(&out);
+	assert_eq!(records.len(), 2, "{}", out.stdout);
+	assert_eq!(records[0]["code"], "echo-in-release");
+	assert_eq!(records[0]["severity"], "warning");
+	assert_eq!(
+		records[0]["help"],
+		"use `println` or telemetry for intentional release output"
+	);
+	assert_eq!(
+		records[1],
+		serde_json::json!({"type": "summary", "success": true, "errors": 0, "warnings": 1})
+	);
+
+	let out = nymph(&[
+		"build",
+		"--message-format",
+		"json",
+		echoing.to_str().unwrap(),
+	]);
+	assert_eq!(out.status.code(), Some(0), "{}", out.stderr);
+	assert_eq!(
+		json_lines(&out),
+		[serde_json::json!({"type": "summary", "success": true, "errors": 0, "warnings": 0})]
+	);
+
+	let _ = std::fs::remove_file(echoing.with_extension("mjs"));
+	let _ = std::fs::remove_file(&echoing);
+	let _ = std::fs::remove_file(&broken);
+}
+
+#[test]
+fn check_exports_a_sarif_log_with_catalog_rules() {
+	let broken = write_source("func f(): int = true\nfunc g(): int = echo false");
+	let out = nymph(&[
+		"check",
+		"--release",
+		"--message-format",
+		"sarif",
+		broken.to_str().unwrap(),
+	]);
+	let _ = std::fs::remove_file(&broken);
+
+	assert_eq!(out.status.code(), Some(1), "{}", out.stderr);
+	assert_eq!(out.stderr, "");
+	let log = serde_json::from_str::<serde_json::Value>(&out.stdout).unwrap();
+	assert_eq!(log["version"], "2.1.0");
+	let run = &log["runs"][0];
+	let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+	assert_eq!(
+		rules
+			.iter()
+			.map(|rule| (rule["id"].as_str().unwrap(), rule["name"].as_str().unwrap()))
+			.collect::<Vec<_>>(),
+		[
+			("2027", "MismatchedTypes"),
+			("echo-in-release", "echo-in-release")
+		]
+	);
+	let results = run["results"].as_array().unwrap();
+	assert_eq!(
+		results
+			.iter()
+			.map(|result| (
+				result["ruleId"].as_str().unwrap(),
+				result["level"].as_str().unwrap(),
+				result["locations"][0]["physicalLocation"]["region"]["startLine"]
+					.as_u64()
+					.unwrap(),
+			))
+			.collect::<Vec<_>>(),
+		[
+			("2027", "error", 1),
+			("2027", "error", 2),
+			("echo-in-release", "warning", 2),
+		],
+		"{:#}",
+		run["results"]
+	);
+	assert_eq!(
+		results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+		url::Url::from_file_path(&broken).unwrap().as_str()
+	);
+}
+
+#[test]
+fn fix_removes_release_echoes_in_rounds_and_previews_them_as_a_diff() {
+	let root = write_project(
+		"main.nym",
+		"import std/io with (println)\nfunc main(): void = {\n\tprintln(echo  echo \"b\")\n}\n",
+	);
+	let main = root.join("src/main.nym");
+
+	let preview = nymph_in(&["fix", "--release", "--dry-run"], &root);
+	assert!(preview.status.success(), "{}", preview.stderr);
+	assert_eq!(
+		preview.stdout,
+		format!(
+			"--- {path}\n+++ {path}\n@@ -1,4 +1,4 @@\n import std/io with (println)\n \
+			 func main(): void = {{\n-\tprintln(echo  echo \"b\")\n+\tprintln(\"b\")\n }}\n",
+			path = main.display()
+		)
+	);
+	assert!(std::fs::read_to_string(&main).unwrap().contains("echo"));
+
+	let fixed = nymph_in(&["fix", "--release"], &root);
+	assert!(fixed.status.success(), "{}", fixed.stderr);
+	assert_eq!(fixed.stdout, "");
+	assert_eq!(
+		fixed.stderr,
+		format!("fixed {} (2 fixes)\n", main.display())
+	);
+	assert_eq!(
+		std::fs::read_to_string(&main).unwrap(),
+		"import std/io with (println)\nfunc main(): void = {\n\tprintln(\"b\")\n}\n"
+	);
+	let check = nymph_in(&["check", "--release"], &root);
+	assert_eq!(check.stdout, "ok\n", "{}", check.stderr);
+
+	let again = nymph_in(&["fix", "--release"], &root);
+	assert!(again.status.success(), "{}", again.stderr);
+	assert_eq!(again.stderr, "");
+
+	std::fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn check_watch_rechecks_changed_modules_until_interrupted() {
+	use std::io::BufRead as _;
+	use std::time::Duration;
+
+	let root = write_project(
+		"main.nym",
+		"import @/model/token with (value)\nfunc main(): void = { let _n: int = value() }\n",
+	);
+	let token = root.join("src/model/token.nym");
+	std::fs::create_dir_all(token.parent().unwrap()).unwrap();
+	std::fs::write(&token, "public func value(): int = 1\n").unwrap();
+
+	let mut child = Command::new(env!("CARGO_BIN_EXE_nymph"))
+		.args(["check", "--watch"])
+		.current_dir(&root)
+		.env("NO_COLOR", "1")
+		.env_remove("FORCE_COLOR")
+		.stdout(Stdio::null())
+		.stderr(Stdio::piped())
+		.spawn()
+		.expect("spawn nymph");
+	let (lines, received) = std::sync::mpsc::channel();
+	let stderr = std::io::BufReader::new(child.stderr.take().unwrap());
+	std::thread::spawn(move || {
+		for line in stderr.lines() {
+			let Ok(line) = line else { break };
+			if lines.send(line).is_err() {
+				break;
+			}
+		}
+	});
+	// Everything written to stderr up to the end of the next cycle.
+	let cycle = || {
+		let mut output = String::new();
+		loop {
+			let line = received
+				.recv_timeout(Duration::from_secs(300))
+				.expect("watch cycle finishes");
+			output.push_str(&line);
+			output.push('\n');
+			if line.ends_with("waiting for changes") {
+				return output;
+			}
+		}
+	};
+
+	let first = cycle();
+	assert!(first.starts_with("[watch] finished in "), "{first}");
+
+	std::fs::write(&token, "public func value(): int = \"one\"\n").unwrap();
+	let broken = cycle();
+	assert!(
+		broken.starts_with(&format!("[watch] reloaded {} in ", token.display())),
+		"{broken}"
+	);
+	assert!(broken.contains("mismatched types"), "{broken}");
+	assert!(broken.contains("[watch] failed in "), "{broken}");
+
+	std::fs::write(&token, "public func value(): int = 2\n").unwrap();
+	let fixed = cycle();
+	assert!(!fixed.contains("error"), "{fixed}");
+	assert!(fixed.contains("[watch] finished in "), "{fixed}");
+
+	child.kill().unwrap();
+	child.wait().unwrap();
+	std::fs::remove_dir_all(root).unwrap();
+}
+
 #[test]
 fn check_accepts_exact_large_integers_without_warning() {
 	let path = write_source("func value(): int = 9007199254740992");
@@ -1164,6 +1768,237 @@ fn run_invokes_main_and_exits_successfully_when_main_is_valid() {
 	);
 }
 
+#[test]
+fn run_forwards_arguments_after_a_double_dash_to_std_env() {
+	let path = write_source(
+		"import std/io with (println)\n\
+		 import std/env with (args, var, Env)\n\
+		 func main(): void + !Env = {\n\
+		 \x20 println(args())\n\
+		 \x20 println(var(\"NYMPH_CLI_TEST_VALUE\"))\n\
+		 \x20 println(var(\"NYMPH_CLI_TEST_UNSET\"))\n\
+		 }",
+	);
+	let out = Command::new(env!("CARGO_BIN_EXE_nymph"))
+		.args([
+			"run",
+			path.to_str().unwrap(),
+			"--",
+			"one",
+			"two words",
+			"--flag",
+		])
+		.env("NO_COLOR", "1")
+		.env("NYMPH_CLI_TEST_VALUE", "set")
+		.env_remove("NYMPH_CLI_TEST_UNSET")
+		.output()
+		.unwrap();
+	let _ = std::fs::remove_file(&path);
+
+	assert!(
+		out.status.success(),
+		"stderr: {}",
+		String::from_utf8_lossy(&out.stderr)
+	);
+	assert_eq!(
+		String::from_utf8_lossy(&out.stdout),
+		"#[\"one\", \"two words\", \"--flag\"]\nOption.Some(value: \"set\")\nOption.None\n"
+	);
+}
+
+#[test]
+fn reading_the_environment_requires_the_env_effect() {
+	let path =
+		write_source("import std/env with (args)\nfunc main(): void = {\n  let arguments = args()\n}");
+	let out = nymph(&["check", path.to_str().unwrap()]);
+	let _ = std::fs::remove_file(&path);
+
+	assert!(!out.status.success());
+	assert!(
+		out
+			.stderr
+			.contains("callable body requires effects outside its declared effect row"),
+		"stderr: {}",
+		out.stderr
+	);
+}
+
+#[test]
+fn std_fs_reads_writes_lists_and_streams_files_with_structured_errors() {
+	let dir = unique_temp_path("nymph_cli_fs", "d");
+	std::fs::create_dir(&dir).unwrap();
+	let root = dir.to_str().unwrap();
+	let path = write_source(&format!(
+		"import std/io with (println)\n\
+		 import std/fs with (Fs, File, read_text, write_text, append_text, read_dir, metadata)\n\
+		 func main(): void + !Fs = {{\n\
+		 \x20 println(write_text(\"{root}/a.txt\", \"héllo\\n\"))\n\
+		 \x20 println(append_text(\"{root}/a.txt\", \"world\\n\"))\n\
+		 \x20 println(read_text(\"{root}/a.txt\"))\n\
+		 \x20 println(read_dir(\"{root}\"))\n\
+		 \x20 println(metadata(\"{root}/a.txt\").map((meta) -> meta.size))\n\
+		 \x20 match (read_text(\"{root}/missing.txt\")) {{\n\
+		 \x20   Ok(...) -> {{}},\n\
+		 \x20   Error(error) -> println(error),\n\
+		 \x20 }}\n\
+		 \x20 match (File.open(\"{root}/a.txt\")) {{\n\
+		 \x20   Ok(file) -> {{\n\
+		 \x20     let use handle = file\n\
+		 \x20     println(handle.read_text(2))\n\
+		 \x20     println(handle.read_text(64))\n\
+		 \x20     println(handle.read_text(64))\n\
+		 \x20   }},\n\
+		 \x20   Error(error) -> println(error),\n\
+		 \x20 }}\n\
+		 }}"
+	));
+	let out = nymph(&["run", path.to_str().unwrap()]);
+	let _ = std::fs::remove_file(&path);
+	let _ = std::fs::remove_dir_all(&dir);
+
+	assert!(out.status.success(), "stderr: {}", out.stderr);
+	assert_eq!(
+		out.stdout,
+		format!(
+			"Result.Ok(value: void)\n\
+			 Result.Ok(value: void)\n\
+			 Result.Ok(value: \"héllo\\nworld\\n\")\n\
+			 Result.Ok(value: #[\"a.txt\"])\n\
+			 Result.Ok(value: 13)\n\
+			 {root}/missing.txt: no such file or directory\n\
+			 Result.Ok(value: Option.Some(value: \"h\"))\n\
+			 Result.Ok(value: Option.Some(value: \"éllo\\nworld\\n\"))\n\
+			 Result.Ok(value: Option.None)\n"
+		)
+	);
+}
+
+#[test]
+fn file_system_access_requires_the_fs_effect() {
+	let path = write_source(
+		"import std/fs with (read_text)\nfunc main(): void = {\n  let text = read_text(\"a.txt\")\n}",
+	);
+	let out = nymph(&["check", path.to_str().unwrap()]);
+	let _ = std::fs::remove_file(&path);
+
+	assert!(!out.status.success());
+	assert!(
+		out
+			.stderr
+			.contains("callable body requires effects outside its declared effect row"),
+		"stderr: {}",
+		out.stderr
+	);
+}
+
+#[test]
+fn std_process_runs_commands_with_arguments_environment_and_working_directory() {
+	let dir = unique_temp_path("nymph_cli_process", "d");
+	std::fs::create_dir(&dir).unwrap();
+	let root = dir.to_str().unwrap();
+	let path = write_source(&format!(
+		"import std/io with (println)\n\
+		 import std/process with (Command, ExitStatus, Process, ProcessError)\n\
+		 async func main(): Result<void, ProcessError> + !Process = {{\n\
+		 \x20 let output = Command(\n\
+		 \x20   program = \"node\",\n\
+		 \x20   args = #[\"-e\", \"console.log(process.env.GREETING, process.cwd()); console.error('é'); process.exit(3)\"],\n\
+		 \x20   env = #{{\"GREETING\": \"hi\"}},\n\
+		 \x20   cwd = Some(\"{root}\"),\n\
+		 \x20 ).spawn().await?\n\
+		 \x20 println(output.stdout_text().trim())\n\
+		 \x20 println(output.stderr_text().trim())\n\
+		 \x20 println(output.status)\n\
+		 \x20 println(output.status.success())\n\
+		 \x20 let killed = Command(program = \"node\", args = #[\"-e\", \"process.kill(process.pid, 'SIGTERM')\"]).spawn().await?\n\
+		 \x20 println(killed.status)\n\
+		 \x20 match (Command(program = \"{root}/missing\").spawn().await) {{\n\
+		 \x20   Ok(...) -> {{}},\n\
+		 \x20   Error(error) -> println(error),\n\
+		 \x20 }}\n\
+		 \x20 Ok({{}})\n\
+		 }}"
+	));
+	let out = nymph(&["run", path.to_str().unwrap()]);
+	let _ = std::fs::remove_file(&path);
+	let _ = std::fs::remove_dir_all(&dir);
+
+	assert!(out.status.success(), "stderr: {}", out.stderr);
+	assert_eq!(
+		out.stdout,
+		format!(
+			"hi {root}\n\
+			 é\n\
+			 ExitStatus.Exited(code: 3)\n\
+			 false\n\
+			 ExitStatus.Signaled(signal: \"SIGTERM\")\n\
+			 {root}/missing: program not found\n"
+		)
+	);
+}
+
+#[test]
+fn std_json_parses_prints_and_converts_values() {
+	let path = write_source(
+		"import std/io with (println)\n\
+		 import std/json with (Json, JsonError, ToJson, FromJson)\n\
+		 func read<T: FromJson>(json: Json): Result<T, JsonError> = T.from_json(json)\n\
+		 func main(): void = {\n\
+		 \x20 match (Json.parse(\"{\\\"a\\\": [1, -2.5e1, true, null], \\\"b\\\": {\\\"c\\\": \\\"x\\\\u00e9\\\"}, \\\"e\\\": []}\")) {\n\
+		 \x20   Ok(json) -> {\n\
+		 \x20     println(json)\n\
+		 \x20     println(json.pretty())\n\
+		 \x20   },\n\
+		 \x20   Error(error) -> println(error),\n\
+		 \x20 }\n\
+		 \x20 match (Json.parse(\"[1,\\n  2,,]\")) {\n\
+		 \x20   Ok(json) -> println(json),\n\
+		 \x20   Error(error) -> println(error),\n\
+		 \x20 }\n\
+		 \x20 println(#{\"k\": Some(value = 1.5), \"n\": None}.to_json())\n\
+		 \x20 let list: Result<#[int], JsonError> = read(#[1, 2].to_json())\n\
+		 \x20 println(list)\n\
+		 \x20 let fraction: Result<int, JsonError> = read(Json.Number(value = 1.5))\n\
+		 \x20 println(fraction)\n\
+		 \x20 let result: Result<int, string> = Ok(value = 4)\n\
+		 \x20 let back: Result<Result<int, string>, JsonError> = read(result.to_json())\n\
+		 \x20 println(back)\n\
+		 }",
+	);
+	let out = nymph(&["run", path.to_str().unwrap()]);
+	let _ = std::fs::remove_file(&path);
+
+	assert!(out.status.success(), "stderr: {}", out.stderr);
+	assert_eq!(
+		out.stdout,
+		"{\"a\":[1,-25,true,null],\"b\":{\"c\":\"xé\"},\"e\":[]}\n\
+		 {\n  \"a\": [\n    1,\n    -25,\n    true,\n    null\n  ],\n  \"b\": {\n    \"c\": \"xé\"\n  },\n  \"e\": []\n}\n\
+		 line 2, column 5: unexpected `,`\n\
+		 {\"k\":1.5,\"n\":null}\n\
+		 Result.Ok(value: #[1, 2])\n\
+		 Result.Error(error: JsonError.Mismatch(expected: \"an integer\", found: \"number\"))\n\
+		 Result.Ok(value: Result.Ok(value: 4))\n"
+	);
+}
+
+#[test]
+fn spawning_a_process_requires_the_process_effect() {
+	let path = write_source(
+		"import std/process with (Command)\nasync func main(): void = {\n  let output = Command(program = \"node\").spawn().await\n}",
+	);
+	let out = nymph(&["check", path.to_str().unwrap()]);
+	let _ = std::fs::remove_file(&path);
+
+	assert!(!out.status.success());
+	assert!(
+		out
+			.stderr
+			.contains("callable body requires effects outside its declared effect row"),
+		"stderr: {}",
+		out.stderr
+	);
+}
+
 #[test]
 fn run_evaluates_an_inline_expression_and_prints_its_value() {
 	// `run -e "<expr>"` wraps the expression in a throwaway nullary function,
@@ -1647,6 +2482,157 @@ fn build_writes_a_valid_main_dot_nym() {
 	assert!(js.contains("main"), "emitted JS was: {js}");
 }
 
+#[test]
+fn build_source_map_embeds_the_nym_source() {
+	let source = "func twice(n: int): int = n * 2\nfunc main(): void = {\n  let four = twice(2)\n}\n";
+	let path = write_main_source(source);
+	let output_path = path.with_extension("mjs");
+	let external = nymph(&["build", "--source-map", path.to_str().unwrap()]);
+	let external_js = std::fs::read_to_string(&output_path).unwrap_or_default();
+	let map = std::fs::read_to_string(path.with_extension("mjs.map")).unwrap_or_default();
+	let inline = nymph(&["build", "--source-map=inline", path.to_str().unwrap()]);
+	let inline_js = std::fs::read_to_string(&output_path).unwrap_or_default();
+	let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+	assert!(external.status.success(), "stderr: {}", external.stderr);
+	assert!(
+		external_js.ends_with("//# sourceMappingURL=main.mjs.map\n"),
+		"emitted JS was: {external_js}"
+	);
+	let map: serde_json::Value = serde_json::from_str(&map).expect("a JSON source map");
+	assert_eq!(map["version"], 3);
+	assert_eq!(map["file"], "main.mjs");
+	let sources = map["sources"].as_array().unwrap();
+	let main = sources
+		.iter()
+		.position(|name| name.as_str().unwrap().ends_with("main.nym"))
+		.unwrap_or_else(|| panic!("no main.nym among {sources:?}"));
+	assert_eq!(map["sourcesContent"][main], source);
+	assert!(!map["mappings"].as_str().unwrap().is_empty());
+
+	assert!(inline.status.success(), "stderr: {}", inline.stderr);
+	assert!(
+		inline_js.contains("//# sourceMappingURL=data:application/json;"),
+		"emitted JS was: {inline_js}"
+	);
+}
+
+#[cfg(unix)]
+#[test]
+fn build_executable_writes_a_program_node_runs_directly() {
+	use std::os::unix::fs::PermissionsExt;
+
+	let path = write_main_source(
+		"import std/io with (println)\n\
+		 func main(): Result<void, string> = {\n  println(\"started\")\n  Error(\"bad\")\n}\n",
+	);
+	let output_path = path.with_extension("mjs");
+	let out = nymph(&["build", "--executable", path.to_str().unwrap()]);
+	let js = std::fs::read_to_string(&output_path).unwrap_or_default();
+	let mode = std::fs::metadata(&output_path).map_or(0, |metadata| metadata.permissions().mode());
+	let program = Command::new(&output_path).output();
+	let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+	assert!(out.status.success(), "stderr: {}", out.stderr);
+	assert!(
+		js.starts_with("#!/usr/bin/env node\n"),
+		"emitted JS was: {js}"
+	);
+	assert!(js.contains("nymphStartRoot"), "emitted JS was: {js}");
+	assert_eq!(mode & 0o111, 0o111, "mode was {mode:o}");
+	let program = program.expect("the output runs without `nymph`");
+	assert_eq!(program.status.code(), Some(1));
+	assert_eq!(String::from_utf8_lossy(&program.stdout), "started\n");
+	assert_eq!(String::from_utf8_lossy(&program.stderr), "error: bad\n");
+}
+
+#[test]
+fn build_emit_modules_writes_one_es_module_per_nym_module() {
+	let root = write_project(
+		"main.nym",
+		"import @/geometry/area with (square)\n\
+		 func main(): void = {\n  let four = square(2)\n}\n",
+	);
+	let area = root.join("src/geometry/area.nym");
+	std::fs::create_dir_all(area.parent().unwrap()).unwrap();
+	std::fs::write(&area, "public func square(n: int): int = n * n\n").unwrap();
+
+	let built = nymph_in(&["build", "--emit", "modules", "--out-dir", "dist"], &root);
+	let main = std::fs::read_to_string(root.join("dist/main.mjs")).unwrap_or_default();
+	let area = std::fs::read_to_string(root.join("dist/geometry/area.mjs")).unwrap_or_default();
+	let runtime = root.join("dist/_nymph/host/std/box.mjs").exists();
+	let loaded = Command::new("node")
+		.arg("--input-type=module")
+		.arg("-e")
+		.arg("const m = await import(process.argv[1]); console.log(typeof m.main);")
+		.arg(root.join("dist/main.mjs"))
+		.output()
+		.expect("run node");
+	let _ = std::fs::remove_dir_all(&root);
+
+	assert!(built.status.success(), "stderr: {}", built.stderr);
+	assert!(
+		main.contains("from \"./geometry/area.mjs\";"),
+		"main.mjs was: {main}"
+	);
+	assert!(
+		area.contains("from \"../_nymph/host/std/box.mjs\";"),
+		"geometry/area.mjs was: {area}"
+	);
+	assert!(runtime, "std/box was not written as its own module");
+	assert!(
+		loaded.status.success(),
+		"node failed: {}",
+		String::from_utf8_lossy(&loaded.stderr)
+	);
+	assert_eq!(String::from_utf8_lossy(&loaded.stdout).trim(), "function");
+}
+
+#[test]
+fn build_declarations_describe_and_export_the_public_api() {
+	let path = write_source(
+		"public struct Point(x: float, y: float)\n\
+		 public enum Shape {\n  Circle(radius: float),\n  Empty,\n}\n\
+		 public func square(n: int): int = n * n\n\
+		 private func hidden(): int = 1\n",
+	);
+	let built = nymph(&["build", "--declarations", path.to_str().unwrap()]);
+	let declarations = std::fs::read_to_string(path.with_extension("d.mts")).unwrap_or_default();
+	let called = Command::new("node")
+		.arg("--input-type=module")
+		.arg("-e")
+		.arg(
+			"const { square, NInt } = await import(process.argv[1]); console.log(square(new NInt(4n)).v);",
+		)
+		.arg(path.with_extension("mjs"))
+		.output()
+		.expect("run node");
+	let _ = std::fs::remove_file(path.with_extension("mjs"));
+	let _ = std::fs::remove_file(path.with_extension("d.mts"));
+	let _ = std::fs::remove_file(&path);
+
+	assert!(built.status.success(), "stderr: {}", built.stderr);
+	for expected in [
+		"export class NInt extends NBox<bigint> {",
+		"export declare class Point {\n\tconstructor(fields: { x: NFloat; y: NFloat });",
+		"export type Shape = Shape.Circle | Shape.Empty;",
+		"\tCircle(fields: { radius: NFloat }): Shape.Circle;\n\treadonly Empty: Shape.Empty;",
+		"export declare function square(n: NInt): NInt;",
+	] {
+		assert!(
+			declarations.contains(expected),
+			"missing {expected:?} in:\n{declarations}"
+		);
+	}
+	assert!(!declarations.contains("hidden"), "{declarations}");
+	assert!(
+		called.status.success(),
+		"node failed: {}",
+		String::from_utf8_lossy(&called.stderr)
+	);
+	assert_eq!(String::from_utf8_lossy(&called.stdout).trim(), "16n");
+}
+
 #[test]
 fn check_passes_a_main_dot_nym_using_a_prelude_operator_impl() {
 	// The entry-mode counterpart of `check_reports_ok_for_a_user_struct_plus_impl_via_the_default_prelude`
@@ -1656,7 +2642,7 @@ fn check_passes_a_main_dot_nym_using_a_prelude_operator_impl() {
 	let path = write_main_source(
 		"struct P(v: int)\n\
 		impl Plus<Other = P, Output = P> for P { func plus(other: P): P = other }\n\
-		func main(): void = {\n  let sum = P(v = 1) + P(v = 2)\n}",
+		func main(): void = {\n  let _sum = P(v = 1) + P(v = 2)\n}",
 	);
 	let out = nymph(&["check", path.to_str().unwrap()]);
 	let dir = path.parent().unwrap().to_path_buf();
@@ -2404,3 +3390,203 @@ fn format_cli_matches_the_shared_canonical_fixture() {
 	);
 	std::fs::remove_dir_all(root).unwrap();
 }
+
+#[test]
+fn test_runs_each_declared_test_in_isolation_and_reports_failures() {
+	let root = write_project(
+		"main.nym",
+		"import ./util/math\nimport std/io with (println)\n\nfunc main() = {}\n\ntest \"adds\" {\n\tprintln(\"hidden on success\")\n\tlet sum = math.add(1, 2)\n}\n\ntest \"returns none\" {\n\tprintln(\"shown on failure\")\n\tlet result: Option<void> = None\n\tresult\n}\n\nasync test \"awaits\" {\n\tlet result: Result<void, string> = Ok(value = {})\n\tresult\n}\n",
+	);
+	std::fs::create_dir_all(root.join("src/util")).unwrap();
+	std::fs::write(
+		root.join("src/util/math.nym"),
+		"public func add(a: int, b: int): int = a + b\n\nfunc remainder(a: int, b: int): int = a % b\n\ntest \"errors\" {\n\tlet result: Result<void, string> = Error(error = \"boom\")\n\tresult\n}\n\ntest \"defects\" {\n\tlet value = remainder(1, 0)\n}\n",
+	)
+	.unwrap();
+
+	let out = nymph_in(&["test"], &root);
+	assert_eq!(out.status.code(), Some(1), "{}", out.stderr);
+	assert_eq!(
+		out.stdout,
+		"running 5 tests\n\
+		 test main::adds ... ok\n\
+		 test main::returns none ... FAILED\n\
+		 test main::awaits ... ok\n\
+		 test util/math::errors ... FAILED\n\
+		 test util/math::defects ... FAILED\n\
+		 \n\
+		 failures:\n\
+		 \n\
+		 ---- main::returns none ----\n\
+		 shown on failure\n\
+		 error: test returned None\n\
+		 \n\
+		 ---- util/math::errors ----\n\
+		 error: boom\n\
+		 \n\
+		 ---- util/math::defects ----\n\
+		 error: program defected: RangeError: Division by zero\n\
+		 \n\
+		 failures:\n\
+		 \x20   main::returns none\n\
+		 \x20   util/math::errors\n\
+		 \x20   util/math::defects\n\
+		 \n\
+		 test result: FAILED. 2 passed; 3 failed; 0 filtered out\n"
+	);
+
+	let out = nymph_in(&["test", "main::a"], &root);
+	assert_eq!(out.status.code(), Some(0), "{}", out.stderr);
+	assert_eq!(
+		out.stdout,
+		"running 2 tests\n\
+		 test main::adds ... ok\n\
+		 test main::awaits ... ok\n\
+		 \n\
+		 test result: ok. 2 passed; 0 failed; 3 filtered out\n"
+	);
+
+	let out = nymph_in(&["build"], &root);
+	assert!(out.status.success(), "{}", out.stderr);
+	let js = std::fs::read_to_string(root.join("src/main.mjs")).unwrap();
+	assert!(!js.contains("hidden on success"));
+	let _ = std::fs::remove_dir_all(root);
+}
+
+#[test]
+fn test_reports_compile_errors_and_unsupported_test_results() {
+	let root = write_project(
+		"main.nym",
+		"func main() = {}\n\ntest \"value\" {\n\t42\n}\n",
+	);
+	let out = nymph_in(&["test"], &root);
+	let _ = std::fs::remove_dir_all(root);
+	assert_eq!(out.status.code(), Some(1));
+	assert!(out.stdout.is_empty(), "{}", out.stdout);
+	assert!(
+		out.stderr.contains("a test must produce `void`"),
+		"{}",
+		out.stderr
+	);
+}
+
+#[test]
+fn std_test_assertions_render_values_and_diff_wide_ones() {
+	let root = write_project(
+		"main.nym",
+		"import std/test with (assert_eq, assert_ne, assert_matches, assert_err)\n\nstruct Point(x: int, y: int)\n\nfunc parse(text: string): Result<int, string> =\n\tif (text == \"1\") Ok(value = 1) else Error(error = \"not one\")\n\nfunc main() = {}\n\ntest \"equal\" {\n\tassert_eq(Point(x = 1, y = 2), Point(x = 1, y = 2))\n\tassert_ne(1, 2)\n\tassert_eq(assert_err(parse(\"2\")), \"not one\")\n}\n\ntest \"points\" { assert_eq(Point(x = 1, y = 2), Point(x = 1, y = 3)) }\n\ntest \"words\" {\n\tassert_eq(\n\t\t#[\"alpha\", \"beta\", \"gamma\", \"delta\", \"epsilon\", \"zeta\", \"theta\"],\n\t\t#[\"alpha\", \"beta\", \"gamma\", \"delta\", \"epsilon\", \"eta\", \"theta\"],\n\t)\n}\n\ntest \"parses\" { assert_matches(parse(\"2\"), (result) -> match (result) { Ok(...) -> true, _ -> false }) }\n",
+	);
+
+	for args in [&["test"][..], &["test", "--release"]] {
+		let out = nymph_in(args, &root);
+		assert_eq!(out.status.code(), Some(1), "{}", out.stderr);
+		assert_eq!(
+			out.stdout,
+			"running 4 tests\n\
+			 test main::equal ... ok\n\
+			 test main::points ... FAILED\n\
+			 test main::words ... FAILED\n\
+			 test main::parses ... FAILED\n\
+			 \n\
+			 failures:\n\
+			 \n\
+			 ---- main::points ----\n\
+			 error: program defected: AssertionError: assertion `left == right` failed\n\
+			 \x20 left: Point(x: 1, y: 2)\n\
+			 \x20right: Point(x: 1, y: 3)\n\
+			 \n\
+			 ---- main::words ----\n\
+			 error: program defected: AssertionError: assertion `left == right` failed\n\
+			 diff (- left, + right):\n\
+			 \x20 #[\n\
+			 \x20   \"alpha\",\n\
+			 \x20   \"beta\",\n\
+			 \x20   \"gamma\",\n\
+			 \x20   \"delta\",\n\
+			 \x20   \"epsilon\",\n\
+			 -   \"zeta\",\n\
+			 +   \"eta\",\n\
+			 \x20   \"theta\",\n\
+			 \x20 ]\n\
+			 \n\
+			 ---- main::parses ----\n\
+			 error: program defected: AssertionError: assertion `matches` failed\n\
+			 \x20value: Result.Error(error: \"not one\")\n\
+			 \n\
+			 failures:\n\
+			 \x20   main::points\n\
+			 \x20   main::words\n\
+			 \x20   main::parses\n\
+			 \n\
+			 test result: FAILED. 1 passed; 3 failed; 0 filtered out\n",
+			"{args:?}"
+		);
+	}
+	let _ = std::fs::remove_dir_all(root);
+}
+
+#[test]
+fn bindgen_generates_host_bindings_that_a_program_can_run() {
+	let root = write_project(
+		"main.nym",
+		"import ./node with (same, entries, delayed, settle)\n\
+		 import std/io with (println)\n\
+		 async func main(): void = {\n  \
+		   println(\"${same(#[1, 2], #[1, 2])}\")\n  \
+		   println(entries(\"src/version\")[0])\n  \
+		   println(delayed(1.0, \"later\").await)\n  \
+		   settle(1.0).await\n\
+		 }\n",
+	);
+	let declarations = root.join("node.d.ts");
+	std::fs::write(
+		&declarations,
+		"declare module \"node:util\" {\n\
+		 \texport function isDeepStrictEqual(left: bigint[], right: bigint[]): boolean;\n\
+		 }\n\
+		 declare module \"node:fs\" {\n\
+		 \texport function readdirSync(path: string): string[];\n\
+		 \texport class Dirent {}\n\
+		 }\n\
+		 declare module \"node:timers/promises\" {\n\
+		 \texport function setTimeout(delay: number, value: string): Promise<string>;\n\
+		 \texport function setImmediate(value: number): Promise<void>;\n\
+		 }\n",
+	)
+	.unwrap();
+	let generated = nymph_in(&["bindgen", "node.d.ts"], &root);
+	let source = generated
+		.stdout
+		.replace("func is_deep_strict_equal", "func same")
+		.replace("func readdir_sync", "func entries")
+		.replace("func set_timeout", "func delayed")
+		.replace("func set_immediate", "func settle");
+	std::fs::write(root.join("src/node.nym"), &source).unwrap();
+	std::fs::create_dir_all(root.join("src/version")).unwrap();
+	std::fs::write(root.join("src/version/entry.txt"), "").unwrap();
+	let run = nymph_in(&["run"], &root);
+	let _ = std::fs::remove_dir_all(&root);
+
+	assert!(generated.status.success(), "stderr: {}", generated.stderr);
+	for expected in [
+		"public external(\"node:util\", \"isDeepStrictEqual\") func is_deep_strict_equal(left: #[int], right: #[int]): boolean",
+		"public external(\"node:fs\", \"readdirSync\") func readdir_sync(path: string): #[string]",
+		"public external(\"node:timers/promises\", \"setTimeout\") async func set_timeout(delay: float, value: string): string",
+		"// - `Dirent` (line 6): classes have no Nymph equivalent",
+	] {
+		assert!(
+			generated.stdout.contains(expected),
+			"missing {expected:?} in:\n{}",
+			generated.stdout
+		);
+	}
+	assert!(
+		generated
+			.stderr
+			.contains("node.d.ts:6: `Dirent` is not bound: classes have no Nymph equivalent"),
+		"stderr: {}",
+		generated.stderr
+	);
+	assert!(run.status.success(), "stderr: {}", run.stderr);
+	assert_eq!(run.stdout, "true\nentry.txt\nlater\n");
+}
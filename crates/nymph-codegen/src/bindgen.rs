@@ -0,0 +1,619 @@
+//! Host bindings generated from a TypeScript declaration file.
+//!
+//! [`generate_bindings`] parses a `.d.ts` file with oxc and writes one
+//! `external("module", "symbol")` declaration per exported function or
+//! variable whose signature maps onto the host-binding ABI: `number`,
+//! `bigint`, `string` and `boolean` become `float`, `int`, `string` and
+//! `boolean`, arrays of those become lists, and a function returning a
+//! `Promise` becomes an `async func`, so callers receive a `Task`. Everything
+//! else — classes, interfaces, generics, overloads, rest parameters and any
+//! type outside that table — is left out and reported as an
+//! [`UnmappedDeclaration`], both in the returned list and as a comment at the
+//! end of the generated module.
+//!
+//! Declarations inside `declare module "specifier" { .. }` bind that
+//! specifier instead of the caller's default module.
+
+use std::collections::BTreeSet;
+
+use oxc::allocator::Allocator;
+use oxc::ast::ast::{
+	Declaration, ExportDefaultDeclarationKind, Function, Statement, TSModuleDeclarationBody,
+	TSModuleDeclarationName, TSType, TSTypeName, TSTypeOperatorOperator, VariableDeclaration,
+};
+use oxc::parser::Parser;
+use oxc::span::{GetSpan, SourceType, Span};
+
+/// Words a generated Nymph name must not collide with.
+const NYMPH_KEYWORDS: &[&str] = &[
+	"true",
+	"false",
+	"public",
+	"internal",
+	"private",
+	"import",
+	"with",
+	"async",
+	"await",
+	"type",
+	"struct",
+	"enum",
+	"let",
+	"external",
+	"effect",
+	"func",
+	"interface",
+	"impl",
+	"namespace",
+	"for",
+	"loop",
+	"if",
+	"else",
+	"match",
+	"int",
+	"uint",
+	"float",
+	"boolean",
+	"char",
+	"string",
+	"void",
+	"never",
+	"self",
+	"as",
+	"is",
+	"in",
+	"return",
+	"break",
+	"continue",
+	"echo",
+	"this",
+];
+
+/// A generated binding module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bindings {
+	/// The `.nym` source of the module.
+	pub source: String,
+	/// Every exported declaration that could not be bound, in source order.
+	pub unmapped: Vec<UnmappedDeclaration>,
+}
+
+/// An exported declaration [`generate_bindings`] left out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnmappedDeclaration {
+	/// The exported name, as written in the declaration file.
+	pub name: String,
+	/// One-based line of the declaration.
+	pub line: usize,
+	/// Why the declaration has no binding.
+	pub reason: String,
+}
+
+/// Generate host bindings for the exports `source` declares. `module` is the
+/// specifier top-level exports are imported from; `file_name` only appears
+/// in the generated header. Returns oxc's messages if `source` does not
+/// parse.
+pub fn generate_bindings(
+	source: &str,
+	module: &str,
+	file_name: &str,
+) -> Result<Bindings, Vec<String>> {
+	let allocator = Allocator::default();
+	let parsed = Parser::new(&allocator, source, SourceType::d_ts()).parse();
+	if parsed.panicked || parsed.diagnostics.has_errors() {
+		return Err(
+			parsed
+				.diagnostics
+				.errors()
+				.map(ToString::to_string)
+				.collect(),
+		);
+	}
+	let mut generator = Generator {
+		source,
+		lines: Vec::new(),
+		unmapped: Vec::new(),
+		bound: BTreeSet::new(),
+		names: BTreeSet::new(),
+	};
+	for statement in &parsed.program.body {
+		generator.statement(statement, module, false);
+	}
+
+	let mut out = format!("// Generated by `nymph bindgen` from {file_name}.\n");
+	if !generator.lines.is_empty() {
+		out.push('\n');
+	}
+	for line in &generator.lines {
+		out.push_str(line);
+		out.push('\n');
+	}
+	if !generator.unmapped.is_empty() {
+		out.push_str("\n// Not bound:\n");
+		for unmapped in &generator.unmapped {
+			out.push_str(&format!(
+				"// - `{}` (line {}): {}\n",
+				unmapped.name, unmapped.line, unmapped.reason
+			));
+		}
+	}
+	Ok(Bindings {
+		source: out,
+		unmapped: generator.unmapped,
+	})
+}
+
+struct Generator<'s> {
+	source: &'s str,
+	lines: Vec<String>,
+	unmapped: Vec<UnmappedDeclaration>,
+	/// `(module, symbol)` pairs already bound, so later overloads are skipped.
+	bound: BTreeSet<(String, String)>,
+	/// Nymph names already declared.
+	names: BTreeSet<String>,
+}
+
+/// Where a mapped type appears; only a result may be `void`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Position {
+	Parameter,
+	Result,
+	Value,
+}
+
+impl<'s> Generator<'s> {
+	/// `exported` is set inside an ambient module, where every declaration is
+	/// an export whether or not it says so.
+	fn statement(&mut self, statement: &Statement<'_>, module: &str, exported: bool) {
+		match statement {
+			Statement::ExportNamedDeclaration(export) => {
+				if let Some(declaration) = &export.declaration {
+					self.declaration(declaration, module);
+				} else if export.source.is_some() {
+					for specifier in &export.specifiers {
+						self.unmapped(
+							specifier.exported.name().as_str(),
+							specifier.span,
+							"re-exports are not followed; generate bindings from the module that declares it",
+						);
+					}
+				} else {
+					for specifier in &export.specifiers {
+						self.unmapped(
+							specifier.exported.name().as_str(),
+							specifier.span,
+							"exports of local declarations are not followed",
+						);
+					}
+				}
+			}
+			Statement::ExportDefaultDeclaration(export) => {
+				let name = match &export.declaration {
+					ExportDefaultDeclarationKind::FunctionDeclaration(function) => function
+						.id
+						.as_ref()
+						.map_or("default", |id| id.name.as_str()),
+					_ => "default",
+				};
+				self.unmapped(name, export.span, "default exports have no name to bind");
+			}
+			Statement::ExportAllDeclaration(export) => {
+				self.unmapped(
+					"*",
+					export.span,
+					"re-exports are not followed; generate bindings from the module that declares them",
+				);
+			}
+			Statement::TSExportAssignment(assignment) => {
+				self.unmapped(
+					"export =",
+					assignment.span,
+					"CommonJS `export =` assignments have no named exports to bind",
+				);
+			}
+			Statement::TSModuleDeclaration(declaration) => {
+				let TSModuleDeclarationName::StringLiteral(specifier) = &declaration.id else {
+					self.unmapped(
+						self.text(declaration.id.span()),
+						declaration.span,
+						"namespaces have no Nymph equivalent",
+					);
+					return;
+				};
+				if let Some(TSModuleDeclarationBody::TSModuleBlock(block)) = &declaration.body {
+					for statement in &block.body {
+						self.statement(statement, specifier.value.as_str(), true);
+					}
+				}
+			}
+			_ if exported => {
+				if let Some(declaration) = statement.as_declaration() {
+					self.declaration(declaration, module);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	fn declaration(&mut self, declaration: &Declaration<'_>, module: &str) {
+		match declaration {
+			Declaration::FunctionDeclaration(function) => self.function(function, module),
+			Declaration::VariableDeclaration(variable) => self.variable(variable, module),
+			Declaration::ClassDeclaration(class) => {
+				let name = class.id.as_ref().map_or("class", |id| id.name.as_str());
+				self.unmapped(name, class.span, "classes have no Nymph equivalent");
+			}
+			Declaration::TSInterfaceDeclaration(interface) => self.unmapped(
+				interface.id.name.as_str(),
+				interface.span,
+				"interfaces have no Nymph equivalent",
+			),
+			Declaration::TSTypeAliasDeclaration(alias) => self.unmapped(
+				alias.id.name.as_str(),
+				alias.span,
+				"type aliases are not expanded",
+			),
+			Declaration::TSEnumDeclaration(declaration) => self.unmapped(
+				declaration.id.name.as_str(),
+				declaration.span,
+				"TypeScript enums have no Nymph equivalent",
+			),
+			Declaration::TSModuleDeclaration(declaration) => self.unmapped(
+				self.text(declaration.id.span()),
+				declaration.span,
+				"namespaces have no Nymph equivalent",
+			),
+			Declaration::TSGlobalDeclaration(_) | Declaration::TSImportEqualsDeclaration(_) => {}
+		}
+	}
+
+	fn function(&mut self, function: &Function<'_>, module: &str) {
+		let Some(id) = &function.id else {
+			return;
+		};
+		let symbol = id.name.as_str();
+		if self
+			.bound
+			.contains(&(module.to_string(), symbol.to_string()))
+		{
+			self.unmapped(
+				symbol,
+				function.span,
+				"overloads are not bound; only the first signature is",
+			);
+			return;
+		}
+		match self.function_signature(function) {
+			Ok((name, signature)) => {
+				self.bound.insert((module.to_string(), symbol.to_string()));
+				self.names.insert(name.clone());
+				self.lines.push(format!(
+					"public external({}, {}) {signature}",
+					quote(module),
+					quote(symbol)
+				));
+			}
+			Err(reason) => self.unmapped(symbol, function.span, &reason),
+		}
+	}
+
+	fn function_signature(&self, function: &Function<'_>) -> Result<(String, String), String> {
+		let symbol = function.id.as_ref().map_or("", |id| id.name.as_str());
+		let name = self.nymph_name(symbol)?;
+		if function.type_parameters.is_some() {
+			return Err("generic functions are not bound".into());
+		}
+		if function.params.rest.is_some() {
+			return Err("rest parameters are not bound".into());
+		}
+		let mut parameters = Vec::new();
+		for (index, parameter) in function.params.items.iter().enumerate() {
+			let parameter_name = parameter
+				.pattern
+				.get_binding_identifier()
+				.map_or_else(|| format!("arg{index}"), |id| snake_case(id.name.as_str()));
+			let parameter_name = escape_keyword(parameter_name);
+			let Some(annotation) = &parameter.type_annotation else {
+				return Err(format!("parameter `{parameter_name}` has no declared type"));
+			};
+			let ty = self
+				.ty(&annotation.type_annotation, Position::Parameter)
+				.map_err(|ty| {
+					format!("parameter `{parameter_name}` has type `{ty}`, which has no Nymph equivalent")
+				})?;
+			parameters.push(format!("{parameter_name}: {ty}"));
+		}
+		let Some(annotation) = &function.return_type else {
+			return Err("the function has no declared return type".into());
+		};
+		// A promise-returning function binds as `async`, so callers get a task.
+		let (keyword, result) = match promise_output(&annotation.type_annotation) {
+			Some(output) => ("async func", output),
+			None => ("func", &annotation.type_annotation),
+		};
+		let result = self
+			.ty(result, Position::Result)
+			.map_err(|ty| format!("the result type `{ty}` has no Nymph equivalent"))?;
+		let signature = format!("{keyword} {name}({}): {result}", parameters.join(", "));
+		Ok((name, signature))
+	}
+
+	fn variable(&mut self, variable: &VariableDeclaration<'_>, module: &str) {
+		for declarator in &variable.declarations {
+			let Some(id) = declarator.id.get_binding_identifier() else {
+				self.unmapped(
+					self.text(declarator.id.span()),
+					declarator.span,
+					"destructuring patterns are not bound",
+				);
+				continue;
+			};
+			let symbol = id.name.as_str();
+			let binding = self.nymph_name(symbol).and_then(|name| {
+				let Some(annotation) = &declarator.type_annotation else {
+					return Err("the binding has no declared type".into());
+				};
+				let ty = self
+					.ty(&annotation.type_annotation, Position::Value)
+					.map_err(|ty| format!("the type `{ty}` has no Nymph equivalent"))?;
+				Ok((name, ty))
+			});
+			match binding {
+				Ok((name, ty)) => {
+					self.bound.insert((module.to_string(), symbol.to_string()));
+					self.names.insert(name.clone());
+					self.lines.push(format!(
+						"public external({}, {}) let {name}: {ty}",
+						quote(module),
+						quote(symbol)
+					));
+				}
+				Err(reason) => self.unmapped(symbol, declarator.span, &reason),
+			}
+		}
+	}
+
+	/// The Nymph spelling of `ty`, or its TypeScript source text when it has
+	/// no host-binding form in `position`.
+	fn ty(&self, ty: &TSType<'_>, position: Position) -> Result<String, String> {
+		let unmapped = || Err(self.text(ty.span()).to_string());
+		match ty {
+			TSType::TSParenthesizedType(inner) => self.ty(&inner.type_annotation, position),
+			TSType::TSVoidKeyword(_) | TSType::TSUndefinedKeyword(_) if position == Position::Result => {
+				Ok("void".into())
+			}
+			_ => {
+				if let Some(scalar) = scalar(ty) {
+					return Ok(scalar.into());
+				}
+				match array_element(ty).and_then(scalar) {
+					Some(element) => Ok(format!("#[{element}]")),
+					None => unmapped(),
+				}
+			}
+		}
+	}
+
+	/// `symbol` in snake case, made safe to declare.
+	fn nymph_name(&self, symbol: &str) -> Result<String, String> {
+		let name = escape_keyword(snake_case(symbol));
+		let valid = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+			&& name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+		if !valid {
+			return Err(format!("`{name}` is not a valid Nymph name"));
+		}
+		if self.names.contains(&name) {
+			return Err(format!("its Nymph name `{name}` is already bound"));
+		}
+		Ok(name)
+	}
+
+	fn unmapped(&mut self, name: &str, span: Span, reason: &str) {
+		self.unmapped.push(UnmappedDeclaration {
+			name: name.to_string(),
+			line: line_of(self.source, span.start),
+			reason: reason.to_string(),
+		});
+	}
+
+	fn text(&self, span: Span) -> &'s str {
+		&self.source[span.start as usize..span.end as usize]
+	}
+}
+
+fn scalar(ty: &TSType<'_>) -> Option<&'static str> {
+	match ty {
+		TSType::TSNumberKeyword(_) => Some("float"),
+		TSType::TSBigIntKeyword(_) => Some("int"),
+		TSType::TSStringKeyword(_) => Some("string"),
+		TSType::TSBooleanKeyword(_) => Some("boolean"),
+		TSType::TSParenthesizedType(inner) => scalar(&inner.type_annotation),
+		_ => None,
+	}
+}
+
+/// The element type of `T[]`, `readonly T[]`, `Array<T>` or `ReadonlyArray<T>`.
+fn array_element<'t, 'a>(ty: &'t TSType<'a>) -> Option<&'t TSType<'a>> {
+	match ty {
+		TSType::TSArrayType(array) => Some(&array.element_type),
+		TSType::TSTypeOperatorType(operator)
+			if operator.operator == TSTypeOperatorOperator::Readonly =>
+		{
+			array_element(&operator.type_annotation)
+		}
+		TSType::TSParenthesizedType(inner) => array_element(&inner.type_annotation),
+		TSType::TSTypeReference(reference) => {
+			match (
+				reference_name(&reference.type_name),
+				type_arguments(reference),
+			) {
+				(Some("Array" | "ReadonlyArray"), [element]) => Some(element),
+				_ => None,
+			}
+		}
+		_ => None,
+	}
+}
+
+/// `T` for a `Promise<T>`.
+fn promise_output<'t, 'a>(ty: &'t TSType<'a>) -> Option<&'t TSType<'a>> {
+	match ty {
+		TSType::TSParenthesizedType(inner) => promise_output(&inner.type_annotation),
+		TSType::TSTypeReference(reference) => {
+			match (
+				reference_name(&reference.type_name),
+				type_arguments(reference),
+			) {
+				(Some("Promise"), [output]) => Some(output),
+				_ => None,
+			}
+		}
+		_ => None,
+	}
+}
+
+fn reference_name<'a>(name: &'a TSTypeName<'_>) -> Option<&'a str> {
+	match name {
+		TSTypeName::IdentifierReference(id) => Some(id.name.as_str()),
+		_ => None,
+	}
+}
+
+fn type_arguments<'t, 'a>(reference: &'t oxc::ast::ast::TSTypeReference<'a>) -> &'t [TSType<'a>] {
+	reference
+		.type_arguments
+		.as_ref()
+		.map_or(&[], |arguments| arguments.params.as_slice())
+}
+
+/// `camelCase`, `PascalCase` and `SCREAMING_CASE` to `snake_case`, keeping
+/// acronyms together (`parseHTTPResponse` → `parse_http_response`).
+fn snake_case(name: &str) -> String {
+	let chars: Vec<char> = name.chars().collect();
+	let mut out = String::with_capacity(name.len() + 4);
+	for (index, &c) in chars.iter().enumerate() {
+		if c.is_uppercase() && index > 0 {
+			let previous = chars[index - 1];
+			let next_is_lower = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+			if previous.is_lowercase()
+				|| previous.is_ascii_digit()
+				|| (previous.is_uppercase() && next_is_lower)
+			{
+				out.push('_');
+			}
+		}
+		out.extend(c.to_lowercase());
+	}
+	out
+}
+
+fn escape_keyword(name: String) -> String {
+	if NYMPH_KEYWORDS.contains(&name.as_str()) {
+		format!("{name}_")
+	} else {
+		name
+	}
+}
+
+fn quote(text: &str) -> String {
+	format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn line_of(source: &str, offset: u32) -> usize {
+	source[..offset as usize].matches('\n').count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn maps_primitives_arrays_and_promises() {
+		let source = "export declare function leftPad(text: string, width: number): string;\n\
+			export declare function sum(values: readonly number[]): bigint;\n\
+			export declare function readLines(path: string): Promise<Array<string>>;\n\
+			export declare function flush(): Promise<void>;\n\
+			export declare const VERSION: string;\n";
+		let bindings = generate_bindings(source, "text-kit", "index.d.ts").unwrap();
+		assert_eq!(
+			bindings.source,
+			"// Generated by `nymph bindgen` from index.d.ts.\n\
+			\n\
+			public external(\"text-kit\", \"leftPad\") func left_pad(text: string, width: float): string\n\
+			public external(\"text-kit\", \"sum\") func sum(values: #[float]): int\n\
+			public external(\"text-kit\", \"readLines\") async func read_lines(path: string): #[string]\n\
+			public external(\"text-kit\", \"flush\") async func flush(): void\n\
+			public external(\"text-kit\", \"VERSION\") let version: string\n"
+		);
+		assert!(bindings.unmapped.is_empty());
+	}
+
+	#[test]
+	fn flags_every_declaration_it_cannot_map() {
+		let source = "export interface Options { width: number }\n\
+			export declare class Pad {}\n\
+			export declare function pad(text: string, options: Options): string;\n\
+			export declare function first<T>(values: T[]): T;\n\
+			export declare function join(...parts: string[]): string;\n\
+			export declare function parse(text: string): number;\n\
+			export declare function parse(text: string, radix: number): number;\n\
+			export declare function wait(ms: Promise<number>): void;\n";
+		let bindings = generate_bindings(source, "pad", "pad.d.ts").unwrap();
+		let flagged: Vec<_> = bindings
+			.unmapped
+			.iter()
+			.map(|unmapped| (unmapped.name.as_str(), unmapped.line))
+			.collect();
+		assert_eq!(
+			flagged,
+			[
+				("Options", 1),
+				("Pad", 2),
+				("pad", 3),
+				("first", 4),
+				("join", 5),
+				("parse", 7),
+				("wait", 8),
+			]
+		);
+		assert!(
+			bindings.source.contains(
+				"// - `pad` (line 3): parameter `options` has type `Options`, which has no Nymph equivalent\n"
+			),
+			"{}",
+			bindings.source
+		);
+		assert!(
+			bindings
+				.source
+				.contains("func parse(text: string): float\n")
+		);
+	}
+
+	#[test]
+	fn ambient_modules_bind_their_own_specifier_and_escape_keywords() {
+		let source = "declare module \"node:strings\" {\n\
+			\tfunction match(pattern: string, type: string): boolean;\n\
+			}\n";
+		let bindings = generate_bindings(source, "ignored", "strings.d.ts").unwrap();
+		assert!(
+			bindings.source.contains(
+				"public external(\"node:strings\", \"match\") func match_(pattern: string, type_: string): boolean\n"
+			),
+			"{}",
+			bindings.source
+		);
+	}
+
+	#[test]
+	fn snake_cases_acronyms_and_constants() {
+		assert_eq!(snake_case("parseHTTPResponse"), "parse_http_response");
+		assert_eq!(snake_case("MAX_WIDTH"), "max_width");
+		assert_eq!(snake_case("toUTF8"), "to_utf8");
+	}
+
+	#[test]
+	fn reports_parse_errors() {
+		assert!(generate_bindings("export declare function (", "m", "m.d.ts").is_err());
+	}
+}
@@ -41,6 +41,7 @@ const BASE: &str = "NBox";
 
 const HASH_MAP_RUNTIME: &str = include_str!("./hashmap_runtime.js");
 const LIST_RUNTIME: &str = include_str!("./list_runtime.js");
+const RENDER_RUNTIME: &str = include_str!("./render_runtime.js");
 const ECHO_RUNTIME: &str = include_str!("./echo_runtime.js");
 const ACTIVATION_RUNTIME: &str = include_str!("./activation_runtime.js");
 const TASK_RUNTIME: &str = include_str!("./task_runtime.js");
@@ -84,7 +85,7 @@ fn class_defs(export: bool, option_enum_name: &str, echo: bool) -> String {
 	out.push_str("\t}\n");
 	out.push_str("}\n");
 	out.push_str("const NYMPH_TYPE_INTERN = new globalThis.Map();\nconst NYMPH_TYPE_RESULT = Symbol(\"nymph.type.result\");\nconst NYMPH_TYPE_ARGUMENTS = new globalThis.WeakMap();\n");
-	out.push_str("function nymphType(base, args) {\n\tif (args.length === 0) return base;\n\targs = Object.freeze([...args]);\n\tlet node = NYMPH_TYPE_INTERN.get(base);\n\tif (node === undefined) { node = new globalThis.Map(); nymphMapSet(NYMPH_TYPE_INTERN, base, node); }\n\tfor (const arg of args) { let next = node.get(arg); if (next === undefined) { next = new globalThis.Map(); nymphMapSet(node, arg, next); } node = next; }\n\tlet result = node.get(NYMPH_TYPE_RESULT);\n\tif (result === undefined) { result = Object.create(base); nymphWeakMapSet(NYMPH_TYPE_ARGUMENTS, result, args); nymphMapSet(node, NYMPH_TYPE_RESULT, result); }\n\treturn result;\n}\nfunction nymphTypeProjection(receiver, path) {\n\tlet type = Object.getPrototypeOf(receiver);\n\tfor (const index of path) { const args = NYMPH_TYPE_ARGUMENTS.get(type); if (args === undefined || index >= args.length) return NBox.prototype; type = args[index]; }\n\treturn type;\n}\n");
+	out.push_str("function nymphType(base, args) {\n\tif (args.length === 0) return base;\n\targs = Object.freeze([...args]);\n\tlet node = NYMPH_TYPE_INTERN.get(base);\n\tif (node === undefined) { node = new globalThis.Map(); nymphMapSet(NYMPH_TYPE_INTERN, base, node); }\n\tfor (const arg of args) { let next = node.get(arg); if (next === undefined) { next = new globalThis.Map(); nymphMapSet(node, arg, next); } node = next; }\n\tlet result = node.get(NYMPH_TYPE_RESULT);\n\tif (result === undefined) { result = Object.create(base); nymphWeakMapSet(NYMPH_TYPE_ARGUMENTS, result, args); nymphMapSet(node, NYMPH_TYPE_RESULT, result); }\n\treturn result;\n}\nfunction nymphTypeProjection(receiver, path) {\n\tlet type = NYMPH_TYPE_ARGUMENTS.has(receiver) ? receiver : Object.getPrototypeOf(receiver);\n\tfor (const index of path) { const args = NYMPH_TYPE_ARGUMENTS.get(type); if (args === undefined || index >= args.length) return NBox.prototype; type = args[index]; }\n\treturn type;\n}\n");
 	out.push_str("const NYMPH_VARIANT_INTERN = new globalThis.WeakMap();\nfunction nymphVariant(type, variant) {\n\tlet variants = NYMPH_VARIANT_INTERN.get(type);\n\tif (variants === undefined) { variants = new globalThis.WeakMap(); nymphWeakMapSet(NYMPH_VARIANT_INTERN, type, variants); }\n\tlet result = variants.get(variant);\n\tif (result === undefined) { result = Object.freeze(nymphAssign(Object.create(type), variant)); nymphWeakMapSet(variants, variant, result); }\n\treturn result;\n}\n");
 	out.push_str(&format!(
 		"const NYMPH_OPTION_ENUM_NAME = \"{option_enum_name}\";\n"
@@ -108,6 +109,14 @@ fn class_defs(export: bool, option_enum_name: &str, echo: bool) -> String {
 			},
 		);
 	out.push_str(&hash_map_runtime);
+	// Development builds already register every box and structural value for
+	// `echo`; release builds recognize them by prototype and shape instead.
+	if echo {
+		out.push_str("function nymphRenderIsBox(value) { return nymphEchoBoxes.has(value); }\nfunction nymphRenderShape(value) { return nymphEchoStructuralShapes.get(value); }\n");
+	} else {
+		out.push_str("function nymphRenderIsBox(value) { return value instanceof NBox; }\nfunction nymphRenderShape(value) { return Object.hasOwn(value, NYMPH_STRUCTURAL_SHAPE) ? value[NYMPH_STRUCTURAL_SHAPE] : undefined; }\n");
+	}
+	out.push_str(RENDER_RUNTIME);
 	if echo {
 		out.push_str(ECHO_RUNTIME);
 	}
@@ -140,6 +149,24 @@ function nymphUnboxOpaque(identity, value) {\n\
 \tif (NYMPH_OPAQUE_IDENTITY.get(value) !== identity) throw new TypeError(\"opaque external identity mismatch\");\n\
 \treturn value.v;\n\
 }\n\
+function nymphHostBox(shape, value) {\n\
+\tif (shape.endsWith(\"[]\")) {\n\
+\t\tif (!Array.isArray(value)) throw new TypeError(\"host binding must produce an array\");\n\
+\t\treturn new NList(value.map((item) => nymphHostBox(shape.slice(0, -2), item)));\n\
+\t}\n\
+\tswitch (shape) {\n\
+\t\tcase \"int\": return new NInt(nymphTrustedInt(value));\n\
+\t\tcase \"uint\": return new NUint(nymphTrustedUInt(value));\n\
+\t\tcase \"float\": return new NFloat(value);\n\
+\t\tcase \"char\": return new NChar(value);\n\
+\t\tcase \"string\": return new NString(value);\n\
+\t\tcase \"boolean\": return new NBool(value);\n\
+\t\tdefault: return undefined;\n\
+\t}\n\
+}\n\
+function nymphHostArray(value) {\n\
+\treturn Array.from(value.v, (item) => item.v);\n\
+}\n\
 function nymphCheckedShift(value, count, left) {\n\
 \tif (typeof count !== \"bigint\" || count < 0n || count >= 64n) throw new RangeError(\"integer shift count must be in 0..63\");\n\
 \treturn left ? value << count : value >> count;\n\
@@ -205,11 +232,11 @@ function nymphCharCode(value) {\n\
 }\n",
 	);
 	if export {
-		out.push_str("export { NymphRange, nymphStructuralValue, nymphProtocolDisplay, nymphProtocolDebug, nymphProtocolDisplayStep, nymphPrintStep, nymphPrintlnStep, ");
+		out.push_str("export { NymphRange, nymphStructuralValue, nymphProtocolDisplay, nymphProtocolDebug, nymphDisplay, nymphDebug, nymphHash, nymphProtocolDisplayStep, nymphPrintStep, nymphPrintlnStep, nymphEquals, nymphAssertionError, ");
 		if echo {
 			out.push_str("nymphEcho, ");
 		}
-		out.push_str("nymphTransactionBegin, nymphTransactionCommit, nymphTransactionRollback, nymphSetProperty, nymphDeleteProperty, nymphAssign, nymphSetPrototypeOf, nymphArraySplice, nymphArrayPush, nymphArrayPop, nymphArraySetLength, nymphMapSet, nymphWeakMapSet, nymphRuntimeClass, nymphRuntimeEnum, nymphHostIndex, nymphListSlice, nymphStringSlice, nymphFloatToInteger, nymphIntegerToFloat, nymphCheckedDivide, nymphCharCode, nymphCheckedShift, nymphCheckedPower, nymphTrustedInt, nymphTrustedUInt, nymphBoxOpaque, nymphUnboxOpaque, nymphActivate, nymphCaptureFrame, nymphCallable, nymphMarkCallable, nymphMethodStep, nymphPush, nymphTailCall, nymphTailCallMember, nymphReturn, nymphSuspend, nymphDefect, nymphResume, nymphRegisterCleanup, nymphEnterCleanupScope, nymphLeaveCleanupScope, nymphUnwindCleanupScopes, nymphCommitStateTransition, nymphTaskRecipe, nymphTaskDrive, nymphTaskSpawn, nymphHandleObserve, nymphHandleCancel, nymphCheckpoint, nymphCurrentExecutionSignal, nymphTaskSelect, nymphTaskRace, nymphStartRoot, nymphRenderDefect, nymphRunTask };\n");
+		out.push_str("nymphTransactionBegin, nymphTransactionCommit, nymphTransactionRollback, nymphSetProperty, nymphDeleteProperty, nymphAssign, nymphSetPrototypeOf, nymphArraySplice, nymphArrayPush, nymphArrayPop, nymphArraySetLength, nymphMapSet, nymphWeakMapSet, nymphRuntimeClass, nymphRuntimeEnum, nymphHostIndex, nymphListSlice, nymphStringSlice, nymphFloatToInteger, nymphIntegerToFloat, nymphCheckedDivide, nymphCharCode, nymphCheckedShift, nymphCheckedPower, nymphTrustedInt, nymphTrustedUInt, nymphBoxOpaque, nymphUnboxOpaque, nymphHostBox, nymphHostArray, nymphHostTask, nymphHostSignalTask, nymphActivate, nymphCaptureFrame, nymphCallable, nymphMarkCallable, nymphMethodStep, nymphPush, nymphTailCall, nymphTailCallMember, nymphReturn, nymphSuspend, nymphDefect, nymphResume, nymphRegisterCleanup, nymphEnterCleanupScope, nymphLeaveCleanupScope, nymphUnwindCleanupScopes, nymphCommitStateTransition, nymphTaskRecipe, nymphTaskDrive, nymphTaskSpawn, nymphHandleObserve, nymphHandleCancel, nymphCheckpoint, nymphCurrentExecutionSignal, nymphTaskSelect, nymphTaskRace, nymphStartRoot, nymphRenderDefect, nymphRunTask };\n");
 	}
 	for (class, _) in BOX_CLASSES {
 		if *class == "NMap" {
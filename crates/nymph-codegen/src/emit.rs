@@ -1,8 +1,8 @@
 use oxc::{
 	allocator::{Allocator, Box as ArenaBox, CloneIn, Vec as ArenaVec},
 	ast::{AstBuilder, ast::*},
-	codegen::Codegen,
-	span::SPAN,
+	codegen::{Codegen, CodegenOptions},
+	span::{GetSpanMut, SPAN, Span},
 	syntax::number::BigintBase,
 };
 
@@ -15,8 +15,8 @@ use nymph_hir::hir::{
 	NumKind, OperationMode, ScalarCastKind, UnOp,
 };
 
-use crate::EchoEmission;
 use crate::box_rt;
+use crate::{EchoEmission, SourcePositions};
 
 fn external_alias(module: &str, symbol: &str, kind: &str) -> String {
 	fn encode(value: &str) -> String {
@@ -31,6 +31,46 @@ fn external_alias(module: &str, symbol: &str, kind: &str) -> String {
 	format!("$nymph_external${kind}${module}${symbol}")
 }
 
+/// The box class a raw host value of `marshal`'s shape is wrapped in.
+fn marshal_box_class(marshal: nymph_hir::hir::MarshalKind) -> &'static str {
+	use nymph_hir::hir::MarshalKind;
+	match marshal {
+		MarshalKind::Int => "NInt",
+		MarshalKind::UInt => "NUint",
+		MarshalKind::Float => "NFloat",
+		MarshalKind::Char => "NChar",
+		MarshalKind::String => "NString",
+		MarshalKind::Boolean => "NBool",
+		MarshalKind::List => "NList",
+		MarshalKind::Tuple => "NTuple",
+		MarshalKind::Map => "NMap",
+		MarshalKind::Array(_) => "NList",
+		MarshalKind::Opaque(_) => unreachable!("opaque host values are boxed by identity"),
+		MarshalKind::Promise(_) => unreachable!("host promises are driven as tasks"),
+	}
+}
+
+/// The shape `nymphHostBox` converts a raw host value of `marshal`'s kind to.
+fn host_shape(marshal: nymph_hir::hir::MarshalKind) -> String {
+	use nymph_hir::hir::{MarshalKind, PromiseMarshal, ScalarMarshal};
+	let scalar = |scalar| match scalar {
+		ScalarMarshal::Int => "int",
+		ScalarMarshal::UInt => "uint",
+		ScalarMarshal::Float => "float",
+		ScalarMarshal::Char => "char",
+		ScalarMarshal::String => "string",
+		ScalarMarshal::Boolean => "boolean",
+	};
+	match marshal {
+		MarshalKind::Array(element) | MarshalKind::Promise(PromiseMarshal::Array(element)) => {
+			format!("{}[]", scalar(element))
+		}
+		MarshalKind::Promise(PromiseMarshal::Scalar(element)) => scalar(element).to_string(),
+		MarshalKind::Promise(PromiseMarshal::Void) => "void".to_string(),
+		other => marshal_box_class(other).to_string(),
+	}
+}
+
 /// A re-emittable reference to a sub-value of the scrutinee, used while compiling a
 /// pattern. oxc expression nodes are arena values that can't be cheaply cloned, so
 /// pattern bindings and tests carry a `Subject` (which re-emits a fresh expression
@@ -220,6 +260,45 @@ fn pattern_binding_names(pattern: &HirPat, names: &mut std::collections::BTreeSe
 	}
 }
 
+/// Move every generated position in `map` down by `lines`, for text prepended
+/// ahead of the code the map was built from.
+pub(crate) fn offset_source_map(
+	map: oxc_sourcemap::SourceMap<'_>,
+	lines: u32,
+) -> oxc_sourcemap::SourceMap<'_> {
+	if lines == 0 {
+		return map;
+	}
+	let mut parts = map.into_parts();
+	parts.tokens = parts
+		.tokens
+		.iter()
+		.map(|token| {
+			oxc_sourcemap::Token::new(
+				token.get_dst_line() + lines,
+				token.get_dst_col(),
+				token.get_src_line(),
+				token.get_src_col(),
+				token.get_source_id(),
+				token.get_name_id(),
+			)
+		})
+		.collect();
+	parts.token_chunks = None;
+	oxc_sourcemap::SourceMap::from_parts(parts)
+}
+
+/// The body node an activation transfer was lowered from, if any.
+fn activation_source(call: &HirExpr) -> Option<u32> {
+	match call {
+		HirExpr::ActivationCall { source, .. }
+		| HirExpr::StaticEnumDispatch { source, .. }
+		| HirExpr::BoundDispatch { source, .. }
+		| HirExpr::UnaryBoundDispatch { source, .. } => Some(*source),
+		_ => None,
+	}
+}
+
 fn activation_protocol_step(module: &str, symbol: &str) -> Option<&'static str> {
 	match (module, symbol) {
 		("std/io", "print") => Some("nymphPrintStep"),
@@ -2151,6 +2230,14 @@ pub struct Emitter<'a> {
 	current_module: Option<String>,
 	representation: RepresentationPolicy,
 	echo_emission: EchoEmission,
+	/// Original positions to map emitted code back to, when building a source
+	/// map. Emitted nodes otherwise carry empty spans and produce no mappings.
+	source_positions: Option<SourcePositions>,
+	/// Positions of the body being emitted, indexed by activation source id.
+	body_positions: std::cell::RefCell<Option<std::sync::Arc<[std::ops::Range<u32>]>>>,
+	/// The class or enum whose methods are being emitted; methods are keyed by
+	/// `Owner.member` in [`SourcePositions::bodies`].
+	position_owner: std::cell::RefCell<Option<EcoString>>,
 }
 
 impl<'a> Emitter<'a> {
@@ -2177,6 +2264,9 @@ impl<'a> Emitter<'a> {
 				source_uri: None,
 				source: String::new(),
 			},
+			source_positions: None,
+			body_positions: std::cell::RefCell::new(None),
+			position_owner: std::cell::RefCell::new(None),
 		}
 	}
 
@@ -2196,6 +2286,35 @@ impl<'a> Emitter<'a> {
 		self
 	}
 
+	pub fn with_source_positions(mut self, positions: SourcePositions) -> Self {
+		self.source_positions = Some(positions);
+		self
+	}
+
+	/// Emit `emit` with the positions of the body bound to `key` in scope, so
+	/// activation calls inside it map back to their source expressions.
+	fn with_body_positions<T>(&self, key: &str, emit: impl FnOnce() -> T) -> T {
+		let positions = self
+			.source_positions
+			.as_ref()
+			.and_then(|positions| positions.bodies.get(key).cloned());
+		let outer = self.body_positions.replace(positions);
+		let emitted = emit();
+		self.body_positions.replace(outer);
+		emitted
+	}
+
+	/// The original span of body node `source` in the body being emitted, or
+	/// an empty span when there is none.
+	fn source_span(&self, source: u32) -> Span {
+		self
+			.body_positions
+			.borrow()
+			.as_ref()
+			.and_then(|positions| positions.get(source as usize))
+			.map_or(SPAN, |range| Span::new(range.start, range.end))
+	}
+
 	pub fn for_project_module(alloc: &'a Allocator, module: &str) -> Self {
 		let mut emitter = Self::for_module(alloc, module);
 		emitter.import_box_runtime = true;
@@ -2363,6 +2482,12 @@ impl<'a> Emitter<'a> {
 	}
 
 	pub fn emit_module(&self, module: &HirModule) -> String {
+		self.emit_module_with_source_map(module).0
+	}
+
+	/// Emit `module`, with its JSON source map when source positions were
+	/// supplied.
+	pub fn emit_module_with_source_map(&self, module: &HirModule) -> (String, Option<String>) {
 		self.class_defaults.borrow_mut().extend(
 			module
 				.classes
@@ -2437,27 +2562,41 @@ impl<'a> Emitter<'a> {
 			with_imports.extend(stmts);
 			stmts = with_imports;
 		}
+		// Mapped spans index the original `.nym` text, so the program carries it
+		// as its source text for the source map builder's line tables.
 		let program = Program::new(
 			SPAN,
 			SourceType::mjs(),
-			"",
+			self
+				.source_positions
+				.as_ref()
+				.map_or("", |positions| positions.source.as_str()),
 			ArenaVec::new_in(&self.ast),
 			None,
 			ArenaVec::new_in(&self.ast),
 			stmts,
 			&self.ast,
 		);
-		let code = Codegen::new().build(&program).code;
+		let built = Codegen::new()
+			.with_options(CodegenOptions {
+				source_map_path: self
+					.source_positions
+					.as_ref()
+					.map(|positions| positions.source_name.clone().into()),
+				..CodegenOptions::default()
+			})
+			.build(&program);
+		let code = built.code;
 		// Uniform value boxing (slice #2): standalone modules carry the runtime
 		// inline for direct Node execution, while project modules import their
 		// exact bindings from the canonical virtual module. Modules that never
 		// reference the runtime remain byte-identical.
 		let box_runtime_bindings = self.box_runtime_bindings.borrow();
-		if box_runtime_bindings.is_empty() {
-			code
+		let prefix = if box_runtime_bindings.is_empty() {
+			String::new()
 		} else if self.import_box_runtime {
 			format!(
-				"import {{ {} }} from \"{}\";\n{code}",
+				"import {{ {} }} from \"{}\";\n",
 				box_runtime_bindings
 					.iter()
 					.cloned()
@@ -2465,14 +2604,16 @@ impl<'a> Emitter<'a> {
 					.join(", "),
 				box_rt::BOX_MODULE_KEY,
 			)
+		} else if box_runtime_bindings.contains("nymphEcho") {
+			box_rt::box_preamble()
 		} else {
-			let preamble = if box_runtime_bindings.contains("nymphEcho") {
-				box_rt::box_preamble()
-			} else {
-				box_rt::box_preamble_release()
-			};
-			format!("{preamble}{code}")
-		}
+			box_rt::box_preamble_release()
+		};
+		let map = built.map.map(|map| {
+			let lines = prefix.matches('\n').count() as u32;
+			offset_source_map(map, lines).to_json_string()
+		});
+		(format!("{prefix}{code}"), map)
 	}
 
 	/// Build `import { <symbol> as <local> } from "<module_specifier>";` (Gap 3, L0).
@@ -2835,11 +2976,15 @@ impl<'a> Emitter<'a> {
 					call,
 					resume_state,
 					result_slot,
-				} => statements.push(Statement::new_return_statement(
-					SPAN,
-					Some(self.emit_activation_transfer(&call, resume_state, result_slot)),
-					&self.ast,
-				)),
+				} => {
+					let span = activation_source(&call).map_or(SPAN, |source| self.source_span(source));
+					let transfer = self.emit_activation_transfer(&call, resume_state, result_slot);
+					statements.push(Statement::new_return_statement(
+						span,
+						Some(transfer),
+						&self.ast,
+					));
+				}
 				ActivationTerminal::Return(value) => statements.push(Statement::new_return_statement(
 					SPAN,
 					Some(self.runtime_call("nymphReturn", vec![self.emit_expr(&value)])),
@@ -2963,13 +3108,21 @@ impl<'a> Emitter<'a> {
 	}
 
 	fn emit_func(&self, func: &HirFunc) -> Statement<'a> {
-		let callable = self.activation_callable(&func.params, &func.body, false);
-		self.plain_decl(&func.name, callable, VariableDeclarationKind::Let)
+		self.with_body_positions(&func.name, || {
+			let callable = self.activation_callable(&func.params, &func.body, false);
+			let mut declaration = self.plain_decl(&func.name, callable, VariableDeclarationKind::Let);
+			*declaration.span_mut() = self.source_span(0);
+			declaration
+		})
 	}
 
 	/// Emit an immutable top-level binding.
 	fn emit_module_let(&self, let_: &HirLet) -> Statement<'a> {
-		self.binding_declaration(&let_.name, self.emit_expr(&let_.value))
+		self.with_body_positions(&let_.name, || {
+			let mut declaration = self.binding_declaration(&let_.name, self.emit_expr(&let_.value));
+			*declaration.span_mut() = self.source_span(0);
+			declaration
+		})
 	}
 
 	/// Emit a struct as `class <Name> { constructor(fields) { … } }`.
@@ -3110,12 +3263,14 @@ impl<'a> Emitter<'a> {
 
 		let mut elements = ArenaVec::new_in(&self.ast);
 		elements.push(ctor);
+		let outer_owner = self.position_owner.replace(Some(class.name.clone()));
 		for method in &class.methods {
 			elements.push(self.emit_method(method, false));
 		}
 		for method in &class.statics {
 			elements.push(self.emit_method(method, true));
 		}
+		self.position_owner.replace(outer_owner);
 		let body = ClassBody::new(SPAN, elements, &self.ast);
 		let class_name = class.name.to_string();
 		let name = BindingIdentifier::new(SPAN, self.ast.allocator.alloc_str(&class.name), &self.ast);
@@ -3183,9 +3338,15 @@ impl<'a> Emitter<'a> {
 	/// Deliberately a plain function, never an arrow: prototype methods need
 	/// their own `this` bound to the receiver at call time.
 	fn method_function(&self, method: &HirMethod) -> ArenaBox<'a, Function<'a>> {
+		let key = self
+			.position_owner
+			.borrow()
+			.as_ref()
+			.map(|owner| format!("{owner}.{}", method.name))
+			.unwrap_or_default();
 		let plan = ActivationPlanner::new(self, &method.params, std::collections::BTreeMap::new())
 			.finish(&method.body);
-		let step = self.emit_activation_plan(plan);
+		let step = self.with_body_positions(&key, || self.emit_activation_plan(plan));
 		let bridge = self.runtime_call(
 			"nymphMethodStep",
 			vec![
@@ -3354,6 +3515,7 @@ impl<'a> Emitter<'a> {
 			.insert("nymphStructuralValue".to_string());
 		let mut stmts = ArenaVec::new_in(&self.ast);
 		let has_methods = true;
+		let outer_owner = self.position_owner.replace(Some(hir_enum.name.clone()));
 		// The prototype is also the enum's canonical runtime type object, so it
 		// exists even when there are no instance methods.
 		stmts.push(self.emit_enum_proto(&hir_enum.methods));
@@ -3442,6 +3604,7 @@ impl<'a> Emitter<'a> {
 		for method in &hir_enum.statics {
 			props.push(self.emit_method_property(method));
 		}
+		self.position_owner.replace(outer_owner);
 		// The canonical enum prototype is also its compiler-only runtime type
 		// object. Exposing this unspellable property lets hidden generic arguments
 		// share the exact object used by every variant instance.
@@ -4832,6 +4995,7 @@ impl<'a> Emitter<'a> {
 				let callee_name = self.route_module_symbol(module, symbol, true);
 				let callee =
 					Expression::new_identifier(SPAN, self.ast.allocator.alloc_str(&callee_name), &self.ast);
+				let host = *call_mode == nymph_hir::hir::ExternalCallMode::Host;
 				let mut arguments = ArenaVec::new_in(&self.ast);
 				for (arg, marshal) in args.iter().zip(argument_marshals) {
 					let argument = self.emit_expr(arg);
@@ -4839,6 +5003,17 @@ impl<'a> Emitter<'a> {
 						Some(nymph_hir::hir::MarshalKind::Int | nymph_hir::hir::MarshalKind::UInt) => {
 							self.unwrap_v(argument)
 						}
+						// Every other scalar keeps its box on the trusted stdlib ABI; a
+						// host binding only ever sees raw values.
+						Some(
+							nymph_hir::hir::MarshalKind::Float
+							| nymph_hir::hir::MarshalKind::Char
+							| nymph_hir::hir::MarshalKind::String
+							| nymph_hir::hir::MarshalKind::Boolean,
+						) if host => self.unwrap_v(argument),
+						Some(nymph_hir::hir::MarshalKind::Array(_)) => {
+							self.runtime_call("nymphHostArray", vec![argument])
+						}
 						Some(nymph_hir::hir::MarshalKind::Opaque(identity)) => {
 							let identity = self.bigint_literal(identity);
 							self.runtime_call("nymphUnboxOpaque", vec![identity, argument])
@@ -4873,6 +5048,33 @@ impl<'a> Emitter<'a> {
 						let identity = self.bigint_literal(identity);
 						self.runtime_call("nymphBoxOpaque", vec![identity, call])
 					}
+					Some(
+						marshal @ (nymph_hir::hir::MarshalKind::Float
+						| nymph_hir::hir::MarshalKind::Char
+						| nymph_hir::hir::MarshalKind::String
+						| nymph_hir::hir::MarshalKind::Boolean),
+					) if host => self.new_box(marshal_box_class(*marshal), call),
+					Some(marshal @ nymph_hir::hir::MarshalKind::Array(_)) => {
+						let shape = Expression::new_string_literal(
+							SPAN,
+							self.ast.allocator.alloc_str(&host_shape(*marshal)),
+							None,
+							&self.ast,
+						);
+						self.runtime_call("nymphHostBox", vec![shape, call])
+					}
+					// The promise is only created once the task is driven, so a
+					// never-driven task never starts the host operation.
+					Some(marshal @ nymph_hir::hir::MarshalKind::Promise(_)) => {
+						let shape = Expression::new_string_literal(
+							SPAN,
+							self.ast.allocator.alloc_str(&host_shape(*marshal)),
+							None,
+							&self.ast,
+						);
+						let start = self.zero_argument_arrow(call);
+						self.runtime_call("nymphHostTask", vec![start, shape])
+					}
 					_ => call,
 				}
 			}
@@ -4902,21 +5104,18 @@ impl<'a> Emitter<'a> {
 						let identity = self.bigint_literal(identity);
 						return self.runtime_call("nymphBoxOpaque", vec![identity, raw]);
 					}
+					nymph_hir::hir::MarshalKind::Array(_) => {
+						let shape = Expression::new_string_literal(
+							SPAN,
+							self.ast.allocator.alloc_str(&host_shape(*marshal)),
+							None,
+							&self.ast,
+						);
+						return self.runtime_call("nymphHostBox", vec![shape, raw]);
+					}
 					_ => raw,
 				};
-				let class = match marshal {
-					nymph_hir::hir::MarshalKind::Int => "NInt",
-					nymph_hir::hir::MarshalKind::UInt => "NUint",
-					nymph_hir::hir::MarshalKind::Float => "NFloat",
-					nymph_hir::hir::MarshalKind::Char => "NChar",
-					nymph_hir::hir::MarshalKind::String => "NString",
-					nymph_hir::hir::MarshalKind::Boolean => "NBool",
-					nymph_hir::hir::MarshalKind::List => "NList",
-					nymph_hir::hir::MarshalKind::Tuple => "NTuple",
-					nymph_hir::hir::MarshalKind::Map => "NMap",
-					nymph_hir::hir::MarshalKind::Opaque(_) => unreachable!(),
-				};
-				self.new_box(class, raw)
+				self.new_box(marshal_box_class(*marshal), raw)
 			}
 			HirExpr::BoundDispatch {
 				method,
@@ -2,6 +2,7 @@
 
 #![warn(clippy::all)]
 
+mod bindgen;
 mod box_rt;
 mod emit;
 mod strip;
@@ -9,6 +10,7 @@ mod strip;
 use nymph_hir::hir::HirModule;
 use oxc::allocator::Allocator;
 
+pub use bindgen::{Bindings, UnmappedDeclaration, generate_bindings};
 pub use box_rt::{
 	BOX_MODULE_DECLARATIONS, BOX_MODULE_KEY, box_module_declarations, box_module_source,
 	box_module_source_with_option_enum, box_module_source_with_option_enum_release, box_preamble,
@@ -26,6 +28,20 @@ pub enum EchoEmission {
 	Release,
 }
 
+/// Original `.nym` positions for one emitted module, from which project
+/// emission builds a v3 source map back to that file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SourcePositions {
+	/// The `sources` entry the map records for the module.
+	pub source_name: String,
+	/// The module's source text, embedded as the map's `sourcesContent`.
+	pub source: String,
+	/// Byte ranges of every expression in each emitted body, indexed by the
+	/// body-node ids activation calls carry. Keyed by binding name, or by
+	/// `Owner.member` for methods.
+	pub bodies: std::collections::HashMap<String, std::sync::Arc<[std::ops::Range<u32>]>>,
+}
+
 /// Emit an ES module string for `module`.
 pub fn emit(module: &HirModule) -> String {
 	let allocator = Allocator::default();
@@ -78,6 +94,33 @@ pub fn emit_for_project_module_with_imports_and_echo(
 		.emit_module(module)
 }
 
+/// [`emit_for_project_module_with_imports_and_echo`], also returning the JSON
+/// source map from the emitted module back to `positions.source`.
+#[must_use]
+pub fn emit_for_project_module_with_source_map(
+	module: &HirModule,
+	module_key: &str,
+	imports: &[(String, String, String)],
+	echo: EchoEmission,
+	positions: SourcePositions,
+) -> (String, String) {
+	let allocator = Allocator::default();
+	let (source, map) = emit::Emitter::for_project_module(&allocator, module_key)
+		.with_needed_imports(imports)
+		.with_echo_emission(echo)
+		.with_source_positions(positions)
+		.emit_module_with_source_map(module);
+	(source, map.expect("source positions always produce a map"))
+}
+
+/// Shift every generated position in the JSON source map `map` down by
+/// `lines`, for a caller that prepends that many lines to the emitted module.
+#[must_use]
+pub fn offset_source_map(map: &str, lines: u32) -> String {
+	let map = oxc_sourcemap::SourceMap::from_json_string(map).expect("an emitted source map");
+	emit::offset_source_map(map, lines).to_json_string()
+}
+
 /// Emit a project module whose Nymph `let` bindings and observable mutations
 /// participate in the synchronous runtime journal. Imported top-level `let`
 /// local names must be supplied because they are cells owned by another ESM.
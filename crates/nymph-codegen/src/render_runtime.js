@@ -0,0 +1,123 @@
+// The recursive structure renderer behind `echo` observations and `std/test`
+// assertion failures. Only values the profile's `nymphRenderIsBox` and
+// `nymphRenderShape` recognize are opened up; anything else renders as a
+// placeholder, so rendering never calls into user-supplied code.
+function nymphRenderPlaceholder(value) {
+	if (typeof value === "function") return "<function>";
+	return "<opaque external>";
+}
+
+// A negative depth renders on one line; otherwise every element of a
+// non-empty collection or structure gets its own line, indented one step
+// deeper than `depth`.
+function nymphRenderGroup(open, items, close, depth) {
+	if (depth < 0 || items.length === 0) return `${open}${items.join(", ")}${close}`;
+	const indent = "  ".repeat(depth + 1);
+	return `${open}\n${items.map((item) => `${indent}${item},\n`).join("")}${"  ".repeat(depth)}${close}`;
+}
+
+function nymphRenderStructure(value, depth = -1, seen = new WeakSet()) {
+	if (value === undefined) return "void";
+	if (value === null) return "<opaque external>";
+	if (typeof value === "function") return "<function>";
+	if (typeof value !== "object") return nymphRenderPlaceholder(value);
+	if (seen.has(value)) return "<cycle>";
+	seen.add(value);
+	const inner = depth < 0 ? -1 : depth + 1;
+	const render = (item) => nymphRenderStructure(item, inner, seen);
+	try {
+		if (nymphRenderIsBox(value)) {
+			const tag = value[NYMPH_TAG]?.description;
+			if (tag === "nymph.int" || tag === "nymph.uint" || tag === "nymph.bool")
+				return String(value.v);
+			if (tag === "nymph.float")
+				return Number.isInteger(value.v) ? value.v.toFixed(1) : String(value.v);
+			if (tag === "nymph.char")
+				return `'${JSON.stringify(value.v).slice(1, -1).replaceAll("'", "\\'")}'`;
+			if (tag === "nymph.string") return JSON.stringify(value.v);
+			if (tag === "nymph.list") return nymphRenderGroup("#[", [...value.v].map(render), "]", depth);
+			if (tag === "nymph.tuple") return nymphRenderGroup("#(", value.v.map(render), ")", depth);
+			if (tag === "nymph.map")
+				return nymphRenderGroup(
+					"#{",
+					[...value.v].map(([key, item]) => `${render(key)}: ${render(item)}`),
+					"}",
+					depth,
+				);
+			return "<opaque external>";
+		}
+		const shape = nymphRenderShape(value);
+		if (shape === undefined) return "<opaque external>";
+		// Module-mangled owners (`$m3$Result.Ok`) render by their source name.
+		const displayName = shape.identity.split(":", 2)[1].split("$").at(-1);
+		if (shape.fields.length === 0) return displayName;
+		return nymphRenderGroup(
+			`${displayName}(`,
+			shape.fields.map((field) => `${field}: ${render(value[field])}`),
+			")",
+			depth,
+		);
+	} finally {
+		seen.delete(value);
+	}
+}
+
+// Values that render wider than this on one line are laid out one element per
+// line, and two unequal values of that size are shown as a line diff.
+const NYMPH_RENDER_WIDTH = 60;
+
+function nymphRenderSafely(value, depth) {
+	try {
+		return nymphRenderStructure(value, depth);
+	} catch {
+		return nymphRenderPlaceholder(value);
+	}
+}
+
+// A longest-common-subsequence diff: a two-space prefix keeps a line both
+// sides share, `-` marks one only the left has, and `+` one only the right has.
+function nymphRenderLineDiff(left, right) {
+	const common = Array.from({ length: left.length + 1 }, () => new Array(right.length + 1).fill(0));
+	for (let i = left.length - 1; i >= 0; i--) {
+		for (let j = right.length - 1; j >= 0; j--) {
+			common[i][j] =
+				left[i] === right[j]
+					? common[i + 1][j + 1] + 1
+					: Math.max(common[i + 1][j], common[i][j + 1]);
+		}
+	}
+	const lines = [];
+	let i = 0;
+	let j = 0;
+	while (i < left.length || j < right.length) {
+		if (i < left.length && j < right.length && left[i] === right[j]) {
+			lines.push(`  ${left[i++]}`);
+			j++;
+		} else if (i < left.length && (j === right.length || common[i + 1][j] >= common[i][j + 1])) {
+			lines.push(`- ${left[i++]}`);
+		} else {
+			lines.push(`+ ${right[j++]}`);
+		}
+	}
+	return lines;
+}
+
+// The defect a failed `std/test` assertion throws. `values` pairs each label
+// with the value it names; a differing `left`/`right` pair too wide for one
+// line is shown as a diff instead.
+function nymphAssertionError(condition, values) {
+	const wide = values.some(([, value]) => nymphRenderSafely(value, -1).length > NYMPH_RENDER_WIDTH);
+	const rendered = values.map(([label, value]) => [label, nymphRenderSafely(value, wide ? 0 : -1)]);
+	let body;
+	if (wide && rendered.length === 2 && rendered[0][1] !== rendered[1][1]) {
+		const lines = nymphRenderLineDiff(rendered[0][1].split("\n"), rendered[1][1].split("\n"));
+		body = `diff (- ${rendered[0][0]}, + ${rendered[1][0]}):\n${lines.join("\n")}`;
+	} else {
+		body = rendered
+			.map(([label, text]) => `${label.padStart(6)}: ${text.replaceAll("\n", "\n        ")}`)
+			.join("\n");
+	}
+	const error = new Error(`assertion \`${condition}\` failed\n${body}`);
+	error.name = "AssertionError";
+	return error;
+}
@@ -321,6 +321,16 @@ function nymphTaskRecipe(callable, nestedContext) {
 	});
 }
 
+function nymphHostTask(start, shape) {
+	return nymphTask((frame) =>
+		nymphAwaitCancellable(frame, start()).then((value) => nymphHostBox(shape, value)),
+	);
+}
+
+function nymphHostSignalTask(start) {
+	return nymphTask((frame) => nymphAwaitCancellable(frame, start(frame.signal)));
+}
+
 function nymphTaskDrive(task) {
 	return task.drive(nymphCurrentExecutionFrame());
 }
@@ -216,7 +216,7 @@ fn duplicate_external_value_declarations_share_one_import_and_box() {
 	};
 	let js = emit(&module);
 	assert_eq!(js.matches("from \"host/limits\"").count(), 1, "{js}");
-	assert_eq!(js.matches("new NFloat(").count(), 1, "{js}");
+	assert_eq!(js.matches("new NFloat($nymph_external").count(), 1, "{js}");
 	assert!(js.contains("const second = first"), "{js}");
 }
 
@@ -0,0 +1,98 @@
+//! Descriptions of every diagnostic the compiler can report, for tools that
+//! explain codes without having a diagnostic in hand (rule metadata in SARIF
+//! exports, for instance).
+//!
+//! Typed diagnostics are described by the [`ErrorCode`] catalogs of the lexer,
+//! parser, and checker. Lints are reported under their `[lints]` name instead
+//! of a numeric code and are listed in [`LINTS`].
+
+use nymph_diagnostics::{CatalogEntry, ErrorCode};
+
+use crate::LintLevel;
+
+/// A lint configurable from a manifest's `[lints]` table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lint {
+	/// The `[lints]` key, which is also the code its diagnostics carry.
+	pub name: &'static str,
+	/// The level applied when no manifest configures the lint.
+	pub default_level: LintLevel,
+	pub summary: &'static str,
+}
+
+/// Every lint, by name.
+pub const LINTS: &[Lint] = &[
+	Lint {
+		name: "echo-in-release",
+		default_level: LintLevel::Warn,
+		summary: "An `echo` observation in a release build, where it is erased.",
+	},
+	Lint {
+		name: "managed-field",
+		default_level: LintLevel::Warn,
+		summary: "A type directly stores a managed resource but does not implement `Close`.",
+	},
+	Lint {
+		name: "managed-child-capture",
+		default_level: LintLevel::Warn,
+		summary: "A spawned child captures a managed local beyond its cleanup boundary.",
+	},
+	Lint {
+		name: "deprecated",
+		default_level: LintLevel::Warn,
+		summary: "A use of a dependency's definition marked `@deprecated`.",
+	},
+	Lint {
+		name: "unused-variable",
+		default_level: LintLevel::Warn,
+		summary: "A local binding that is never read.",
+	},
+	Lint {
+		name: "unused-import",
+		default_level: LintLevel::Warn,
+		summary: "An imported name, or a whole import, that nothing in the module uses.",
+	},
+	Lint {
+		name: "unused-private-function",
+		default_level: LintLevel::Warn,
+		summary: "A `private` function that nothing but itself calls.",
+	},
+	Lint {
+		name: "unused-parameter",
+		default_level: LintLevel::Warn,
+		summary: "A function or closure parameter the body never reads.",
+	},
+	Lint {
+		name: "unused-type-parameter",
+		default_level: LintLevel::Warn,
+		summary: "A function's type parameter that neither its signature nor its body mentions.",
+	},
+	Lint {
+		name: "unused-result",
+		default_level: LintLevel::Warn,
+		summary: "A `Result` value discarded as a statement.",
+	},
+];
+
+/// The typed diagnostic catalogs of lexing, parsing, and checking, in code order.
+pub fn error_catalog() -> impl Iterator<Item = &'static CatalogEntry> {
+	nymph_syntax::LexError::CATALOG
+		.iter()
+		.chain(nymph_syntax::ParseError::CATALOG)
+		.chain(nymph_sema::TypeError::CATALOG)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::error_catalog;
+
+	#[test]
+	fn error_catalog_codes_are_unique_and_ordered() {
+		let codes = error_catalog().map(|entry| entry.code).collect::<Vec<_>>();
+		assert!(codes.windows(2).all(|pair| pair[0] < pair[1]), "{codes:?}");
+		assert!(
+			error_catalog().any(|entry| entry.code == 2027 && entry.name == "MismatchedTypes"),
+			"the checker's mismatched-type error keeps code 2027"
+		);
+	}
+}
@@ -44,6 +44,7 @@ enum SourceProvider {
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub(crate) enum CompilerRuntimeRole {
 	Option,
+	Result,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -76,6 +77,11 @@ const OPTION: ImportDependency = ImportDependency {
 	destination: "std/option",
 	target: DependencyTarget::CompilerRuntimeRole(CompilerRuntimeRole::Option),
 };
+const RESULT: ImportDependency = ImportDependency {
+	source: "std/result",
+	destination: "std/result",
+	target: DependencyTarget::CompilerRuntimeRole(CompilerRuntimeRole::Result),
+};
 const HOST_MODULES: &[HostModuleDescriptor] = &[
 	HostModuleDescriptor {
 		module: "std/equality",
@@ -102,21 +108,51 @@ const HOST_MODULES: &[HostModuleDescriptor] = &[
 		provider: SourceProvider::EmbeddedTs(include_str!("../../../stdlib/src/collections/set.ts")),
 		dependencies: &[BOX],
 	},
+	HostModuleDescriptor {
+		module: "std/env",
+		provider: SourceProvider::EmbeddedTs(include_str!("../../../stdlib/src/env.ts")),
+		dependencies: &[BOX, OPTION],
+	},
+	HostModuleDescriptor {
+		module: "std/fs",
+		provider: SourceProvider::EmbeddedTs(include_str!("../../../stdlib/src/fs.ts")),
+		dependencies: &[BOX, OPTION, RESULT],
+	},
 	HostModuleDescriptor {
 		module: "std/io",
 		provider: SourceProvider::EmbeddedTs(include_str!("../../../stdlib/src/io.ts")),
 		dependencies: &[BOX],
 	},
+	HostModuleDescriptor {
+		module: "std/js",
+		provider: SourceProvider::EmbeddedTs(include_str!("../../../stdlib/src/js.ts")),
+		dependencies: &[BOX, OPTION],
+	},
+	HostModuleDescriptor {
+		module: "std/json",
+		provider: SourceProvider::EmbeddedTs(include_str!("../../../stdlib/src/json.ts")),
+		dependencies: &[BOX],
+	},
 	HostModuleDescriptor {
 		module: "std/math/intrinsics",
 		provider: SourceProvider::EmbeddedTs(include_str!("../../../stdlib/src/math/mod.ts")),
 		dependencies: &[BOX],
 	},
+	HostModuleDescriptor {
+		module: "std/process",
+		provider: SourceProvider::EmbeddedTs(include_str!("../../../stdlib/src/process.ts")),
+		dependencies: &[BOX, OPTION, RESULT],
+	},
 	HostModuleDescriptor {
 		module: "std/string",
 		provider: SourceProvider::EmbeddedTs(include_str!("../../../stdlib/src/string.ts")),
 		dependencies: &[BOX, OPTION],
 	},
+	HostModuleDescriptor {
+		module: "std/test",
+		provider: SourceProvider::EmbeddedTs(include_str!("../../../stdlib/src/test.ts")),
+		dependencies: &[BOX],
+	},
 	HostModuleDescriptor {
 		module: "std/box",
 		provider: SourceProvider::GeneratedBox,
@@ -220,7 +256,7 @@ static HOST_RUNTIME_GRAPH: OnceLock<HostRuntimeGraph> = OnceLock::new();
 /// file is fatal to bundling: an unrelated, still-unlinked `import` inside it
 /// would be a dangling specifier rolldown resolves eagerly, before
 /// tree-shaking ever gets a chance to drop it). Stable project emission
-/// separately supplies the canonical `std/option` module referenced by
+/// separately supplies the canonical `std/option` and `std/result` modules referenced by
 /// rewritten imports.
 ///
 /// Keyed by the SAME module specifier the registry names (e.g.
@@ -390,6 +426,11 @@ impl HostRuntimeGraph {
 		let Some(adapter) = abi.adapter() else {
 			return Ok(());
 		};
+		// A user-declared host binding names a module the host's own loader
+		// resolves; this graph only vouches for the compiler's embedded sources.
+		if nymph_ast::decl::host_binding(&abi.marker).is_some() {
+			return Ok(());
+		}
 		let expected = nymph_hir::linkage::lookup_value(&abi.marker)
 			.ok()
 			.filter(|value| {
@@ -437,20 +478,26 @@ impl HostRuntimeGraph {
 	pub(crate) const fn role_import_specifier(role: CompilerRuntimeRole) -> &'static str {
 		match role {
 			CompilerRuntimeRole::Option => "std/option",
+			CompilerRuntimeRole::Result => "std/result",
 		}
 	}
 
 	pub(crate) fn module_sources(
 		&self,
-		option_enum_name: &str,
-		option_module: &str,
+		option: (&str, &str),
+		result: (&str, &str),
 		echo: bool,
 	) -> FxHashMap<String, String> {
-		let option_import = if option_enum_name == "Option" {
-			format!("import {{ Option }} from \"{option_module}\";")
-		} else {
-			format!("import {{ {option_enum_name} as Option }} from \"{option_module}\";")
+		let option_enum_name = option.0;
+		let role_import = |role: &str, (name, module): (&str, &str)| {
+			if name == role {
+				format!("import {{ {role} }} from \"{module}\";")
+			} else {
+				format!("import {{ {name} as {role} }} from \"{module}\";")
+			}
 		};
+		let option_import = role_import("Option", option);
+		let result_import = role_import("Result", result);
 		let mut symbols: FxHashMap<&str, Vec<&str>> = FxHashMap::default();
 		for &(module, symbol) in self.exports.keys() {
 			symbols.entry(module).or_default().push(symbol);
@@ -467,7 +514,8 @@ impl HostRuntimeGraph {
 						.map(|dependency| (dependency.source, dependency.destination))
 						.collect::<Vec<_>>();
 					let source = nymph_codegen::strip_ts_to_js(source, &keep, &rewrites)
-						.replace("import { Option } from \"std/option\";", &option_import);
+						.replace("import { Option } from \"std/option\";", &option_import)
+						.replace("import { Result } from \"std/result\";", &result_import);
 					sources.insert(descriptor.module.to_string(), source);
 				}
 				SourceProvider::GeneratedBox => {
@@ -635,8 +683,8 @@ mod tests {
 	#[test]
 	fn generated_io_source_uses_the_box_protocol_boundary() {
 		let sources = HostRuntimeGraph::compiler_facts().module_sources(
-			"Option",
-			"@nymph/runtime/std/option",
+			("Option", "@nymph/runtime/std/option"),
+			("Result", "@nymph/runtime/std/result"),
 			false,
 		);
 		let io_js = sources
@@ -649,8 +697,8 @@ mod tests {
 	#[test]
 	fn math_source_exports_every_registry_symbol_through_the_canonical_box_runtime() {
 		let sources = HostRuntimeGraph::compiler_facts().module_sources(
-			"Option",
-			"@nymph/runtime/std/option",
+			("Option", "@nymph/runtime/std/option"),
+			("Result", "@nymph/runtime/std/result"),
 			false,
 		);
 		let math_js = sources
@@ -828,8 +876,8 @@ mod tests {
 	#[test]
 	fn injects_a_list_primitive_module_with_a_resolvable_option_import() {
 		let sources = HostRuntimeGraph::compiler_facts().module_sources(
-			"Option",
-			"@nymph/runtime/std/option",
+			("Option", "@nymph/runtime/std/option"),
+			("Result", "@nymph/runtime/std/result"),
 			false,
 		);
 		let list_js = sources
@@ -859,8 +907,8 @@ mod tests {
 	#[test]
 	fn injects_a_map_module_with_every_linked_symbol_and_a_resolvable_option_import() {
 		let sources = HostRuntimeGraph::compiler_facts().module_sources(
-			"Option",
-			"@nymph/runtime/std/option",
+			("Option", "@nymph/runtime/std/option"),
+			("Result", "@nymph/runtime/std/result"),
 			false,
 		);
 		let map_js = sources
@@ -900,8 +948,8 @@ mod tests {
 	#[test]
 	fn does_not_fabricate_the_canonical_option_module() {
 		let sources = HostRuntimeGraph::compiler_facts().module_sources(
-			"Option",
-			"@nymph/runtime/std/option",
+			("Option", "@nymph/runtime/std/option"),
+			("Result", "@nymph/runtime/std/result"),
 			false,
 		);
 		assert!(
@@ -912,7 +960,12 @@ mod tests {
 
 	#[test]
 	fn option_dependency_is_structured_and_module_specific() {
-		for module in ["std/collections/list", "std/collections/map", "std/string"] {
+		for module in [
+			"std/collections/list",
+			"std/collections/map",
+			"std/js",
+			"std/string",
+		] {
 			assert_eq!(
 				HostRuntimeGraph::compiler_facts()
 					.semantic_dependencies(module)
@@ -920,6 +973,14 @@ mod tests {
 				vec![CompilerRuntimeRole::Option]
 			);
 		}
+		for module in ["std/fs", "std/process"] {
+			assert_eq!(
+				HostRuntimeGraph::compiler_facts()
+					.semantic_dependencies(module)
+					.collect::<Vec<_>>(),
+				vec![CompilerRuntimeRole::Option, CompilerRuntimeRole::Result]
+			);
+		}
 		for module in ["std/io", "std/math/intrinsics"] {
 			assert_eq!(
 				HostRuntimeGraph::compiler_facts()
@@ -24,6 +24,7 @@
 //! interfaces without local declarations. Runtime bodies are projected and
 //! lowered by stable definition identity.
 
+mod catalog;
 mod host_runtime;
 mod prelude;
 pub mod project;
@@ -31,25 +32,32 @@ mod std_source;
 
 use std::path::{Path, PathBuf};
 
-pub use nymph_diagnostics::{Diagnostic, Severity};
+pub use catalog::{LINTS, Lint, error_catalog};
+pub use nymph_codegen::{Bindings, UnmappedDeclaration, generate_bindings};
+pub use nymph_diagnostics::{CatalogEntry, Diagnostic, Severity};
 pub use project::documentation::{
 	DocFragment, DocImplementation, DocItem, DocModule, DocOptions, DocProject, DocSignature,
-	StaticDocSite, document_project, document_project_with_std,
+	StaticDocSite, document_project, document_project_packages, document_project_with_std,
 };
 pub use project::{
 	AmbientCoreModuleKey, BuildProfile, BuiltinRuntimeOwnerArtifact, BuiltinRuntimeOwnerShape,
-	CompiledEntryRoot, CompiledProject, CompilerOptions, CompilerSession, LintLevel, ModuleAnalysis,
-	ModulePath, PackageGraphError, PackageId, ProjectDiagnostic, ProjectId, ReplInputStatus,
-	ReplSession, ReplStageError, SourceVersion, StagedReplSubmission, ToolingModuleDeclarations,
-	check_project, check_project_library, check_project_library_with_embedded_std,
-	check_project_library_with_embedded_std_and_options, check_project_library_with_std,
+	CompiledEntryRoot, CompiledModule, CompiledProject, CompiledTest, CompilerOptions, CompilerSession, LintLevel, ModuleAnalysis,
+	ModuleLookup, ModulePath, OutputLayout, PackageGraphError, PackageId, PackageSources, ProjectDiagnostic,
+	ProjectId, ProjectSession, ReplInputStatus, ReplSession, ReplStageError, SourceVersion, StagedReplSubmission,
+	ToolingModuleDeclarations, check_project, check_project_library,
+	check_project_library_packages_with_embedded_std_and_options,
+	check_project_library_with_embedded_std, check_project_library_with_embedded_std_and_options,
+	check_project_library_with_std, check_project_packages_with_embedded_std_and_options,
 	check_project_with_embedded_std, check_project_with_embedded_std_and_options,
 	check_project_with_std, compile_project, compile_project_library,
+	compile_project_library_packages_with_embedded_std_and_options,
 	compile_project_library_with_embedded_std_and_options,
 	compile_project_library_with_embedded_std_options_and_source_uris,
-	compile_project_library_with_std, compile_project_with_embedded_std_and_options,
+	compile_project_library_with_std, compile_project_packages_with_embedded_std_and_options,
+	compile_project_tests_packages_with_embedded_std_and_options,
+	compile_project_with_embedded_std_and_options,
 	compile_project_with_embedded_std_options_and_source_uris, compile_project_with_std,
-	repl_input_status,
+	declares_tests, package_module, repl_input_status,
 };
 pub use std_source::embedded_std_provider;
 
@@ -62,6 +62,8 @@ const CORE_SOURCES: &[(&str, &str)] = &[
 		"std/collections/map",
 		include_str!("../../../stdlib/src/collections/map.nym"),
 	),
+	// `JsValue` is ambient so interop code can name it without an import.
+	("std/js", include_str!("../../../stdlib/src/js.nym")),
 ];
 
 pub(crate) const CORE_SOURCE_COUNT: usize = CORE_SOURCES.len();
@@ -22,7 +22,7 @@ use rolldown::plugin::{
 	HookResolveIdReturn, HookUsage, Plugin, PluginContext, SharedLoadPluginContext,
 };
 #[cfg(all(not(target_arch = "wasm32"), feature = "bundler-rolldown"))]
-use rolldown::{Bundler, BundlerOptions, InputItem, OutputFormat};
+use rolldown::{Bundler, BundlerOptions, InputItem, OutputFormat, SourceMapType};
 use rustc_hash::FxHashMap;
 
 /// A `rolldown` plugin serving every module source out of an in-memory map
@@ -32,6 +32,9 @@ use rustc_hash::FxHashMap;
 #[cfg(all(not(target_arch = "wasm32"), feature = "bundler-rolldown"))]
 struct VirtualFsPlugin {
 	sources: FxHashMap<String, String>,
+	/// JSON source maps for the sources that have one, chained by rolldown
+	/// into the bundle's own map.
+	maps: FxHashMap<String, String>,
 }
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "bundler-rolldown"))]
@@ -62,6 +65,10 @@ impl Plugin for VirtualFsPlugin {
 		match self.sources.get(args.id) {
 			Some(source) => Ok(Some(HookLoadOutput {
 				code: source.as_str().into(),
+				map: match self.maps.get(args.id) {
+					Some(map) => Some(oxc_sourcemap::SourceMap::from_json_string(map)?.into_owned()),
+					None => None,
+				},
 				..Default::default()
 			})),
 			None => Ok(None),
@@ -73,7 +80,9 @@ impl Plugin for VirtualFsPlugin {
 /// canonical module path) starting from `entry_key`, returning the single
 /// output chunk's JS as a string. `sources` must contain `entry_key` and
 /// every module transitively `import`ed from it — anything else is simply
-/// never visited.
+/// never visited. With `maps` (per-module JSON source maps, keyed the same
+/// way), the bundle's own JSON source map is returned alongside it; the SWC
+/// backend never produces one.
 ///
 /// # Errors
 /// Returns a human-readable message on any rolldown build failure. Bundling a
@@ -83,30 +92,40 @@ impl Plugin for VirtualFsPlugin {
 pub(crate) fn bundle(
 	entry_key: &str,
 	sources: FxHashMap<String, String>,
-) -> Result<String, String> {
+	maps: Option<FxHashMap<String, String>>,
+) -> Result<(String, Option<String>), String> {
 	#[cfg(all(not(target_arch = "wasm32"), feature = "bundler-rolldown"))]
-	return bundle_rolldown(entry_key, sources);
+	return bundle_rolldown(entry_key, sources, maps);
 
 	#[cfg(all(
 		feature = "bundler-swc",
 		any(target_arch = "wasm32", not(feature = "bundler-rolldown"))
 	))]
-	return swc::bundle(entry_key, sources);
+	return swc::bundle(entry_key, sources).map(|js| (js, None));
 
 	#[allow(unreachable_code)]
 	Err("nymph-compiler requires a bundler backend feature".to_string())
 }
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "bundler-rolldown"))]
-fn bundle_rolldown(entry_key: &str, sources: FxHashMap<String, String>) -> Result<String, String> {
+fn bundle_rolldown(
+	entry_key: &str,
+	sources: FxHashMap<String, String>,
+	maps: Option<FxHashMap<String, String>>,
+) -> Result<(String, Option<String>), String> {
 	if let Some(entry) = sources.get(entry_key)
 		&& !entry.lines().any(|line| line.starts_with("import "))
 		&& is_valid_esm(entry)
 	{
-		return Ok(entry.clone());
+		let map = maps.as_ref().and_then(|maps| maps.get(entry_key).cloned());
+		return Ok((entry.clone(), map));
 	}
 
-	let plugin: Arc<dyn rolldown::plugin::Pluginable> = Arc::new(VirtualFsPlugin { sources });
+	let sourcemap = maps.is_some().then_some(SourceMapType::Hidden);
+	let plugin: Arc<dyn rolldown::plugin::Pluginable> = Arc::new(VirtualFsPlugin {
+		sources,
+		maps: maps.clone().unwrap_or_default(),
+	});
 
 	let options = BundlerOptions {
 		input: Some(vec![InputItem {
@@ -117,7 +136,7 @@ fn bundle_rolldown(entry_key: &str, sources: FxHashMap<String, String>) -> Resul
 		// never depends on where the compiler happens to run from.
 		cwd: Some(std::path::PathBuf::from("/nymph/virtual")),
 		format: Some(OutputFormat::Esm),
-		sourcemap: None,
+		sourcemap,
 		..Default::default()
 	};
 
@@ -139,8 +158,72 @@ fn bundle_rolldown(entry_key: &str, sources: FxHashMap<String, String>) -> Resul
 		.find(|a| a.filename().ends_with(".js"))
 		.or_else(|| output.assets.first())
 		.ok_or_else(|| "rolldown produced no output chunk".to_string())?;
+	// A hidden source map arrives as its own asset rather than a comment.
+	let map = match (
+		&maps,
+		output
+			.assets
+			.iter()
+			.find(|a| a.filename().ends_with(".js.map")),
+	) {
+		(Some(maps), Some(map)) => Some(restore_module_sources(
+			&String::from_utf8_lossy(map.content_as_bytes()),
+			maps,
+		)?),
+		_ => None,
+	};
+
+	Ok((
+		String::from_utf8_lossy(chunk.content_as_bytes()).into_owned(),
+		map,
+	))
+}
 
-	Ok(String::from_utf8_lossy(chunk.content_as_bytes()).into_owned())
+/// Point the bundle's source map back at the sources the module `maps`
+/// name. Rolldown rewrites every source as a path relative to the virtual
+/// output and adds the modules that came without a map, such as `std/box`;
+/// those are left unmapped, and the rest are recognized by their content.
+#[cfg(all(not(target_arch = "wasm32"), feature = "bundler-rolldown"))]
+fn restore_module_sources(map: &str, maps: &FxHashMap<String, String>) -> Result<String, String> {
+	let module_maps = maps
+		.values()
+		.map(|map| oxc_sourcemap::SourceMap::from_json_string(map))
+		.collect::<Result<Vec<_>, _>>()
+		.map_err(|e| format!("invalid module source map: {e}"))?;
+	let originals = module_maps
+		.iter()
+		.flat_map(|map| map.get_sources().zip(map.get_source_contents()))
+		.filter_map(|(source, content)| Some((content?, source)))
+		.collect::<FxHashMap<_, _>>();
+	let bundled = oxc_sourcemap::SourceMap::from_json_string(map)
+		.map_err(|e| format!("invalid bundle source map: {e}"))?;
+	let mut builder = oxc_sourcemap::SourceMapBuilder::default();
+	let sources = bundled
+		.get_source_contents()
+		.map(|content| {
+			let content = content?;
+			let source = originals.get(content)?;
+			Some(builder.add_source_and_content(source, content))
+		})
+		.collect::<Vec<_>>();
+	for token in bundled.get_tokens() {
+		let source = token
+			.get_source_id()
+			.and_then(|id| sources.get(id as usize).copied().flatten());
+		let name = source
+			.and(token.get_name_id())
+			.and_then(|id| bundled.get_name(id))
+			.map(|name| builder.add_name(name));
+		builder.add_token(
+			token.get_dst_line(),
+			token.get_dst_col(),
+			token.get_src_line(),
+			token.get_src_col(),
+			source,
+			name,
+		);
+	}
+	Ok(builder.into_sourcemap().to_json_string())
 }
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "bundler-rolldown"))]
@@ -299,8 +382,8 @@ mod tests {
 	#[test]
 	fn compiler_host_graph_bundles_and_runs_the_structured_task_kernel() {
 		let mut sources = crate::host_runtime::HostRuntimeGraph::compiler_facts().module_sources(
-			"Option",
-			"@nymph/runtime/std/option",
+			("Option", "@nymph/runtime/std/option"),
+			("Result", "@nymph/runtime/std/result"),
 			false,
 		);
 		sources.insert(
@@ -311,7 +394,7 @@ console.log(await nymphRunTask(task));
 "#
 			.to_string(),
 		);
-		let bundled = bundle("task-test", sources).expect("bundle task runtime");
+		let (bundled, _) = bundle("task-test", sources, None).expect("bundle task runtime");
 		let path = std::env::temp_dir().join(format!(
 			"nymph_task_host_runtime_{}.mjs",
 			std::process::id()
@@ -333,8 +416,8 @@ console.log(await nymphRunTask(task));
 	#[test]
 	fn node_adapter_boundary_preserves_modes_bigints_opaque_aliases_and_defects() {
 		let mut sources = crate::host_runtime::HostRuntimeGraph::compiler_facts().module_sources(
-			"Option",
-			"@nymph/runtime/std/option",
+			("Option", "@nymph/runtime/std/option"),
+			("Result", "@nymph/runtime/std/result"),
 			false,
 		);
 		sources.insert(
@@ -379,7 +462,7 @@ console.log([typeof exact, exact, nymphUnboxOpaque(117n, file) === nymphUnboxOpa
 "#
 			.to_string(),
 		);
-		let bundled = bundle("adapter-test", sources).expect("bundle adapter runtime");
+		let (bundled, _) = bundle("adapter-test", sources, None).expect("bundle adapter runtime");
 		let path = std::env::temp_dir().join(format!(
 			"nymph_adapter_host_runtime_{}.mjs",
 			std::process::id()
@@ -406,7 +489,7 @@ console.log([typeof exact, exact, nymphUnboxOpaque(117n, file) === nymphUnboxOpa
 		let source = "function main() {}\nexport { main };\n";
 		let sources = FxHashMap::from_iter([("main".to_string(), source.to_string())]);
 
-		let js = bundle("main", sources).expect("self-contained entry should compile");
+		let (js, _) = bundle("main", sources, None).expect("self-contained entry should compile");
 
 		#[cfg(all(not(target_arch = "wasm32"), feature = "bundler-rolldown"))]
 		assert_eq!(js, source);
@@ -419,7 +502,7 @@ console.log([typeof exact, exact, nymphUnboxOpaque(117n, file) === nymphUnboxOpa
 		let source = "function default() {}\nexport { default };\n";
 		let sources = FxHashMap::from_iter([("main".to_string(), source.to_string())]);
 
-		let result = bundle("main", sources);
+		let result = bundle("main", sources, None);
 
 		assert!(result.is_err(), "invalid ESM must not bypass validation");
 	}
@@ -449,7 +532,7 @@ console.log([typeof exact, exact, nymphUnboxOpaque(117n, file) === nymphUnboxOpa
 					.to_string(),
 			),
 		]);
-		let js = bundle("main", sources).expect("bundle should succeed");
+		let (js, _) = bundle("main", sources, None).expect("bundle should succeed");
 		assert!(
 			js.contains("console.log(\"helper loaded\")"),
 			"expected the genuinely side-effecting (unreferenced) export to survive bundling, got:\n{js}"
@@ -474,8 +557,9 @@ console.log([typeof exact, exact, nymphUnboxOpaque(117n, file) === nymphUnboxOpa
 				),
 			])
 		};
-		let first = bundle("main", sources()).expect("alias graph should bundle");
-		let second = bundle("main", sources()).expect("alias graph should bundle repeatedly");
+		let (first, _) = bundle("main", sources(), None).expect("alias graph should bundle");
+		let (second, _) =
+			bundle("main", sources(), None).expect("alias graph should bundle repeatedly");
 		assert_eq!(first, second);
 		assert!(first.contains("42"));
 		assert!(!first.contains("from \"api\""));
@@ -495,6 +579,6 @@ console.log([typeof exact, exact, nymphUnboxOpaque(117n, file) === nymphUnboxOpa
 			),
 			("dep".into(), "export const value = 42;\n".into()),
 		]);
-		assert!(bundle("main", sources).is_err());
+		assert!(bundle("main", sources, None).is_err());
 	}
 }
@@ -5,10 +5,10 @@ use std::{
 	sync::Arc,
 };
 
-use nymph_ast::decl::Visibility;
+use nymph_ast::decl::{AttributeArgument, Visibility};
 use nymph_sema::{
-	DefinitionId, DefinitionShapeKind, ExportedDefinition, ExportedImpl, GenericConstraint,
-	GenericParameter, InterfaceType, MemberKind, MemberShape, ParameterShape,
+	AttributeShape, DefinitionId, DefinitionShapeKind, Deprecation, ExportedDefinition, ExportedImpl,
+	GenericConstraint, GenericParameter, InterfaceType, MemberKind, MemberShape, ParameterShape,
 };
 
 use super::{CompilerSession, ModulePath, ProjectDiagnostic, ProjectId};
@@ -36,6 +36,8 @@ pub struct DocItem {
 	pub name: String,
 	pub kind: DefinitionShapeKind,
 	pub private: bool,
+	/// Set when the declaration is marked `@deprecated`.
+	pub deprecation: Option<Deprecation>,
 	pub anchor: String,
 	pub signature: DocSignature,
 }
@@ -90,6 +92,18 @@ pub fn document_project_with_std(
 	extract(&session, project, entry, options).map_err(|d| d.iter().cloned().collect())
 }
 
+/// Document the root package (`packages[0]`) of a resolved package graph.
+/// Dependency packages are checked alongside it but are not documented.
+pub fn document_project_packages(
+	entry: &str,
+	packages: &[super::PackageSources<'_>],
+	options: DocOptions,
+) -> Result<DocProject, Vec<ProjectDiagnostic>> {
+	let (session, project, entry) =
+		super::packages_facade_session(entry, packages, &super::CompilerOptions::default());
+	extract(&session, project, entry, options).map_err(|d| d.iter().cloned().collect())
+}
+
 fn extract(
 	session: &CompilerSession,
 	project: ProjectId,
@@ -199,6 +213,7 @@ fn extract(
 
 fn item(definition: ExportedDefinition, document_private_items: bool) -> DocItem {
 	let private = definition.visibility == Some(Visibility::Private);
+	let deprecation = Deprecation::from_attributes(&definition.name, &definition.attributes);
 	let anchor = definition_anchor(&definition.id);
 	let signature = signature(&definition, document_private_items);
 	DocItem {
@@ -206,6 +221,7 @@ fn item(definition: ExportedDefinition, document_private_items: bool) -> DocItem
 		name: definition.name.to_string(),
 		kind: definition.kind,
 		private,
+		deprecation,
 		anchor,
 		signature,
 	}
@@ -275,6 +291,7 @@ fn signature(d: &ExportedDefinition, document_private_items: bool) -> DocSignatu
 		.filter(|field| document_private_items || field.visibility != Some(Visibility::Private))
 	{
 		text(&mut out, "\n  ");
+		deprecated(&mut out, &field.attributes);
 		visibility(&mut out, field.visibility);
 		text(&mut out, &field.name);
 		text(&mut out, ": ");
@@ -282,6 +299,7 @@ fn signature(d: &ExportedDefinition, document_private_items: bool) -> DocSignatu
 	}
 	for variant in &d.variants {
 		text(&mut out, "\n  ");
+		deprecated(&mut out, &variant.attributes);
 		text(&mut out, &variant.name);
 		let fields = variant
 			.fields
@@ -294,6 +312,7 @@ fn signature(d: &ExportedDefinition, document_private_items: bool) -> DocSignatu
 				if i > 0 {
 					text(&mut out, ", ");
 				}
+				deprecated(&mut out, &f.attributes);
 				visibility(&mut out, f.visibility);
 				text(&mut out, &f.name);
 				text(&mut out, ": ");
@@ -445,6 +464,7 @@ fn member_doc(
 			.map(|binder| (binder.id.clone(), binder.name.as_ref())),
 	);
 	text(out, "\n  ");
+	deprecated(out, &member.attributes);
 	visibility(out, member.visibility);
 	external(out, member.external.as_ref());
 	text(
@@ -674,6 +694,27 @@ fn definition(out: &mut DocSignature, target: &DefinitionId) {
 fn text(out: &mut DocSignature, value: &str) {
 	out.0.push(DocFragment::Text(value.to_string()));
 }
+/// A member, field or variant keeps its marker in the signature, written as in
+/// source; a deprecated declaration gets a badge instead.
+fn deprecated(out: &mut DocSignature, attributes: &[AttributeShape]) {
+	let Some(marker) = AttributeShape::find(attributes, "deprecated") else {
+		return;
+	};
+	text(out, "@deprecated");
+	if !marker.arguments.is_empty() {
+		let arguments = marker
+			.arguments
+			.iter()
+			.map(|argument| match argument {
+				AttributeArgument::String(value) => format!("{value:?}"),
+				AttributeArgument::Name(name) => name.to_string(),
+			})
+			.collect::<Vec<_>>();
+		text(out, &format!("({})", arguments.join(", ")));
+	}
+	text(out, " ");
+}
+
 fn visibility(out: &mut DocSignature, value: Option<Visibility>) {
 	let keyword = match value {
 		Some(Visibility::Public) => "public ",
@@ -778,7 +819,16 @@ impl DocProject {
 				html.push_str(&item.anchor);
 				html.push_str("\"><h2>");
 				html.push_str(&escape(&item.name));
-				html.push_str("</h2><pre><code>");
+				if item.deprecation.is_some() {
+					html.push_str(" <span class=\"deprecated\">Deprecated</span>");
+				}
+				html.push_str("</h2>");
+				if let Some(note) = item.deprecation.as_ref().and_then(|d| d.note.as_ref()) {
+					html.push_str("<p class=\"deprecated\">");
+					html.push_str(&escape(note));
+					html.push_str("</p>");
+				}
+				html.push_str("<pre><code>");
 				render_signature(&mut html, &item.signature, &self.modules, &root);
 				html.push_str("</code></pre></section>");
 			}
@@ -792,7 +842,7 @@ impl DocProject {
 			page_end(&mut html);
 			files.insert(module.url.clone(), html);
 		}
-		files.insert("assets/style.css".into(), "body{font-family:system-ui,sans-serif;max-width:70rem;margin:2rem auto;padding:0 1rem}pre{background:#f4f4f4;padding:1rem;overflow:auto}code{white-space:pre-wrap}".into());
+		files.insert("assets/style.css".into(), "body{font-family:system-ui,sans-serif;max-width:70rem;margin:2rem auto;padding:0 1rem}pre{background:#f4f4f4;padding:1rem;overflow:auto}code{white-space:pre-wrap}.deprecated{color:#8a4b00}span.deprecated{font-size:.6em;font-weight:normal;border:1px solid;border-radius:.3em;padding:0 .3em;vertical-align:middle}".into());
 		files
 	}
 }
@@ -4,22 +4,38 @@ use std::sync::Arc;
 
 use nymph_ast::{Span, decl::Visibility};
 use nymph_diagnostics::Diagnostic;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::queries::Db;
-use super::session::{ProjectKey, SemanticModuleDomain, SemanticModuleInput};
-use super::{CompiledEntryRoot, CompiledProject, ProjectDiagnostic, bundle, link_plan, queries};
+use super::session::{OutputLayout, ProjectKey, SemanticModuleDomain, SemanticModuleInput};
+use super::{
+	CompiledEntryRoot, CompiledProject, CompiledTest, ProjectDiagnostic, bundle, link_plan, queries,
+	type_declarations, unbundled,
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct StableEmittedProject {
 	pub module_sources: FxHashMap<String, String>,
+	/// JSON source maps for the modules in `module_sources` that have one.
+	pub module_maps: FxHashMap<String, String>,
 	pub entry_tag: usize,
 	pub entry_root: Option<CompiledEntryRoot>,
+	pub tests: Vec<CompiledTest>,
 	pub(crate) compiler_option_binding: String,
 	pub(crate) compiler_option_module: String,
+	pub(crate) compiler_result_binding: String,
+	pub(crate) compiler_result_module: String,
 	pub(crate) echo_runtime: bool,
 }
 
+/// One module's emitted source, with its source map when the project policy
+/// asks for maps.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct EmittedModule {
+	pub(crate) source: String,
+	pub(crate) map: Option<String>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) enum StableEmissionResult<T> {
 	Value(Arc<T>),
@@ -56,13 +72,19 @@ fn compiler_option_definition<'db>(
 		.ok_or_else(|| "compiler Option definition is unavailable".to_string())
 }
 
-fn compiler_result_binding<'db>(db: &'db dyn Db, key: ProjectKey<'db>) -> Result<String, String> {
-	let definition = queries::compiler_runtime_roles(db, key.ambient_core_registry(db))
+fn compiler_result_definition<'db>(
+	db: &'db dyn Db,
+	key: ProjectKey<'db>,
+) -> Result<nymph_sema::DefinitionId, String> {
+	queries::compiler_runtime_roles(db, key.ambient_core_registry(db))
 		.result
 		.as_ref()
 		.map(|role| role.result.clone())
-		.ok_or_else(|| "compiler Result definition is unavailable".to_string())?;
-	queries::binding_name(db, key, definition)
+		.ok_or_else(|| "compiler Result definition is unavailable".to_string())
+}
+
+fn compiler_result_binding<'db>(db: &'db dyn Db, key: ProjectKey<'db>) -> Result<String, String> {
+	queries::binding_name(db, key, compiler_result_definition(db, key)?)
 		.map(|name| name.as_str().to_string())
 		.map_err(|error| format!("compiler Result binding is unavailable: {error:?}"))
 }
@@ -170,6 +192,81 @@ fn echo_emission(
 	}
 }
 
+/// The original positions of every body `stable` emits, keyed the way the
+/// emitter names them: by binding, or by `Owner.member` for attached methods.
+fn source_positions(
+	db: &dyn Db,
+	key: ProjectKey<'_>,
+	module: SemanticModuleInput,
+	stable: &nymph_sema::StableHirModule,
+) -> nymph_codegen::SourcePositions {
+	let (source_name, source) = match module {
+		SemanticModuleInput::Project(module) => (
+			module
+				.source_uri(db)
+				.map_or_else(|| module.source_name(db).to_string(), |uri| uri.to_string()),
+			module.source(db).unwrap_or_default().to_string(),
+		),
+		SemanticModuleInput::Builtin(module) => (
+			format!("{}.nym", module.key(db).path),
+			module.source(db).to_string(),
+		),
+	};
+	let spans = queries::runtime_body_spans(db, key, module);
+	let shells = stable
+		.fragments
+		.iter()
+		.filter_map(|fragment| match fragment.fragment() {
+			nymph_sema::LoweredHirFragment::StructShell(class) => {
+				Some((fragment.definition(), class.name.clone()))
+			}
+			nymph_sema::LoweredHirFragment::EnumShell(hir_enum) => {
+				Some((fragment.definition(), hir_enum.name.clone()))
+			}
+			_ => None,
+		})
+		.collect::<std::collections::HashMap<_, _>>();
+	let bodies = stable
+		.fragments
+		.iter()
+		.filter_map(|fragment| {
+			let body = spans
+				.iter()
+				.find(|body| &body.definition == fragment.definition())?;
+			let name = match (fragment.fragment(), fragment.placement()) {
+				(nymph_sema::LoweredHirFragment::TopLevelFunction(func), _) => func.name.to_string(),
+				(nymph_sema::LoweredHirFragment::TopLevelValue(let_), _) => let_.name.to_string(),
+				(
+					nymph_sema::LoweredHirFragment::AttachedInstance { method, .. }
+					| nymph_sema::LoweredHirFragment::AttachedStatic { method, .. }
+					| nymph_sema::LoweredHirFragment::AttachedMember { method, .. },
+					nymph_sema::RuntimeAssemblyPlacement::Shell(owner),
+				) => format!("{}.{}", shells.get(owner)?, method.name),
+				_ => return None,
+			};
+			// Generated code (a derived impl) lies past the end of the source and
+			// has no original position to map back to.
+			let nodes = body
+				.nodes
+				.iter()
+				.map(|span| {
+					if span.end > source.len() {
+						0..0
+					} else {
+						span.start as u32..span.end as u32
+					}
+				})
+				.collect();
+			Some((name, nodes))
+		})
+		.collect();
+	nymph_codegen::SourcePositions {
+		source_name,
+		source,
+		bodies,
+	}
+}
+
 fn virtual_echo_emission(
 	db: &dyn Db,
 	key: ProjectKey<'_>,
@@ -192,7 +289,7 @@ pub(crate) fn emitted_interface_module<'db>(
 	key: ProjectKey<'db>,
 	module: SemanticModuleInput,
 	transactional: bool,
-) -> StableEmissionResult<String> {
+) -> StableEmissionResult<EmittedModule> {
 	#[cfg(feature = "test-support")]
 	db.semantic_query_will_execute("emitted_interface_module", module);
 	#[cfg(feature = "test-support")]
@@ -231,6 +328,9 @@ pub(crate) fn emitted_interface_module<'db>(
 				.map(|fragment| fragment.definition().clone()),
 		);
 	}
+	// A method lowered to a top-level function (an impl on a primitive, a
+	// list or a map) is the target of tag dispatch in whichever module calls
+	// it, so it is importable like a public definition.
 	public.extend(stable.fragments.iter().filter_map(|fragment| {
 		(matches!(
 			fragment.fragment(),
@@ -238,13 +338,29 @@ pub(crate) fn emitted_interface_module<'db>(
 		) || matches!(
 			(&fragment.definition().key, fragment.fragment()),
 			(
-				nymph_sema::DeclarationKey::MethodBody { name, .. }
-					| nymph_sema::DeclarationKey::Member { name, .. },
+				nymph_sema::DeclarationKey::MethodBody { .. } | nymph_sema::DeclarationKey::Member { .. },
 				nymph_sema::LoweredHirFragment::TopLevelFunction(_)
-			) if name == "power"
+			)
 		))
 		.then(|| fragment.definition().clone())
 	}));
+	// An impl on a compiler-owned type (`impl ToJson for Option<T>`) is
+	// attached inside that type's runtime module, which imports whatever of
+	// this module its bodies use.
+	public.extend(
+		stable
+			.virtual_runtime
+			.iter()
+			.flat_map(|fragment| fragment.fragment.direct_demands())
+			.filter(|demand| {
+				demand.module == stable.module
+					&& !stable
+						.virtual_runtime
+						.iter()
+						.any(|fragment| fragment.definition == **demand)
+			})
+			.cloned(),
+	);
 	let preserve = key.preserve_names(db) && stable.module.path == key.entry(db).as_str();
 	let fragments = stable
 		.fragments
@@ -286,6 +402,9 @@ pub(crate) fn emitted_interface_module<'db>(
 			format!("strict REPL mode rejects unaudited stateful external `{module}::{symbol}`"),
 		));
 	}
+	// Dispatch targets name their module by specifier (`std::json`, not
+	// `json`), so the emitter must too to recognise its own bindings.
+	let current_module = module_specifier(&stable.module);
 	let imports = plan
 		.imports
 		.iter()
@@ -298,6 +417,7 @@ pub(crate) fn emitted_interface_module<'db>(
 			)
 		})
 		.collect::<Vec<_>>();
+	let mut map = None;
 	let mut source = if transactional {
 		let imported_top_level_lets = plan
 			.imports
@@ -315,7 +435,7 @@ pub(crate) fn emitted_interface_module<'db>(
 			.collect::<Vec<_>>();
 		match nymph_codegen::emit_for_transactional_project_module_checked(
 			&stable.hir,
-			&stable.module.path,
+			&current_module,
 			&imports,
 			&imported_top_level_lets,
 		) {
@@ -329,14 +449,51 @@ pub(crate) fn emitted_interface_module<'db>(
 			}
 		}
 	} else {
-		nymph_codegen::emit_for_project_module_with_imports_and_echo(
-			&stable.hir,
-			&stable.module.path,
-			&imports,
-			echo_emission(db, key, module),
-		)
+		// A test body only runs under `nymph test`, which compiles its module with
+		// preserved names; every other build leaves it out entirely rather than
+		// relying on the bundler to drop an unreferenced callable.
+		let mut hir = std::borrow::Cow::Borrowed(&stable.hir);
+		if !preserve {
+			let tests = stable
+				.fragments
+				.iter()
+				.filter(|fragment| {
+					matches!(&fragment.definition().key, nymph_sema::DeclarationKey::TopLevel { name, .. }
+						if nymph_ast::decl::is_test_function_name(name))
+				})
+				.filter_map(|fragment| queries::binding_name(db, key, fragment.definition().clone()).ok())
+				.map(|binding| binding.as_str().to_string())
+				.collect::<std::collections::HashSet<_>>();
+			if !tests.is_empty() {
+				hir
+					.to_mut()
+					.funcs
+					.retain(|func| !tests.contains(func.name.as_str()));
+			}
+		}
+		if key.policy_input(db).source_maps(db) {
+			let (source, module_map) = nymph_codegen::emit_for_project_module_with_source_map(
+				&hir,
+				&current_module,
+				&imports,
+				echo_emission(db, key, module),
+				source_positions(db, key, module, &stable),
+			);
+			map = Some(module_map);
+			source
+		} else {
+			nymph_codegen::emit_for_project_module_with_imports_and_echo(
+				&hir,
+				&current_module,
+				&imports,
+				echo_emission(db, key, module),
+			)
+		}
 	};
 	prepend_external_aliases(&mut source, &plan.external_aliases);
+	// Each alias import is one prepended line.
+	let map =
+		map.map(|map| nymph_codegen::offset_source_map(&map, plan.external_aliases.len() as u32));
 	if !plan.exports.is_empty() {
 		source.push_str(&format!(
 			"export {{ {} }};\n",
@@ -348,7 +505,7 @@ pub(crate) fn emitted_interface_module<'db>(
 				.join(", ")
 		));
 	}
-	StableEmissionResult::Value(Arc::new(source))
+	StableEmissionResult::Value(Arc::new(EmittedModule { source, map }))
 }
 
 #[salsa::tracked(returns(clone))]
@@ -392,10 +549,25 @@ pub(crate) fn emitted_interface_project<'db>(
 			));
 		}
 	};
+	let (compiler_result, compiler_result_binding) = match compiler_result_definition(db, key)
+		.and_then(|definition| compiler_result_binding(db, key).map(|binding| (definition, binding)))
+	{
+		Ok(result) => result,
+		Err(message) => {
+			return StableEmissionResult::Diagnostics(internal_diagnostic(
+				key.entry(db).as_str(),
+				"STABLE-INTRINSIC-DEPENDENCY",
+				message,
+			));
+		}
+	};
 	let mut sources = FxHashMap::default();
+	let mut maps = FxHashMap::default();
 	let mut virtual_fragments = std::collections::BTreeMap::new();
-	let mut option_requested = false;
+	let mut requested_roles = FxHashSet::default();
 	let mut option_definition = None;
+	let mut result_definition = None;
+	let mut project_deliveries = Vec::new();
 	let host_runtime = crate::host_runtime::HostRuntimeGraph::compiler_facts();
 	for module in graph
 		.semantic_order
@@ -413,14 +585,37 @@ pub(crate) fn emitted_interface_project<'db>(
 				));
 			}
 		};
-		option_requested |= stable.fragments.iter().any(|fragment| {
-			matches!(fragment.fragment(), nymph_sema::LoweredHirFragment::TopLevelExternal { abi, .. }
-				if abi.linked().is_some_and(|(module, _)| host_runtime.semantic_dependencies(module).next().is_some()))
-		});
+		for fragment in &stable.fragments {
+			if let nymph_sema::LoweredHirFragment::TopLevelExternal { abi, .. } = fragment.fragment()
+				&& let Some((module, _)) = abi.linked()
+			{
+				requested_roles.extend(host_runtime.semantic_dependencies(module));
+			}
+		}
+		project_deliveries.extend(
+			stable
+				.fragments
+				.iter()
+				.filter(|fragment| fragment.definition().module == stable.module)
+				.map(|fragment| {
+					let delivery = if matches!(
+						link_artifact(fragment.fragment()),
+						link_plan::LinkArtifact::Attached
+					) {
+						link_plan::VirtualDemandDelivery::Attached
+					} else {
+						link_plan::VirtualDemandDelivery::Binding
+					};
+					(fragment.definition().clone(), delivery)
+				}),
+		);
 		for fragment in &stable.virtual_runtime {
 			if fragment.definition == compiler_option {
 				option_definition = Some(fragment.definition.clone());
 			}
+			if fragment.definition == compiler_result {
+				result_definition = Some(fragment.definition.clone());
+			}
 			if let Err(conflict) =
 				super::assembly::insert_exact_virtual_fragment(&mut virtual_fragments, fragment.clone())
 			{
@@ -435,11 +630,12 @@ pub(crate) fn emitted_interface_project<'db>(
 			}
 		}
 		match emitted_interface_module(db, key, module, transactional) {
-			StableEmissionResult::Value(source) => {
-				sources.insert(
-					module_specifier(&module.identity(db)),
-					source.as_ref().clone(),
-				);
+			StableEmissionResult::Value(emitted) => {
+				let specifier = module_specifier(&module.identity(db));
+				if let Some(map) = &emitted.map {
+					maps.insert(specifier.clone(), map.clone());
+				}
+				sources.insert(specifier, emitted.source.clone());
 			}
 			StableEmissionResult::Diagnostics(diagnostics) => {
 				return StableEmissionResult::Diagnostics(diagnostics);
@@ -447,9 +643,11 @@ pub(crate) fn emitted_interface_project<'db>(
 		}
 	}
 	let mut by_owner: std::collections::BTreeMap<_, Vec<_>> = std::collections::BTreeMap::new();
-	let virtual_deliveries = virtual_fragments
-		.values()
-		.map(|fragment| {
+	// A relocated impl reaches a project definition through that definition's
+	// own module, unless it was itself relocated into a runtime module.
+	let virtual_deliveries = project_deliveries
+		.into_iter()
+		.chain(virtual_fragments.values().map(|fragment| {
 			let delivery = if matches!(
 				fragment.fragment.placement(),
 				nymph_sema::RuntimeAssemblyPlacement::Module(_)
@@ -467,7 +665,7 @@ pub(crate) fn emitted_interface_project<'db>(
 				link_plan::VirtualDemandDelivery::Attached
 			};
 			(fragment.definition.clone(), delivery)
-		})
+		}))
 		.collect::<std::collections::HashMap<_, _>>();
 	let execution_fragments = virtual_fragments.values().cloned().collect::<Vec<_>>();
 	for fragment in virtual_fragments.into_values() {
@@ -509,19 +707,35 @@ pub(crate) fn emitted_interface_project<'db>(
 			}
 		}
 	}
-	if option_requested {
-		let Some(option) = option_definition else {
+	for (role, name, compiler, assembled) in [
+		(
+			crate::host_runtime::CompilerRuntimeRole::Option,
+			"Option",
+			&compiler_option,
+			option_definition,
+		),
+		(
+			crate::host_runtime::CompilerRuntimeRole::Result,
+			"Result",
+			&compiler_result,
+			result_definition,
+		),
+	] {
+		if !requested_roles.contains(&role) {
+			continue;
+		}
+		let Some(assembled) = assembled else {
 			return StableEmissionResult::Diagnostics(internal_diagnostic(
 				key.entry(db).as_str(),
 				"STABLE-INTRINSIC-DEPENDENCY",
-				"selected intrinsic requires the compiler Option definition".to_string(),
+				format!("selected intrinsic requires the compiler {name} definition"),
 			));
 		};
-		if option != compiler_option {
+		if &assembled != compiler {
 			return StableEmissionResult::Diagnostics(internal_diagnostic(
 				key.entry(db).as_str(),
 				"STABLE-INTRINSIC-DEPENDENCY",
-				"assembled Option definition does not match the compiler Option".to_string(),
+				format!("assembled {name} definition does not match the compiler {name}"),
 			));
 		}
 	}
@@ -547,11 +761,11 @@ pub(crate) fn emitted_interface_project<'db>(
 		.copied()
 		.find(|module| module.identity(db) == entry_identity)
 		.expect("entry module was located above");
-	let semantic_root = queries::interface_module_analysis(db, key, entry_module)
+	let checked = &queries::interface_module_analysis(db, key, entry_module)
 		.semantic
-		.checked
-		.entry_root;
-	let entry_root = match compiled_entry_root(db, key, semantic_root, &compiler_option_binding) {
+		.checked;
+	let entry_root = match compiled_entry_root(db, key, checked.entry_root, &compiler_option_binding)
+	{
 		Ok(root) => root,
 		Err(message) => {
 			return StableEmissionResult::Diagnostics(internal_diagnostic(
@@ -561,12 +775,38 @@ pub(crate) fn emitted_interface_project<'db>(
 			));
 		}
 	};
+	// Tests are only reachable when the entry's own names are exported
+	// unmangled; otherwise the bundler drops them as unused private functions.
+	let mut tests = Vec::new();
+	if key.preserve_names(db) && key.mode(db) == nymph_sema::EntryMode::Library {
+		for test in &checked.tests {
+			match compiled_entry_root(db, key, Some(test.shape), &compiler_option_binding) {
+				Ok(Some(root)) => tests.push(CompiledTest {
+					name: test.name.to_string(),
+					binding: test.function.to_string(),
+					root,
+				}),
+				Ok(None) => {}
+				Err(message) => {
+					return StableEmissionResult::Diagnostics(internal_diagnostic(
+						key.entry(db).as_str(),
+						"STABLE-ENTRY-ADAPTER",
+						message,
+					));
+				}
+			}
+		}
+	}
 	StableEmissionResult::Value(Arc::new(StableEmittedProject {
 		module_sources: sources,
+		module_maps: maps,
 		entry_tag,
 		entry_root,
+		tests,
 		compiler_option_binding,
 		compiler_option_module: format!("@nymph/runtime/{}", compiler_option.module.path),
+		compiler_result_binding,
+		compiler_result_module: format!("@nymph/runtime/{}", compiler_result.module.path),
 		echo_runtime,
 	}))
 }
@@ -693,7 +933,7 @@ pub(crate) fn compiled_interface_project<'db>(
 		}
 	};
 	let mut module_sources = emitted.module_sources.clone();
-	if emitted.entry_root.is_some() {
+	if emitted.entry_root.is_some() || !emitted.tests.is_empty() {
 		let entry = module_sources
 			.get_mut(key.entry(db).as_str())
 			.expect("emitted project contains its entry source");
@@ -701,11 +941,39 @@ pub(crate) fn compiled_interface_project<'db>(
 			"export { nymphActivate, nymphProtocolDisplayStep, nymphRenderDefect, nymphStartRoot } from \"std/box\";\n",
 		);
 	}
+	let declarations = if key.policy_input(db).declarations(db) {
+		match type_declarations::entry_declarations(db, key) {
+			Ok(declared) => {
+				module_sources
+					.get_mut(key.entry(db).as_str())
+					.expect("emitted project contains its entry source")
+					.push_str(&declared.exports);
+				Some(declared.declarations)
+			}
+			Err(message) => {
+				return StableEmissionResult::Diagnostics(internal_diagnostic(
+					key.entry(db).as_str(),
+					"STABLE-DECLARATIONS",
+					format!("declaring the entry module for TypeScript failed: {message}"),
+				));
+			}
+		}
+	} else {
+		None
+	};
+	let mut host_keys = FxHashSet::default();
 	for (module, source) in crate::host_runtime::HostRuntimeGraph::compiler_facts().module_sources(
-		&emitted.compiler_option_binding,
-		&emitted.compiler_option_module,
+		(
+			&emitted.compiler_option_binding,
+			&emitted.compiler_option_module,
+		),
+		(
+			&emitted.compiler_result_binding,
+			&emitted.compiler_result_module,
+		),
 		emitted.echo_runtime,
 	) {
+		host_keys.insert(module.clone());
 		if module_sources.insert(module.clone(), source).is_some() {
 			return StableEmissionResult::Diagnostics(internal_diagnostic(
 				&module,
@@ -714,14 +982,52 @@ pub(crate) fn compiled_interface_project<'db>(
 			));
 		}
 	}
+	if key.policy_input(db).layout(db) == OutputLayout::Modules {
+		let maps = if key.policy_input(db).source_maps(db) {
+			emitted.module_maps.clone()
+		} else {
+			FxHashMap::default()
+		};
+		return match unbundled::split_modules(
+			key.entry(db).as_str(),
+			&module_sources,
+			&host_keys,
+			&maps,
+		) {
+			Ok(modules) => StableEmissionResult::Value(Arc::new(CompiledProject {
+				js: String::new(),
+				entry_main: "main".to_string(),
+				entry_root: emitted.entry_root.clone(),
+				tests: emitted.tests.clone(),
+				entry_tag: emitted.entry_tag,
+				source_map: None,
+				modules,
+				declarations,
+			})),
+			Err(error) => StableEmissionResult::Diagnostics(internal_diagnostic(
+				key.entry(db).as_str(),
+				"MODULE-LAYOUT-FAILED",
+				format!("laying out the stable project as modules failed: {error}"),
+			)),
+		};
+	}
 	#[cfg(feature = "test-support")]
 	let _timing = super::benchmark_support::phase(super::benchmark_support::Phase::Bundling);
-	match bundle::bundle(key.entry(db).as_str(), module_sources) {
-		Ok(js) => StableEmissionResult::Value(Arc::new(CompiledProject {
+	let maps = if key.policy_input(db).source_maps(db) {
+		Some(emitted.module_maps.clone())
+	} else {
+		None
+	};
+	match bundle::bundle(key.entry(db).as_str(), module_sources, maps) {
+		Ok((js, source_map)) => StableEmissionResult::Value(Arc::new(CompiledProject {
 			js,
 			entry_main: "main".to_string(),
 			entry_root: emitted.entry_root.clone(),
+			tests: emitted.tests.clone(),
 			entry_tag: emitted.entry_tag,
+			source_map,
+			modules: Vec::new(),
+			declarations,
 		})),
 		Err(error) => StableEmissionResult::Diagnostics(internal_diagnostic(
 			key.entry(db).as_str(),
@@ -17,19 +17,23 @@ mod bundle;
 pub mod documentation;
 mod emission;
 mod link_plan;
+mod project_session;
 mod queries;
 mod repl;
 mod resolve;
 mod session;
+mod type_declarations;
+mod unbundled;
 
+pub use project_session::ProjectSession;
 pub use repl::{
 	ReplInputStatus, ReplSession, ReplStageError, StagedReplSubmission, repl_input_status,
 };
 
 pub use session::{
 	AmbientCoreModuleKey, BuildProfile, BuiltinRuntimeOwnerArtifact, BuiltinRuntimeOwnerShape,
-	CompilerSession, LintLevel, ModuleAnalysis, ModulePath, PackageGraphError, PackageId,
-	ProjectDiagnostics, ProjectId, RuntimeDefinitionError, ToolingModuleDeclarations,
+	CompilerSession, LintLevel, ModuleAnalysis, ModulePath, OutputLayout, PackageGraphError,
+	PackageId, ProjectDiagnostics, ProjectId, RuntimeDefinitionError, ToolingModuleDeclarations,
 };
 
 pub use nymph_diagnostics::SourceVersion;
@@ -54,6 +58,13 @@ use nymph_diagnostics::Diagnostic;
 pub struct CompilerOptions {
 	pub profile: BuildProfile,
 	pub lints: std::collections::BTreeMap<String, LintLevel>,
+	/// Also produce [`CompiledProject::source_map`].
+	pub source_maps: bool,
+	/// Keep every module as its own file in [`CompiledProject::modules`]
+	/// instead of bundling them into [`CompiledProject::js`].
+	pub layout: OutputLayout,
+	/// Also produce [`CompiledProject::declarations`].
+	pub declarations: bool,
 }
 
 #[cfg(feature = "test-support")]
@@ -210,6 +221,57 @@ pub struct ProjectDiagnostic {
 	pub diag: Diagnostic,
 }
 
+impl ProjectDiagnostic {
+	/// The index of the [`PackageSources`] that loaded this diagnostic's module
+	/// (`0` for the root package) and the package-relative module key, or
+	/// `None` for a compiler-owned standard-library module.
+	#[must_use]
+	pub fn package_module(&self) -> Option<(usize, &str)> {
+		package_module(&self.module)
+	}
+}
+
+/// Split a diagnostic module key — also the module of every
+/// [`nymph_diagnostics::SourceId`] a diagnostic's edits name — into the index
+/// of the [`PackageSources`] that loaded it and the package-relative module
+/// key. `None` for a compiler-owned standard-library module.
+#[must_use]
+pub fn package_module(key: &str) -> Option<(usize, &str)> {
+	if let Some(key) = key.strip_prefix("package::") {
+		let (node, path) = key.split_once("::")?;
+		return Some((node.parse().ok()?, path));
+	}
+	(!key.starts_with(resolve::STD_KEY_PREFIX)).then_some((0, key))
+}
+
+/// A per-module lookup keyed by package-relative module path.
+pub type ModuleLookup<'a> = dyn Fn(&str) -> Option<String> + 'a;
+
+/// Source acquisition for one node of a resolved package graph, as supplied
+/// to the `*_packages_*` facades. The first element of a package slice is the
+/// root package; every later element becomes one exact compiler package whose
+/// module diagnostics are keyed by its slice index (see
+/// [`ProjectDiagnostic::package_module`]).
+pub struct PackageSources<'a> {
+	pub load: &'a ModuleLookup<'a>,
+	/// The runtime source location of a package-relative module key.
+	pub source_uri: Option<&'a ModuleLookup<'a>>,
+	/// Direct dependencies importable as `import alias/…`, by slice index.
+	pub dependencies: std::collections::BTreeMap<String, usize>,
+}
+
+impl<'a> PackageSources<'a> {
+	/// A root package with no dependencies and no runtime source locations.
+	#[must_use]
+	pub fn root(load: &'a ModuleLookup<'a>) -> Self {
+		Self {
+			load,
+			source_uri: None,
+			dependencies: Default::default(),
+		}
+	}
+}
+
 /// Statically selected executable-root adapter and its exact canonical enum
 /// binding when value classification is required.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -222,6 +284,16 @@ pub enum CompiledEntryRoot {
 	TaskResult { binding: String },
 }
 
+/// One `test` declaration of a compiled module: the bundle exposes `binding`
+/// as a parameterless function whose result `root` classifies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledTest {
+	/// The name written in the declaration's string literal.
+	pub name: String,
+	pub binding: String,
+	pub root: CompiledEntryRoot,
+}
+
 /// The result of a successful [`compile_project`]: the whole program as one
 /// runnable JS string, plus the entry module's `main` — every OTHER
 /// top-level name in the project is renamed (step 2 above) to stay globally
@@ -236,12 +308,40 @@ pub struct CompiledProject {
 	/// Present only when this project was compiled in entry mode. The emitted
 	/// `js` remains inert; executable hosts consume this separate adapter fact.
 	pub entry_root: Option<CompiledEntryRoot>,
+	/// The entry module's `test` declarations, in source order. Populated only
+	/// by [`compile_project_tests_packages_with_embedded_std_and_options`];
+	/// every other compilation leaves tests unexported and tree-shaken.
+	pub tests: Vec<CompiledTest>,
 	/// The entry module's own per-project tag. Every top-level name the entry
 	/// module declares OTHER than `main` is mangled `$m{entry_tag}$<name>`
 	/// (see [`Self::entry_symbol`]) — exposed so a caller (chiefly tests)
 	/// that wants to reach some entry-module symbol other than `main`
 	/// doesn't need to know the mangling scheme itself.
 	pub entry_tag: usize,
+	/// The v3 source map from `js` back to the `.nym` sources, as JSON, when
+	/// [`CompilerOptions::source_maps`] was set.
+	pub source_map: Option<String>,
+	/// With [`OutputLayout::Modules`], every module reachable from the entry
+	/// as its own ES module, entry first; `js` and `source_map` are then
+	/// empty. Always empty for a bundle.
+	pub modules: Vec<CompiledModule>,
+	/// TypeScript declarations (`.d.mts`) for the entry module's public API,
+	/// when [`CompilerOptions::declarations`] was set. The entry then also
+	/// exports that API, and the boxed runtime classes, under their own names.
+	pub declarations: Option<String>,
+}
+
+/// One ES module of an unbundled [`CompiledProject`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledModule {
+	/// Where the module goes, relative to the output directory
+	/// (`geometry/vec.mjs`, `_nymph/host/std/box.mjs`, ...). Every import
+	/// between modules is relative to these paths.
+	pub path: String,
+	pub js: String,
+	/// The module's v3 source map, when [`CompilerOptions::source_maps`] was
+	/// set and the module came from `.nym` source.
+	pub source_map: Option<String>,
 }
 
 impl CompiledProject {
@@ -277,6 +377,9 @@ fn facade_session_with_options(
 	let mut session =
 		CompilerSession::from_source_loaders(project.clone(), entry, load, std_provider);
 	session.set_build_profile(options.profile);
+	session.set_source_maps(options.source_maps);
+	session.set_output_layout(options.layout);
+	session.set_declarations(options.declarations);
 	session.set_project_lints(project.clone(), options.lints.clone());
 	let entry = ModulePath::new(entry).expect("project entry must be a canonical module path");
 	(session, project, entry)
@@ -466,6 +569,170 @@ pub fn compile_project_library_with_embedded_std_options_and_source_uris(
 		.map_err(|diagnostics| diagnostics.iter().cloned().collect())
 }
 
+fn packages_facade_session(
+	entry: &str,
+	packages: &[PackageSources<'_>],
+	options: &CompilerOptions,
+) -> (CompilerSession, ProjectId, ModulePath) {
+	let project = ProjectId::new(FACADE_PROJECT);
+	let mut session = CompilerSession::from_package_loaders(
+		project.clone(),
+		&[entry],
+		packages,
+		&crate::embedded_std_provider,
+	);
+	session.set_build_profile(options.profile);
+	session.set_source_maps(options.source_maps);
+	session.set_output_layout(options.layout);
+	session.set_declarations(options.declarations);
+	session.set_project_lints(project.clone(), options.lints.clone());
+	let entry = ModulePath::new(entry).expect("project entry must be a canonical module path");
+	(session, project, entry)
+}
+
+/// Check an entry-mode project whose root package (`packages[0]`) imports
+/// resolved dependency packages. See [`PackageSources`] for how dependency
+/// diagnostics are attributed.
+pub fn check_project_packages_with_embedded_std_and_options(
+	entry: &str,
+	packages: &[PackageSources<'_>],
+	options: &CompilerOptions,
+) -> Vec<ProjectDiagnostic> {
+	let (session, project, entry) = packages_facade_session(entry, packages, options);
+	session
+		.check_project(project, entry, nymph_sema::EntryMode::Entry)
+		.iter()
+		.cloned()
+		.collect()
+}
+
+/// Library-mode counterpart of
+/// [`check_project_packages_with_embedded_std_and_options`].
+pub fn check_project_library_packages_with_embedded_std_and_options(
+	entry: &str,
+	packages: &[PackageSources<'_>],
+	options: &CompilerOptions,
+) -> Vec<ProjectDiagnostic> {
+	let (session, project, entry) = packages_facade_session(entry, packages, options);
+	session
+		.check_project(project, entry, nymph_sema::EntryMode::Library)
+		.iter()
+		.cloned()
+		.collect()
+}
+
+/// Compile an entry-mode project across a resolved package graph. Every
+/// package is emitted into the same bundle under its own exact module
+/// specifiers.
+///
+/// # Errors
+/// Returns `Err` with every project diagnostic if resolution, binding, or
+/// type-checking fails in any package.
+pub fn compile_project_packages_with_embedded_std_and_options(
+	entry: &str,
+	packages: &[PackageSources<'_>],
+	options: &CompilerOptions,
+) -> Result<CompiledProject, Vec<ProjectDiagnostic>> {
+	let (session, project, entry) = packages_facade_session(entry, packages, options);
+	session
+		.compile_project(project, entry, nymph_sema::EntryMode::Entry)
+		.map(|compiled| compiled.as_ref().clone())
+		.map_err(|diagnostics| diagnostics.iter().cloned().collect())
+}
+
+/// Library-mode counterpart of
+/// [`compile_project_packages_with_embedded_std_and_options`].
+///
+/// # Errors
+/// Returns `Err` with every project diagnostic if resolution, binding, or
+/// type-checking fails in any package.
+pub fn compile_project_library_packages_with_embedded_std_and_options(
+	entry: &str,
+	packages: &[PackageSources<'_>],
+	options: &CompilerOptions,
+) -> Result<CompiledProject, Vec<ProjectDiagnostic>> {
+	let (session, project, entry) = packages_facade_session(entry, packages, options);
+	session
+		.compile_project(project, entry, nymph_sema::EntryMode::Library)
+		.map(|compiled| compiled.as_ref().clone())
+		.map_err(|diagnostics| diagnostics.iter().cloned().collect())
+}
+
+/// Compile every module in `modules` of the root package as a test library:
+/// each entry of the result is one module's own bundle, with its
+/// [`CompiledProject::tests`] exposed under unmangled names and the root
+/// runtime (`nymphStartRoot` and friends) linked in. All modules share one
+/// session, so common dependencies are checked once.
+///
+/// # Errors
+/// Returns `Err` with every distinct project diagnostic if resolution,
+/// binding, or type-checking fails for any of the modules.
+pub fn compile_project_tests_packages_with_embedded_std_and_options(
+	modules: &[&str],
+	packages: &[PackageSources<'_>],
+	options: &CompilerOptions,
+) -> Result<Vec<(String, CompiledProject)>, Vec<ProjectDiagnostic>> {
+	let project = ProjectId::new(FACADE_PROJECT);
+	let mut session = CompilerSession::from_package_loaders(
+		project.clone(),
+		modules,
+		packages,
+		&crate::embedded_std_provider,
+	);
+	session.set_build_profile(options.profile);
+	session.set_source_maps(options.source_maps);
+	session.set_output_layout(options.layout);
+	session.set_declarations(options.declarations);
+	session.set_project_lints(project.clone(), options.lints.clone());
+	compile_tests_in(&session, &project, modules)
+}
+
+/// Compile each of `modules` in `session` as a test library, collecting
+/// every distinct diagnostic if any fails.
+fn compile_tests_in(
+	session: &CompilerSession,
+	project: &ProjectId,
+	modules: &[&str],
+) -> Result<Vec<(String, CompiledProject)>, Vec<ProjectDiagnostic>> {
+	let mut compiled = Vec::with_capacity(modules.len());
+	let mut diagnostics: Vec<ProjectDiagnostic> = Vec::new();
+	for module in modules {
+		let entry = ModulePath::new(*module).expect("test module must be a canonical module path");
+		match session.compile_project_with_options(
+			project.clone(),
+			entry,
+			nymph_sema::EntryMode::Library,
+			true,
+		) {
+			Ok(project) => compiled.push(((*module).to_string(), project.as_ref().clone())),
+			Err(errors) => {
+				for diagnostic in errors.iter() {
+					if !diagnostics.contains(diagnostic) {
+						diagnostics.push(diagnostic.clone());
+					}
+				}
+			}
+		}
+	}
+	if diagnostics.is_empty() {
+		Ok(compiled)
+	} else {
+		Err(diagnostics)
+	}
+}
+
+/// Whether `source` declares any top-level `test`. This only parses, so a
+/// test runner can select the modules worth compiling without checking the
+/// whole project first.
+#[must_use]
+pub fn declares_tests(source: &str, source_name: &str) -> bool {
+	nymph_syntax::parse_module(source, source_name)
+		.tree
+		.members
+		.iter()
+		.any(|declaration| matches!(declaration, nymph_ast::decl::Declaration::Test { .. }))
+}
+
 /// Compile one standalone source through the canonical virtual-module
 /// assembly while retaining the facade's unmangled top-level names.
 fn standalone_session(source: &str, source_name: &str) -> (CompilerSession, ProjectId, ModulePath) {
@@ -482,6 +749,9 @@ fn standalone_session_with_options(
 	let path = ModulePath::new(STANDALONE_ENTRY).expect("standalone key is canonical");
 	let mut session = CompilerSession::from_builtin_sources(Default::default());
 	session.set_build_profile(options.profile);
+	session.set_source_maps(options.source_maps);
+	session.set_output_layout(options.layout);
+	session.set_declarations(options.declarations);
 	session.set_project_lints(project.clone(), options.lints.clone());
 	session.set_source_with_location(
 		project.clone(),
@@ -0,0 +1,213 @@
+//! A project session that outlives one command.
+//!
+//! The facade functions acquire a project's modules through its package
+//! loaders, answer one question, and drop the session. [`ProjectSession`]
+//! keeps the session and the import graph it acquired, so a caller that
+//! learns some modules changed on disk reloads only those. Salsa then
+//! recomputes only the queries that read them, while everything else stays
+//! memoized from the previous run.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use nymph_diagnostics::SourceVersion;
+
+use super::{
+	CompiledProject, CompilerOptions, CompilerSession, FACADE_PROJECT, ModulePath, PackageId,
+	PackageSources, ProjectDiagnostic, ProjectId, resolve::STD_KEY_PREFIX,
+};
+
+/// One project module: its package index and package-relative module key.
+type ModuleKey = (usize, String);
+
+/// A compiler session over a resolved package graph that stays alive across
+/// source changes. `packages` passed to each method must describe the same
+/// graph the session was created with.
+///
+/// The session holds exactly the modules reachable from its roots, as a
+/// fresh facade session would: a module no longer imported is dropped, and a
+/// module a changed import now names is acquired.
+pub struct ProjectSession {
+	session: CompilerSession,
+	project: ProjectId,
+	packages: Vec<PackageId>,
+	roots: BTreeSet<String>,
+	/// Every module whose source the session holds.
+	held: BTreeSet<ModuleKey>,
+	/// Every held module that is current, and the project modules it imports.
+	imports: BTreeMap<ModuleKey, Vec<ModuleKey>>,
+	next_version: i64,
+}
+
+impl ProjectSession {
+	/// A session over `packages` with no modules acquired yet; see
+	/// [`Self::set_roots`].
+	#[must_use]
+	pub fn new(packages: &[PackageSources<'_>], options: &CompilerOptions) -> Self {
+		let project = ProjectId::new(FACADE_PROJECT);
+		let mut session = CompilerSession::new();
+		session.set_build_profile(options.profile);
+		session.set_source_maps(options.source_maps);
+		session.set_output_layout(options.layout);
+		session.set_declarations(options.declarations);
+		session.set_project_lints(project.clone(), options.lints.clone());
+		let packages = session.link_packages(project.clone(), packages);
+		Self {
+			session,
+			project,
+			packages,
+			roots: BTreeSet::new(),
+			held: BTreeSet::new(),
+			imports: BTreeMap::new(),
+			next_version: 1,
+		}
+	}
+
+	/// Hold exactly the root package's `roots` and every module they reach.
+	pub fn set_roots(&mut self, roots: &[&str], packages: &[PackageSources<'_>]) {
+		let roots = roots.iter().map(|root| (*root).to_string()).collect();
+		if roots != self.roots {
+			self.roots = roots;
+			self.acquire(packages);
+		}
+	}
+
+	/// Read `modules`, by package index and module key, through their loaders
+	/// again. A module that no longer loads is dropped; one that loads for the
+	/// first time is acquired if an import reaches it.
+	pub fn reload(
+		&mut self,
+		modules: impl IntoIterator<Item = (usize, String)>,
+		packages: &[PackageSources<'_>],
+	) {
+		for module in modules {
+			self.imports.remove(&module);
+		}
+		self.acquire(packages);
+	}
+
+	/// Check the root package in entry mode from `entry`, one of the roots.
+	#[must_use]
+	pub fn check(&self, entry: &str) -> Vec<ProjectDiagnostic> {
+		self.check_with(entry, nymph_sema::EntryMode::Entry)
+	}
+
+	/// Library-mode counterpart of [`Self::check`].
+	#[must_use]
+	pub fn check_library(&self, entry: &str) -> Vec<ProjectDiagnostic> {
+		self.check_with(entry, nymph_sema::EntryMode::Library)
+	}
+
+	/// Compile the root package in entry mode from `entry`, one of the roots.
+	///
+	/// # Errors
+	/// Returns `Err` with every project diagnostic if resolution, binding, or
+	/// type-checking fails in any package.
+	pub fn compile(&self, entry: &str) -> Result<CompiledProject, Vec<ProjectDiagnostic>> {
+		self.compile_with(entry, nymph_sema::EntryMode::Entry)
+	}
+
+	/// Library-mode counterpart of [`Self::compile`].
+	///
+	/// # Errors
+	/// See [`Self::compile`].
+	pub fn compile_library(&self, entry: &str) -> Result<CompiledProject, Vec<ProjectDiagnostic>> {
+		self.compile_with(entry, nymph_sema::EntryMode::Library)
+	}
+
+	/// Compile each of `modules`, all of them roots, as a test library. See
+	/// [`super::compile_project_tests_packages_with_embedded_std_and_options`].
+	///
+	/// # Errors
+	/// Returns `Err` with every distinct project diagnostic if any module
+	/// fails to compile.
+	pub fn compile_tests(
+		&self,
+		modules: &[&str],
+	) -> Result<Vec<(String, CompiledProject)>, Vec<ProjectDiagnostic>> {
+		super::compile_tests_in(&self.session, &self.project, modules)
+	}
+
+	fn check_with(&self, entry: &str, mode: nymph_sema::EntryMode) -> Vec<ProjectDiagnostic> {
+		let entry = ModulePath::new(entry).expect("project entry must be a canonical module path");
+		self
+			.session
+			.check_project(self.project.clone(), entry, mode)
+			.iter()
+			.cloned()
+			.collect()
+	}
+
+	fn compile_with(
+		&self,
+		entry: &str,
+		mode: nymph_sema::EntryMode,
+	) -> Result<CompiledProject, Vec<ProjectDiagnostic>> {
+		let entry = ModulePath::new(entry).expect("project entry must be a canonical module path");
+		self
+			.session
+			.compile_project(self.project.clone(), entry, mode)
+			.map(|compiled| compiled.as_ref().clone())
+			.map_err(|diagnostics| diagnostics.iter().cloned().collect())
+	}
+
+	/// Walk imports from the roots, loading every module not already held,
+	/// then drop every held module the walk no longer reaches. Standard-library
+	/// modules are all embedded in the session up front.
+	fn acquire(&mut self, packages: &[PackageSources<'_>]) {
+		let mut reached = BTreeSet::new();
+		let mut pending = self
+			.roots
+			.iter()
+			.rev()
+			.map(|root| (0, root.clone()))
+			.collect::<Vec<_>>();
+		while let Some(module) = pending.pop() {
+			if module.1.starts_with(STD_KEY_PREFIX) || !reached.insert(module.clone()) {
+				continue;
+			}
+			if !self.imports.contains_key(&module) {
+				let (package, key) = &module;
+				let Ok(path) = ModulePath::new(key.as_str()) else {
+					continue;
+				};
+				let Some(source) = (packages[*package].load)(key) else {
+					continue;
+				};
+				let imports = super::session::source_imports(&source, key, *package, packages);
+				let source_uri = packages[*package]
+					.source_uri
+					.and_then(|source_uri| source_uri(key));
+				self
+					.session
+					.set_package_source_with_location(
+						self.packages[*package].clone(),
+						path.clone(),
+						source,
+						SourceVersion(self.next_version),
+						format!("{path}.nym"),
+						source_uri,
+					)
+					.expect("linked packages are minted");
+				self.next_version += 1;
+				self.held.insert(module.clone());
+				self.imports.insert(module.clone(), imports);
+			}
+			pending.extend(self.imports[&module].iter().rev().cloned());
+		}
+		self.imports.retain(|module, _| reached.contains(module));
+		let dropped = self
+			.held
+			.iter()
+			.filter(|module| !self.imports.contains_key(*module))
+			.cloned()
+			.collect::<Vec<_>>();
+		for (package, key) in dropped {
+			self.held.remove(&(package, key.clone()));
+			let path = ModulePath::new(key).expect("held module keys are canonical");
+			self
+				.session
+				.remove_package_source(self.packages[package].clone(), path)
+				.expect("linked packages are minted");
+		}
+	}
+}
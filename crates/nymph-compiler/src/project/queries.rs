@@ -4,7 +4,7 @@ use nymph_ast::{
 	Ident, Span,
 	decl::{Declaration, Module},
 };
-use nymph_diagnostics::Diagnostic;
+use nymph_diagnostics::{Diagnostic, EditGroup, SourceEdit, SourceId, TextReplacement};
 #[cfg(not(target_arch = "wasm32"))]
 use rayon::prelude::*;
 use rustc_hash::FxHashMap;
@@ -44,7 +44,7 @@ fn apply_semantic_lint(
 	mut diagnostic: Diagnostic,
 ) -> Option<Diagnostic> {
 	let lint = match diagnostic.code.as_str() {
-		"managed-field" | "managed-child-capture" => diagnostic.code.clone(),
+		"managed-field" | "managed-child-capture" | "deprecated" => diagnostic.code.clone(),
 		_ => return Some(diagnostic),
 	};
 	let SemanticModuleInput::Project(module) = module else {
@@ -66,6 +66,40 @@ fn apply_semantic_lint(
 	}
 }
 
+/// The root package's unused-code findings for `module`, each at its `[lints]`
+/// level and carrying its fix.
+fn unused_lint_diagnostics(
+	db: &dyn Db,
+	key: ProjectKey<'_>,
+	module: ModuleInput,
+	analysis: &nymph_sema::SemanticAnalysis,
+) -> Vec<ProjectDiagnostic> {
+	let source = module.source(db).unwrap_or_default();
+	nymph_sema::unused_bindings(analysis, &source)
+		.into_iter()
+		.filter_map(|unused| {
+			let diagnostic = match effective_lint_level(
+				db,
+				key.policy_input(db),
+				module.package(db),
+				Arc::from(unused.lint),
+				LintLevel::Warn,
+			) {
+				LintLevel::Allow => return None,
+				LintLevel::Warn => Diagnostic::warning(unused.lint.into(), unused.message, unused.span),
+				LintLevel::Deny => Diagnostic::error(unused.lint.into(), unused.message, unused.span),
+			};
+			Some(ProjectDiagnostic {
+				module: SemanticModuleInput::Project(module).display_key(db),
+				diag: match module_edit(db, module, &unused.fix, unused.replacements) {
+					Some(edit) => diagnostic.with_edit(edit),
+					None => diagnostic,
+				},
+			})
+		})
+		.collect()
+}
+
 #[salsa::tracked(returns(clone))]
 fn policy_project_diagnostics(
 	db: &dyn Db,
@@ -92,14 +126,30 @@ fn policy_project_diagnostics(
 			continue;
 		}
 		let module_name = module.path(db).to_string();
-		for span in nymph_sema::query::echo_sites(&parse(db, module).tree) {
+		let source = module.source(db).unwrap_or_default();
+		for site in nymph_sema::query::echo_sites(&parse(db, module).tree) {
+			let span = site.keyword;
 			let message = "`echo` is erased from release builds";
-			let diagnostic = match level {
+			let mut diagnostic = match level {
 				LintLevel::Allow => unreachable!(),
 				LintLevel::Warn => Diagnostic::warning("echo-in-release".into(), message, span),
 				LintLevel::Deny => Diagnostic::error("echo-in-release".into(), message, span),
 			}
 			.with_help("use `println` or telemetry for intentional release output");
+			// Dropping the keyword keeps the operand, which is all a release
+			// build evaluates; a comment between the two is left in place.
+			let removed = match source.get(span.end..site.operand.start) {
+				Some(gap) if gap.trim().is_empty() => Span::new(span.start, site.operand.start),
+				_ => span,
+			};
+			if let Some(edit) = module_edit(
+				db,
+				module,
+				"Remove `echo`",
+				vec![TextReplacement::new(removed, "")],
+			) {
+				diagnostic = diagnostic.with_edit(edit);
+			}
 			diagnostics.push(ProjectDiagnostic {
 				module: module_name.clone(),
 				diag: diagnostic,
@@ -109,6 +159,23 @@ fn policy_project_diagnostics(
 	super::session::ProjectDiagnostics(diagnostics.into())
 }
 
+/// A machine-applicable edit of `module`'s current source. The source is
+/// identified by its project and diagnostic module key, so frontends locate an
+/// edit exactly as they locate the diagnostic carrying it.
+pub(crate) fn module_edit(
+	db: &dyn Db,
+	module: ModuleInput,
+	title: &str,
+	replacements: Vec<TextReplacement>,
+) -> Option<EditGroup> {
+	let source = SourceId::new(
+		module.project(db).as_str(),
+		SemanticModuleInput::Project(module).display_key(db),
+	);
+	let edit = SourceEdit::new(source, module.version(db), replacements).ok()?;
+	EditGroup::new(title, vec![edit]).ok()
+}
+
 #[salsa::db]
 pub(crate) trait Db: salsa::Database {
 	#[cfg(not(target_arch = "wasm32"))]
@@ -512,11 +579,12 @@ pub(crate) fn compiler_runtime_roles(
 			.copied()
 			.find(|module| module.key(db).path.as_ref() == path)
 	};
-	let interface =
-		|path: &str,
-		 select: fn(&nymph_sema::CompilerRuntimeRoles) -> &Option<nymph_sema::InterfaceRuntimeRole>| {
-			select(&ambient_runtime_role_inventory(db, module(path)?)).clone()
-		};
+	let interface = |path: &str,
+	                 select: fn(
+		&nymph_sema::CompilerRuntimeRoles,
+	) -> &Option<nymph_sema::InterfaceRuntimeRole>| {
+		select(&ambient_runtime_role_inventory(db, module(path)?)).clone()
+	};
 	let option =
 		module("option").and_then(|module| ambient_runtime_role_inventory(db, module).option.clone());
 	let result =
@@ -1248,7 +1316,8 @@ pub(crate) fn parse_builtin(db: &dyn Db, module: BuiltinModuleInput) -> Arc<Pars
 fn parse_source(source: Arc<str>, path: String) -> Arc<ParsedModule> {
 	#[cfg(feature = "test-support")]
 	let _timing = super::benchmark_support::phase(super::benchmark_support::Phase::Parse);
-	let parsed = nymph_syntax::parse_module(&source, path);
+	let mut parsed = nymph_syntax::parse_module(&source, path);
+	nymph_sema::expand_derives(&mut parsed.tree, &source);
 	Arc::new(ParsedModule {
 		tree: parsed.tree,
 		diagnostics: parsed.diagnostics.into(),
@@ -1761,6 +1830,27 @@ pub(crate) fn runtime_manifest<'db>(
 	RuntimeManifest::new(db, definitions).map_err(super::session::RuntimeDefinitionError::Extraction)
 }
 
+/// Source positions of `module`'s runtime bodies. Only source-mapped emission
+/// reads these, so position-only edits leave every other artifact reusable.
+#[salsa::tracked(returns(clone))]
+pub(crate) fn runtime_body_spans<'db>(
+	db: &'db dyn Db,
+	key: ProjectKey<'db>,
+	module: SemanticModuleInput,
+) -> Arc<[nymph_sema::RuntimeBodySpans]> {
+	let environment = exact_module_environment(db, key, module);
+	let nymph_sema::ModuleEnvironment::Complete(interface) = environment.as_ref() else {
+		return Arc::new([]);
+	};
+	let analysis = interface_module_analysis(db, key, module);
+	nymph_sema::runtime_body_spans(
+		&analysis.semantic.module,
+		&analysis.semantic.checked,
+		interface,
+	)
+	.map_or_else(|_| Arc::new([]) as Arc<[_]>, Into::into)
+}
+
 /// Runtime-bearing identities owned by `module`, in language output order.
 /// This is intentionally distinct from lookup: module assembly depends on the
 /// identities, then requests each body through its exact per-definition query.
@@ -2443,7 +2533,17 @@ pub(crate) fn lower_interface_module<'db>(
 		.collect::<std::collections::VecDeque<_>>();
 	let mut seen = std::collections::HashSet::new();
 	let mut lowered: Vec<nymph_sema::LoweredRuntimeDefinition> = Vec::new();
-	let mut unresolved_calls = Vec::new();
+	// The runtime renders values through their `Display` and `Debug` slots
+	// without a call the lowering could see, so every demanded shell keeps them.
+	let roles = compiler_runtime_roles(db, key.ambient_core_registry(db));
+	let mut unresolved_calls = [roles.display.as_ref(), roles.debug.as_ref()]
+		.into_iter()
+		.flatten()
+		.map(|role| nymph_sema::UnresolvedRuntimeCall::GenericDispatch {
+			interface: role.interface.clone(),
+			member: role.member.clone(),
+		})
+		.collect::<Vec<_>>();
 	while let Some(definition) = queue.pop_front() {
 		if !seen.insert(definition.clone()) {
 			continue;
@@ -2475,6 +2575,12 @@ pub(crate) fn lower_interface_module<'db>(
 							.as_ref()
 							.map(|role| role.option.clone())
 					}
+					crate::host_runtime::CompilerRuntimeRole::Result => {
+						compiler_runtime_roles(db, key.ambient_core_registry(db))
+							.result
+							.as_ref()
+							.map(|role| role.result.clone())
+					}
 				}
 				.ok_or_else(|| nymph_sema::StableModuleAssemblyError::UnresolvedDemand {
 					definition: definition.clone(),
@@ -2793,6 +2899,15 @@ pub(crate) fn interface_module_analysis<'db>(
 	}
 	let mut semantic = result.analysis.as_ref().clone();
 	semantic.import_references = resolved.references.clone().into();
+	// Unused-code lints describe a module that checked cleanly; after an error,
+	// missing references would only make more bindings look unused.
+	if let SemanticModuleInput::Project(project_module) = module
+		&& key.mode(db) != nymph_sema::EntryMode::Repl
+		&& project_module.package(db) == key.policy_input(db).root_package(db)
+		&& !diagnostics.iter().any(|diagnostic| diagnostic.diag.is_error())
+	{
+		diagnostics.extend(unused_lint_diagnostics(db, key, project_module, &semantic));
+	}
 	Arc::new(super::session::ModuleAnalysis {
 		source: match module {
 			SemanticModuleInput::Project(module) => module.source(db).unwrap_or_default(),
@@ -2899,7 +3014,8 @@ pub(crate) fn interface_module_environment<'db>(
 	db.semantic_query_will_execute("interface_module_environment", module);
 	let analysis = interface_module_analysis(db, key, module);
 	let diagnostics = interface_module_diagnostics(db, key, module);
-	if diagnostics.is_empty()
+	// Lint warnings leave a module's checked facts complete.
+	if !diagnostics.iter().any(|item| item.diag.is_error())
 		&& let Ok(interface) = interface_module_interface(db, key, module)
 	{
 		return Arc::new(nymph_sema::ModuleEnvironment::Complete(
@@ -3415,6 +3531,9 @@ mod tests {
 			PackageId::root(project.clone()),
 			crate::project::BuildProfile::Development,
 			Arc::new([]),
+			false,
+			crate::project::OutputLayout::Bundle,
+			false,
 		);
 		let input = ProjectInput::new(&db, project, modules, Arc::new([]));
 		let registry = BuiltinRegistryInput::new(&db, builtin_modules);
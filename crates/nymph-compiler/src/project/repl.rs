@@ -721,6 +721,7 @@ fn declaration_visibility(declaration: &Declaration) -> Option<Visibility> {
 		Declaration::ExternalLet(visibility, ..) | Declaration::ExternalFunc(visibility, ..) => {
 			*visibility
 		}
+		Declaration::Test { .. } => Some(Visibility::Private),
 	}
 }
 
@@ -729,7 +730,9 @@ fn declaration_names(declaration: &Declaration, names: &mut BTreeSet<String>) {
 		Declaration::Let { meta, .. } | Declaration::ExternalLet(_, _, meta) => {
 			pattern_names(&meta.name.0, names);
 		}
-		Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) => {
+		Declaration::Func { meta, .. }
+		| Declaration::Test { meta, .. }
+		| Declaration::ExternalFunc(_, _, meta) => {
 			names.insert(meta.name.0.to_string());
 		}
 		Declaration::Effect { name, .. } => {
@@ -35,6 +35,15 @@ pub enum BuildProfile {
 	Release,
 }
 
+/// How a compiled project's modules are laid out: linked into one bundle, or
+/// kept as one ES module per Nymph module (see [`super::CompiledProject::modules`]).
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug, salsa::SalsaValue)]
+pub enum OutputLayout {
+	#[default]
+	Bundle,
+	Modules,
+}
+
 #[derive(
 	Clone,
 	Copy,
@@ -287,6 +296,15 @@ pub(crate) struct ProjectPolicyInput {
 	pub profile: BuildProfile,
 	#[returns(clone)]
 	pub lints: Arc<[LintSetting]>,
+	/// Whether emission also maps the bundle back to `.nym` sources. Mapped
+	/// emission depends on source positions, so it is opt-in.
+	#[returns(copy)]
+	pub source_maps: bool,
+	#[returns(copy)]
+	pub layout: OutputLayout,
+	/// Whether emission also declares the entry's public API for TypeScript.
+	#[returns(copy)]
+	pub declarations: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, salsa::SalsaValue)]
@@ -513,6 +531,9 @@ pub struct CompilerSession {
 	policy_inputs: Mutex<BTreeMap<ProjectId, ProjectPolicyInput>>,
 	project_lints: BTreeMap<ProjectId, Arc<[LintSetting]>>,
 	build_profile: BuildProfile,
+	source_maps: bool,
+	output_layout: OutputLayout,
+	declarations: bool,
 	builtin_sources: BTreeMap<Arc<str>, Arc<str>>,
 	builtins: BTreeMap<BuiltinModuleKey, BuiltinModuleInput>,
 	builtin_registry: BuiltinRegistryInput,
@@ -932,7 +953,7 @@ impl CompilerSession {
 			.expect("test emission must name an active project module");
 		let key = self.project_key(project, entry, mode, false, true);
 		match super::emission::emitted_interface_module(&self.db, key, input, false) {
-			super::emission::StableEmissionResult::Value(value) => Ok(value),
+			super::emission::StableEmissionResult::Value(value) => Ok(Arc::new(value.source.clone())),
 			super::emission::StableEmissionResult::Diagnostics(diagnostics) => Err(diagnostics),
 		}
 	}
@@ -1221,6 +1242,26 @@ impl CompilerSession {
 		self.module_analysis(project, input, key)
 	}
 
+	/// [`Self::tooling_analyze_module`] for a module of any package in the
+	/// project, such as a resolved dependency reachable from `entry`.
+	#[doc(hidden)]
+	#[must_use]
+	pub fn tooling_analyze_package_module(
+		&self,
+		project: ProjectId,
+		entry: ModulePath,
+		package: PackageId,
+		module: ModulePath,
+		ambient_prelude: bool,
+	) -> Option<Arc<ModuleAnalysis>> {
+		if package.project != project {
+			return None;
+		}
+		let input = self.registry.get(&(package, module))?.input;
+		let key = self.tooling_key(project.clone(), entry, ambient_prelude);
+		self.module_analysis(project, input, key)
+	}
+
 	/// Return recovered tooling analysis for one active module even when its
 	/// import graph is incomplete. This is intended for isolated editor
 	/// documents: unresolved imports contribute diagnostics and no bindings,
@@ -1316,6 +1357,36 @@ impl CompilerSession {
 			.collect()
 	}
 
+	/// Every active module of one non-root `package`, analyzed under the same
+	/// key as `entry`. A module `entry` does not reach has no analysis.
+	#[doc(hidden)]
+	#[must_use]
+	pub fn tooling_package_analyses(
+		&self,
+		project: ProjectId,
+		entry: ModulePath,
+		package: PackageId,
+		ambient_prelude: bool,
+	) -> Vec<(ModulePath, Arc<str>, Option<Arc<ModuleAnalysis>>)> {
+		let mut modules = self
+			.registry
+			.iter()
+			.filter(|((owner, _), record)| {
+				*owner == package && owner.project == project && record.source.is_some()
+			})
+			.filter_map(|((_, path), record)| Some((path.clone(), record.input, record.source.clone()?)))
+			.collect::<Vec<_>>();
+		modules.sort_by(|(left, _, _), (right, _, _)| left.cmp(right));
+		let key = self.tooling_key(project.clone(), entry, ambient_prelude);
+		modules
+			.into_iter()
+			.map(|(path, input, source)| {
+				let analysis = self.module_analysis(project.clone(), input, key);
+				(path, source, analysis)
+			})
+			.collect()
+	}
+
 	/// Check a tooling project with the exact key used by
 	/// [`Self::tooling_analyze_module`].
 	#[doc(hidden)]
@@ -1394,61 +1465,96 @@ impl CompilerSession {
 		entry: &str,
 		load: &dyn Fn(&str) -> Option<String>,
 		std_provider: &dyn Fn(&str) -> Option<String>,
+	) -> Self {
+		Self::from_package_loaders(
+			project,
+			&[entry],
+			&[super::PackageSources::root(load)],
+			std_provider,
+		)
+	}
+
+	/// Acquire every module reachable from the root package's `roots` across a
+	/// resolved package graph. `packages[0]` is the root; every other entry is
+	/// minted in slice order, so its package node equals its slice index.
+	pub(crate) fn from_package_loaders(
+		project: ProjectId,
+		roots: &[&str],
+		packages: &[super::PackageSources<'_>],
+		std_provider: &dyn Fn(&str) -> Option<String>,
 	) -> Self {
 		let mut project_sources = BTreeMap::new();
 		let mut builtin_sources = BTreeMap::new();
 		let mut seen = BTreeSet::new();
-		let mut pending = vec![entry.to_string()];
-		while let Some(key) = pending.pop() {
-			if !seen.insert(key.clone()) {
+		let mut pending = roots
+			.iter()
+			.rev()
+			.map(|root| (0, root.to_string()))
+			.collect::<Vec<_>>();
+		while let Some((package, key)) = pending.pop() {
+			if !seen.insert((package, key.clone())) {
 				continue;
 			}
 			let source = if let Some(path) = key.strip_prefix(super::resolve::STD_KEY_PREFIX) {
 				std_provider(path)
 			} else {
-				load(&key)
+				(packages[package].load)(&key)
 			};
 			let Some(source) = source else {
 				continue;
 			};
-			let parsed = nymph_syntax::parse_module(&source, &format!("{key}.nym"));
-			let mut imports = Vec::new();
-			for declaration in &parsed.tree.members {
-				let Declaration::Import {
-					root, path, alias, ..
-				} = declaration
-				else {
-					continue;
-				};
-				if path.is_empty() && alias.is_none() {
-					continue;
-				}
-				if let Ok(target) =
-					super::resolve::resolve_import_target(root, path, &key, nymph_ast::Span::new(0, 0))
-					&& let Some(target) = target.loader_key()
-				{
-					imports.push(target);
-				}
-			}
+			let imports = source_imports(&source, &key, package, packages);
 			pending.extend(imports.into_iter().rev());
 			if let Some(path) = key.strip_prefix(super::resolve::STD_KEY_PREFIX) {
 				builtin_sources.insert(path.to_string(), source);
 			} else {
-				project_sources.insert(key, source);
+				project_sources.insert((package, key), source);
 			}
 		}
 		let mut session = Self::from_builtin_sources(builtin_sources);
-		for (path, source) in project_sources {
-			session.set_source(
-				project.clone(),
-				ModulePath::new(path).expect("resolved source key is canonical"),
+		let ids = session.link_packages(project, packages);
+		for ((package, path), source) in project_sources {
+			let module = ModulePath::new(path).expect("resolved source key is canonical");
+			let source_uri = packages[package]
+				.source_uri
+				.and_then(|source_uri| source_uri(module.as_str()));
+			let source_name = format!("{module}.nym");
+			session.set_package_source_inner(
+				ids[package].clone(),
+				module,
 				source,
 				SourceVersion(1),
+				source_name,
+				source_uri.map(Arc::from),
 			);
 		}
 		session
 	}
 
+	/// Mint every package of a resolved graph after the root `packages[0]`,
+	/// in slice order so each package node equals its slice index, and link
+	/// their import aliases. Returns each package's id by slice index.
+	pub(crate) fn link_packages(
+		&mut self,
+		project: ProjectId,
+		packages: &[super::PackageSources<'_>],
+	) -> Vec<PackageId> {
+		let mut ids = vec![self.root_package(project.clone())];
+		for index in 1..packages.len() {
+			let package = self.mint_package(project.clone());
+			debug_assert_eq!(package.node(), index as u64);
+			ids.push(package);
+		}
+		for (owner, package) in packages.iter().enumerate() {
+			for (alias, &target) in &package.dependencies {
+				self
+					.set_package_alias(ids[owner].clone(), alias.as_str(), ids[target].clone())
+					.expect("resolved package aliases name minted packages");
+			}
+		}
+		ids
+	}
+
 	#[doc(hidden)]
 	pub fn with_event_callback_and_tombstone_threshold(
 		callback: impl Fn(&str) + Send + Sync + 'static,
@@ -1476,6 +1582,9 @@ impl CompilerSession {
 			self.tombstone_threshold,
 		);
 		isolated.build_profile = self.build_profile;
+		isolated.source_maps = self.source_maps;
+		isolated.output_layout = self.output_layout;
+		isolated.declarations = self.declarations;
 		isolated
 	}
 
@@ -1497,6 +1606,9 @@ impl CompilerSession {
 			policy_inputs: Mutex::new(BTreeMap::new()),
 			project_lints: BTreeMap::new(),
 			build_profile: BuildProfile::default(),
+			source_maps: false,
+			output_layout: OutputLayout::default(),
+			declarations: false,
 			builtin_sources,
 			builtins,
 			builtin_registry,
@@ -1691,13 +1803,15 @@ impl CompilerSession {
 		);
 	}
 
+	/// Attach runtime source URIs to the root package's modules. Dependency
+	/// packages receive theirs when their sources are acquired.
 	pub fn set_project_source_uris(
 		&mut self,
 		project: &ProjectId,
 		uri_for_module: &dyn Fn(&str) -> Option<String>,
 	) {
 		for ((package, path), record) in &mut self.registry {
-			if package.project() != project {
+			if package.project() != project || !package.is_root() {
 				continue;
 			}
 			let source_uri = uri_for_module(path.as_str()).map(Arc::from);
@@ -1807,6 +1921,52 @@ impl CompilerSession {
 		}
 	}
 
+	/// Make later compilations also produce a source map for their bundle.
+	pub fn set_source_maps(&mut self, enabled: bool) {
+		if self.source_maps == enabled {
+			return;
+		}
+		self.source_maps = enabled;
+		let inputs = self
+			.policy_inputs
+			.get_mut()
+			.unwrap_or_else(|error| error.into_inner());
+		for input in inputs.values() {
+			input.set_source_maps(&mut self.db).to(enabled);
+		}
+	}
+
+	/// Make later compilations emit `layout` instead of the current layout.
+	pub fn set_output_layout(&mut self, layout: OutputLayout) {
+		if self.output_layout == layout {
+			return;
+		}
+		self.output_layout = layout;
+		let inputs = self
+			.policy_inputs
+			.get_mut()
+			.unwrap_or_else(|error| error.into_inner());
+		for input in inputs.values() {
+			input.set_layout(&mut self.db).to(layout);
+		}
+	}
+
+	/// Make later compilations also produce TypeScript declarations for
+	/// their entry module.
+	pub fn set_declarations(&mut self, enabled: bool) {
+		if self.declarations == enabled {
+			return;
+		}
+		self.declarations = enabled;
+		let inputs = self
+			.policy_inputs
+			.get_mut()
+			.unwrap_or_else(|error| error.into_inner());
+		for input in inputs.values() {
+			input.set_declarations(&mut self.db).to(enabled);
+		}
+	}
+
 	pub fn set_project_lints(
 		&mut self,
 		project: ProjectId,
@@ -2046,6 +2206,9 @@ impl CompilerSession {
 					.get(&project)
 					.cloned()
 					.unwrap_or_default(),
+				self.source_maps,
+				self.output_layout,
+				self.declarations,
 			)
 		})
 	}
@@ -2250,8 +2413,14 @@ impl CompilerSession {
 				let echo = sources.values().any(|source| source.contains("nymphEcho"));
 				for (module, source) in crate::host_runtime::HostRuntimeGraph::compiler_facts()
 					.module_sources(
-						&emitted.compiler_option_binding,
-						&emitted.compiler_option_module,
+						(
+							&emitted.compiler_option_binding,
+							&emitted.compiler_option_module,
+						),
+						(
+							&emitted.compiler_result_binding,
+							&emitted.compiler_result_module,
+						),
 						echo,
 					) {
 					if sources.insert(module.clone(), source).is_some() {
@@ -2392,6 +2561,48 @@ impl CompilerSession {
 	}
 }
 
+/// The project modules `source`, module `key` of package `package`, imports:
+/// each as its owning package index and module key. Standard-library targets
+/// keep their [`super::resolve::STD_KEY_PREFIX`] key under package 0.
+pub(super) fn source_imports(
+	source: &str,
+	key: &str,
+	package: usize,
+	packages: &[super::PackageSources<'_>],
+) -> Vec<(usize, String)> {
+	let parsed = nymph_syntax::parse_module(source, &format!("{key}.nym"));
+	let mut imports = Vec::new();
+	for declaration in &parsed.tree.members {
+		let Declaration::Import {
+			root, path, alias, ..
+		} = declaration
+		else {
+			continue;
+		};
+		if path.is_empty() && alias.is_none() {
+			continue;
+		}
+		let Ok(target) =
+			super::resolve::resolve_import_target(root, path, key, nymph_ast::Span::new(0, 0))
+		else {
+			continue;
+		};
+		if let Some(target) = target.loader_key() {
+			let owner = if target.starts_with(super::resolve::STD_KEY_PREFIX) {
+				0
+			} else {
+				package
+			};
+			imports.push((owner, target));
+		} else if let super::resolve::ResolvedImportTarget::Package { alias, path } = target
+			&& let Some(&dependency) = packages[package].dependencies.get(&alias)
+		{
+			imports.push((dependency, path));
+		}
+	}
+	imports
+}
+
 #[cfg(test)]
 mod tests {
 	use std::{cell::RefCell, collections::HashMap};
@@ -0,0 +1,579 @@
+//! TypeScript declarations for the entry module's public API.
+//!
+//! Declarations are rendered from the same checked module interfaces the
+//! documentation extractor reads, and describe the boxed runtime ABI exactly
+//! as the emitter produces it: an `int` crosses the boundary as an `NInt`, a
+//! list as an `NList`, a struct as an instance of its class, and an enum
+//! value as one of its tagged variant objects. The boxed classes themselves
+//! are declared from the runtime's own `std/box` declarations.
+//!
+//! Emitted bindings are mangled, so alongside the declarations the entry
+//! gains an `export { <binding> as <name> }` line (see [`EntryDeclarations`])
+//! that makes the declared names real.
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt::Write as _;
+
+use nymph_ast::decl::Visibility;
+use nymph_sema::{
+	DefinitionId, DefinitionShapeKind, ExportedDefinition, FieldShape, GenericParameter,
+	GenericParameterId, GenericParameterKind, InterfaceType, ModuleEnvironment, ParameterShape,
+};
+
+use super::queries::{self, Db};
+use super::session::ProjectKey;
+
+/// What `--declarations` adds to a compiled project.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct EntryDeclarations {
+	/// Export statements appended to the entry module's source: its public
+	/// API under source names, and the boxed runtime classes from `std/box`.
+	pub(crate) exports: String,
+	/// The `.d.mts` text describing those exports.
+	pub(crate) declarations: String,
+}
+
+/// Declare every non-private export of `key`'s entry module, plus whatever
+/// struct, enum, and alias types their signatures reach in other modules.
+///
+/// # Errors
+/// Returns a message when the entry's interface was recovered from errors or
+/// one of its exports has no emitted binding.
+pub(crate) fn entry_declarations(
+	db: &dyn Db,
+	key: ProjectKey<'_>,
+) -> Result<EntryDeclarations, String> {
+	let graph = queries::project_graph(db, key);
+	let entry_identity = nymph_sema::ModuleIdentity::resolved_project(
+		key.project_input(db).project(db).as_str(),
+		0,
+		key.entry(db).as_str(),
+	);
+	let mut definitions = HashMap::new();
+	let mut entry_exports = Vec::new();
+	for module in graph.semantic_order.iter().copied() {
+		let environment = queries::interface_module_environment(db, key, module);
+		let ModuleEnvironment::Complete(interface) = environment.as_ref() else {
+			if module.identity(db) == entry_identity {
+				return Err("the entry module's interface was recovered from errors".to_string());
+			}
+			continue;
+		};
+		if interface.module == entry_identity {
+			entry_exports = interface
+				.exports
+				.iter()
+				.filter(|definition| definition.visibility != Some(Visibility::Private))
+				.cloned()
+				.collect();
+		}
+		for definition in interface.exports.iter().chain(
+			interface
+				.support_definitions
+				.iter()
+				.map(|support| &support.definition),
+		) {
+			definitions.insert(definition.id.clone(), definition.clone());
+		}
+	}
+	entry_exports.sort_by(|a, b| a.name.cmp(&b.name));
+
+	let runtime = runtime_declarations();
+	let runtime_classes = runtime_classes(&runtime);
+	let mut declarer = Declarer {
+		definitions: &definitions,
+		names: BTreeMap::new(),
+		taken: runtime_classes.iter().map(ToString::to_string).collect(),
+		pending: VecDeque::new(),
+	};
+	for definition in &entry_exports {
+		declarer.taken.insert(definition.name.to_string());
+		declarer
+			.names
+			.insert(definition.id.clone(), definition.name.to_string());
+	}
+
+	let mut aliases = Vec::new();
+	let mut exported = String::new();
+	for definition in &entry_exports {
+		if !matches!(
+			definition.kind,
+			DefinitionShapeKind::Function
+				| DefinitionShapeKind::Let
+				| DefinitionShapeKind::Struct
+				| DefinitionShapeKind::Enum
+				| DefinitionShapeKind::TypeAlias
+				| DefinitionShapeKind::Interface
+		) {
+			continue;
+		}
+		if matches!(
+			definition.kind,
+			DefinitionShapeKind::Function
+				| DefinitionShapeKind::Let
+				| DefinitionShapeKind::Struct
+				| DefinitionShapeKind::Enum
+		) {
+			let binding = queries::binding_name(db, key, definition.id.clone())
+				.map_err(|error| format!("`{}` has no emitted binding: {error:?}", definition.name))?;
+			if binding.as_str() != definition.name.as_str() {
+				aliases.push(format!("{} as {}", binding.as_str(), definition.name));
+			}
+		}
+		exported.push_str(&declarer.declare(definition, "export "));
+	}
+	let mut local = String::new();
+	let mut declared = HashSet::new();
+	while let Some(id) = declarer.pending.pop_front() {
+		if declared.insert(id.clone()) {
+			let definition = &definitions[&id];
+			local.push_str(&declarer.declare(definition, ""));
+		}
+	}
+
+	let shadowed = entry_exports
+		.iter()
+		.map(|definition| definition.name.as_str())
+		.collect::<HashSet<_>>();
+	let reexported = runtime_classes
+		.iter()
+		.filter(|class| !shadowed.contains(*class))
+		.copied()
+		.collect::<Vec<_>>();
+	let mut exports = String::new();
+	if !aliases.is_empty() {
+		writeln!(exports, "export {{ {} }};", aliases.join(", ")).unwrap();
+	}
+	writeln!(
+		exports,
+		"export {{ {} }} from \"{}\";",
+		reexported.join(", "),
+		nymph_codegen::BOX_MODULE_KEY
+	)
+	.unwrap();
+
+	let mut declarations = String::from(runtime.as_str());
+	for class in &runtime_classes {
+		// A public export of the same name wins; the class stays a local type.
+		if shadowed.contains(class) {
+			declarations = declarations.replace(
+				&format!("export class {class}"),
+				&format!("declare class {class}"),
+			);
+		}
+	}
+	declarations.push('\n');
+	declarations.push_str(&exported);
+	declarations.push_str(&local);
+	declarations.push_str("export {};\n");
+	Ok(EntryDeclarations {
+		exports,
+		declarations,
+	})
+}
+
+/// The runtime's declarations of its boxed classes and the task types they
+/// mention: the `std/box` block of the runtime declarations, up to its
+/// helper functions, as top-level module declarations.
+fn runtime_declarations() -> String {
+	let source = nymph_codegen::box_module_declarations();
+	let block = source
+		.split_once(&format!(
+			"declare module \"{}\" {{\n",
+			nymph_codegen::BOX_MODULE_KEY
+		))
+		.map_or("", |(_, block)| block);
+	let mut out = String::new();
+	for line in block.lines() {
+		if line.starts_with("\texport function") || line == "}" {
+			break;
+		}
+		out.push_str(line.strip_prefix('\t').unwrap_or(line));
+		out.push('\n');
+	}
+	out.trim_end().to_string() + "\n"
+}
+
+/// The classes `runtime` declares, which the runtime module also exports.
+fn runtime_classes(runtime: &str) -> Vec<&str> {
+	runtime
+		.lines()
+		.filter_map(|line| line.strip_prefix("export class "))
+		.filter_map(|rest| rest.split(['<', ' ']).next())
+		.collect()
+}
+
+struct Declarer<'a> {
+	definitions: &'a HashMap<DefinitionId, ExportedDefinition>,
+	names: BTreeMap<DefinitionId, String>,
+	taken: HashSet<String>,
+	/// Types referenced from a signature that still need a local declaration.
+	pending: VecDeque<DefinitionId>,
+}
+
+type Generics<'a> = HashMap<GenericParameterId, &'a str>;
+
+impl Declarer<'_> {
+	fn declare(&mut self, definition: &ExportedDefinition, export: &str) -> String {
+		let name = self.name(&definition.id);
+		let generics = generics(&definition.binders);
+		let binders = binders(&definition.binders);
+		let mut out = String::new();
+		match definition.kind {
+			DefinitionShapeKind::Function => {
+				let return_type = definition
+					.return_type
+					.as_ref()
+					.map_or_else(|| "void".to_string(), |ty| self.ty(ty, &generics));
+				writeln!(
+					out,
+					"{export}declare function {name}{binders}({}): {return_type};",
+					self.parameters(&definition.parameters, &generics)
+				)
+				.unwrap();
+			}
+			DefinitionShapeKind::Let => {
+				let ty = definition
+					.ty
+					.as_ref()
+					.or(definition.return_type.as_ref())
+					.map_or_else(|| "unknown".to_string(), |ty| self.ty(ty, &generics));
+				writeln!(out, "{export}declare const {name}: {ty};").unwrap();
+			}
+			DefinitionShapeKind::Struct => {
+				writeln!(out, "{export}declare class {name}{binders} {{").unwrap();
+				writeln!(
+					out,
+					"\tconstructor(fields: {});",
+					self.fields(&definition.fields, &generics)
+				)
+				.unwrap();
+				for field in public_fields(&definition.fields) {
+					writeln!(
+						out,
+						"\treadonly {}: {};",
+						field.name,
+						self.ty(&field.ty, &generics)
+					)
+					.unwrap();
+				}
+				out.push_str("}\n");
+			}
+			DefinitionShapeKind::Enum => {
+				let variant_ty = |variant: &str| format!("{name}.{variant}{binders}");
+				let union = if definition.variants.is_empty() {
+					"never".to_string()
+				} else {
+					definition
+						.variants
+						.iter()
+						.map(|variant| variant_ty(&variant.name))
+						.collect::<Vec<_>>()
+						.join(" | ")
+				};
+				writeln!(out, "{export}type {name}{binders} = {union};").unwrap();
+				writeln!(out, "{export}declare const {name}: {{").unwrap();
+				for variant in &definition.variants {
+					if variant.fields.is_empty() {
+						let never = type_binders(&definition.binders)
+							.map(|_| "never")
+							.collect::<Vec<_>>();
+						let arguments = if never.is_empty() {
+							String::new()
+						} else {
+							format!("<{}>", never.join(", "))
+						};
+						writeln!(
+							out,
+							"\treadonly {}: {name}.{}{arguments};",
+							variant.name, variant.name
+						)
+						.unwrap();
+					} else {
+						writeln!(
+							out,
+							"\t{}{binders}(fields: {}): {};",
+							variant.name,
+							self.fields(&variant.fields, &generics),
+							variant_ty(&variant.name)
+						)
+						.unwrap();
+					}
+				}
+				out.push_str("};\n");
+				writeln!(out, "{export}declare namespace {name} {{").unwrap();
+				for variant in &definition.variants {
+					writeln!(out, "\tinterface {}{binders} {{", variant.name).unwrap();
+					out.push_str("\t\treadonly [tag: symbol]: unknown;\n");
+					for field in public_fields(&variant.fields) {
+						writeln!(
+							out,
+							"\t\treadonly {}: {};",
+							field.name,
+							self.ty(&field.ty, &generics)
+						)
+						.unwrap();
+					}
+					out.push_str("\t}\n");
+				}
+				out.push_str("}\n");
+			}
+			DefinitionShapeKind::TypeAlias => {
+				let ty = definition
+					.ty
+					.as_ref()
+					.map_or_else(|| "unknown".to_string(), |ty| self.ty(ty, &generics));
+				writeln!(out, "{export}type {name}{binders} = {ty};").unwrap();
+			}
+			// A value of interface type is whichever implementation it holds.
+			DefinitionShapeKind::Interface => {
+				writeln!(out, "{export}type {name}{binders} = unknown;").unwrap();
+			}
+			DefinitionShapeKind::Namespace | DefinitionShapeKind::Effect => {}
+		}
+		out
+	}
+
+	/// The declared name of `id`, queueing a local declaration the first
+	/// time a type outside the entry's exports is named.
+	fn name(&mut self, id: &DefinitionId) -> String {
+		if let Some(name) = self.names.get(id) {
+			return name.clone();
+		}
+		let base = self.definitions[id].name.to_string();
+		let mut name = base.clone();
+		let mut suffix = 2;
+		while !self.taken.insert(name.clone()) {
+			name = format!("{base}_{suffix}");
+			suffix += 1;
+		}
+		self.names.insert(id.clone(), name.clone());
+		self.pending.push_back(id.clone());
+		name
+	}
+
+	fn ty(&mut self, ty: &InterfaceType, generics: &Generics<'_>) -> String {
+		match ty {
+			InterfaceType::Int => "NInt".to_string(),
+			InterfaceType::UInt => "NUint".to_string(),
+			InterfaceType::Float => "NFloat".to_string(),
+			InterfaceType::Char => "NChar".to_string(),
+			InterfaceType::String => "NString".to_string(),
+			InterfaceType::Boolean => "NBool".to_string(),
+			InterfaceType::Void => "void".to_string(),
+			InterfaceType::Never => "never".to_string(),
+			InterfaceType::SelfType => "unknown".to_string(),
+			InterfaceType::List(item) => format!("NList<{}>", self.ty(item, generics)),
+			InterfaceType::Tuple(items) => {
+				let items = items
+					.iter()
+					.map(|item| self.ty(item, generics))
+					.collect::<Vec<_>>();
+				if items.is_empty() {
+					"NTuple<never>".to_string()
+				} else {
+					format!("NTuple<{}>", items.join(" | "))
+				}
+			}
+			InterfaceType::Map(key, value) => format!(
+				"NMap<{}, {}>",
+				self.ty(key, generics),
+				self.ty(value, generics)
+			),
+			InterfaceType::Function {
+				parameters,
+				return_type,
+				..
+			} => format!(
+				"({}) => {}",
+				parameters
+					.iter()
+					.enumerate()
+					.map(|(index, parameter)| format!("arg{index}: {}", self.ty(parameter, generics)))
+					.collect::<Vec<_>>()
+					.join(", "),
+				self.ty(return_type, generics)
+			),
+			InterfaceType::Task { output, .. } => {
+				format!("NymphTask<{}>", self.ty(output, generics))
+			}
+			InterfaceType::Handle(output) => format!("NymphHandle<{}>", self.ty(output, generics)),
+			InterfaceType::HandleOutcome(output) => {
+				format!("NymphTaskOutcome<{}>", self.ty(output, generics))
+			}
+			InterfaceType::Named {
+				definition,
+				positional,
+				..
+			} => {
+				let Some(target) = self.definitions.get(definition) else {
+					return "unknown".to_string();
+				};
+				if matches!(
+					target.kind,
+					DefinitionShapeKind::Namespace | DefinitionShapeKind::Effect
+				) {
+					return "unknown".to_string();
+				}
+				let name = self.name(definition);
+				if positional.is_empty() {
+					name
+				} else {
+					format!(
+						"{name}<{}>",
+						positional
+							.iter()
+							.map(|argument| self.ty(argument, generics))
+							.collect::<Vec<_>>()
+							.join(", ")
+					)
+				}
+			}
+			InterfaceType::Intersection(items) => items
+				.iter()
+				.map(|item| self.ty(item, generics))
+				.collect::<Vec<_>>()
+				.join(" & "),
+			InterfaceType::Generic(parameter) => generics
+				.get(parameter)
+				.map_or_else(|| "unknown".to_string(), ToString::to_string),
+		}
+	}
+
+	fn parameters(
+		&mut self,
+		parameters: &[ParameterShape<InterfaceType>],
+		generics: &Generics<'_>,
+	) -> String {
+		parameters
+			.iter()
+			.enumerate()
+			.map(|(index, parameter)| {
+				let name = parameter
+					.name
+					.as_deref()
+					.map_or_else(|| format!("arg{index}"), parameter_name);
+				let ty = self.ty(&parameter.ty, generics);
+				if parameter.spread {
+					format!("...{name}: unknown[]")
+				} else {
+					format!("{name}: {ty}")
+				}
+			})
+			.collect::<Vec<_>>()
+			.join(", ")
+	}
+
+	/// The object a struct or variant constructor takes: every field,
+	/// private ones included, since the runtime constructor copies them all.
+	fn fields(&mut self, fields: &[FieldShape<InterfaceType>], generics: &Generics<'_>) -> String {
+		if fields.is_empty() {
+			return "{}".to_string();
+		}
+		format!(
+			"{{ {} }}",
+			fields
+				.iter()
+				.map(|field| format!("{}: {}", field.name, self.ty(&field.ty, generics)))
+				.collect::<Vec<_>>()
+				.join("; ")
+		)
+	}
+}
+
+fn public_fields(
+	fields: &[FieldShape<InterfaceType>],
+) -> impl Iterator<Item = &FieldShape<InterfaceType>> {
+	fields
+		.iter()
+		.filter(|field| field.visibility != Some(Visibility::Private))
+}
+
+fn type_binders(binders: &[GenericParameter]) -> impl Iterator<Item = &GenericParameter> {
+	binders
+		.iter()
+		.filter(|binder| binder.kind == GenericParameterKind::Type)
+}
+
+fn generics(binders: &[GenericParameter]) -> Generics<'_> {
+	type_binders(binders)
+		.map(|binder| (binder.id.clone(), binder.name.as_str()))
+		.collect()
+}
+
+fn binders(binders: &[GenericParameter]) -> String {
+	let names = type_binders(binders)
+		.map(|binder| binder.name.as_str())
+		.collect::<Vec<_>>();
+	if names.is_empty() {
+		String::new()
+	} else {
+		format!("<{}>", names.join(", "))
+	}
+}
+
+/// A Nymph parameter name that TypeScript reserves gets a trailing `_`.
+fn parameter_name(name: &str) -> String {
+	const RESERVED: &[&str] = &[
+		"break",
+		"case",
+		"catch",
+		"class",
+		"const",
+		"continue",
+		"debugger",
+		"default",
+		"delete",
+		"do",
+		"else",
+		"enum",
+		"export",
+		"extends",
+		"false",
+		"finally",
+		"for",
+		"function",
+		"if",
+		"import",
+		"in",
+		"instanceof",
+		"new",
+		"null",
+		"return",
+		"super",
+		"switch",
+		"this",
+		"throw",
+		"true",
+		"try",
+		"typeof",
+		"var",
+		"void",
+		"while",
+		"with",
+	];
+	if RESERVED.contains(&name) {
+		format!("{name}_")
+	} else {
+		name.to_string()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn runtime_declarations_cover_every_boxed_class_and_no_helpers() {
+		let runtime = runtime_declarations();
+		assert_eq!(
+			runtime_classes(&runtime),
+			[
+				"NBox", "NInt", "NUint", "NFloat", "NChar", "NBool", "NString", "NList", "NTuple", "NMap"
+			]
+		);
+		assert!(runtime.contains("export interface NymphTask<T> {}"));
+		assert!(!runtime.contains("export function"));
+		assert!(runtime.starts_with("export interface NymphOption<T> {\n"));
+	}
+}
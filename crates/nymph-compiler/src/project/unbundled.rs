@@ -0,0 +1,230 @@
+//! Lay an in-memory ES module graph out as one file per module instead of
+//! bundling it.
+//!
+//! The emitted graph imports every module by its canonical key (`"main"`,
+//! `"std::list"`, `"@nymph/runtime/std/option"`, `"std/box"`, ...), which only
+//! the in-memory bundler can resolve. Here each key gets a file path under the
+//! output directory and every import of a key is rewritten to the relative
+//! path between the two files, so the result loads as-is under Node or any
+//! downstream bundler. Paths depend only on module identity, never on where
+//! the compiler runs, so the same sources always produce the same files.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::CompiledModule;
+
+/// The directory every module that is not a project module lives under.
+const SUPPORT_DIR: &str = "_nymph";
+
+/// Where the module `key` goes, relative to the output directory. `host`
+/// marks the host runtime modules (`std/box` and friends), which share their
+/// keys' shape with the compiler-owned runtime modules but not their files.
+fn module_path(key: &str, host: bool) -> String {
+	let stem = if host {
+		format!("{SUPPORT_DIR}/host/{key}")
+	} else if let Some(path) = key.strip_prefix("@nymph/runtime/") {
+		format!("{SUPPORT_DIR}/runtime/{path}")
+	} else if let Some(path) = key.strip_prefix("std::") {
+		format!("{SUPPORT_DIR}/std/{path}")
+	} else if let Some(rest) = key.strip_prefix("package::") {
+		let (node, path) = rest.split_once("::").unwrap_or(("", rest));
+		format!("{SUPPORT_DIR}/packages/{node}/{path}")
+	} else {
+		key.to_string()
+	};
+	format!("{stem}.mjs")
+}
+
+/// The specifier `from` uses to import `to`, both relative to the output
+/// directory.
+fn relative_specifier(from: &str, to: &str) -> String {
+	let from_dirs = from.split('/').collect::<Vec<_>>();
+	let from_dirs = &from_dirs[..from_dirs.len() - 1];
+	let to_parts = to.split('/').collect::<Vec<_>>();
+	let shared = from_dirs
+		.iter()
+		.zip(&to_parts)
+		.take_while(|(from, to)| from == to)
+		.count();
+	let mut parts = vec![".."; from_dirs.len() - shared];
+	if parts.is_empty() {
+		parts.push(".");
+	}
+	parts.extend(&to_parts[shared..]);
+	parts.join("/")
+}
+
+/// The specifier of a single-line `import ... from "..."` or
+/// `export ... from "..."` statement, which is the only form the emitters
+/// produce.
+fn static_specifier(line: &str) -> Option<&str> {
+	if !line.starts_with("import ") && !line.starts_with("export ") {
+		return None;
+	}
+	let (_, specifier) = line.rsplit_once(" from \"")?;
+	specifier.strip_suffix("\";")
+}
+
+/// Split `sources` (keyed by canonical module key, like [`super::bundle`]'s
+/// input) into the modules reachable from `entry_key`, entry first and the
+/// rest in path order, each with its imports rewritten to relative paths.
+/// `host_keys` names the host runtime modules among `sources`; `maps` carries
+/// the source maps of the modules that have one.
+///
+/// # Errors
+/// Returns a message when two modules would share one file, which means a
+/// project module is named like the reserved `_nymph` directory.
+pub(crate) fn split_modules(
+	entry_key: &str,
+	sources: &FxHashMap<String, String>,
+	host_keys: &FxHashSet<String>,
+	maps: &FxHashMap<String, String>,
+) -> Result<Vec<CompiledModule>, String> {
+	let mut paths = FxHashMap::default();
+	let mut owners = BTreeMap::new();
+	for key in sources.keys() {
+		let path = module_path(key, host_keys.contains(key));
+		if let Some(other) = owners.insert(path.clone(), key.as_str()) {
+			return Err(format!(
+				"modules `{other}` and `{key}` would both be written to `{path}`"
+			));
+		}
+		paths.insert(key.as_str(), path);
+	}
+
+	let mut reachable = BTreeSet::new();
+	let mut queue = VecDeque::from([entry_key]);
+	while let Some(key) = queue.pop_front() {
+		let Some(source) = sources.get(key) else {
+			continue;
+		};
+		if !reachable.insert(key) {
+			continue;
+		}
+		queue.extend(
+			source
+				.lines()
+				.filter_map(static_specifier)
+				.filter(|specifier| sources.contains_key(*specifier)),
+		);
+	}
+
+	let mut modules = reachable
+		.into_iter()
+		.map(|key| {
+			let path = &paths[key];
+			let mut js = String::with_capacity(sources[key].len());
+			for line in sources[key].split_inclusive('\n') {
+				match static_specifier(line.trim_end_matches('\n')).and_then(|specifier| {
+					let target = paths.get(specifier)?;
+					Some((specifier, target))
+				}) {
+					Some((specifier, target)) => {
+						let (statement, _) = line
+							.rsplit_once(&format!("\"{specifier}\""))
+							.expect("the specifier was found in this line");
+						js.push_str(&format!(
+							"{statement}\"{}\";\n",
+							relative_specifier(path, target)
+						));
+					}
+					None => js.push_str(line),
+				}
+			}
+			CompiledModule {
+				path: path.clone(),
+				js,
+				source_map: maps.get(key).cloned(),
+			}
+		})
+		.collect::<Vec<_>>();
+	let entry_path = module_path(entry_key, false);
+	modules.sort_by(|a, b| {
+		(a.path != entry_path)
+			.cmp(&(b.path != entry_path))
+			.then_with(|| a.path.cmp(&b.path))
+	});
+	Ok(modules)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn module_keys_map_to_stable_paths_per_origin() {
+		assert_eq!(module_path("geometry/vec", false), "geometry/vec.mjs");
+		assert_eq!(module_path("std::list", false), "_nymph/std/list.mjs");
+		assert_eq!(
+			module_path("package::3::shapes/circle", false),
+			"_nymph/packages/3/shapes/circle.mjs"
+		);
+		assert_eq!(
+			module_path("@nymph/runtime/std/option", false),
+			"_nymph/runtime/std/option.mjs"
+		);
+		assert_eq!(module_path("std/box", true), "_nymph/host/std/box.mjs");
+	}
+
+	#[test]
+	fn relative_specifiers_walk_up_to_the_shared_directory() {
+		assert_eq!(
+			relative_specifier("main.mjs", "geometry/vec.mjs"),
+			"./geometry/vec.mjs"
+		);
+		assert_eq!(
+			relative_specifier("geometry/vec.mjs", "_nymph/host/std/box.mjs"),
+			"../_nymph/host/std/box.mjs"
+		);
+		assert_eq!(
+			relative_specifier("_nymph/host/std/io.mjs", "_nymph/host/std/box.mjs"),
+			"./box.mjs"
+		);
+	}
+
+	#[test]
+	fn only_reachable_modules_are_split_with_relative_imports() {
+		let sources = FxHashMap::from_iter([
+			(
+				"main".to_string(),
+				"import { area } from \"geometry/vec\";\nexport { area };\n".to_string(),
+			),
+			(
+				"geometry/vec".to_string(),
+				"import { nymphReturn } from \"std/box\";\nconst area = 1;\nexport { area };\n".to_string(),
+			),
+			(
+				"std/box".to_string(),
+				"export const nymphReturn = 0;\n".to_string(),
+			),
+			(
+				"std/io".to_string(),
+				"export const print = 0;\n".to_string(),
+			),
+		]);
+		let host = FxHashSet::from_iter(["std/box".to_string(), "std/io".to_string()]);
+
+		let modules =
+			split_modules("main", &sources, &host, &FxHashMap::default()).expect("distinct module paths");
+
+		let paths = modules
+			.iter()
+			.map(|module| module.path.as_str())
+			.collect::<Vec<_>>();
+		assert_eq!(
+			paths,
+			["main.mjs", "_nymph/host/std/box.mjs", "geometry/vec.mjs"]
+		);
+		assert_eq!(
+			modules[0].js,
+			"import { area } from \"./geometry/vec.mjs\";\nexport { area };\n"
+		);
+		assert!(
+			modules[2]
+				.js
+				.starts_with("import { nymphReturn } from \"../_nymph/host/std/box.mjs\";\n")
+		);
+	}
+}
@@ -16,6 +16,10 @@
 /// `import std/…` (i.e. it is NOT in `prelude::CORE_SOURCES`).
 const STD_SOURCES: &[(&str, &str)] = &[
 	("io", include_str!("../../../stdlib/src/io.nym")),
+	("env", include_str!("../../../stdlib/src/env.nym")),
+	("fs", include_str!("../../../stdlib/src/fs.nym")),
+	("process", include_str!("../../../stdlib/src/process.nym")),
+	("json", include_str!("../../../stdlib/src/json.nym")),
 	(
 		"collections/set",
 		include_str!("../../../stdlib/src/collections/set.nym"),
@@ -32,6 +36,7 @@ const STD_SOURCES: &[(&str, &str)] = &[
 		"math/complex",
 		include_str!("../../../stdlib/src/math/complex.nym"),
 	),
+	("test", include_str!("../../../stdlib/src/test.nym")),
 ];
 
 pub(crate) fn embedded_std_sources() -> impl Iterator<Item = (&'static str, &'static str)> {
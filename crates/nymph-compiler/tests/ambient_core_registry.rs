@@ -251,7 +251,7 @@ fn same_named_method_on_an_earlier_bound_cannot_replace_iterator_next() {
 		main.clone(),
 		"interface Pretender { func next(): string }
 		 func consume<T: Pretender + Iterator<Item = int>>(items: T): void = {
-		   for (item in items) { let result: int = item }
+		   for (item in items) { let _result: int = item }
 		 }"
 		.into(),
 		SourceVersion(1),
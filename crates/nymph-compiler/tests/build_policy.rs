@@ -101,6 +101,9 @@ fn configured_one_shot_and_retained_diagnostics_are_identical_in_both_profiles()
 			lints: [("echo-in-release".to_string(), LintLevel::Deny)]
 				.into_iter()
 				.collect(),
+			source_maps: false,
+			layout: nymph_compiler::OutputLayout::Bundle,
+			declarations: false,
 		};
 		let one_shot = nymph_compiler::check_project_library_with_embedded_std_and_options(
 			"main",
@@ -195,7 +198,7 @@ fn managed_resource_warnings_honor_allow_and_deny() {
 		 struct Borrowed(resource: Resource)\n\
 		 async func risky(): void = {\n\
 		   let use resource = Resource()\n\
-		   let child = async { resource.close() }.spawn()\n\
+		   let _child = async { resource.close() }.spawn()\n\
 		 }"
 		.into(),
 		SourceVersion(1),
@@ -237,6 +240,66 @@ fn managed_resource_warnings_honor_allow_and_deny() {
 	assert_eq!(denied[0].diag.code, "managed-child-capture");
 }
 
+#[test]
+fn deprecated_dependency_uses_warn_at_each_site_and_honor_lint_levels() {
+	let mut session = CompilerSession::new();
+	let project = ProjectId::new("deprecation-policy");
+	let root = session.root_package(project.clone());
+	let dependency = session.mint_package(project.clone());
+	session
+		.set_package_alias(root, "dep", dependency.clone())
+		.unwrap();
+	session
+		.set_package_source(
+			dependency,
+			ModulePath::new("lib").unwrap(),
+			"@deprecated(\"use area\")\n\
+			 public func size(width: int): int = width\n\
+			 public func area(width: int): int = width\n\
+			 public struct Meter(public value: int)\n\
+			 impl Meter { @deprecated public func doubled(): int = this.value * 2 }"
+				.into(),
+			SourceVersion(1),
+		)
+		.unwrap();
+	let main = ModulePath::new("main").unwrap();
+	session.set_source(
+		project.clone(),
+		main.clone(),
+		"import dep/lib with (size, area, Meter)\n\
+		 public func root(): int = size(1) + size(2) + area(3) + Meter(value = 4).doubled()"
+			.into(),
+		SourceVersion(1),
+	);
+	let warned = session.check_project(project.clone(), main.clone(), EntryMode::Library);
+	let deprecated = warned
+		.iter()
+		.filter(|diagnostic| diagnostic.diag.code == "deprecated")
+		.collect::<Vec<_>>();
+	assert_eq!(deprecated.len(), 3, "{warned:?}");
+	assert!(
+		deprecated
+			.iter()
+			.all(|diagnostic| !diagnostic.diag.is_error())
+	);
+	assert!(
+		deprecated
+			.iter()
+			.any(|diagnostic| diagnostic.diag.help.as_deref() == Some("use area"))
+	);
+
+	session.set_project_lints(project.clone(), [("deprecated".into(), LintLevel::Allow)]);
+	assert!(
+		session
+			.check_project(project.clone(), main.clone(), EntryMode::Library)
+			.is_empty()
+	);
+	session.set_project_lints(project.clone(), [("deprecated".into(), LintLevel::Deny)]);
+	let denied = session.check_project(project, main, EntryMode::Library);
+	assert_eq!(denied.len(), 3);
+	assert!(denied.iter().all(|diagnostic| diagnostic.diag.is_error()));
+}
+
 #[test]
 fn echo_source_uris_reach_development_output_and_release_removes_all_observer_bytes() {
 	let source = "public func observed(): int = echo 1";
@@ -260,6 +323,9 @@ fn echo_source_uris_reach_development_output_and_release_removes_all_observer_by
 			lints: [("echo-in-release".into(), LintLevel::Allow)]
 				.into_iter()
 				.collect(),
+			source_maps: false,
+			layout: nymph_compiler::OutputLayout::Bundle,
+			declarations: false,
 		},
 	)
 	.unwrap();
@@ -284,6 +350,9 @@ fn a_release_user_binding_named_nymph_echo_does_not_demand_the_observer() {
 		&CompilerOptions {
 			profile: BuildProfile::Release,
 			lints: Default::default(),
+			source_maps: false,
+			layout: nymph_compiler::OutputLayout::Bundle,
+			declarations: false,
 		},
 	)
 	.unwrap();
@@ -299,3 +368,68 @@ fn a_release_user_binding_named_nymph_echo_does_not_demand_the_observer() {
 		);
 	}
 }
+
+#[test]
+fn unused_code_lints_warn_with_fixes_and_honor_lint_levels() {
+	let mut session = CompilerSession::new();
+	let project = ProjectId::new("unused-policy");
+	session.set_source(
+		project.clone(),
+		ModulePath::new("util").unwrap(),
+		"public func helper(): int = 1\npublic func spare(): int = 2".into(),
+		SourceVersion(1),
+	);
+	let main = ModulePath::new("main").unwrap();
+	session.set_source(
+		project.clone(),
+		main.clone(),
+		"import @/util with (helper, spare)\n\
+		 func risky(n: int): Result<int, string> = Ok(n)\n\
+		 private func lonely(): int = 0\n\
+		 public func pick<T, _U>(value: int, _extra: int): int = {\n\
+		   let unused = 2\n\
+		   let _quiet = 3\n\
+		   risky(1)\n\
+		   helper()\n\
+		 }"
+		.into(),
+		SourceVersion(1),
+	);
+	let lints = [
+		"unused-variable",
+		"unused-import",
+		"unused-private-function",
+		"unused-parameter",
+		"unused-type-parameter",
+		"unused-result",
+	];
+	let warned = session.check_project(project.clone(), main.clone(), EntryMode::Library);
+	for lint in lints {
+		let found = warned
+			.iter()
+			.filter(|diagnostic| diagnostic.diag.code == lint)
+			.collect::<Vec<_>>();
+		assert_eq!(found.len(), 1, "{lint}: {warned:?}");
+		assert!(!found[0].diag.is_error());
+		assert_eq!(found[0].diag.edits().len(), 1, "{lint}");
+	}
+	assert_eq!(warned.len(), lints.len(), "{warned:?}");
+
+	session.set_project_lints(
+		project.clone(),
+		lints.map(|lint| (lint.to_string(), LintLevel::Allow)),
+	);
+	assert!(
+		session
+			.check_project(project.clone(), main.clone(), EntryMode::Library)
+			.is_empty()
+	);
+	session.set_project_lints(project.clone(), [("unused-result".into(), LintLevel::Deny)]);
+	let denied = session.check_project(project, main, EntryMode::Library);
+	assert_eq!(denied.len(), lints.len());
+	assert!(
+		denied
+			.iter()
+			.all(|diagnostic| diagnostic.diag.is_error() == (diagnostic.diag.code == "unused-result"))
+	);
+}
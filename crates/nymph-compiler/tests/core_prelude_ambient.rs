@@ -344,6 +344,27 @@ func map_some(): int = Option.Some(value = 7).map_or_default((value: int) -> val
 	assert_eq!(run(source, "map_some()"), "7");
 }
 
+/// A generic list or map implementation has no exact prototype of its own, so
+/// `T.default()` with `T = #[int]` reaches it through the shared list prototype
+/// the type witness inherits from.
+#[test]
+fn default_generic_bound_executes_for_list_and_map_implementations() {
+	let source = r#"
+func make<T: Default>(): T = T.default()
+func list_length(): uint = {
+	let list: #[int] = make()
+	list.length()
+}
+func map_length(): uint = {
+	let map: #{string: int} = make()
+	map.size()
+}
+"#;
+
+	assert_eq!(run(source, "list_length()"), "0");
+	assert_eq!(run(source, "map_length()"), "0");
+}
+
 /// A user redefinition of an ambient core name (`Option`, here — not `ops`)
 /// must never leak a `std/…` (or any prelude-internal) span to the user: the
 /// `Redefinition` diagnostic is anchored entirely at the user's OWN
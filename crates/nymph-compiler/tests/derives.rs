@@ -0,0 +1,180 @@
+//! `derives` clauses end to end: the generated impls run like written ones,
+//! reach a downstream package through the extracted interface, and report a
+//! field whose type lacks the derived interface at that field.
+
+use std::collections::BTreeMap;
+
+use nymph_compiler::{
+	CompilerOptions, PackageSources, check, compile_project_packages_with_embedded_std_and_options,
+};
+
+const SHAPES: &str = "\
+public struct Point(public x: int, public y: int) derives Equals, Hash, Display, Debug, Comparable, Default
+public struct Labelled<T>(public value: T, public label: string = \"none\") derives Equals, Debug, Display, Default
+public enum Shape derives Equals, Hash, Display, Debug, Comparable, Default {
+  Empty,
+  Circle(radius: int),
+  Rect(corner: Point, label: string),
+}
+public struct Tagged(public tags: #[string], public pair: #(Point, char)) derives Debug, Display
+";
+
+const MAIN: &str = "\
+import std/io with (println)
+import shapes/shapes with (Labelled, Point, Shape, Tagged)
+
+func fresh<T: Default>(): T = T.default()
+func hashed<T: Hash>(value: T): int = value.hash()
+
+func main(): void = {
+  let a = Point(x = 1, y = 2)
+  let b = Point(x = 1, y = 3)
+  println(a == Point(x = 1, y = 2))
+  println(a == b)
+  println(a.compare_to(b))
+  println(hashed(a) == hashed(Point(x = 1, y = 2)))
+  println(a)
+  let origin: Point = fresh()
+  println(origin.debug())
+  let labelled = Labelled(value = \"v\")
+  println(labelled.debug())
+  println(labelled.display())
+  let blank: Labelled<string> = fresh()
+  println(labelled == blank)
+  let rect = Shape.Rect(corner = a, label = \"x\")
+  println(rect.debug())
+  println(Shape.Circle(radius = 2))
+  println(Shape.Circle(radius = 2) == Shape.Circle(radius = 2))
+  println(Shape.Empty.compare_to(rect))
+  let shape: Shape = fresh()
+  println(shape)
+  let tagged = Tagged(tags = #[\"t\"], pair = #(a, 'c'))
+  println(tagged.debug())
+  println(tagged)
+}
+";
+
+#[test]
+fn derived_impls_run_across_a_package_boundary() {
+	let main = |key: &str| (key == "main").then(|| MAIN.to_string());
+	let shapes = |key: &str| (key == "shapes").then(|| SHAPES.to_string());
+	let mut root = PackageSources::root(&main);
+	root.dependencies = BTreeMap::from([("shapes".to_string(), 1)]);
+	let packages = [root, PackageSources::root(&shapes)];
+	let compiled = compile_project_packages_with_embedded_std_and_options(
+		"main",
+		&packages,
+		&CompilerOptions::default(),
+	)
+	.unwrap_or_else(|diagnostics| panic!("{diagnostics:#?}"));
+
+	let path = std::env::temp_dir().join(format!("nymph_derives_{}.mjs", std::process::id()));
+	std::fs::write(
+		&path,
+		format!("{}\n{}();\n", compiled.js, compiled.entry_main),
+	)
+	.unwrap();
+	let output = std::process::Command::new("node")
+		.arg(&path)
+		.env("NO_COLOR", "1")
+		.env_remove("FORCE_COLOR")
+		.output()
+		.expect("run node");
+	let _ = std::fs::remove_file(&path);
+	assert!(
+		output.status.success(),
+		"{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	assert_eq!(
+		String::from_utf8_lossy(&output.stdout)
+			.lines()
+			.collect::<Vec<_>>(),
+		[
+			"true",
+			"false",
+			"Order.LessThan",
+			"true",
+			"Point(x: 1, y: 2)",
+			"Point(x: 0, y: 0)",
+			"Labelled(value: \"v\", label: \"none\")",
+			"Labelled(value: v, label: none)",
+			"false",
+			"Shape.Rect(corner: Point(x: 1, y: 2), label: \"x\")",
+			"Shape.Circle(radius: 2)",
+			"true",
+			"Order.LessThan",
+			"Shape.Empty",
+			"Tagged(tags: #[\"t\"], pair: #(Point(x: 1, y: 2), 'c'))",
+			"Tagged(tags: #[\"t\"], pair: #(Point(x: 1, y: 2), 'c'))",
+		]
+	);
+}
+
+#[test]
+fn a_field_without_the_derived_interface_is_reported_at_the_field() {
+	let source = "struct Handler(name: string, run: () -> int) derives Equals, Display";
+	let diagnostics = check(source, "derives");
+	for message in [
+		"cannot derive `Equals` for `Handler`: field `run` has type `() -> int`, which does not implement `Equals`",
+		"cannot derive `Display` for `Handler`: field `run` has type `() -> int`, which does not implement `Display`",
+	] {
+		let diagnostic = diagnostics
+			.iter()
+			.find(|diagnostic| diagnostic.message == message)
+			.unwrap_or_else(|| panic!("{message}: {diagnostics:#?}"));
+		assert_eq!(
+			&source[diagnostic.span.start..diagnostic.span.end],
+			"() -> int"
+		);
+	}
+	assert_eq!(diagnostics.len(), 2, "{diagnostics:#?}");
+}
+
+#[test]
+fn derived_bounds_follow_the_generic_parameters() {
+	let diagnostics = check(
+		"struct Slot<T>(value: T) derives Equals\n\
+		 func same(left: Slot<() -> int>, right: Slot<() -> int>): boolean = left == right\n",
+		"derives",
+	);
+	assert!(!diagnostics.is_empty());
+	assert!(
+		check(
+			"struct Slot<T>(value: T) derives Equals\n\
+			 func same(left: Slot<int>, right: Slot<int>): boolean = left == right\n",
+			"derives",
+		)
+		.is_empty()
+	);
+}
+
+#[test]
+fn unknown_repeated_and_impossible_derives_are_rejected() {
+	for (source, message) in [
+		(
+			"struct A(x: int) derives Iterable",
+			"`Iterable` cannot be derived",
+		),
+		(
+			"struct A(x: int) derives Equals, Equals",
+			"`Equals` is derived more than once",
+		),
+		(
+			"struct Ranked(tags: #[string]) derives Comparable",
+			"cannot derive `Comparable` for `Ranked`: field `tags` has type `#[string]`, which does not implement `Comparable`",
+		),
+		(
+			"enum Never derives Default {}",
+			"cannot derive `Default` for `Never`, which has no variants",
+		),
+	] {
+		let diagnostics = check(source, "derives");
+		assert!(
+			diagnostics
+				.iter()
+				.any(|diagnostic| diagnostic.message == message),
+			"{source}: {diagnostics:#?}"
+		);
+	}
+}
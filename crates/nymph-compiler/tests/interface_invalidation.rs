@@ -557,7 +557,7 @@ fn private_to_public_edit_revalidates_and_allows_namespace_access() {
 	session.set_source(
 		project.clone(),
 		main.clone(),
-		"import @/leaf with (value)\nfunc read(): int = leaf.helper()\nfunc main(): void = { let ignored = read() }"
+		"import @/leaf with (value)\nfunc read(): int = leaf.helper() + value()\nfunc main(): void = { let _ignored = read() }"
 			.into(),
 		SourceVersion(1),
 	);
@@ -70,7 +70,7 @@ fn package_nodes_survive_source_edits_and_aliases_share_only_their_exact_target(
 		&mut session,
 		root.clone(),
 		"main",
-		"import left/types as left_types with (Value as Left)\nimport right/types as right_types with (Value as Right)\npublic func same(left: Left, right: Right): boolean = true",
+		"import left/types as left_types with (Value as Left)\nimport right/types as right_types with (Value as Right)\npublic func same(_left: Left, _right: Right): boolean = true",
 		1,
 	);
 
@@ -130,7 +130,7 @@ fn independent_copies_and_resolution_replacements_have_distinct_exact_owners() {
 		&mut session,
 		root.clone(),
 		"main",
-		"import selected/types as selected_types with (Value as Selected)\nimport untouched/types as untouched_types with (Value as Untouched)\npublic func use(a: Selected, b: Untouched): boolean = true",
+		"import selected/types as selected_types with (Value as Selected)\nimport untouched/types as untouched_types with (Value as Untouched)\npublic func use(_a: Selected, _b: Untouched): boolean = true",
 		1,
 	);
 	let diagnostics = session.check_project(project.clone(), path("main"), EntryMode::Library);
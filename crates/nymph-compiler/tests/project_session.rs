@@ -0,0 +1,60 @@
+//! A [`ProjectSession`] kept across source changes reaches the same results
+//! as a fresh facade session over the changed sources.
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use nymph_compiler::{
+	CompilerOptions, PackageSources, ProjectSession,
+	check_project_packages_with_embedded_std_and_options,
+};
+
+fn check_fresh(files: &RefCell<BTreeMap<&str, &str>>) -> Vec<String> {
+	let load = |key: &str| files.borrow().get(key).map(|source| (*source).to_string());
+	let packages = [PackageSources::root(&load)];
+	check_project_packages_with_embedded_std_and_options(
+		"main",
+		&packages,
+		&CompilerOptions::default(),
+	)
+	.into_iter()
+	.map(|diagnostic| diagnostic.diag.message.to_string())
+	.collect()
+}
+
+#[test]
+fn reloaded_modules_are_rechecked_and_unreachable_modules_dropped() {
+	let files = RefCell::new(BTreeMap::from([
+		(
+			"main",
+			"import @/helper with (value)\nfunc main(): void = { let n: int = value() }",
+		),
+		("helper", "public func value(): int = 1"),
+	]));
+	let load = |key: &str| files.borrow().get(key).map(|source| (*source).to_string());
+	let packages = [PackageSources::root(&load)];
+	let mut session = ProjectSession::new(&packages, &CompilerOptions::default());
+	session.set_roots(&["main"], &packages);
+	let messages = |session: &ProjectSession| {
+		session
+			.check("main")
+			.into_iter()
+			.map(|diagnostic| diagnostic.diag.message.to_string())
+			.collect::<Vec<_>>()
+	};
+	assert!(messages(&session).is_empty());
+
+	files
+		.borrow_mut()
+		.insert("helper", "public func value(): string = \"one\"");
+	session.reload([(0, "helper".to_string())], &packages);
+	let broken = messages(&session);
+	assert!(!broken.is_empty());
+	assert_eq!(broken, check_fresh(&files));
+
+	files
+		.borrow_mut()
+		.insert("main", "func main(): void = { let n: int = 1 }");
+	session.reload([(0, "main".to_string())], &packages);
+	assert!(messages(&session).is_empty());
+	assert_eq!(messages(&session), check_fresh(&files));
+}
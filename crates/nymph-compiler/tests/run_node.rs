@@ -3448,3 +3448,64 @@ func outer(): Result<int, Wide> = Ok(inner()?)
 "#;
 	assert_eq!(run(source, "outer().error.code.v"), "5");
 }
+
+#[test]
+fn host_bindings_marshal_raw_values_across_the_module_boundary() {
+	let source = r#"
+external("node:path", "basename") func basename(path: string): string
+external("node:path", "sep") let separator: string
+external("node:util", "isDeepStrictEqual") func same(left: float, right: float): boolean
+func file(): string = basename("/tmp/nymph/notes.txt")
+func root(): string = separator
+func equal(): boolean = same(1.5, 1.5)
+func unequal(): boolean = same(1.5, 2.5)
+"#;
+	let js = compile(source);
+	assert!(
+		js.contains("from \"node:path\""),
+		"a host binding must import its own module specifier: {js}"
+	);
+	assert_eq!(run(source, "file()"), "notes.txt");
+	assert_eq!(run(source, "root()"), "/");
+	assert_eq!(run(source, "equal()"), "true");
+	assert_eq!(run(source, "unequal()"), "false");
+	assert_eq!(run(source, "file() instanceof NString"), "true");
+}
+
+#[test]
+fn js_values_are_read_and_called_through_checked_accessors() {
+	let source = r#"
+func json(): JsValue = JsValue.global().get_field("JSON").unwrap(JsValue.undefined())
+func parsed(text: string): Result<JsValue, JsValue> = json().call_method("parse", #[text.into()])
+func name(): string = match (parsed("{\"name\": \"nymph\", \"none\": null}")) {
+  Ok(value) -> value.get_field("name").and_then((name) -> name.as_string()).unwrap("?"),
+  Error(_) -> "threw",
+}
+func null_field(): boolean = match (parsed("{\"none\": null}")) {
+  Ok(value) -> value.get_field("none").map((none) -> none.is_null()).unwrap(false),
+  Error(_) -> false,
+}
+func missing_field(): boolean = JsValue.undefined().get_field("length").is_none()
+func thrown(): string = match (parsed("{")) {
+  Ok(_) -> "parsed",
+  Error(error) -> "${error.get_field("name").unwrap(JsValue.undefined())}",
+}
+func not_callable(): boolean = JsValue.undefined().call(#[]).is_err()
+func largest(): float = {
+  let math = JsValue.global().get_field("Math").unwrap(JsValue.undefined())
+  let max = math.call_method("max", #[1.5.into(), 7.5.into()]).unwrap(JsValue.undefined())
+  max.as_float().unwrap(0.0)
+}
+func mismatched(): boolean = 42.into().as_float().is_none() && true.into().as_boolean().unwrap(false)
+func rendered(): string = "${JsValue.global().get_field("Math").unwrap(JsValue.undefined())}"
+"#;
+	let js = compile(source);
+	assert_eq!(run_js(js.clone(), "name()"), "nymph");
+	assert_eq!(run_js(js.clone(), "null_field()"), "true");
+	assert_eq!(run_js(js.clone(), "missing_field()"), "true");
+	assert_eq!(run_js(js.clone(), "thrown()"), "SyntaxError");
+	assert_eq!(run_js(js.clone(), "not_callable()"), "true");
+	assert_eq!(run_js(js.clone(), "largest()"), "7.5");
+	assert_eq!(run_js(js.clone(), "mismatched()"), "true");
+	assert_eq!(run_js(js, "rendered()"), "[object Math]");
+}
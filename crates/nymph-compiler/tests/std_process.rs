@@ -0,0 +1,68 @@
+//! End-to-end proof that `std/process`'s child processes belong to the
+//! structured task runtime: `Command.spawn()` is a task like any other, and
+//! cancelling the execution that awaits it kills the child rather than
+//! leaving it running detached from the program.
+
+use std::path::PathBuf;
+
+use nymph_compiler::compile_project_with_std;
+
+fn stdlib_src_root() -> PathBuf {
+	PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+		.join("../../stdlib/src")
+		.canonicalize()
+		.unwrap()
+}
+
+/// The real, on-disk-backed `std_provider` — mirrors `std_provider.rs`'s
+/// `real_stdlib_provider` exactly.
+fn real_stdlib_provider(path: &str) -> Option<String> {
+	std::fs::read_to_string(stdlib_src_root().join(format!("{path}.nym"))).ok()
+}
+
+/// A child that would write `marker` after a second is spawned and its
+/// execution cancelled well before then: the handle settles as cancelled, and
+/// the marker never appears because the child was killed with it.
+#[test]
+fn cancelling_the_awaiting_execution_kills_the_child_process() {
+	let marker = std::env::temp_dir().join(format!("nymph_std_process_{}", std::process::id()));
+	let _ = std::fs::remove_file(&marker);
+	let entry = format!(
+		"import std/process with (Command, Output, Process, ProcessError)\n\
+		 async func slow(): Result<Output, ProcessError> + !Process =\n\
+		 \x20 Command(program = \"node\", args = #[\"-e\", \"setTimeout(() => require('fs').writeFileSync('{marker}', 'late'), 1000)\"]).spawn().await\n\
+		 func main(): void = {{}}\n",
+		marker = marker.to_str().unwrap(),
+	);
+	let load = |key: &str| (key == "main").then(|| entry.clone());
+	let compiled = compile_project_with_std("main", &load, &real_stdlib_provider)
+		.expect("std/process project should compile");
+	let slow = compiled.entry_symbol("slow");
+	let mut js = compiled.js;
+	js.push_str(&format!(
+		"\nconst handle = {slow}().spawn(null);\n\
+		 setTimeout(() => handle.cancel(), 300);\n\
+		 console.log((await handle.observe()).tag);\n\
+		 await new Promise((resolve) => setTimeout(resolve, 1500));\n"
+	));
+	let path = std::env::temp_dir().join(format!("nymph_std_process_{}.mjs", std::process::id()));
+	std::fs::write(&path, &js).unwrap();
+	let output = std::process::Command::new("node")
+		.arg(&path)
+		.output()
+		.expect("run node");
+	let _ = std::fs::remove_file(&path);
+	let survived = marker.exists();
+	let _ = std::fs::remove_file(&marker);
+
+	assert!(
+		output.status.success(),
+		"{}\n{js}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "cancelled");
+	assert!(
+		!survived,
+		"the cancelled child kept running and wrote its marker"
+	);
+}
@@ -504,10 +504,23 @@ pub trait IntoDiagnostic: ErrorCode {
 }
 
 pub trait ErrorCode {
+	/// Every variant of the catalog, in declaration (and so code) order.
+	const CATALOG: &'static [CatalogEntry];
+
 	/// The stable error code assigned to this catalog variant.
 	fn code(&self) -> u16;
 }
 
+/// One variant of a typed diagnostic catalog, described without an instance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CatalogEntry {
+	pub code: u16,
+	/// The variant's name, e.g. `MismatchedTypes`.
+	pub name: &'static str,
+	/// The variant's doc comment, joined onto one line.
+	pub summary: &'static str,
+}
+
 fn range(span: Span) -> std::ops::Range<usize> {
 	span.start..span.end
 }
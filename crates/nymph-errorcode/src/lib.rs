@@ -3,10 +3,13 @@
 //! Derive stable four-digit diagnostic codes from a phase prefix and catalog
 //! position. Existing catalog variants must never be reordered or removed;
 //! new variants are appended so every published code keeps its meaning.
+//!
+//! The derive also publishes the catalog itself: each variant's code, name,
+//! and doc comment, for tools that describe codes without an instance.
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{DeriveInput, Fields, LitInt, Meta, Variant, parse_macro_input};
+use syn::{Attribute, DeriveInput, Expr, Fields, Lit, LitInt, Meta, Variant, parse_macro_input};
 
 #[proc_macro_derive(ErrorCode, attributes(error_code))]
 pub fn error_code_derive(input: TokenStream) -> TokenStream {
@@ -39,12 +42,17 @@ pub fn error_code_derive(input: TokenStream) -> TokenStream {
 		})
 		.unwrap();
 
-	let (variants, codes): (Vec<_>, Vec<_>) = data_enum
+	let (variants, (codes, entries)): (Vec<_>, (Vec<_>, Vec<_>)) = data_enum
 		.variants
 		.iter()
 		.enumerate()
 		.filter_map(|(i, variant)| {
-			let Variant { ident, fields, .. } = variant;
+			let Variant {
+				ident,
+				fields,
+				attrs,
+				..
+			} = variant;
 
 			let fields = match fields {
 				Fields::Named(..) => quote! { { .. } },
@@ -54,12 +62,19 @@ pub fn error_code_derive(input: TokenStream) -> TokenStream {
 			let Ok(code) = format!("{leading_digit}{i:0>3}").parse::<u16>() else {
 				return None;
 			};
-			Some((quote! { #ident #fields }, code))
+			let name = ident.to_string();
+			let summary = doc_summary(attrs);
+			let entry = quote! {
+				::nymph_diagnostics::CatalogEntry { code: #code, name: #name, summary: #summary }
+			};
+			Some((quote! { #ident #fields }, (code, entry)))
 		})
 		.unzip();
 
 	quote! {
 		impl ErrorCode for #error_enum_name {
+			const CATALOG: &'static [::nymph_diagnostics::CatalogEntry] = &[ #( #entries ),* ];
+
 			fn code(&self) -> u16 {
 				match self {
 					#( Self::#variants => #codes ),*
@@ -69,3 +84,21 @@ pub fn error_code_derive(input: TokenStream) -> TokenStream {
 	}
 	.into()
 }
+
+/// A variant's `///` doc comment as one line of text.
+fn doc_summary(attrs: &[Attribute]) -> String {
+	attrs
+		.iter()
+		.filter_map(|attr| match &attr.meta {
+			Meta::NameValue(doc) if doc.path.is_ident("doc") => match &doc.value {
+				Expr::Lit(expr) => match &expr.lit {
+					Lit::Str(text) => Some(text.value().trim().to_string()),
+					_ => None,
+				},
+				_ => None,
+			},
+			_ => None,
+		})
+		.collect::<Vec<_>>()
+		.join(" ")
+}
@@ -1,11 +1,15 @@
-use nymph_diagnostics::ErrorCode;
+use nymph_diagnostics::{CatalogEntry, ErrorCode};
 use nymph_errorcode::ErrorCode;
 
 #[derive(ErrorCode)]
 #[error_code(8)]
 enum StableCatalog {
+	/// The first variant,
+	/// documented over two lines.
 	First,
-	Structured { _value: usize },
+	Structured {
+		_value: usize,
+	},
 	Tuple(#[allow(dead_code)] usize),
 }
 
@@ -15,3 +19,27 @@ fn catalog_positions_allocate_append_only_codes() {
 	assert_eq!(StableCatalog::Structured { _value: 1 }.code(), 8001);
 	assert_eq!(StableCatalog::Tuple(1).code(), 8002);
 }
+
+#[test]
+fn catalog_lists_every_variant_with_its_doc_comment() {
+	assert_eq!(
+		StableCatalog::CATALOG,
+		[
+			CatalogEntry {
+				code: 8000,
+				name: "First",
+				summary: "The first variant, documented over two lines.",
+			},
+			CatalogEntry {
+				code: 8001,
+				name: "Structured",
+				summary: "",
+			},
+			CatalogEntry {
+				code: 8002,
+				name: "Tuple",
+				summary: "",
+			},
+		]
+	);
+}
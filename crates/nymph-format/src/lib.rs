@@ -7,8 +7,11 @@
 use std::collections::{HashMap, HashSet};
 
 use nymph_ast::{
-	Span,
-	decl::{Declaration, ImplMember, InterfaceElement, InterfaceMember, Module},
+	Span, Spanned,
+	decl::{
+		Attribute, AttributeArgument, Declaration, ImplMember, InterfaceElement, InterfaceMember,
+		Module, StructField,
+	},
 	expr::{Expr, ExprKind, ListItem, MapEntry, Statement, StringPart},
 };
 use nymph_diagnostics::Diagnostic;
@@ -176,6 +179,15 @@ struct Hints {
 	units: Vec<Span>,
 	removable_units: Vec<Span>,
 	continuation_before: HashSet<usize>,
+	/// Contextual keywords that open a declaration here, such as `test`, which
+	/// is an ordinary identifier everywhere else.
+	declaration_starts: HashSet<usize>,
+	/// Ends of attributes that sit on their own line above a declaration or
+	/// member; their starts are in `line_before`.
+	line_after: HashSet<usize>,
+	/// The hyphens and later parts of a hyphen-joined attribute argument, which
+	/// is one name rather than a subtraction.
+	joined: HashSet<usize>,
 }
 
 impl Hints {
@@ -217,7 +229,8 @@ impl Hints {
 							line_width = 0;
 						}
 						declaration_prefix = true;
-					} else if is_declaration_start(item.text) {
+					} else if is_declaration_start(item.text) || self.declaration_starts.contains(&item.start)
+					{
 						if !declaration_prefix {
 							line_width = 0;
 						}
@@ -297,22 +310,52 @@ impl Hints {
 		}
 	}
 
+	fn visit_attributes(&mut self, attributes: &[Spanned<Attribute>], own_line: bool) {
+		for attribute in attributes {
+			if own_line {
+				self.line_before.insert(attribute.1.start);
+				self.line_after.insert(attribute.1.end);
+			}
+			for argument in &attribute.0.arguments {
+				if let AttributeArgument::Name(name) = &argument.0 {
+					for (index, _) in name.match_indices('-') {
+						self.joined.insert(argument.1.start + index);
+						self.joined.insert(argument.1.start + index + 1);
+					}
+				}
+			}
+		}
+	}
+
+	fn visit_fields(&mut self, source: &str, fields: &[Spanned<StructField>]) {
+		for field in fields {
+			self.visit_attributes(&field.0.attributes, false);
+			if let Some(default) = &field.0.default {
+				self.visit_expr(source, default, true);
+			}
+		}
+	}
+
 	fn visit_declaration(&mut self, source: &str, declaration: &Declaration) {
+		self.visit_attributes(declaration.attributes(), true);
 		match declaration {
 			Declaration::Let { value, .. } | Declaration::Func { body: value, .. } => {
 				self.visit_expr(source, value, true);
 			}
+			Declaration::Test { name, body, .. } => {
+				let keyword = source[..name.1.start].trim_end();
+				if keyword.ends_with("test") {
+					self.declaration_starts.insert(keyword.len() - "test".len());
+				}
+				self.visit_expr(source, body, true);
+			}
 			Declaration::Struct {
 				fields,
 				members,
 				impls,
 				..
 			} => {
-				for field in fields {
-					if let Some(default) = &field.0.default {
-						self.visit_expr(source, default, true);
-					}
-				}
+				self.visit_fields(source, fields);
 				for member in members {
 					self.line_before.insert(member.1.start);
 					self.visit_impl_member(source, &member.0);
@@ -343,11 +386,8 @@ impl Hints {
 					if !source[variant.1.end..].trim_start().starts_with(',') {
 						self.comma_after.insert(variant.1.end);
 					}
-					for field in &variant.0.fields {
-						if let Some(default) = &field.0.default {
-							self.visit_expr(source, default, true);
-						}
-					}
+					self.visit_attributes(&variant.0.attributes, false);
+					self.visit_fields(source, &variant.0.fields);
 				}
 				for member in members {
 					self.line_before.insert(member.1.start);
@@ -384,6 +424,7 @@ impl Hints {
 	}
 
 	fn visit_impl_member(&mut self, source: &str, member: &ImplMember) {
+		self.visit_attributes(member.attributes(), true);
 		match member {
 			ImplMember::Let { value, .. } | ImplMember::Func { body: value, .. } => {
 				self.visit_expr(source, value, true);
@@ -394,18 +435,21 @@ impl Hints {
 
 	fn visit_interface_member(&mut self, source: &str, member: &InterfaceMember) {
 		match member {
-			InterfaceMember::Element(element) => match &element.0 {
-				InterfaceElement::Let { value, .. } => {
-					if let Some(value) = value {
-						self.visit_expr(source, value, true);
+			InterfaceMember::Element(element) => {
+				self.visit_attributes(element.0.attributes(), true);
+				match &element.0 {
+					InterfaceElement::Let { value, .. } => {
+						if let Some(value) = value {
+							self.visit_expr(source, value, true);
+						}
 					}
-				}
-				InterfaceElement::Func { body, .. } => {
-					if let Some(body) = body {
-						self.visit_expr(source, body, true);
+					InterfaceElement::Func { body, .. } => {
+						if let Some(body) = body {
+							self.visit_expr(source, body, true);
+						}
 					}
 				}
-			},
+			}
 			InterfaceMember::Impl { members, .. } => {
 				for member in members {
 					self.line_before.insert(member.1.start);
@@ -546,8 +590,10 @@ impl Hints {
 				}
 				for arm in arms {
 					self.line_before.insert(arm.pattern.1.start);
+					// A guard keeps its parentheses: without them a trailing
+					// identifier would take the arm's `->` as a closure arrow.
 					if let Some(guard) = &arm.guard {
-						self.visit_expr(source, guard, true);
+						self.visit_expr(source, guard, false);
 					}
 					self.visit_expr(source, &arm.body, true);
 				}
@@ -577,7 +623,10 @@ impl Hints {
 				for statement in body {
 					match &statement.0 {
 						Statement::Expr(value) => self.visit_expr(source, value, true),
-						Statement::Let { value, .. } => self.visit_expr(source, value, true),
+						Statement::Let { meta, value } => {
+							self.visit_attributes(&meta.attributes, true);
+							self.visit_expr(source, value, true);
+						}
 					}
 				}
 			}
@@ -934,7 +983,17 @@ impl<'a> Scanner<'a> {
 			"::", "|>", "**", "&&", "||", "<<", ">>", "==", "!=", "<=", ">=", "+=", "-=", "*=", "/=",
 			"%=", "&=", "|=", "^=", "~=", "..=", "..",
 		];
-		if let Some(op) = OPS.iter().find(|op| rest.starts_with(**op)) {
+		// Like the lexer, `!in`/`!is` only merge with a whole keyword, so
+		// `!is_empty()` stays `!` followed by an identifier.
+		let is_op = |op: &&&str| {
+			rest.starts_with(**op)
+				&& !(op.starts_with('!')
+					&& rest[op.len()..]
+						.chars()
+						.next()
+						.is_some_and(unicode_ident::is_xid_continue))
+		};
+		if let Some(op) = OPS.iter().find(is_op) {
 			self.at += op.len();
 		} else if first.is_ascii_alphanumeric() || first == '_' || unicode_ident::is_xid_start(first) {
 			self.at += first.len_utf8();
@@ -1089,15 +1148,26 @@ impl<'a> Formatter<'a> {
 					self.pending_blank |= item.text.matches('\n').count() > 1;
 				}
 				Kind::LineComment => {
-					if item.text.starts_with("///")
+					let doc = item.text.starts_with("///");
+					// A doc comment's later lines continue the one above it.
+					let continues_doc = self
+						.out
+						.trim_end_matches('\n')
+						.rsplit('\n')
+						.next()
+						.is_some_and(|line| line.trim_start().starts_with("///"));
+					if doc
 						&& self.depth == 0
+						&& !continues_doc
 						&& !self.out.ends_with("\n\n")
 						&& !self.out.is_empty()
 					{
 						self.newline();
 						self.out.push('\n');
 					}
-					if !self.at_line_start {
+					if doc && !self.at_line_start {
+						self.newline();
+					} else if !self.at_line_start {
 						self.space();
 					}
 					self.write_raw(item.text.trim_end());
@@ -1131,6 +1201,12 @@ impl<'a> Formatter<'a> {
 			self.pending_space = true;
 			return;
 		}
+		if self.hints.joined.remove(&item.start) {
+			self.write_raw(token);
+			self.previous_was_prefix = false;
+			self.previous = Some(token);
+			return;
+		}
 		if self.hints.line_before.contains(&item.start) {
 			if !self.at_line_start {
 				self.newline();
@@ -1162,7 +1238,9 @@ impl<'a> Formatter<'a> {
 			}
 			self.declaration_prefix = true;
 			self.in_import = false;
-		} else if self.depth == 0 && is_declaration_start(token) {
+		} else if self.depth == 0
+			&& (is_declaration_start(token) || self.hints.declaration_starts.contains(&item.start))
+		{
 			if !self.declaration_prefix && self.previous.is_some() {
 				self.newline();
 			}
@@ -1336,6 +1414,9 @@ impl<'a> Formatter<'a> {
 			self.write_raw(",");
 			self.newline();
 		}
+		if self.hints.line_after.contains(&item.end) {
+			self.newline();
+		}
 		self.previous_was_prefix = is_prefix_operator(token, self.previous);
 		self.previous = Some(token);
 	}
@@ -273,6 +273,44 @@ pub enum MarshalKind {
 	/// The identity is part of the stable ABI plan; backends must reject a box
 	/// minted for any other external type instead of inspecting or repairing it.
 	Opaque(u64),
+	/// A host array of scalars, converted element by element to and from a
+	/// list. Only host bindings produce it.
+	Array(ScalarMarshal),
+	/// A host promise, driven as a cancellable task whose settled value is
+	/// converted to the given shape. Only a host binding's result produces it.
+	Promise(PromiseMarshal),
+}
+
+/// A scalar crossing a host binding as its raw JavaScript value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScalarMarshal {
+	Int,
+	UInt,
+	Float,
+	Char,
+	String,
+	Boolean,
+}
+
+impl From<ScalarMarshal> for MarshalKind {
+	fn from(scalar: ScalarMarshal) -> Self {
+		match scalar {
+			ScalarMarshal::Int => Self::Int,
+			ScalarMarshal::UInt => Self::UInt,
+			ScalarMarshal::Float => Self::Float,
+			ScalarMarshal::Char => Self::Char,
+			ScalarMarshal::String => Self::String,
+			ScalarMarshal::Boolean => Self::Boolean,
+		}
+	}
+}
+
+/// The value a host promise settles with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PromiseMarshal {
+	Void,
+	Scalar(ScalarMarshal),
+	Array(ScalarMarshal),
 }
 
 /// Backend-neutral external invocation mode. A backend may translate the
@@ -283,6 +321,10 @@ pub enum ExternalCallMode {
 	#[default]
 	Ordinary,
 	Cancellable,
+	/// A user-declared host binding. The adapter knows nothing of boxing, so
+	/// every marshalled argument crosses as its raw value and a marshalled
+	/// result is boxed again on return.
+	Host,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -58,7 +58,10 @@ pub enum ExternalEffect {
 pub fn external_effect(module: &str, symbol: &str) -> ExternalEffect {
 	match (module, symbol) {
 		("std/io", "print" | "println") => ExternalEffect::IrreversibleHostIo,
-		("std/equality", "primitive_equals")
+		(
+			"std/equality",
+			"primitive_equals" | "primitive_hash" | "primitive_display" | "primitive_debug",
+		)
 		| ("std/collections/list", "length" | "get" | "slice")
 		| (
 			"std/collections/map",
@@ -77,7 +80,23 @@ pub fn external_effect(module: &str, symbol: &str) -> ExternalEffect {
 			"std/string",
 			"char_at" | "concat" | "index_of" | "last_index_of" | "length" | "substring" | "to_lower"
 			| "to_upper" | "trim" | "trim_end" | "trim_start",
+		)
+		| ("std/test", "assert_eq" | "assert_ne" | "value_assertion_failed")
+		| ("std/env", "env_args" | "env_var" | "env_vars")
+		| ("std/process", "process_decode")
+		| ("std/json", "json_quote" | "json_unescape" | "json_number_text" | "json_parse_number")
+		| (
+			"std/js",
+			"js_global" | "js_wrap" | "js_is_null" | "js_as_string" | "js_as_float" | "js_as_boolean"
+			| "js_get_field" | "js_display" | "js_debug",
 		) => ExternalEffect::Pure,
+		("std/js", "js_invoke")
+		| (
+			"std/fs",
+			"fs_read_text" | "fs_write_text" | "fs_read_dir" | "fs_metadata" | "fs_open"
+			| "fs_read_chunk" | "fs_write_chunk" | "fs_close",
+		)
+		| ("std/process", "process_spawn") => ExternalEffect::IrreversibleHostIo,
 		_ => ExternalEffect::UnauditedStateful,
 	}
 }
@@ -189,6 +208,30 @@ pub const REGISTRY: &[(&str, Linked)] = &[
 			receiver_tag: None,
 		},
 	),
+	(
+		"primitive_hash",
+		Linked {
+			module: "std/equality",
+			symbol: "primitive_hash",
+			receiver_tag: None,
+		},
+	),
+	(
+		"primitive_display",
+		Linked {
+			module: "std/equality",
+			symbol: "primitive_display",
+			receiver_tag: None,
+		},
+	),
+	(
+		"primitive_debug",
+		Linked {
+			module: "std/equality",
+			symbol: "primitive_debug",
+			receiver_tag: None,
+		},
+	),
 	(
 		"compare_char",
 		Linked {
@@ -268,6 +311,175 @@ pub const REGISTRY: &[(&str, Linked)] = &[
 			receiver_tag: None,
 		},
 	),
+	// `std/env`'s process state: receiverless, like `print`.
+	(
+		"env_args",
+		Linked {
+			module: "std/env",
+			symbol: "env_args",
+			receiver_tag: None,
+		},
+	),
+	(
+		"env_var",
+		Linked {
+			module: "std/env",
+			symbol: "env_var",
+			receiver_tag: None,
+		},
+	),
+	(
+		"env_vars",
+		Linked {
+			module: "std/env",
+			symbol: "env_vars",
+			receiver_tag: None,
+		},
+	),
+	// `std/fs`'s file system access: receiverless, like `print`. Failures
+	// come back as a `Result` carrying Node's error code.
+	(
+		"fs_read_text",
+		Linked {
+			module: "std/fs",
+			symbol: "fs_read_text",
+			receiver_tag: None,
+		},
+	),
+	(
+		"fs_write_text",
+		Linked {
+			module: "std/fs",
+			symbol: "fs_write_text",
+			receiver_tag: None,
+		},
+	),
+	(
+		"fs_read_dir",
+		Linked {
+			module: "std/fs",
+			symbol: "fs_read_dir",
+			receiver_tag: None,
+		},
+	),
+	(
+		"fs_metadata",
+		Linked {
+			module: "std/fs",
+			symbol: "fs_metadata",
+			receiver_tag: None,
+		},
+	),
+	(
+		"fs_open",
+		Linked {
+			module: "std/fs",
+			symbol: "fs_open",
+			receiver_tag: None,
+		},
+	),
+	(
+		"fs_read_chunk",
+		Linked {
+			module: "std/fs",
+			symbol: "fs_read_chunk",
+			receiver_tag: None,
+		},
+	),
+	(
+		"fs_write_chunk",
+		Linked {
+			module: "std/fs",
+			symbol: "fs_write_chunk",
+			receiver_tag: None,
+		},
+	),
+	(
+		"fs_close",
+		Linked {
+			module: "std/fs",
+			symbol: "fs_close",
+			receiver_tag: None,
+		},
+	),
+	// `std/json`'s text conversions: receiverless, like `print`. The parser
+	// itself is Nymph; these only quote, unescape and format what it checked.
+	(
+		"json_quote",
+		Linked {
+			module: "std/json",
+			symbol: "json_quote",
+			receiver_tag: None,
+		},
+	),
+	(
+		"json_unescape",
+		Linked {
+			module: "std/json",
+			symbol: "json_unescape",
+			receiver_tag: None,
+		},
+	),
+	(
+		"json_number_text",
+		Linked {
+			module: "std/json",
+			symbol: "json_number_text",
+			receiver_tag: None,
+		},
+	),
+	(
+		"json_parse_number",
+		Linked {
+			module: "std/json",
+			symbol: "json_parse_number",
+			receiver_tag: None,
+		},
+	),
+	// `std/process`'s child processes: receiverless, like `print`. Spawning
+	// returns a task that kills the child when it is cancelled.
+	(
+		"process_spawn",
+		Linked {
+			module: "std/process",
+			symbol: "process_spawn",
+			receiver_tag: None,
+		},
+	),
+	(
+		"process_decode",
+		Linked {
+			module: "std/process",
+			symbol: "process_decode",
+			receiver_tag: None,
+		},
+	),
+	// `std/test`'s assertions: receiverless, like `print`. A failure throws
+	// the rendered values as a defect.
+	(
+		"assert_eq",
+		Linked {
+			module: "std/test",
+			symbol: "assert_eq",
+			receiver_tag: None,
+		},
+	),
+	(
+		"assert_ne",
+		Linked {
+			module: "std/test",
+			symbol: "assert_ne",
+			receiver_tag: None,
+		},
+	),
+	(
+		"value_assertion_failed",
+		Linked {
+			module: "std/test",
+			symbol: "value_assertion_failed",
+			receiver_tag: None,
+		},
+	),
 	// Map and list share the `get` marker; the receiver tag selects the matching JS implementation.
 	(
 		"get",
@@ -341,6 +553,88 @@ pub const REGISTRY: &[(&str, Linked)] = &[
 			receiver_tag: None,
 		},
 	),
+	// `js.nym`'s `JsValue` accessors. Each result is a `JsValue` copied from a
+	// received one, so these need no compiler-owned class of their own.
+	(
+		"js_global",
+		Linked {
+			module: "std/js",
+			symbol: "js_global",
+			receiver_tag: None,
+		},
+	),
+	(
+		"js_wrap",
+		Linked {
+			module: "std/js",
+			symbol: "js_wrap",
+			receiver_tag: None,
+		},
+	),
+	(
+		"js_invoke",
+		Linked {
+			module: "std/js",
+			symbol: "js_invoke",
+			receiver_tag: None,
+		},
+	),
+	(
+		"js_is_null",
+		Linked {
+			module: "std/js",
+			symbol: "js_is_null",
+			receiver_tag: None,
+		},
+	),
+	(
+		"js_as_string",
+		Linked {
+			module: "std/js",
+			symbol: "js_as_string",
+			receiver_tag: None,
+		},
+	),
+	(
+		"js_as_float",
+		Linked {
+			module: "std/js",
+			symbol: "js_as_float",
+			receiver_tag: None,
+		},
+	),
+	(
+		"js_as_boolean",
+		Linked {
+			module: "std/js",
+			symbol: "js_as_boolean",
+			receiver_tag: None,
+		},
+	),
+	(
+		"js_get_field",
+		Linked {
+			module: "std/js",
+			symbol: "js_get_field",
+			receiver_tag: None,
+		},
+	),
+	(
+		"js_display",
+		Linked {
+			module: "std/js",
+			symbol: "js_display",
+			receiver_tag: None,
+		},
+	),
+	(
+		"js_debug",
+		Linked {
+			module: "std/js",
+			symbol: "js_debug",
+			receiver_tag: None,
+		},
+	),
 	// `string.nym`'s host primitives. `string` is a primitive, so its receiver
 	// tag (`inherent_self_type_tag` → `primitive_type_tag`) is `"string"`.
 	(
@@ -551,6 +845,20 @@ pub fn is_value_marker(name: &str) -> bool {
 	VALUE_REGISTRY.iter().any(|(marker, _)| *marker == name)
 }
 
+/// Whether `name` links to an intrinsic that returns a task rather than a
+/// value. The host builds that task itself (with the box runtime's
+/// `nymphHostSignalTask`), so its declaration is `external(name) async func`
+/// and the call's result crosses unconverted.
+#[must_use]
+pub fn is_task_marker(name: &str) -> bool {
+	lookup(name, None).is_some_and(|linked| {
+		matches!(
+			(linked.module, linked.symbol),
+			("std/process", "process_spawn")
+		)
+	})
+}
+
 /// Every distinct registry MODULE, each paired with the DEDUPED symbols it
 /// must export — used by the driver (`nymph-compiler`) to know which virtual
 /// modules to inject into the bundle graph and which symbols each one needs
@@ -690,8 +998,54 @@ mod tests {
 				),
 				("std/collections/set", vec!["set_inserted", "set_removed"]),
 				("std/comparison", vec!["compare_char", "compare_string"]),
-				("std/equality", vec!["primitive_equals"]),
+				("std/env", vec!["env_args", "env_var", "env_vars"]),
+				(
+					"std/equality",
+					vec![
+						"primitive_debug",
+						"primitive_display",
+						"primitive_equals",
+						"primitive_hash"
+					]
+				),
+				(
+					"std/fs",
+					vec![
+						"fs_close",
+						"fs_metadata",
+						"fs_open",
+						"fs_read_chunk",
+						"fs_read_dir",
+						"fs_read_text",
+						"fs_write_chunk",
+						"fs_write_text",
+					]
+				),
 				("std/io", vec!["print", "println"]),
+				(
+					"std/js",
+					vec![
+						"js_as_boolean",
+						"js_as_float",
+						"js_as_string",
+						"js_debug",
+						"js_display",
+						"js_get_field",
+						"js_global",
+						"js_invoke",
+						"js_is_null",
+						"js_wrap",
+					]
+				),
+				(
+					"std/json",
+					vec![
+						"json_number_text",
+						"json_parse_number",
+						"json_quote",
+						"json_unescape",
+					]
+				),
 				(
 					"std/math/intrinsics",
 					vec![
@@ -719,6 +1073,7 @@ mod tests {
 						"tanh",
 					]
 				),
+				("std/process", vec!["process_decode", "process_spawn"]),
 				(
 					"std/string",
 					vec![
@@ -735,6 +1090,10 @@ mod tests {
 						"trim_start",
 					]
 				),
+				(
+					"std/test",
+					vec!["assert_eq", "assert_ne", "value_assertion_failed"]
+				),
 			]
 		);
 	}
@@ -181,6 +181,12 @@ pub struct CompilerState {
 	workspace_symbol_refresh_errors: HashSet<PathBuf>,
 	diagnostic_targets: HashMap<String, Vec<String>>,
 	diagnostic_owners: HashMap<String, String>,
+	/// Exact package nodes minted for dependency manifests, per project, so
+	/// refreshing a root's dependency graph updates rather than duplicates them.
+	dependency_packages: HashMap<(ProjectId, PathBuf), PackageId>,
+	/// Source root of every minted dependency package, used to map its
+	/// modules back to file URIs for cross-package navigation.
+	dependency_roots: HashMap<PackageId, PathBuf>,
 }
 
 impl Default for CompilerState {
@@ -211,6 +217,8 @@ impl CompilerState {
 			workspace_symbol_refresh_errors: HashSet::new(),
 			diagnostic_targets: HashMap::new(),
 			diagnostic_owners: HashMap::new(),
+			dependency_packages: HashMap::new(),
+			dependency_roots: HashMap::new(),
 		}
 	}
 
@@ -233,6 +241,8 @@ impl CompilerState {
 			workspace_symbol_refresh_errors: HashSet::new(),
 			diagnostic_targets: HashMap::new(),
 			diagnostic_owners: HashMap::new(),
+			dependency_packages: HashMap::new(),
+			dependency_roots: HashMap::new(),
 		}
 	}
 
@@ -1101,18 +1111,38 @@ impl CompilerState {
 		) {
 			return None;
 		}
-		if !snapshot.package.owns_module(&definition.module) {
-			return None;
-		}
 		let module = ModulePath::new(definition.module.path.as_str()).ok()?;
-		if module == snapshot.module {
-			return None;
-		}
 		let session = if snapshot.without_prelude {
 			&self.stdlib_session
 		} else {
 			&self.session
 		};
+		// A definition in a dependency package (another workspace member or a
+		// path dependency) resolves through that package's own source root.
+		if !snapshot.package.owns_module(&definition.module) {
+			let (package, root) = self.dependency_roots.iter().find(|(package, _)| {
+				*package.project() == snapshot.project && package.owns_module(&definition.module)
+			})?;
+			let analysis = session.tooling_analyze_package_module(
+				snapshot.project.clone(),
+				snapshot.entry.clone(),
+				package.clone(),
+				module.clone(),
+				!snapshot.without_prelude,
+			)?;
+			let span = analysis.declaration_provenance(&definition)?.name_span;
+			let uri = workspace::key_to_uri(root, module.as_str())?;
+			let source = analysis.source.clone();
+			return valid_source_span(&source, span).then_some(DefinitionTargetSnapshot {
+				uri,
+				source,
+				span,
+				requires_disk_validation: true,
+			});
+		}
+		if module == snapshot.module {
+			return None;
+		}
 		if !session.has_source(snapshot.project.clone(), module.clone()) {
 			return None;
 		}
@@ -1267,6 +1297,59 @@ impl CompilerState {
 				has_equivalent_open_documents: open_uris.len() > 1,
 			});
 		}
+		let stable = matches!(
+			symbol,
+			nymph_sema::query::SymbolIdentity::Definition(_)
+				| nymph_sema::query::SymbolIdentity::Generic(nymph_sema::GenericSymbolIdentity::Stable(_))
+				| nymph_sema::query::SymbolIdentity::Module(_)
+		);
+		if stable && !isolated {
+			// Dependency packages are read-only disk inputs of this project, so
+			// their occurrences are reported but never renamed.
+			for (package, root) in &self.dependency_roots {
+				if *package.project() != snapshot.project {
+					continue;
+				}
+				for (module, source, analysis) in session.tooling_package_analyses(
+					snapshot.project.clone(),
+					snapshot.entry.clone(),
+					package.clone(),
+					!snapshot.without_prelude,
+				) {
+					cancellation.checkpoint()?;
+					let Some(analysis) = analysis else {
+						continue;
+					};
+					let occurrences = nymph_sema::query::references_to(&analysis.semantic, symbol);
+					if occurrences.is_empty() {
+						continue;
+					}
+					let Some(uri) = workspace::key_to_uri(root, module.as_str()) else {
+						continue;
+					};
+					let document = docs.get(&uri);
+					if document.is_some_and(|document| *document.text != *source) {
+						continue;
+					}
+					if occurrences
+						.iter()
+						.any(|occurrence| !valid_source_span(&source, occurrence.span))
+					{
+						continue;
+					}
+					let document_version = document.map(|document| document.version);
+					modules.push(ReferenceModuleSnapshot {
+						uri,
+						source,
+						occurrences,
+						rename_occurrences: None,
+						document_version,
+						requires_disk_validation: document_version.is_none(),
+						has_equivalent_open_documents: false,
+					});
+				}
+			}
+		}
 		Ok(Some(modules))
 	}
 
@@ -1572,13 +1655,19 @@ impl CompilerState {
 			}
 			Ok(class) => class,
 		};
+		let mut manifest_path = None;
+		let mut workspace_manifest = None;
 		let (root, module, kind, lints) = match class {
-			workspace::UriClass::ProjectFile { path, project } => (
-				project.src_root,
-				ModulePath::new(project.entry_key).unwrap(),
-				DocumentKind::Project(path),
-				project.lints,
-			),
+			workspace::UriClass::ProjectFile { path, project } => {
+				manifest_path = Some(project.manifest_path);
+				workspace_manifest = project.workspace_manifest;
+				(
+					project.src_root,
+					ModulePath::new(project.entry_key).unwrap(),
+					DocumentKind::Project(path),
+					project.lints,
+				)
+			}
 			workspace::UriClass::LooseFile { path } => {
 				let root = path
 					.parent()
@@ -1658,6 +1747,22 @@ impl CompilerState {
 
 		if matches!(kind, DocumentKind::Project(_)) && self.synchronized_roots.insert(root.clone()) {
 			self.synchronize_project_files(docs, &root, &project, without_prelude)?;
+			if let Some(manifest_path) = &manifest_path
+				&& let Err(error) =
+					self.synchronize_dependency_packages(manifest_path, &project, without_prelude)
+			{
+				// Retry on the next synchronization so every document of the
+				// root reports the broken dependency graph until it is fixed.
+				self.synchronized_roots.remove(&root);
+				self.manifest_errors.insert(uri.clone(), error.to_string());
+			}
+			if let Some(workspace_manifest) = &workspace_manifest
+				&& let Err(error) =
+					self.synchronize_workspace_members(docs, workspace_manifest, without_prelude)
+			{
+				self.synchronized_roots.remove(&root);
+				self.manifest_errors.insert(uri.clone(), error.to_string());
+			}
 		}
 		if docs.get(uri).is_some() {
 			self
@@ -1815,6 +1920,102 @@ impl CompilerState {
 		Ok(())
 	}
 
+	/// Load the source graph and dependency packages of every other member of
+	/// a workspace into the session, each as its own project, so workspace
+	/// symbols and navigation span the whole workspace after one open.
+	fn synchronize_workspace_members(
+		&mut self,
+		docs: &DocumentStore,
+		workspace_manifest: &std::path::Path,
+		without_prelude: bool,
+	) -> anyhow::Result<()> {
+		for member in workspace::workspace_members(workspace_manifest)? {
+			let root = std::path::absolute(member.source_root())?;
+			if !self.synchronized_roots.insert(root.clone()) {
+				continue;
+			}
+			let project = self
+				.workspaces
+				.entry(root.clone())
+				.or_insert_with_key(|key| ProjectId::new(key.to_string_lossy().into_owned()))
+				.clone();
+			self
+				.session_mut(without_prelude)
+				.set_project_lints(project.clone(), member.lints());
+			let synchronized = self
+				.synchronize_project_files(docs, &root, &project, without_prelude)
+				.and_then(|()| {
+					self.synchronize_dependency_packages(member.manifest_path(), &project, without_prelude)
+				});
+			if let Err(error) = synchronized {
+				self.synchronized_roots.remove(&root);
+				return Err(error);
+			}
+		}
+		Ok(())
+	}
+
+	/// Load every package of the root manifest's local dependency graph into
+	/// the session from disk and point each owner's import aliases at it.
+	fn synchronize_dependency_packages(
+		&mut self,
+		manifest_path: &std::path::Path,
+		project: &ProjectId,
+		without_prelude: bool,
+	) -> anyhow::Result<()> {
+		let graph = workspace::dependency_graph(manifest_path)?;
+		let mut packages = vec![self.session.root_package(project.clone())];
+		for dependency in graph.dependencies() {
+			let key = (project.clone(), dependency.manifest_path.clone());
+			let package = match self.dependency_packages.get(&key) {
+				Some(package) => package.clone(),
+				None => {
+					let package = self
+						.session_mut(without_prelude)
+						.mint_package(project.clone());
+					self.dependency_packages.insert(key, package.clone());
+					package
+				}
+			};
+			self
+				.dependency_roots
+				.insert(package.clone(), dependency.source_root.clone());
+			packages.push(package);
+		}
+		for (owner, package) in graph.packages().iter().enumerate() {
+			for (alias, &target) in &package.dependencies {
+				self
+					.session_mut(without_prelude)
+					.set_package_alias(
+						packages[owner].clone(),
+						alias.as_str(),
+						packages[target].clone(),
+					)
+					.expect("minted dependency packages belong to their project");
+			}
+		}
+		for (dependency, package) in graph.dependencies().iter().zip(&packages[1..]) {
+			for (path, module) in nymph_files(&dependency.source_root) {
+				let Ok(source) = fs::read_to_string(&path) else {
+					continue;
+				};
+				let source_uri = workspace::path_to_uri(&path).map(|uri| uri.as_str().to_string());
+				self
+					.session_mut(without_prelude)
+					.set_package_source_with_location(
+						package.clone(),
+						module.clone(),
+						source,
+						SourceVersion(0),
+						format!("{module}.nym"),
+						source_uri,
+					)
+					.expect("minted dependency packages belong to their session");
+			}
+		}
+		Ok(())
+	}
+
 	fn session_for(&self, identity: &DocumentIdentity) -> &CompilerSession {
 		if identity.without_prelude {
 			&self.stdlib_session
@@ -11,10 +11,10 @@
 use std::collections::HashSet;
 
 use lsp_types::{
-	CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse, CompletionTextEdit,
-	TextEdit,
+	CompletionItem, CompletionItemKind, CompletionItemTag, CompletionParams, CompletionResponse,
+	CompletionTextEdit, TextEdit,
 };
-use nymph_ast::decl::Declaration;
+use nymph_ast::decl::{Declaration, find_attribute};
 
 use crate::{
 	compiler_state::CompletionSnapshot, document_store::DocumentStore, line_index::LineIndex,
@@ -147,6 +147,12 @@ fn complete(
 					kind: Some(member_kind(candidate.kind)),
 					detail: Some(candidate.detail),
 					sort_text: Some(format!("0:{}", candidate.name)),
+					tags: deprecated_tags(
+						candidate
+							.target
+							.as_ref()
+							.is_some_and(|target| semantic.deprecations.contains_key(target)),
+					),
 					text_edit: Some(CompletionTextEdit::Edit(TextEdit {
 						range,
 						new_text: candidate.name.to_string(),
@@ -174,7 +180,7 @@ fn complete(
 		&mut seen,
 		scope_names
 			.into_iter()
-			.map(|name| (name, CompletionItemKind::VARIABLE)),
+			.map(|name| (name, CompletionItemKind::VARIABLE, false)),
 		&prefix,
 		0,
 	);
@@ -182,9 +188,13 @@ fn complete(
 	push_tier(
 		&mut items,
 		&mut seen,
-		imported_names
-			.iter()
-			.map(|imported| (imported.name.clone(), imported_kind(imported.kind))),
+		imported_names.iter().map(|imported| {
+			(
+				imported.name.clone(),
+				imported_kind(imported.kind),
+				imported.deprecated,
+			)
+		}),
 		&prefix,
 		1,
 	);
@@ -195,9 +205,13 @@ fn complete(
 	push_tier(
 		&mut items,
 		&mut seen,
-		ambient_names
-			.iter()
-			.map(|ambient| (ambient.name.clone(), imported_kind(ambient.kind))),
+		ambient_names.iter().map(|ambient| {
+			(
+				ambient.name.clone(),
+				imported_kind(ambient.kind),
+				ambient.deprecated,
+			)
+		}),
 		&prefix,
 		3,
 	);
@@ -207,7 +221,7 @@ fn complete(
 		&mut seen,
 		KEYWORDS
 			.iter()
-			.map(|keyword| ((*keyword).to_string(), CompletionItemKind::KEYWORD)),
+			.map(|keyword| ((*keyword).to_string(), CompletionItemKind::KEYWORD, false)),
 		&prefix,
 		4,
 	);
@@ -215,7 +229,7 @@ fn complete(
 		push_tier(
 			&mut items,
 			&mut seen,
-			[("use".to_string(), CompletionItemKind::KEYWORD)],
+			[("use".to_string(), CompletionItemKind::KEYWORD, false)],
 			&prefix,
 			4,
 		);
@@ -245,18 +259,19 @@ fn member_kind(kind: nymph_sema::MemberCompletionKind) -> CompletionItemKind {
 fn push_tier(
 	items: &mut Vec<CompletionItem>,
 	seen: &mut HashSet<String>,
-	candidates: impl IntoIterator<Item = (String, CompletionItemKind)>,
+	candidates: impl IntoIterator<Item = (String, CompletionItemKind, bool)>,
 	prefix: &str,
 	rank: u8,
 ) {
 	let mut tier = candidates
 		.into_iter()
-		.filter(|(name, _)| prefix.is_empty() || name.starts_with(prefix))
-		.filter(|(name, _)| seen.insert(name.clone()))
-		.map(|(label, kind)| CompletionItem {
+		.filter(|(name, ..)| prefix.is_empty() || name.starts_with(prefix))
+		.filter(|(name, ..)| seen.insert(name.clone()))
+		.map(|(label, kind, deprecated)| CompletionItem {
 			sort_text: Some(format!("{rank}:{label}")),
 			label,
 			kind: Some(kind),
+			tags: deprecated_tags(deprecated),
 			..Default::default()
 		})
 		.collect::<Vec<_>>();
@@ -264,6 +279,11 @@ fn push_tier(
 	items.extend(tier);
 }
 
+/// Editors strike through an item tagged deprecated.
+fn deprecated_tags(deprecated: bool) -> Option<Vec<CompletionItemTag>> {
+	deprecated.then(|| vec![CompletionItemTag::DEPRECATED])
+}
+
 fn imported_kind(kind: nymph_sema::query::ImportedNameKind) -> CompletionItemKind {
 	use nymph_sema::query::ImportedNameKind;
 	match kind {
@@ -310,31 +330,34 @@ fn identifier_prefix(text: &str, offset: usize) -> (String, Option<usize>, usize
 
 /// Every top-level declaration's own name and a matching [`CompletionItemKind`]
 /// — the same set `document_symbols` lists, minus the nesting/ranges this
-/// doesn't need. Skips `import`/anonymous `impl` blocks, which introduce no
-/// name of their own.
-fn top_level_items(module: &nymph_ast::decl::Module) -> Vec<(String, CompletionItemKind)> {
+/// doesn't need. Skips `import`/anonymous `impl` blocks and `test`s, which
+/// introduce no name of their own.
+fn top_level_items(module: &nymph_ast::decl::Module) -> Vec<(String, CompletionItemKind, bool)> {
 	module
 		.members
 		.iter()
-		.filter_map(|decl| match decl {
-			Declaration::Effect { name, .. } => Some((name.0.to_string(), CompletionItemKind::CLASS)),
-			Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) => {
-				Some((meta.name.0.to_string(), CompletionItemKind::FUNCTION))
-			}
-			Declaration::Let { meta, .. } | Declaration::ExternalLet(_, _, meta) => {
-				let name = meta.name.0.as_binding()?;
-				Some((name.0.to_string(), CompletionItemKind::CONSTANT))
-			}
-			Declaration::Struct { name, .. } => Some((name.0.to_string(), CompletionItemKind::STRUCT)),
-			Declaration::Enum { name, .. } => Some((name.0.to_string(), CompletionItemKind::ENUM)),
-			Declaration::Interface { name, .. } => {
-				Some((name.0.to_string(), CompletionItemKind::INTERFACE))
-			}
-			Declaration::Namespace { name, .. } => Some((name.0.to_string(), CompletionItemKind::MODULE)),
-			Declaration::TypeAlias { meta, .. } => {
-				Some((meta.name.0.to_string(), CompletionItemKind::CLASS))
-			}
-			Declaration::Import { .. } | Declaration::Impl { .. } | Declaration::ImplFor { .. } => None,
+		.filter_map(|decl| {
+			let (name, kind) = match decl {
+				Declaration::Effect { name, .. } => (name.0.to_string(), CompletionItemKind::CLASS),
+				Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) => {
+					(meta.name.0.to_string(), CompletionItemKind::FUNCTION)
+				}
+				Declaration::Let { meta, .. } | Declaration::ExternalLet(_, _, meta) => {
+					let name = meta.name.0.as_binding()?;
+					(name.0.to_string(), CompletionItemKind::CONSTANT)
+				}
+				Declaration::Struct { name, .. } => (name.0.to_string(), CompletionItemKind::STRUCT),
+				Declaration::Enum { name, .. } => (name.0.to_string(), CompletionItemKind::ENUM),
+				Declaration::Interface { name, .. } => (name.0.to_string(), CompletionItemKind::INTERFACE),
+				Declaration::Namespace { name, .. } => (name.0.to_string(), CompletionItemKind::MODULE),
+				Declaration::TypeAlias { meta, .. } => (meta.name.0.to_string(), CompletionItemKind::CLASS),
+				Declaration::Import { .. }
+				| Declaration::Impl { .. }
+				| Declaration::ImplFor { .. }
+				| Declaration::Test { .. } => return None,
+			};
+			let deprecated = find_attribute(decl.attributes(), "deprecated").is_some();
+			Some((name, kind, deprecated))
 		})
 		.collect()
 }
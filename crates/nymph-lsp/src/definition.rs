@@ -466,6 +466,63 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn workspace_member_definition_targets_the_dependency_member_source() {
+		let temp = tempfile::tempdir().unwrap();
+		std::fs::write(
+			temp.path().join("nymph.toml"),
+			"[workspace]\nmembers = ['app', 'text']\n",
+		)
+		.unwrap();
+		for (name, dependencies, source) in [
+			(
+				"app",
+				"text = { path = '../text' }",
+				"import text/lib with (name)\nfunc main(): string = name()\n",
+			),
+			(
+				"text",
+				"",
+				"// text\npublic func name(): string = \"text\"\n",
+			),
+		] {
+			let root = temp.path().join(name);
+			std::fs::create_dir_all(root.join("src")).unwrap();
+			std::fs::write(
+				root.join("nymph.toml"),
+				format!("[package]\nname = '{name}'\nversion = '0.1.0'\n[dependencies]\n{dependencies}\n"),
+			)
+			.unwrap();
+			std::fs::write(root.join("src/lib.nym"), source).unwrap();
+		}
+		let main_path = temp.path().join("app/src/lib.nym");
+		let main_uri = crate::workspace::path_to_uri(&main_path).unwrap();
+		let mut docs = DocumentStore::default();
+		let mut state = CompilerState::new();
+		state
+			.open(
+				&mut docs,
+				main_uri.clone(),
+				std::fs::read_to_string(&main_path).unwrap(),
+				1,
+			)
+			.unwrap();
+		let snapshot = state.analysis_for_uri(&docs, &main_uri).unwrap();
+
+		let location = scalar(
+			definition_snapshot(&docs, &state, &snapshot, &params(&main_uri, 1, 23))
+				.expect("definition in the dependency member"),
+		);
+		assert_eq!(
+			location.uri,
+			crate::workspace::path_to_uri(&temp.path().join("text/src/lib.nym")).unwrap()
+		);
+		assert_eq!(
+			location.range,
+			Range::new(Position::new(1, 12), Position::new(1, 16))
+		);
+	}
+
 	#[test]
 	fn embedded_std_and_out_of_scope_members_do_not_fabricate_project_locations() {
 		let std_main =
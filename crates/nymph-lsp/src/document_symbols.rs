@@ -124,6 +124,17 @@ fn decl_symbol(
 				None,
 			))
 		}
+		Declaration::Test { name, body, .. } => {
+			let selection = index.range(text, name.1);
+			let full = index.range(text, name.1.to(body.span));
+			Some(make_symbol(
+				&format!("test {:?}", name.0.as_str()),
+				symbol_kind(DeclarationCategory::Function),
+				full,
+				selection,
+				None,
+			))
+		}
 		Declaration::ExternalFunc(_, _, meta) => {
 			let selection = index.range(text, meta.name.1);
 			Some(make_symbol(
@@ -381,6 +392,19 @@ type Pair<A, B> = #(A, B)
 		assert_eq!(symbols[0].name, "Point");
 	}
 
+	#[test]
+	fn lists_tests_as_functions_named_by_their_description() {
+		let uri: Uri = "file:///symbols_tests.nym".parse().unwrap();
+		let text = "func double(n: int): int = n * 2\ntest \"doubles\" = {}\n";
+		let docs = docs_with(&uri, text);
+
+		let response = document_symbols(&docs, &params(&uri)).expect("document is open");
+		let symbols = nested(response);
+
+		let test = symbols.iter().find(|s| s.name == "test \"doubles\"").unwrap();
+		assert_eq!(test.kind, SymbolKind::FUNCTION);
+	}
+
 	#[test]
 	fn survives_a_syntactically_broken_buffer() {
 		let uri: Uri = "file:///symbols_broken.nym".parse().unwrap();
@@ -240,6 +240,59 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn workspace_member_references_include_the_dependency_member_declaration() {
+		let temp = tempfile::tempdir().unwrap();
+		std::fs::write(
+			temp.path().join("nymph.toml"),
+			"[workspace]\nmembers = ['app', 'text']\n",
+		)
+		.unwrap();
+		for (name, dependencies, source) in [
+			(
+				"app",
+				"text = { path = '../text' }",
+				"import text/lib with (name)\nfunc main(): string = name()\n",
+			),
+			("text", "", "public func name(): string = \"text\"\n"),
+		] {
+			let root = temp.path().join(name);
+			std::fs::create_dir_all(root.join("src")).unwrap();
+			std::fs::write(
+				root.join("nymph.toml"),
+				format!("[package]\nname = '{name}'\nversion = '0.1.0'\n[dependencies]\n{dependencies}\n"),
+			)
+			.unwrap();
+			std::fs::write(root.join("src/lib.nym"), source).unwrap();
+		}
+		let path = temp.path().join("app/src/lib.nym");
+		let uri = crate::workspace::path_to_uri(&path).unwrap();
+		let mut docs = DocumentStore::default();
+		let mut state = CompilerState::new();
+		state
+			.open(
+				&mut docs,
+				uri.clone(),
+				std::fs::read_to_string(&path).unwrap(),
+				1,
+			)
+			.unwrap();
+
+		let found = locations(&docs, &state, &params(&uri, 1, 23, true)).unwrap();
+		let text_uri = crate::workspace::path_to_uri(&temp.path().join("text/src/lib.nym")).unwrap();
+		assert!(
+			found
+				.iter()
+				.any(|location| location.uri == text_uri && location.range.start == Position::new(0, 12)),
+			"{found:?}"
+		);
+		assert!(
+			found
+				.iter()
+				.any(|location| location.uri == uri && location.range.start == Position::new(1, 22))
+		);
+	}
+
 	#[test]
 	fn same_spelled_function_generics_have_disjoint_references() {
 		let source = "func first<T>(value: T): T = value\nfunc second<T>(value: T): T = value";
@@ -97,6 +97,7 @@ const READONLY: u32 = 1 << 1;
 	reason = "reserved modifier bit: no builtin is tagged defaultLibrary yet"
 )]
 const DEFAULT_LIBRARY: u32 = 1 << 2;
+const DEPRECATED: u32 = 1 << 3;
 
 /// The fixed token-type/modifier legend this server advertises and encodes
 /// against. Must be registered verbatim in `server_capabilities()`.
@@ -122,6 +123,7 @@ pub fn legend() -> SemanticTokensLegend {
 			SemanticTokenModifier::DECLARATION,
 			SemanticTokenModifier::READONLY,
 			SemanticTokenModifier::DEFAULT_LIBRARY,
+			SemanticTokenModifier::DEPRECATED,
 		],
 	}
 }
@@ -564,7 +566,8 @@ type RoleMap = HashMap<usize, (u32, u32)>;
 /// time any of its uses are resolved. Uses never overwrite an existing
 /// entry — decl-site and use-site spans are always disjoint byte positions,
 /// but `or_insert` keeps that invariant even if two use-walks ever visit the
-/// same span twice.
+/// same span twice. A final pass adds the `deprecated` modifier to every use,
+/// import-list names included, of a dependency definition marked `@deprecated`.
 fn build_role_map(analysis: &SemanticAnalysis) -> RoleMap {
 	let module = &analysis.module;
 	let mut map = RoleMap::new();
@@ -582,6 +585,25 @@ fn build_role_map(analysis: &SemanticAnalysis) -> RoleMap {
 		map.entry(start).or_insert(role);
 	}
 
+	let deprecated_imports = analysis
+		.import_references
+		.iter()
+		.filter_map(|(span, target)| match target {
+			nymph_sema::ImportReferenceTarget::Definition(target)
+				if analysis.deprecations.contains_key(target) =>
+			{
+				Some(*span)
+			}
+			_ => None,
+		});
+	for span in nymph_sema::query::deprecated_uses(analysis)
+		.into_iter()
+		.map(|(span, _)| span)
+		.chain(deprecated_imports)
+	{
+		map.entry(span.start).or_insert((VARIABLE, 0)).1 |= DEPRECATED;
+	}
+
 	map
 }
 
@@ -647,6 +669,7 @@ fn walk_decl(decl: &Declaration, map: &mut RoleMap) {
 		Declaration::ExternalFunc(_, _, meta) => {
 			bind_func(meta, FUNCTION, map);
 		}
+		Declaration::Test { body, .. } => walk_expr(body, map),
 		Declaration::TypeAlias { meta, value, .. } => {
 			map.insert(meta.name.1.start, (TYPE, DECLARATION));
 			walk_generics(&meta.generics, map);
@@ -1299,6 +1322,9 @@ fn walk_decl_uses(
 			walk_expr_uses(body, analysis, variant_names, decls, out);
 		}
 		Declaration::ExternalFunc(_, _, meta) => walk_func_param_bindings(meta, analysis, out),
+		Declaration::Test { body, .. } => {
+			walk_expr_uses(body, analysis, variant_names, decls, out);
+		}
 		Declaration::Struct {
 			fields,
 			members,
@@ -1907,7 +1933,7 @@ mod tests {
 		"comment",
 		"namespace",
 	];
-	const MODIFIER_NAMES: [&str; 3] = ["declaration", "readonly", "defaultLibrary"];
+	const MODIFIER_NAMES: [&str; 4] = ["declaration", "readonly", "defaultLibrary", "deprecated"];
 
 	fn decode(data: &[SemanticToken]) -> Vec<Decoded> {
 		let mut line = 0u32;
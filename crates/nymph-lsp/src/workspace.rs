@@ -26,6 +26,9 @@ pub struct Project {
 	pub src_root: PathBuf,
 	pub entry_key: String,
 	pub lints: std::collections::BTreeMap<String, nymph_compiler::LintLevel>,
+	pub manifest_path: PathBuf,
+	/// The root manifest of the workspace the project is a member of.
+	pub workspace_manifest: Option<PathBuf>,
 }
 
 /// Filesystem policy for an LSP document URI.
@@ -105,10 +108,29 @@ pub fn detect(file: &Path) -> anyhow::Result<Option<Project>> {
 	Ok(Some(Project {
 		src_root,
 		entry_key,
-		lints: project.manifest().lints.clone(),
+		lints: project.lints(),
+		manifest_path: project.manifest_path().to_path_buf(),
+		workspace_manifest: project.workspace_manifest().map(Path::to_path_buf),
 	}))
 }
 
+/// Resolve the local dependency graph declared by the manifest at
+/// `manifest_path`. Dependency sources are read-only editor inputs: they are
+/// loaded from disk and never become project documents of the root.
+pub fn dependency_graph(manifest_path: &Path) -> anyhow::Result<nymph_project::PackageGraph> {
+	Ok(nymph_project::Project::load(manifest_path)?.resolve_packages()?)
+}
+
+/// Every member package of the workspace declared by `workspace_manifest`,
+/// in member order. Opening any member loads all of them into the session.
+pub fn workspace_members(workspace_manifest: &Path) -> anyhow::Result<Vec<nymph_project::Project>> {
+	Ok(
+		nymph_project::WorkspaceRoot::load(workspace_manifest)?
+			.map(|workspace| workspace.members().to_vec())
+			.unwrap_or_default(),
+	)
+}
+
 /// Build the FS-backed `load` closure a `src_root`'s project driver call
 /// needs: a canonical key `"a/b"` maps to `<src_root>/a/b.nym`.
 pub fn fs_loader(src_root: PathBuf) -> impl Fn(&str) -> Option<String> {
@@ -254,6 +254,51 @@ fn effect_syntax_participates_in_completion_hover_and_semantic_tokens() {
 	assert_eq!(parameter_reference.token_modifiers_bitset & 1, 0);
 }
 
+#[test]
+fn deprecated_dependency_names_are_tagged_in_completion_and_semantic_tokens() {
+	let temp = tempfile::tempdir().unwrap();
+	fs::write(
+		temp.path().join("nymph.toml"),
+		"[package]\nname='deprecation-tooling'\nversion='0.1.0'\n",
+	)
+	.unwrap();
+	fs::create_dir(temp.path().join("src")).unwrap();
+	let main_path = temp.path().join("src/main.nym");
+	let source = "import @/shapes with (size, area)\nfunc main(): int = size(1) + area(2)\nfunc next(): int = area(3)";
+	fs::write(&main_path, source).unwrap();
+	fs::write(
+		temp.path().join("src/shapes.nym"),
+		"@deprecated(\"use area\")\npublic func size(width: int): int = width\npublic func area(width: int): int = width",
+	)
+	.unwrap();
+	let main_uri = uri(&main_path);
+	let mut docs = DocumentStore::default();
+	let mut compiler = CompilerState::new();
+	compiler
+		.open(&mut docs, main_uri.clone(), source.into(), 1)
+		.unwrap();
+
+	let items = completion_items(&compiler, &docs, &main_uri, 2, 19);
+	let tags = |label: &str| {
+		items
+			.iter()
+			.find(|item| item.label == label)
+			.unwrap_or_else(|| panic!("missing completion `{label}`"))
+			.tags
+			.clone()
+	};
+	assert_eq!(
+		tags("size"),
+		Some(vec![lsp_types::CompletionItemTag::DEPRECATED])
+	);
+	assert_eq!(tags("area"), None);
+
+	let deprecated = semantic_token_at(&compiler, &docs, &main_uri, source, "size(1)");
+	assert_eq!(deprecated.token_modifiers_bitset & 8, 8);
+	let current = semantic_token_at(&compiler, &docs, &main_uri, source, "area(2)");
+	assert_eq!(current.token_modifiers_bitset & 8, 0);
+}
+
 #[test]
 fn project_completion_matches_variant_precedence_and_ambiguity() {
 	let temp = tempfile::tempdir().unwrap();
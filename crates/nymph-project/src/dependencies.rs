@@ -0,0 +1,716 @@
+//! Package-graph resolution for `[dependencies]`.
+//!
+//! Resolution starts at a loaded [`Project`] and follows `path`, `git`, and
+//! registry version requirement dependencies transitively. Each distinct package
+//! directory becomes exactly one graph node, so a diamond shares its common
+//! dependency. Two different directories claiming the same package name, or a
+//! dependency chain that returns to one of its own ancestors, are errors: the
+//! compiler package graph is acyclic and a name identifies one package.
+//!
+//! Version requirements select one version per package name from the root
+//! project's [`LocalRegistry`], preferring the version pinned by the root
+//! `nymph.lock` while it still satisfies every requirement. Git dependencies
+//! are checked out into the root project's git cache (see [`crate::git`]) at
+//! the commit the lock pins for the same repository and reference, or else at
+//! the commit the reference currently names.
+
+use std::{
+	collections::BTreeMap,
+	path::{Path, PathBuf},
+};
+
+use semver::{Version, VersionReq};
+
+use crate::{
+	GIT_CACHE_DIR, GitError, GitReference, GitSource, LOCK_FILE, LocalRegistry, LockedPackage,
+	LockedSource, Lockfile, LockfileError, MANIFEST_FILE, ManifestError, Project, REGISTRY_ENV, git,
+	normalize_path,
+};
+
+/// One resolved package. Index `0` of a [`PackageGraph`] is always the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphPackage {
+	pub name: String,
+	pub version: Version,
+	pub manifest_path: PathBuf,
+	pub source_root: PathBuf,
+	pub source: PackageSource,
+	/// Direct dependencies by import alias (see [`import_alias`]), mapped to
+	/// their index in the owning [`PackageGraph`].
+	pub dependencies: BTreeMap<String, usize>,
+}
+
+/// Where a [`GraphPackage`] was loaded from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageSource {
+	/// The root project or a `path` dependency; the directory itself is the pin.
+	Path,
+	/// A version selected from the root project's [`LocalRegistry`].
+	Registry,
+	/// A commit checked out into the root project's git cache.
+	Git(GitSource),
+}
+
+/// The closed, acyclic package graph reachable from one root project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageGraph {
+	packages: Vec<GraphPackage>,
+}
+
+impl PackageGraph {
+	#[must_use]
+	pub fn root(&self) -> &GraphPackage {
+		&self.packages[0]
+	}
+
+	#[must_use]
+	pub fn packages(&self) -> &[GraphPackage] {
+		&self.packages
+	}
+
+	/// Dependency packages only, in graph-index order starting at `1`.
+	#[must_use]
+	pub fn dependencies(&self) -> &[GraphPackage] {
+		&self.packages[1..]
+	}
+
+	/// The lock recording every selection this graph made.
+	#[must_use]
+	pub fn lockfile(&self) -> Lockfile {
+		Lockfile {
+			packages: self
+				.dependencies()
+				.iter()
+				.filter_map(|package| {
+					let source = match &package.source {
+						PackageSource::Path => return None,
+						PackageSource::Registry => LockedSource::Registry,
+						PackageSource::Git(git) => LockedSource::Git(git.clone()),
+					};
+					Some(LockedPackage {
+						name: package.name.clone(),
+						version: package.version.clone(),
+						source,
+					})
+				})
+				.collect(),
+			..Lockfile::default()
+		}
+	}
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DependencyError {
+	#[error("could not load dependency `{name}` declared in {declared_in}: {source}")]
+	Manifest {
+		name: String,
+		declared_in: PathBuf,
+		#[source]
+		source: Box<ManifestError>,
+	},
+	#[error(transparent)]
+	Lockfile(Box<LockfileError>),
+	#[error(
+		"dependency `{name}` declared in {declared_in} has no `path`, `git`, or version requirement"
+	)]
+	UnsupportedSource { name: String, declared_in: PathBuf },
+	#[error("could not check out git dependency `{name}` declared in {declared_in}: {source}")]
+	Git {
+		name: String,
+		declared_in: PathBuf,
+		#[source]
+		source: Box<GitError>,
+	},
+	#[error(
+		"dependency `{name}` declared in {declared_in} requires a registry; set `[registry] path` in the root manifest or {REGISTRY_ENV}"
+	)]
+	NoRegistry { name: String, declared_in: PathBuf },
+	#[error("could not read registry {path}: {source}")]
+	Registry {
+		path: PathBuf,
+		#[source]
+		source: std::io::Error,
+	},
+	#[error(
+		"no version of `{name}` in registry {registry} matches `{requirement}` required by {declared_in}"
+	)]
+	NoMatchingVersion {
+		name: String,
+		requirement: VersionReq,
+		declared_in: PathBuf,
+		registry: PathBuf,
+	},
+	#[error(
+		"dependency `{name}` declared in {declared_in} requires `{requirement}`, but version {selected} is already selected"
+	)]
+	VersionConflict {
+		name: String,
+		requirement: VersionReq,
+		declared_in: PathBuf,
+		selected: Version,
+	},
+	#[error("registry package {manifest} declares version {found} but is published as {published}")]
+	PublishedVersionMismatch {
+		manifest: PathBuf,
+		published: Version,
+		found: Version,
+	},
+	#[error(
+		"dependency `{name}` declared in {declared_in} resolves to package `{found}` at {manifest}"
+	)]
+	NameMismatch {
+		name: String,
+		declared_in: PathBuf,
+		found: String,
+		manifest: PathBuf,
+	},
+	#[error("package `{name}` is provided by both {first} and {second}")]
+	DuplicateName {
+		name: String,
+		first: PathBuf,
+		second: PathBuf,
+	},
+	#[error("dependency cycle: {}", cycle.join(" -> "))]
+	Cycle { cycle: Vec<String> },
+}
+
+/// The identifier importers use for a dependency key: `import alias/module`.
+/// Package names may contain hyphens, which are not identifier characters, so
+/// each hyphen becomes an underscore (`http-client` → `http_client`).
+#[must_use]
+pub fn import_alias(dependency: &str) -> String {
+	dependency.replace('-', "_")
+}
+
+impl Project {
+	/// Resolve this project's `[dependencies]` table and every transitive
+	/// dependency into a [`PackageGraph`] rooted at this project.
+	pub fn resolve_packages(&self) -> Result<PackageGraph, DependencyError> {
+		let locked = Lockfile::read(&self.lock_path())
+			.map_err(|error| DependencyError::Lockfile(Box::new(error)))?;
+		let git_cache = self.workspace_root().join(GIT_CACHE_DIR);
+		let mut resolver = Resolver {
+			registry: self.registry(),
+			git_cache: normalize_path(&git_cache).unwrap_or(git_cache),
+			locked,
+			packages: Vec::new(),
+			by_manifest: BTreeMap::new(),
+			by_name: BTreeMap::new(),
+			stack: Vec::new(),
+		};
+		resolver.visit(self.clone(), PackageSource::Path)?;
+		Ok(PackageGraph {
+			packages: resolver.packages,
+		})
+	}
+
+	/// The root lock file, next to the manifest or, for a workspace member,
+	/// next to the workspace root manifest.
+	#[must_use]
+	pub fn lock_path(&self) -> PathBuf {
+		self.workspace_root().join(LOCK_FILE)
+	}
+}
+
+struct Resolver {
+	/// The registry, git cache, and lock of the root project; nested
+	/// manifests never choose any of them.
+	registry: Option<LocalRegistry>,
+	git_cache: PathBuf,
+	locked: Lockfile,
+	packages: Vec<GraphPackage>,
+	by_manifest: BTreeMap<PathBuf, usize>,
+	by_name: BTreeMap<String, usize>,
+	/// Indices of the packages whose dependencies are being resolved, from the
+	/// root down to the current package.
+	stack: Vec<usize>,
+}
+
+impl Resolver {
+	fn visit(&mut self, project: Project, source: PackageSource) -> Result<usize, DependencyError> {
+		let manifest_path =
+			normalize_path(project.manifest_path()).map_err(|source| DependencyError::Manifest {
+				name: project.manifest().package.name.clone(),
+				declared_in: project.manifest_path().into(),
+				source: Box::new(ManifestError::Read {
+					path: project.manifest_path().into(),
+					source,
+				}),
+			})?;
+		let name = project.manifest().package.name.clone();
+		if let Some(&first) = self.by_name.get(&name) {
+			return Err(DependencyError::DuplicateName {
+				name,
+				first: self.packages[first].manifest_path.clone(),
+				second: manifest_path,
+			});
+		}
+		let index = self.packages.len();
+		self.packages.push(GraphPackage {
+			name: name.clone(),
+			version: project.manifest().package.version.clone(),
+			source_root: project.root().join(&project.manifest().package.src),
+			manifest_path: manifest_path.clone(),
+			source,
+			dependencies: BTreeMap::new(),
+		});
+		self.by_manifest.insert(manifest_path.clone(), index);
+		self.by_name.insert(name, index);
+		self.stack.push(index);
+
+		for (dependency, declaration) in &project.manifest().dependencies {
+			let target = if let Some(path) = declaration.path() {
+				self.resolve_path(
+					dependency,
+					&manifest_path,
+					&project.root().join(path),
+					PackageSource::Path,
+				)?
+			} else if let Some((url, reference)) = declaration.git() {
+				self.resolve_git(dependency, &manifest_path, project.root(), url, reference)?
+			} else if let Some(requirement) = declaration.version() {
+				self.resolve_registry(dependency, &manifest_path, requirement)?
+			} else {
+				return Err(DependencyError::UnsupportedSource {
+					name: dependency.clone(),
+					declared_in: manifest_path.clone(),
+				});
+			};
+			if let Some(requirement) = declaration.version() {
+				self.check_requirement(dependency, &manifest_path, requirement, target)?;
+			}
+			self.packages[index]
+				.dependencies
+				.insert(import_alias(dependency), target);
+		}
+
+		self.stack.pop();
+		Ok(index)
+	}
+
+	fn resolve_path(
+		&mut self,
+		dependency: &str,
+		declared_in: &Path,
+		directory: &Path,
+		source: PackageSource,
+	) -> Result<usize, DependencyError> {
+		let load_error = |source| DependencyError::Manifest {
+			name: dependency.to_string(),
+			declared_in: declared_in.into(),
+			source: Box::new(source),
+		};
+		let manifest_path = normalize_path(directory.join(MANIFEST_FILE)).map_err(|source| {
+			load_error(ManifestError::Read {
+				path: directory.join(MANIFEST_FILE),
+				source,
+			})
+		})?;
+		if let Some(&existing) = self.by_manifest.get(&manifest_path) {
+			self.check_cycle(existing)?;
+			self.check_name(dependency, declared_in, existing)?;
+			return Ok(existing);
+		}
+		let project = Project::load(&manifest_path).map_err(load_error)?;
+		if project.manifest().package.name != dependency {
+			return Err(DependencyError::NameMismatch {
+				name: dependency.to_string(),
+				declared_in: declared_in.into(),
+				found: project.manifest().package.name.clone(),
+				manifest: manifest_path,
+			});
+		}
+		self.visit(project, source)
+	}
+
+	fn resolve_git(
+		&mut self,
+		dependency: &str,
+		declared_in: &Path,
+		declaring_root: &Path,
+		url: &str,
+		reference: GitReference,
+	) -> Result<usize, DependencyError> {
+		let locked = self
+			.locked
+			.package(dependency)
+			.and_then(|package| match &package.source {
+				LockedSource::Git(locked) if locked.url == url && locked.reference == reference => {
+					Some(locked.commit.as_str())
+				}
+				_ => None,
+			});
+		let (commit, directory) = git::checkout(
+			&self.git_cache,
+			dependency,
+			url,
+			&git::remote_location(declaring_root, url),
+			&reference,
+			locked,
+		)
+		.map_err(|source| DependencyError::Git {
+			name: dependency.to_string(),
+			declared_in: declared_in.into(),
+			source: Box::new(source),
+		})?;
+		let source = PackageSource::Git(GitSource {
+			url: url.into(),
+			reference,
+			commit,
+		});
+		self.resolve_path(dependency, declared_in, &directory, source)
+	}
+
+	fn resolve_registry(
+		&mut self,
+		dependency: &str,
+		declared_in: &Path,
+		requirement: &VersionReq,
+	) -> Result<usize, DependencyError> {
+		if let Some(&existing) = self.by_name.get(dependency) {
+			self.check_cycle(existing)?;
+			self.check_requirement(dependency, declared_in, requirement, existing)?;
+			return Ok(existing);
+		}
+		let Some(registry) = &self.registry else {
+			return Err(DependencyError::NoRegistry {
+				name: dependency.to_string(),
+				declared_in: declared_in.into(),
+			});
+		};
+		let locked = self
+			.locked
+			.package(dependency)
+			.filter(|package| package.source == LockedSource::Registry)
+			.map(|package| &package.version);
+		let version = registry
+			.select(dependency, requirement, locked)
+			.map_err(|source| DependencyError::Registry {
+				path: registry.root().into(),
+				source,
+			})?
+			.ok_or_else(|| DependencyError::NoMatchingVersion {
+				name: dependency.to_string(),
+				requirement: requirement.clone(),
+				declared_in: declared_in.into(),
+				registry: registry.root().into(),
+			})?;
+		let load_error = |source| DependencyError::Manifest {
+			name: dependency.to_string(),
+			declared_in: declared_in.into(),
+			source: Box::new(source),
+		};
+		let published = registry.manifest_path(dependency, &version);
+		let manifest_path = normalize_path(&published).map_err(|source| {
+			load_error(ManifestError::Read {
+				path: published.clone(),
+				source,
+			})
+		})?;
+		let project = Project::load(&manifest_path).map_err(load_error)?;
+		let package = &project.manifest().package;
+		if package.name != dependency {
+			return Err(DependencyError::NameMismatch {
+				name: dependency.to_string(),
+				declared_in: declared_in.into(),
+				found: package.name.clone(),
+				manifest: manifest_path,
+			});
+		}
+		if package.version != version {
+			return Err(DependencyError::PublishedVersionMismatch {
+				manifest: manifest_path,
+				published: version,
+				found: package.version.clone(),
+			});
+		}
+		self.visit(project, PackageSource::Registry)
+	}
+
+	fn check_cycle(&self, existing: usize) -> Result<(), DependencyError> {
+		let Some(position) = self.stack.iter().position(|&open| open == existing) else {
+			return Ok(());
+		};
+		let mut cycle = self.stack[position..]
+			.iter()
+			.map(|&open| self.packages[open].name.clone())
+			.collect::<Vec<_>>();
+		cycle.push(self.packages[existing].name.clone());
+		Err(DependencyError::Cycle { cycle })
+	}
+
+	fn check_requirement(
+		&self,
+		dependency: &str,
+		declared_in: &Path,
+		requirement: &VersionReq,
+		existing: usize,
+	) -> Result<(), DependencyError> {
+		let selected = &self.packages[existing].version;
+		if requirement.matches(selected) {
+			Ok(())
+		} else {
+			Err(DependencyError::VersionConflict {
+				name: dependency.to_string(),
+				requirement: requirement.clone(),
+				declared_in: declared_in.into(),
+				selected: selected.clone(),
+			})
+		}
+	}
+
+	fn check_name(
+		&self,
+		dependency: &str,
+		declared_in: &Path,
+		existing: usize,
+	) -> Result<(), DependencyError> {
+		let package = &self.packages[existing];
+		if package.name == dependency {
+			Ok(())
+		} else {
+			Err(DependencyError::NameMismatch {
+				name: dependency.to_string(),
+				declared_in: declared_in.into(),
+				found: package.name.clone(),
+				manifest: package.manifest_path.clone(),
+			})
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn package(root: &Path, name: &str, dependencies: &str) {
+		std::fs::create_dir_all(root.join(name).join("src")).unwrap();
+		std::fs::write(
+			root.join(name).join(MANIFEST_FILE),
+			format!("[package]\nname='{name}'\nversion='1.0.0'\n[dependencies]\n{dependencies}"),
+		)
+		.unwrap();
+	}
+
+	fn graph(root: &Path, name: &str) -> Result<PackageGraph, DependencyError> {
+		Project::load(&root.join(name).join(MANIFEST_FILE))
+			.unwrap()
+			.resolve_packages()
+	}
+
+	#[test]
+	fn transitive_path_dependencies_share_one_node_per_directory() {
+		let temp = tempfile::tempdir().unwrap();
+		package(
+			temp.path(),
+			"app",
+			"left = { path = '../left' }\nhttp-client = { path = '../http-client' }",
+		);
+		package(
+			temp.path(),
+			"left",
+			"http-client = { path = '../http-client' }",
+		);
+		package(temp.path(), "http-client", "");
+
+		let graph = graph(temp.path(), "app").unwrap();
+		let names = graph
+			.packages()
+			.iter()
+			.map(|package| package.name.as_str())
+			.collect::<Vec<_>>();
+		assert_eq!(names, ["app", "http-client", "left"]);
+		assert_eq!(
+			graph.root().dependencies,
+			BTreeMap::from([("http_client".to_string(), 1), ("left".to_string(), 2)])
+		);
+		assert_eq!(
+			graph.packages()[2].dependencies,
+			BTreeMap::from([("http_client".to_string(), 1)])
+		);
+		assert_eq!(
+			graph.packages()[1].source_root,
+			temp.path().join("http-client/src")
+		);
+	}
+
+	#[test]
+	fn cycles_report_the_package_chain() {
+		let temp = tempfile::tempdir().unwrap();
+		package(temp.path(), "app", "a = { path = '../a' }");
+		package(temp.path(), "a", "b = { path = '../b' }");
+		package(temp.path(), "b", "a = { path = '../a' }");
+
+		let error = graph(temp.path(), "app").unwrap_err();
+		assert!(
+			matches!(&error, DependencyError::Cycle { cycle } if cycle == &["a", "b", "a"]),
+			"{error}"
+		);
+		assert_eq!(error.to_string(), "dependency cycle: a -> b -> a");
+	}
+
+	#[test]
+	fn one_name_from_two_directories_is_rejected() {
+		let temp = tempfile::tempdir().unwrap();
+		package(
+			temp.path(),
+			"app",
+			"a = { path = '../a' }\nb = { path = '../b' }",
+		);
+		package(temp.path(), "a", "shared = { path = '../one/shared' }");
+		package(temp.path(), "b", "shared = { path = '../two/shared' }");
+		package(&temp.path().join("one"), "shared", "");
+		package(&temp.path().join("two"), "shared", "");
+
+		assert!(matches!(
+			graph(temp.path(), "app"),
+			Err(DependencyError::DuplicateName { name, .. }) if name == "shared"
+		));
+	}
+
+	#[test]
+	fn dependency_keys_must_name_the_package_they_load() {
+		let temp = tempfile::tempdir().unwrap();
+		package(temp.path(), "app", "utilities = { path = '../other' }");
+		package(temp.path(), "other", "");
+
+		assert!(matches!(
+			graph(temp.path(), "app"),
+			Err(DependencyError::NameMismatch { found, .. }) if found == "other"
+		));
+	}
+
+	#[test]
+	fn missing_and_non_path_dependencies_are_errors() {
+		let temp = tempfile::tempdir().unwrap();
+		package(temp.path(), "app", "missing = { path = '../missing' }");
+		assert!(matches!(
+			graph(temp.path(), "app"),
+			Err(DependencyError::Manifest { source, .. })
+				if matches!(*source, ManifestError::Read { .. })
+		));
+
+		package(temp.path(), "versioned", "utilities = '^1.0'");
+		assert!(matches!(
+			graph(temp.path(), "versioned"),
+			Err(DependencyError::NoRegistry { name, .. }) if name == "utilities"
+		));
+
+		package(temp.path(), "empty", "utilities = {}");
+		assert!(matches!(
+			graph(temp.path(), "empty"),
+			Err(DependencyError::UnsupportedSource { name, .. }) if name == "utilities"
+		));
+	}
+
+	fn publish(registry: &Path, name: &str, version: &str, dependencies: &str) {
+		let dir = registry.join(name).join(version);
+		std::fs::create_dir_all(&dir).unwrap();
+		std::fs::write(
+			dir.join(MANIFEST_FILE),
+			format!("[package]\nname='{name}'\nversion='{version}'\n[dependencies]\n{dependencies}"),
+		)
+		.unwrap();
+	}
+
+	fn registry_app(root: &Path, dependencies: &str) {
+		std::fs::create_dir_all(root.join("app")).unwrap();
+		std::fs::write(
+			root.join("app").join(MANIFEST_FILE),
+			format!(
+				"[package]\nname='app'\nversion='1.0.0'\n[registry]\npath='../registry'\n[dependencies]\n{dependencies}"
+			),
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn registry_requirements_select_the_newest_match_and_honor_the_lock() {
+		let temp = tempfile::tempdir().unwrap();
+		let registry = temp.path().join("registry");
+		publish(&registry, "text-tools", "1.0.0", "");
+		publish(&registry, "text-tools", "1.4.2", "");
+		publish(&registry, "text-tools", "2.0.0", "");
+		publish(&registry, "formatting", "0.3.1", "text-tools = '^1.2'");
+		registry_app(temp.path(), "formatting = '0.3'\ntext-tools = '^1'");
+
+		let graph = graph(temp.path(), "app").unwrap();
+		let selected = graph
+			.dependencies()
+			.iter()
+			.map(|package| (package.name.as_str(), package.version.to_string()))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			selected,
+			[
+				("formatting", "0.3.1".to_string()),
+				("text-tools", "1.4.2".to_string())
+			]
+		);
+		assert_eq!(
+			graph.dependencies()[1].source_root,
+			registry.join("text-tools/1.4.2/src")
+		);
+		assert!(
+			graph
+				.dependencies()
+				.iter()
+				.all(|package| package.source == PackageSource::Registry)
+		);
+
+		let mut lock = graph.lockfile();
+		lock.packages[1].version = Version::new(1, 0, 0);
+		std::fs::write(
+			temp.path().join("app").join(LOCK_FILE),
+			lock.to_toml().unwrap(),
+		)
+		.unwrap();
+		// The pinned 1.0.0 no longer satisfies `formatting`'s `^1.2`, which is
+		// resolved first, so the newest match is selected again.
+		assert_eq!(
+			self::graph(temp.path(), "app").unwrap().lockfile().packages[1].version,
+			Version::new(1, 4, 2)
+		);
+
+		registry_app(temp.path(), "text-tools = '^1'");
+		assert_eq!(
+			self::graph(temp.path(), "app").unwrap().dependencies()[0].version,
+			Version::new(1, 0, 0)
+		);
+	}
+
+	#[test]
+	fn registry_requirements_report_conflicts_and_missing_versions() {
+		let temp = tempfile::tempdir().unwrap();
+		let registry = temp.path().join("registry");
+		publish(&registry, "text-tools", "1.0.0", "");
+		publish(&registry, "text-tools", "2.0.0", "");
+		publish(&registry, "formatting", "0.3.1", "text-tools = '^2'");
+
+		registry_app(temp.path(), "formatting = '0.3'\ntext-tools = '^1'");
+		assert!(matches!(
+			graph(temp.path(), "app"),
+			Err(DependencyError::VersionConflict { name, selected, .. })
+				if name == "text-tools" && selected == Version::new(2, 0, 0)
+		));
+
+		registry_app(temp.path(), "text-tools = '^3'");
+		let error = graph(temp.path(), "app").unwrap_err();
+		assert!(
+			matches!(&error, DependencyError::NoMatchingVersion { name, .. } if name == "text-tools"),
+			"{error}"
+		);
+
+		publish(&registry, "mislabeled", "1.0.0", "");
+		std::fs::write(
+			registry.join("mislabeled/1.0.0").join(MANIFEST_FILE),
+			"[package]\nname='mislabeled'\nversion='1.0.1'\n",
+		)
+		.unwrap();
+		registry_app(temp.path(), "mislabeled = '1'");
+		assert!(matches!(
+			graph(temp.path(), "app"),
+			Err(DependencyError::PublishedVersionMismatch { .. })
+		));
+	}
+}
@@ -0,0 +1,408 @@
+//! Git dependency checkouts.
+//!
+//! Each git dependency is fetched with the `git` command into one bare clone
+//! per package name under the root project's `target/nymph/git/db`, and every
+//! resolved commit is checked out once into
+//! `target/nymph/git/checkouts/<name>/<commit>`. A commit pinned by
+//! `nymph.lock` is reused without contacting the repository for as long as the
+//! cached clone already contains it, so locked builds work offline.
+
+use std::{
+	fmt, io,
+	path::{Path, PathBuf},
+	process::Command,
+};
+
+/// The cache directory, relative to the root project, that git dependencies
+/// are fetched into.
+pub const GIT_CACHE_DIR: &str = "target/nymph/git";
+
+/// Which commit of a git dependency to build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitReference {
+	/// The commit the repository's `HEAD` names.
+	DefaultBranch,
+	Branch(String),
+	Tag(String),
+	/// A commit hash or any other revision `git rev-parse` accepts.
+	Rev(String),
+}
+
+/// A git dependency resolved to one commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitSource {
+	/// The repository exactly as the declaring manifest wrote it.
+	pub url: String,
+	pub reference: GitReference,
+	pub commit: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GitError {
+	#[error("could not run `git`; install Git to use git dependencies: {0}")]
+	Spawn(#[source] io::Error),
+	#[error("`git {command}` failed: {stderr}")]
+	Command { command: String, stderr: String },
+	#[error("{reference} does not name a commit in {url}")]
+	UnknownReference {
+		url: String,
+		reference: GitReference,
+	},
+	#[error("could not prepare git cache {path}: {source}")]
+	Cache {
+		path: PathBuf,
+		#[source]
+		source: io::Error,
+	},
+}
+
+impl fmt::Display for GitReference {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::DefaultBranch => f.write_str("the default branch"),
+			Self::Branch(branch) => write!(f, "branch `{branch}`"),
+			Self::Tag(tag) => write!(f, "tag `{tag}`"),
+			Self::Rev(rev) => write!(f, "rev `{rev}`"),
+		}
+	}
+}
+
+impl GitReference {
+	/// The `key=value` query naming this reference in a lock entry.
+	fn query(&self) -> Option<(&'static str, &str)> {
+		match self {
+			Self::DefaultBranch => None,
+			Self::Branch(branch) => Some(("branch", branch)),
+			Self::Tag(tag) => Some(("tag", tag)),
+			Self::Rev(rev) => Some(("rev", rev)),
+		}
+	}
+
+	/// The revision to resolve in a cache clone after a fetch.
+	fn revision(&self) -> String {
+		match self {
+			Self::DefaultBranch => "refs/remotes/origin/HEAD".to_string(),
+			Self::Branch(branch) => format!("refs/remotes/origin/{branch}"),
+			Self::Tag(tag) => format!("refs/tags/{tag}"),
+			Self::Rev(rev) => rev.clone(),
+		}
+	}
+}
+
+impl fmt::Display for GitSource {
+	/// The lock entry form: `git+<url>[?branch=|tag=|rev=<name>]#<commit>`.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "git+{}", self.url)?;
+		if let Some((key, value)) = self.reference.query() {
+			write!(f, "?{key}={value}")?;
+		}
+		write!(f, "#{}", self.commit)
+	}
+}
+
+impl std::str::FromStr for GitSource {
+	type Err = String;
+
+	fn from_str(source: &str) -> Result<Self, Self::Err> {
+		let invalid = || format!("invalid git source `{source}`");
+		let rest = source.strip_prefix("git+").ok_or_else(invalid)?;
+		let (location, commit) = rest.rsplit_once('#').ok_or_else(invalid)?;
+		let (url, reference) = match location.rsplit_once('?') {
+			Some((url, query)) => {
+				let reference = match query.split_once('=') {
+					Some(("branch", branch)) => GitReference::Branch(branch.into()),
+					Some(("tag", tag)) => GitReference::Tag(tag.into()),
+					Some(("rev", rev)) => GitReference::Rev(rev.into()),
+					_ => return Err(invalid()),
+				};
+				(url, reference)
+			}
+			None => (location, GitReference::DefaultBranch),
+		};
+		if url.is_empty() || commit.is_empty() {
+			return Err(invalid());
+		}
+		Ok(Self {
+			url: url.into(),
+			reference,
+			commit: commit.into(),
+		})
+	}
+}
+
+/// Where `git` fetches a manifest's `git` value from. URLs are used as
+/// written; a plain path is relative to the declaring manifest's directory.
+pub(crate) fn remote_location(declaring_root: &Path, url: &str) -> String {
+	if url.contains("://") || Path::new(url).is_absolute() {
+		url.to_string()
+	} else {
+		declaring_root.join(url).to_string_lossy().into_owned()
+	}
+}
+
+/// Resolve `reference` in the repository at `remote` to a commit and check it
+/// out under `cache`, returning the commit and its checkout directory. A
+/// `locked` commit is used as-is once the cache clone contains it.
+pub(crate) fn checkout(
+	cache: &Path,
+	name: &str,
+	url: &str,
+	remote: &str,
+	reference: &GitReference,
+	locked: Option<&str>,
+) -> Result<(String, PathBuf), GitError> {
+	let database = cache.join("db").join(name);
+	if !database.join("HEAD").is_file() {
+		create_dir(&database)?;
+		git(
+			None,
+			&[
+				"init".as_ref(),
+				"--bare".as_ref(),
+				"--quiet".as_ref(),
+				database.as_os_str(),
+			],
+		)?;
+	}
+	let commit = match locked.filter(|commit| has_commit(&database, commit)) {
+		Some(commit) => commit.to_string(),
+		None => {
+			git(
+				Some(&database),
+				&[
+					"fetch",
+					"--quiet",
+					"--force",
+					remote,
+					"+refs/heads/*:refs/remotes/origin/*",
+					"+refs/tags/*:refs/tags/*",
+					"+HEAD:refs/remotes/origin/HEAD",
+				],
+			)?;
+			match locked.filter(|commit| has_commit(&database, commit)) {
+				Some(commit) => commit.to_string(),
+				None => resolve(&database, reference).ok_or_else(|| GitError::UnknownReference {
+					url: url.into(),
+					reference: reference.clone(),
+				})?,
+			}
+		}
+	};
+
+	let checkouts = cache.join("checkouts").join(name);
+	let directory = checkouts.join(&commit);
+	if !directory.is_dir() {
+		create_dir(&checkouts)?;
+		let staging = checkouts.join(format!(".{commit}.partial"));
+		match std::fs::remove_dir_all(&staging) {
+			Ok(()) => {}
+			Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+			Err(source) => {
+				return Err(GitError::Cache {
+					path: staging,
+					source,
+				});
+			}
+		}
+		git(
+			None,
+			&[
+				"clone".as_ref(),
+				"--quiet".as_ref(),
+				"--no-checkout".as_ref(),
+				database.as_os_str(),
+				staging.as_os_str(),
+			],
+		)?;
+		git(
+			Some(&staging.join(".git")),
+			&[
+				"--work-tree".as_ref(),
+				staging.as_os_str(),
+				"checkout".as_ref(),
+				"--quiet".as_ref(),
+				"--detach".as_ref(),
+				commit.as_ref(),
+			],
+		)?;
+		std::fs::rename(&staging, &directory).map_err(|source| GitError::Cache {
+			path: directory.clone(),
+			source,
+		})?;
+	}
+	Ok((commit, directory))
+}
+
+fn create_dir(path: &Path) -> Result<(), GitError> {
+	std::fs::create_dir_all(path).map_err(|source| GitError::Cache {
+		path: path.into(),
+		source,
+	})
+}
+
+fn has_commit(database: &Path, commit: &str) -> bool {
+	git(
+		Some(database),
+		&["cat-file", "-e", &format!("{commit}^{{commit}}")],
+	)
+	.is_ok()
+}
+
+fn resolve(database: &Path, reference: &GitReference) -> Option<String> {
+	let revision = format!("{}^{{commit}}", reference.revision());
+	git(
+		Some(database),
+		&["rev-parse", "--verify", "--quiet", &revision],
+	)
+	.ok()
+	.map(|commit| commit.trim().to_string())
+	.filter(|commit| !commit.is_empty())
+}
+
+/// Run `git` against `git_dir` (when given) and return its standard output.
+fn git<S: AsRef<std::ffi::OsStr>>(git_dir: Option<&Path>, args: &[S]) -> Result<String, GitError> {
+	let mut command = Command::new("git");
+	if let Some(git_dir) = git_dir {
+		command.arg("--git-dir").arg(git_dir);
+	}
+	let output = command
+		.args(args)
+		.env_remove("GIT_DIR")
+		.env_remove("GIT_WORK_TREE")
+		.output()
+		.map_err(GitError::Spawn)?;
+	if !output.status.success() {
+		return Err(GitError::Command {
+			command: args
+				.iter()
+				.map(|arg| arg.as_ref().to_string_lossy())
+				.collect::<Vec<_>>()
+				.join(" "),
+			stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+		});
+	}
+	Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn commit_file(repository: &Path, file: &str, contents: &str) -> String {
+		std::fs::write(repository.join(file), contents).unwrap();
+		let run = |args: &[&str]| {
+			let output = Command::new("git")
+				.arg("-C")
+				.arg(repository)
+				.args([
+					"-c",
+					"user.name=nymph",
+					"-c",
+					"user.email=nymph@example.com",
+				])
+				.args(args)
+				.output()
+				.unwrap();
+			assert!(output.status.success(), "{output:?}");
+			String::from_utf8(output.stdout).unwrap()
+		};
+		run(&["add", "--all"]);
+		run(&["commit", "--quiet", "-m", file]);
+		run(&["rev-parse", "HEAD"]).trim().to_string()
+	}
+
+	#[test]
+	fn lock_sources_round_trip_every_reference_kind() {
+		for reference in [
+			GitReference::DefaultBranch,
+			GitReference::Branch("main".into()),
+			GitReference::Tag("v1.0.0".into()),
+			GitReference::Rev("abc123".into()),
+		] {
+			let source = GitSource {
+				url: "file:///srv/repos/text-tools".into(),
+				reference,
+				commit: "0123456789abcdef".into(),
+			};
+			assert_eq!(source.to_string().parse::<GitSource>(), Ok(source));
+		}
+		assert_eq!(
+			GitSource {
+				url: "../tools".into(),
+				reference: GitReference::Tag("v1".into()),
+				commit: "c0ffee".into(),
+			}
+			.to_string(),
+			"git+../tools?tag=v1#c0ffee"
+		);
+		for invalid in [
+			"registry",
+			"git+url",
+			"git+url?color=red#c0ffee",
+			"git+#c0ffee",
+		] {
+			assert!(invalid.parse::<GitSource>().is_err(), "{invalid}");
+		}
+	}
+
+	#[test]
+	fn checkouts_resolve_references_and_reuse_locked_commits() {
+		let temp = tempfile::tempdir().unwrap();
+		let repository = temp.path().join("repository");
+		std::fs::create_dir_all(&repository).unwrap();
+		git(
+			None,
+			&[
+				"init",
+				"--quiet",
+				"--initial-branch=main",
+				repository.to_str().unwrap(),
+			],
+		)
+		.unwrap();
+		let first = commit_file(&repository, "version.txt", "1");
+		git(None, &["-C", repository.to_str().unwrap(), "tag", "v1"]).unwrap();
+		let second = commit_file(&repository, "version.txt", "2");
+
+		let cache = temp.path().join("cache");
+		let url = format!("file://{}", repository.display());
+		let checkout = |reference: GitReference, locked: Option<&str>| {
+			checkout(&cache, "tools", &url, &url, &reference, locked).unwrap()
+		};
+
+		let (commit, directory) = checkout(GitReference::Tag("v1".into()), None);
+		assert_eq!(commit, first);
+		assert_eq!(
+			std::fs::read_to_string(directory.join("version.txt")).unwrap(),
+			"1"
+		);
+		assert_eq!(
+			checkout(GitReference::Branch("main".into()), None).0,
+			second
+		);
+		assert_eq!(checkout(GitReference::DefaultBranch, None).0, second);
+		assert_eq!(
+			checkout(GitReference::Rev(first[..10].into()), None).0,
+			first
+		);
+
+		// A locked commit is honored even though the branch has moved on, and
+		// without the repository once the cache has it.
+		std::fs::rename(&repository, temp.path().join("moved")).unwrap();
+		let (commit, directory) = checkout(GitReference::Branch("main".into()), Some(&first));
+		assert_eq!(commit, first);
+		assert_eq!(directory, cache.join("checkouts/tools").join(&first));
+
+		assert!(matches!(
+			super::checkout(
+				&cache,
+				"tools",
+				&url,
+				&url,
+				&GitReference::Branch("main".into()),
+				None
+			),
+			Err(GitError::Command { .. })
+		));
+	}
+}
@@ -14,6 +14,20 @@ use nymph_compiler::ModulePath;
 use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
 
+mod dependencies;
+mod git;
+mod lockfile;
+mod registry;
+mod workspace;
+
+pub use dependencies::{DependencyError, GraphPackage, PackageGraph, PackageSource, import_alias};
+pub use git::{GIT_CACHE_DIR, GitError, GitReference, GitSource};
+pub use lockfile::{
+	LOCK_FILE, LOCK_FORMAT_VERSION, LockedPackage, LockedSource, Lockfile, LockfileError,
+};
+pub use registry::{LocalRegistry, REGISTRY_ENV};
+pub use workspace::{Workspace, WorkspaceError, WorkspaceRoot};
+
 pub const MANIFEST_FILE: &str = "nymph.toml";
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -25,6 +39,10 @@ pub struct Manifest {
 	pub build: Build,
 	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
 	pub lints: BTreeMap<String, nymph_compiler::LintLevel>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub registry: Option<Registry>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub workspace: Option<Workspace>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -46,6 +64,48 @@ pub enum Dependency {
 	Detailed(DependencyDetail),
 }
 
+impl Dependency {
+	/// The local directory this dependency is loaded from, relative to the
+	/// declaring manifest's directory.
+	#[must_use]
+	pub fn path(&self) -> Option<&Path> {
+		match self {
+			Self::Version(_) => None,
+			Self::Detailed(detail) => detail.path.as_deref(),
+		}
+	}
+
+	/// The repository and reference of a `git` dependency.
+	#[must_use]
+	pub fn git(&self) -> Option<(&str, GitReference)> {
+		match self {
+			Self::Version(_) => None,
+			Self::Detailed(detail) => {
+				let url = detail.git.as_deref()?;
+				let reference = if let Some(rev) = &detail.rev {
+					GitReference::Rev(rev.clone())
+				} else if let Some(tag) = &detail.tag {
+					GitReference::Tag(tag.clone())
+				} else if let Some(branch) = &detail.branch {
+					GitReference::Branch(branch.clone())
+				} else {
+					GitReference::DefaultBranch
+				};
+				Some((url, reference))
+			}
+		}
+	}
+
+	/// The version requirement this dependency must satisfy, if any.
+	#[must_use]
+	pub fn version(&self) -> Option<&VersionReq> {
+		match self {
+			Self::Version(requirement) => Some(requirement),
+			Self::Detailed(detail) => detail.version.as_ref(),
+		}
+	}
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct DependencyDetail {
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -54,6 +114,19 @@ pub struct DependencyDetail {
 	pub path: Option<PathBuf>,
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub git: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub rev: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub tag: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub branch: Option<String>,
+}
+
+/// The `[registry]` table. Only the root manifest's registry is used.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Registry {
+	/// The local registry directory, relative to the manifest's directory.
+	pub path: PathBuf,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
@@ -138,6 +211,14 @@ pub enum ManifestError {
 		field: &'static str,
 		value: PathBuf,
 	},
+	#[error("invalid dependency `{name}` in manifest {path}: {reason}")]
+	InvalidDependency {
+		path: PathBuf,
+		name: String,
+		reason: &'static str,
+	},
+	#[error("manifest {path} declares a workspace but no package; select one of its members")]
+	VirtualManifest { path: PathBuf },
 }
 
 impl Manifest {
@@ -154,6 +235,8 @@ impl Manifest {
 			dependencies: BTreeMap::new(),
 			build: Build::default(),
 			lints: BTreeMap::new(),
+			registry: None,
+			workspace: None,
 		}
 	}
 
@@ -162,14 +245,10 @@ impl Manifest {
 	}
 
 	pub fn read(path: &Path) -> Result<Self, ManifestError> {
-		let contents = std::fs::read_to_string(path).map_err(|source| ManifestError::Read {
-			path: path.into(),
-			source,
-		})?;
-		let value: toml::Value = toml::from_str(&contents).map_err(|source| ManifestError::Parse {
-			path: path.into(),
-			source,
-		})?;
+		let value = read_manifest_value(path)?;
+		if value.get("package").is_none() && value.get("workspace").is_some() {
+			return Err(ManifestError::VirtualManifest { path: path.into() });
+		}
 		let manifest: Self = value.try_into().map_err(|source| ManifestError::Schema {
 			path: path.into(),
 			source,
@@ -202,10 +281,47 @@ impl Manifest {
 				value: self.build.entry.clone(),
 			});
 		}
+		if let Some(workspace) = &self.workspace {
+			workspace::validate_members(workspace, manifest_path)?;
+		}
+		for (name, dependency) in &self.dependencies {
+			let Dependency::Detailed(detail) = dependency else {
+				continue;
+			};
+			let references = [&detail.rev, &detail.tag, &detail.branch]
+				.into_iter()
+				.filter(|reference| reference.is_some())
+				.count();
+			let reason = if detail.path.is_some() && detail.git.is_some() {
+				"`path` and `git` are mutually exclusive"
+			} else if references > 0 && detail.git.is_none() {
+				"`rev`, `tag`, and `branch` require `git`"
+			} else if references > 1 {
+				"at most one of `rev`, `tag`, and `branch` may be given"
+			} else {
+				continue;
+			};
+			return Err(ManifestError::InvalidDependency {
+				path: manifest_path.into(),
+				name: name.clone(),
+				reason,
+			});
+		}
 		Ok(())
 	}
 }
 
+fn read_manifest_value(path: &Path) -> Result<toml::Value, ManifestError> {
+	let contents = std::fs::read_to_string(path).map_err(|source| ManifestError::Read {
+		path: path.into(),
+		source,
+	})?;
+	toml::from_str(&contents).map_err(|source| ManifestError::Parse {
+		path: path.into(),
+		source,
+	})
+}
+
 fn is_contained_relative(path: &Path) -> bool {
 	!path.as_os_str().is_empty()
 		&& !path.is_absolute()
@@ -230,6 +346,8 @@ pub struct Project {
 	manifest_path: PathBuf,
 	root: PathBuf,
 	manifest: Manifest,
+	workspace_manifest: Option<PathBuf>,
+	workspace_lints: BTreeMap<String, nymph_compiler::LintLevel>,
 }
 
 impl Project {
@@ -237,8 +355,21 @@ impl Project {
 	///
 	/// Unlike [`discover`], this never searches ancestors or substitutes the
 	/// conventional manifest filename. Relative manifest fields are resolved
-	/// from the directory containing the selected file.
+	/// from the directory containing the selected file. A workspace that lists
+	/// the project as a member is still found, since membership decides its
+	/// lints and lock file.
 	pub fn load(manifest_path: &Path) -> Result<Self, ManifestError> {
+		let mut project = Self::read(manifest_path)?;
+		if let Some((path, workspace)) =
+			workspace::enclosing_workspace(manifest_path, &project.root, &project.manifest)?
+		{
+			project.workspace_manifest = Some(path);
+			project.workspace_lints = workspace.lints;
+		}
+		Ok(project)
+	}
+
+	fn read(manifest_path: &Path) -> Result<Self, ManifestError> {
 		let root = manifest_path
 			.parent()
 			.filter(|path| !path.as_os_str().is_empty())
@@ -247,6 +378,8 @@ impl Project {
 			manifest: Manifest::read(manifest_path)?,
 			manifest_path: manifest_path.into(),
 			root: root.into(),
+			workspace_manifest: None,
+			workspace_lints: BTreeMap::new(),
 		})
 	}
 
@@ -265,6 +398,33 @@ impl Project {
 		&self.manifest
 	}
 
+	/// The root manifest of the workspace this project is a member of.
+	#[must_use]
+	pub fn workspace_manifest(&self) -> Option<&Path> {
+		self.workspace_manifest.as_deref()
+	}
+
+	/// The directory owning the lock file and `target/`: the workspace root for
+	/// a member, otherwise the project root.
+	#[must_use]
+	pub fn workspace_root(&self) -> &Path {
+		self
+			.workspace_manifest
+			.as_deref()
+			.and_then(Path::parent)
+			.filter(|path| !path.as_os_str().is_empty())
+			.unwrap_or(&self.root)
+	}
+
+	/// The effective lint levels: the workspace's `[workspace.lints]`, each
+	/// overridden by the same lint in this package's `[lints]`.
+	#[must_use]
+	pub fn lints(&self) -> BTreeMap<String, nymph_compiler::LintLevel> {
+		let mut lints = self.workspace_lints.clone();
+		lints.extend(self.manifest.lints.clone());
+		lints
+	}
+
 	#[must_use]
 	pub fn source_root(&self) -> PathBuf {
 		self.root.join(&self.manifest.package.src)
@@ -619,6 +779,40 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn git_references_require_git_and_are_exclusive() {
+		let temp = tempfile::tempdir().unwrap();
+		let path = temp.path().join(MANIFEST_FILE);
+		let read = |dependency: &str| {
+			std::fs::write(
+				&path,
+				format!("[package]\nname='x'\nversion='1.0.0'\n[dependencies]\ntools = {dependency}\n"),
+			)
+			.unwrap();
+			Manifest::read(&path)
+		};
+
+		let manifest = read("{ git = '../tools', tag = 'v1' }").unwrap();
+		assert_eq!(
+			manifest.dependencies["tools"].git(),
+			Some(("../tools", GitReference::Tag("v1".into())))
+		);
+		assert_eq!(
+			read("{ git = '../tools' }").unwrap().dependencies["tools"].git(),
+			Some(("../tools", GitReference::DefaultBranch))
+		);
+		for invalid in [
+			"{ git = '../tools', branch = 'main', tag = 'v1' }",
+			"{ path = '../tools', tag = 'v1' }",
+			"{ path = '../tools', git = '../tools' }",
+		] {
+			assert!(
+				matches!(read(invalid), Err(ManifestError::InvalidDependency { name, .. }) if name == "tools"),
+				"{invalid}"
+			);
+		}
+	}
+
 	#[test]
 	fn tracked_manifests_use_the_canonical_schema() {
 		let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
@@ -0,0 +1,210 @@
+//! The `nymph.lock` file recorded next to a root or workspace manifest.
+//!
+//! The lock pins every package whose contents are chosen by resolution rather
+//! than named exactly by a manifest. Path dependencies are never locked: their
+//! directory is the pin. Because a package name identifies one package in a
+//! graph, a lock is simply one entry per name.
+
+use std::{
+	io,
+	path::{Path, PathBuf},
+};
+
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use crate::GitSource;
+
+pub const LOCK_FILE: &str = "nymph.lock";
+
+/// The only lock format this toolchain reads and writes.
+pub const LOCK_FORMAT_VERSION: u32 = 1;
+
+const LOCK_HEADER: &str = "# This file is generated by nymph. Do not edit it by hand.\n";
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Lockfile {
+	pub version: u32,
+	#[serde(default, rename = "package", skip_serializing_if = "Vec::is_empty")]
+	pub packages: Vec<LockedPackage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LockedPackage {
+	pub name: String,
+	pub version: Version,
+	pub source: LockedSource,
+}
+
+/// Serialized as `"registry"` or in the [`GitSource`] `git+…#commit` form.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum LockedSource {
+	/// Selected from the project's local directory registry.
+	Registry,
+	/// A commit of a git repository.
+	Git(GitSource),
+}
+
+impl From<LockedSource> for String {
+	fn from(source: LockedSource) -> Self {
+		match source {
+			LockedSource::Registry => "registry".into(),
+			LockedSource::Git(git) => git.to_string(),
+		}
+	}
+}
+
+impl TryFrom<String> for LockedSource {
+	type Error = String;
+
+	fn try_from(source: String) -> Result<Self, Self::Error> {
+		if source == "registry" {
+			Ok(Self::Registry)
+		} else {
+			source.parse().map(Self::Git)
+		}
+	}
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LockfileError {
+	#[error("could not read lock file {path}: {source}")]
+	Read {
+		path: PathBuf,
+		#[source]
+		source: io::Error,
+	},
+	#[error("malformed lock file {path}: {source}")]
+	Parse {
+		path: PathBuf,
+		#[source]
+		source: toml::de::Error,
+	},
+	#[error(
+		"lock file {path} has format version {found}; this toolchain reads version {LOCK_FORMAT_VERSION}"
+	)]
+	UnsupportedVersion { path: PathBuf, found: u32 },
+}
+
+impl Default for Lockfile {
+	fn default() -> Self {
+		Self {
+			version: LOCK_FORMAT_VERSION,
+			packages: Vec::new(),
+		}
+	}
+}
+
+impl Lockfile {
+	/// Read the lock at `path`. A missing file is an empty lock.
+	pub fn read(path: &Path) -> Result<Self, LockfileError> {
+		let contents = match std::fs::read_to_string(path) {
+			Ok(contents) => contents,
+			Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+			Err(source) => {
+				return Err(LockfileError::Read {
+					path: path.into(),
+					source,
+				});
+			}
+		};
+		let lock: Self = toml::from_str(&contents).map_err(|source| LockfileError::Parse {
+			path: path.into(),
+			source,
+		})?;
+		if lock.version != LOCK_FORMAT_VERSION {
+			return Err(LockfileError::UnsupportedVersion {
+				path: path.into(),
+				found: lock.version,
+			});
+		}
+		Ok(lock)
+	}
+
+	#[must_use]
+	pub fn package(&self, name: &str) -> Option<&LockedPackage> {
+		self.packages.iter().find(|package| package.name == name)
+	}
+
+	/// The canonical file contents: a generated-file header followed by the
+	/// packages sorted by name, so equal locks always serialize identically.
+	pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+		let mut sorted = self.clone();
+		sorted
+			.packages
+			.sort_by(|left, right| left.name.cmp(&right.name));
+		Ok(format!("{LOCK_HEADER}{}", toml::to_string_pretty(&sorted)?))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn locks_round_trip_sorted_and_missing_files_are_empty() {
+		let temp = tempfile::tempdir().unwrap();
+		let path = temp.path().join(LOCK_FILE);
+		assert_eq!(Lockfile::read(&path).unwrap(), Lockfile::default());
+
+		let lock = Lockfile {
+			version: LOCK_FORMAT_VERSION,
+			packages: ["zeta", "alpha"]
+				.map(|name| LockedPackage {
+					name: name.into(),
+					version: Version::new(1, 2, 3),
+					source: LockedSource::Registry,
+				})
+				.into(),
+		};
+		let serialized = lock.to_toml().unwrap();
+		assert!(serialized.starts_with(LOCK_HEADER));
+		assert!(
+			serialized.find("alpha").unwrap() < serialized.find("zeta").unwrap(),
+			"{serialized}"
+		);
+		std::fs::write(&path, &serialized).unwrap();
+		let read = Lockfile::read(&path).unwrap();
+		assert_eq!(read.package("zeta"), lock.package("zeta"));
+		assert_eq!(read.packages.len(), 2);
+		assert!(serialized.contains("source = \"registry\""), "{serialized}");
+
+		let git = LockedPackage {
+			name: "tools".into(),
+			version: Version::new(0, 1, 0),
+			source: LockedSource::Git(GitSource {
+				url: "file:///srv/tools".into(),
+				reference: crate::GitReference::Branch("main".into()),
+				commit: "c0ffee".into(),
+			}),
+		};
+		let lock = Lockfile {
+			packages: vec![git.clone()],
+			..Lockfile::default()
+		};
+		let serialized = lock.to_toml().unwrap();
+		assert!(
+			serialized.contains("source = \"git+file:///srv/tools?branch=main#c0ffee\""),
+			"{serialized}"
+		);
+		std::fs::write(&path, &serialized).unwrap();
+		assert_eq!(Lockfile::read(&path).unwrap().packages, [git]);
+	}
+
+	#[test]
+	fn unknown_format_versions_and_bad_toml_are_errors() {
+		let temp = tempfile::tempdir().unwrap();
+		let path = temp.path().join(LOCK_FILE);
+		std::fs::write(&path, "version = 7\n").unwrap();
+		assert!(matches!(
+			Lockfile::read(&path),
+			Err(LockfileError::UnsupportedVersion { found: 7, .. })
+		));
+		std::fs::write(&path, "version = [").unwrap();
+		assert!(matches!(
+			Lockfile::read(&path),
+			Err(LockfileError::Parse { .. })
+		));
+	}
+}
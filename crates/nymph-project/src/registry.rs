@@ -0,0 +1,174 @@
+//! Package selection from a registry on the local filesystem.
+//!
+//! A registry is a plain directory with one subdirectory per package name and,
+//! inside it, one unpacked package tree per published version:
+//!
+//! ```text
+//! registry/
+//!   text-tools/
+//!     1.0.0/nymph.toml
+//!     1.2.0/nymph.toml
+//! ```
+//!
+//! Nothing is fetched or hosted; copying or syncing the directory is the whole
+//! publishing story, which keeps builds reproducible offline.
+
+use std::{
+	io,
+	path::{Path, PathBuf},
+};
+
+use semver::{Version, VersionReq};
+
+use crate::{MANIFEST_FILE, Project};
+
+/// Environment variable naming the registry directory. It takes precedence
+/// over the root manifest's `[registry] path`.
+pub const REGISTRY_ENV: &str = "NYMPH_REGISTRY";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalRegistry {
+	root: PathBuf,
+}
+
+impl LocalRegistry {
+	#[must_use]
+	pub fn new(root: impl Into<PathBuf>) -> Self {
+		Self { root: root.into() }
+	}
+
+	#[must_use]
+	pub fn root(&self) -> &Path {
+		&self.root
+	}
+
+	/// Every published version of `name`, ascending. Directories whose name is
+	/// not a semantic version are ignored; an unknown package has no versions.
+	pub fn versions(&self, name: &str) -> io::Result<Vec<Version>> {
+		let entries = match std::fs::read_dir(self.root.join(name)) {
+			Ok(entries) => entries,
+			Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+			Err(error) => return Err(error),
+		};
+		let mut versions = Vec::new();
+		for entry in entries {
+			let entry = entry?;
+			if !entry.file_type()?.is_dir() {
+				continue;
+			}
+			if let Some(version) = entry
+				.file_name()
+				.to_str()
+				.and_then(|name| Version::parse(name).ok())
+			{
+				versions.push(version);
+			}
+		}
+		versions.sort();
+		Ok(versions)
+	}
+
+	/// Pick the version of `name` satisfying `requirement`. A `locked` version
+	/// wins while it still satisfies the requirement and is still published;
+	/// otherwise the newest matching version is chosen.
+	pub fn select(
+		&self,
+		name: &str,
+		requirement: &VersionReq,
+		locked: Option<&Version>,
+	) -> io::Result<Option<Version>> {
+		let versions = self.versions(name)?;
+		if let Some(locked) = locked
+			&& requirement.matches(locked)
+			&& versions.contains(locked)
+		{
+			return Ok(Some(locked.clone()));
+		}
+		Ok(
+			versions
+				.into_iter()
+				.rev()
+				.find(|version| requirement.matches(version)),
+		)
+	}
+
+	#[must_use]
+	pub fn manifest_path(&self, name: &str, version: &Version) -> PathBuf {
+		self
+			.root
+			.join(name)
+			.join(version.to_string())
+			.join(MANIFEST_FILE)
+	}
+}
+
+impl Project {
+	/// The registry version requirements resolve against: [`REGISTRY_ENV`] when
+	/// set, otherwise `[registry] path` relative to this manifest's directory.
+	#[must_use]
+	pub fn registry(&self) -> Option<LocalRegistry> {
+		if let Some(path) = std::env::var_os(REGISTRY_ENV).filter(|path| !path.is_empty()) {
+			return Some(LocalRegistry::new(path));
+		}
+		self
+			.manifest()
+			.registry
+			.as_ref()
+			.map(|registry| LocalRegistry::new(self.root().join(&registry.path)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn publish(registry: &Path, name: &str, version: &str) {
+		let dir = registry.join(name).join(version);
+		std::fs::create_dir_all(&dir).unwrap();
+		std::fs::write(
+			dir.join(MANIFEST_FILE),
+			format!("[package]\nname='{name}'\nversion='{version}'\n"),
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn selection_prefers_a_matching_lock_then_the_newest_match() {
+		let temp = tempfile::tempdir().unwrap();
+		for version in ["1.0.0", "1.2.0", "1.10.1", "2.0.0", "2.1.0-beta.1"] {
+			publish(temp.path(), "text-tools", version);
+		}
+		std::fs::create_dir_all(temp.path().join("text-tools/not-a-version")).unwrap();
+		let registry = LocalRegistry::new(temp.path());
+		let caret_one = VersionReq::parse("^1.0").unwrap();
+
+		assert_eq!(
+			registry.versions("text-tools").unwrap(),
+			["1.0.0", "1.2.0", "1.10.1", "2.0.0", "2.1.0-beta.1"]
+				.map(|version| Version::parse(version).unwrap())
+		);
+		assert_eq!(
+			registry.select("text-tools", &caret_one, None).unwrap(),
+			Some(Version::new(1, 10, 1))
+		);
+		assert_eq!(
+			registry
+				.select("text-tools", &caret_one, Some(&Version::new(1, 2, 0)))
+				.unwrap(),
+			Some(Version::new(1, 2, 0))
+		);
+		assert_eq!(
+			registry
+				.select("text-tools", &caret_one, Some(&Version::new(2, 0, 0)))
+				.unwrap(),
+			Some(Version::new(1, 10, 1))
+		);
+		assert_eq!(
+			registry
+				.select("text-tools", &VersionReq::parse("^3").unwrap(), None)
+				.unwrap(),
+			None
+		);
+		assert!(registry.versions("missing").unwrap().is_empty());
+	}
+}
@@ -0,0 +1,412 @@
+//! Multi-package workspaces declared by a `[workspace]` table.
+//!
+//! A workspace root manifest lists member package directories. It may also
+//! declare its own `[package]`, which is then the first member; a manifest
+//! with only `[workspace]` is *virtual* and is never itself a project.
+//!
+//! ```text
+//! monorepo/
+//!   nymph.toml          [workspace] members = ["app", "libs/*"]
+//!   nymph.lock          shared by every member
+//!   app/nymph.toml
+//!   libs/text/nymph.toml
+//! ```
+//!
+//! Members inherit `[workspace.lints]`, with their own `[lints]` winning per
+//! lint, and share the root's `nymph.lock` and `target/` directory. Dependencies
+//! between members are ordinary `path` dependencies.
+
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	io,
+	path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	DependencyError, Lockfile, MANIFEST_FILE, ManifestError, Project, is_contained_relative,
+	normalize_path,
+};
+
+/// The `[workspace]` table.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Workspace {
+	/// Member package directories, relative to the workspace root. A final `*`
+	/// component selects every direct subdirectory containing a manifest.
+	pub members: Vec<PathBuf>,
+	/// Lint levels inherited by every member.
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub lints: BTreeMap<String, nymph_compiler::LintLevel>,
+}
+
+impl Workspace {
+	pub(crate) fn is_valid_member(member: &Path) -> bool {
+		let wildcard = member.file_name().is_some_and(|name| name == "*");
+		let prefix = if wildcard {
+			member.parent().unwrap_or(Path::new(""))
+		} else {
+			member
+		};
+		((wildcard && prefix.as_os_str().is_empty()) || is_contained_relative(prefix))
+			&& prefix
+				.components()
+				.all(|component| !component.as_os_str().to_string_lossy().contains('*'))
+	}
+
+	/// The normalized member directories under `root`, in declaration order.
+	/// Explicit members are returned whether or not they contain a manifest;
+	/// wildcard members select only directories that do.
+	pub(crate) fn member_roots(&self, root: &Path) -> io::Result<Vec<PathBuf>> {
+		let root = normalize_path(root)?;
+		let mut roots = Vec::new();
+		for member in &self.members {
+			if member.file_name().is_some_and(|name| name == "*") {
+				let parent = root.join(member.parent().unwrap_or(Path::new("")));
+				let mut matched = BTreeSet::new();
+				for entry in std::fs::read_dir(&parent)? {
+					let entry = entry?;
+					if entry.file_type()?.is_dir() && entry.path().join(MANIFEST_FILE).is_file() {
+						matched.insert(normalize_path(entry.path())?);
+					}
+				}
+				roots.extend(matched);
+			} else {
+				roots.push(normalize_path(root.join(member))?);
+			}
+		}
+		let mut seen = BTreeSet::new();
+		roots.retain(|member| seen.insert(member.clone()));
+		Ok(roots)
+	}
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WorkspaceError {
+	#[error(transparent)]
+	Manifest(Box<ManifestError>),
+	#[error("could not list workspace members of {path}: {source}")]
+	Members {
+		path: PathBuf,
+		#[source]
+		source: io::Error,
+	},
+	#[error("workspace member {member} of {path} has no {MANIFEST_FILE}")]
+	MissingMember { path: PathBuf, member: PathBuf },
+	#[error("workspace {path} has more than one member named `{name}`")]
+	DuplicateMember { path: PathBuf, name: String },
+}
+
+impl From<ManifestError> for WorkspaceError {
+	fn from(error: ManifestError) -> Self {
+		Self::Manifest(Box::new(error))
+	}
+}
+
+/// A loaded workspace root manifest and every member project.
+#[derive(Debug, Clone)]
+pub struct WorkspaceRoot {
+	manifest_path: PathBuf,
+	root: PathBuf,
+	workspace: Workspace,
+	members: Vec<Project>,
+}
+
+impl WorkspaceRoot {
+	/// Load the workspace declared by exactly `manifest_path`, or `None` when
+	/// that manifest has no `[workspace]` table.
+	pub fn load(manifest_path: &Path) -> Result<Option<Self>, WorkspaceError> {
+		let Some((workspace, has_package)) = read_workspace(manifest_path)? else {
+			return Ok(None);
+		};
+		let root = manifest_path
+			.parent()
+			.filter(|path| !path.as_os_str().is_empty())
+			.unwrap_or_else(|| Path::new("."))
+			.to_path_buf();
+		let member_roots = workspace
+			.member_roots(&root)
+			.map_err(|source| WorkspaceError::Members {
+				path: manifest_path.into(),
+				source,
+			})?;
+		let mut members = Vec::new();
+		if has_package {
+			members.push(Project::load(manifest_path)?);
+		}
+		let own_root = normalize_path(&root).ok();
+		for member_root in member_roots {
+			if own_root.as_ref() == Some(&member_root) && has_package {
+				continue;
+			}
+			let member_manifest = member_root.join(MANIFEST_FILE);
+			if !member_manifest.is_file() {
+				return Err(WorkspaceError::MissingMember {
+					path: manifest_path.into(),
+					member: member_root,
+				});
+			}
+			let mut member = Project::read(&member_manifest)?;
+			member.workspace_manifest = Some(manifest_path.into());
+			member.workspace_lints = workspace.lints.clone();
+			members.push(member);
+		}
+		let mut names = BTreeSet::new();
+		for member in &members {
+			if !names.insert(member.manifest().package.name.as_str()) {
+				return Err(WorkspaceError::DuplicateMember {
+					path: manifest_path.into(),
+					name: member.manifest().package.name.clone(),
+				});
+			}
+		}
+		Ok(Some(Self {
+			manifest_path: manifest_path.into(),
+			root,
+			workspace,
+			members,
+		}))
+	}
+
+	/// Load the workspace declared by the nearest manifest at or above `start`.
+	/// Returns `None` when there is no manifest or the nearest one declares no
+	/// workspace; a member's own manifest therefore never selects its workspace.
+	pub fn discover(start: &Path) -> Result<Option<Self>, WorkspaceError> {
+		let mut dir = start.to_path_buf();
+		loop {
+			let path = dir.join(MANIFEST_FILE);
+			let found = path.try_exists().map_err(|source| ManifestError::Read {
+				path: path.clone(),
+				source,
+			})?;
+			if found {
+				return Self::load(&path);
+			}
+			if !dir.pop() {
+				return Ok(None);
+			}
+		}
+	}
+
+	#[must_use]
+	pub fn manifest_path(&self) -> &Path {
+		&self.manifest_path
+	}
+
+	#[must_use]
+	pub fn root(&self) -> &Path {
+		&self.root
+	}
+
+	#[must_use]
+	pub fn workspace(&self) -> &Workspace {
+		&self.workspace
+	}
+
+	/// Every member project; a root `[package]` comes first, then the members
+	/// in declaration order.
+	#[must_use]
+	pub fn members(&self) -> &[Project] {
+		&self.members
+	}
+
+	/// The shared lock: the union of every member's own selections.
+	pub fn lockfile(&self) -> Result<Lockfile, DependencyError> {
+		let mut lock = Lockfile::default();
+		for member in &self.members {
+			for package in member.resolve_packages()?.lockfile().packages {
+				if !lock.packages.contains(&package) {
+					lock.packages.push(package);
+				}
+			}
+		}
+		Ok(lock)
+	}
+}
+
+/// The `[workspace]` table of the manifest at `path` and whether that manifest
+/// also declares a `[package]`, without requiring one.
+pub(crate) fn read_workspace(path: &Path) -> Result<Option<(Workspace, bool)>, ManifestError> {
+	let value = crate::read_manifest_value(path)?;
+	let Some(table) = value.get("workspace") else {
+		return Ok(None);
+	};
+	let workspace: Workspace = table
+		.clone()
+		.try_into()
+		.map_err(|source| ManifestError::Schema {
+			path: path.into(),
+			source,
+		})?;
+	validate_members(&workspace, path)?;
+	Ok(Some((workspace, value.get("package").is_some())))
+}
+
+pub(crate) fn validate_members(workspace: &Workspace, path: &Path) -> Result<(), ManifestError> {
+	match workspace
+		.members
+		.iter()
+		.find(|member| !Workspace::is_valid_member(member))
+	{
+		Some(member) => Err(ManifestError::InvalidPath {
+			path: path.into(),
+			field: "workspace.members",
+			value: member.clone(),
+		}),
+		None => Ok(()),
+	}
+}
+
+/// The manifest path and lints of the workspace `root` (a package directory)
+/// belongs to: its own `[workspace]`, or else the nearest ancestor manifest
+/// declaring one that lists `root` as a member.
+pub(crate) fn enclosing_workspace(
+	manifest_path: &Path,
+	root: &Path,
+	manifest: &crate::Manifest,
+) -> Result<Option<(PathBuf, Workspace)>, ManifestError> {
+	if let Some(workspace) = &manifest.workspace {
+		return Ok(Some((manifest_path.into(), workspace.clone())));
+	}
+	let read_error = |path: &Path| {
+		let path = path.to_path_buf();
+		move |source| ManifestError::Read { path, source }
+	};
+	let member = normalize_path(root).map_err(read_error(root))?;
+	let mut dir = member.clone();
+	while dir.pop() {
+		let path = dir.join(MANIFEST_FILE);
+		if !path.is_file() {
+			continue;
+		}
+		let Some((workspace, _)) = read_workspace(&path)? else {
+			continue;
+		};
+		let members = workspace.member_roots(&dir).map_err(read_error(&path))?;
+		return Ok(members.contains(&member).then_some((path, workspace)));
+	}
+	Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use nymph_compiler::LintLevel;
+
+	fn write(path: &Path, contents: &str) {
+		std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+		std::fs::write(path, contents).unwrap();
+	}
+
+	fn package(name: &str) -> String {
+		format!("[package]\nname='{name}'\nversion='1.0.0'\n")
+	}
+
+	#[test]
+	fn members_expand_inherit_lints_and_share_the_root_lock() {
+		let temp = tempfile::tempdir().unwrap();
+		let root = temp.path();
+		write(
+			&root.join(MANIFEST_FILE),
+			"[workspace]\nmembers=['app', 'libs/*']\n[workspace.lints]\necho-in-release='deny'\nunused='warn'\n",
+		);
+		write(
+			&root.join("app/nymph.toml"),
+			&format!("{}[lints]\nunused='allow'\n", package("app")),
+		);
+		write(&root.join("libs/text/nymph.toml"), &package("text"));
+		write(&root.join("libs/json/nymph.toml"), &package("json"));
+		std::fs::create_dir_all(root.join("libs/notes")).unwrap();
+
+		let workspace = WorkspaceRoot::load(&root.join(MANIFEST_FILE))
+			.unwrap()
+			.expect("a workspace manifest");
+		let names = workspace
+			.members()
+			.iter()
+			.map(|member| member.manifest().package.name.as_str())
+			.collect::<Vec<_>>();
+		assert_eq!(names, ["app", "json", "text"]);
+		let app = &workspace.members()[0];
+		assert_eq!(
+			app.lints(),
+			BTreeMap::from([
+				("echo-in-release".to_string(), LintLevel::Deny),
+				("unused".to_string(), LintLevel::Allow),
+			])
+		);
+		assert_eq!(app.lock_path(), root.join("nymph.lock"));
+
+		// Loading a member on its own finds the same workspace.
+		let text = Project::load(&root.join("libs/text/nymph.toml")).unwrap();
+		assert_eq!(
+			text.workspace_manifest(),
+			Some(root.join(MANIFEST_FILE).as_path())
+		);
+		assert_eq!(text.lints().get("unused"), Some(&LintLevel::Warn));
+		assert!(
+			WorkspaceRoot::discover(&root.join("libs/text"))
+				.unwrap()
+				.is_none()
+		);
+		assert!(WorkspaceRoot::discover(root).unwrap().is_some());
+	}
+
+	#[test]
+	fn a_package_outside_the_member_list_is_standalone() {
+		let temp = tempfile::tempdir().unwrap();
+		write(
+			&temp.path().join(MANIFEST_FILE),
+			&format!("{}[workspace]\nmembers=['tools']\n", package("root")),
+		);
+		write(&temp.path().join("tools/nymph.toml"), &package("tools"));
+		write(&temp.path().join("scratch/nymph.toml"), &package("scratch"));
+
+		let workspace = WorkspaceRoot::load(&temp.path().join(MANIFEST_FILE))
+			.unwrap()
+			.unwrap();
+		assert_eq!(workspace.members().len(), 2);
+		assert_eq!(workspace.members()[0].manifest().package.name, "root");
+		let scratch = Project::load(&temp.path().join("scratch/nymph.toml")).unwrap();
+		assert_eq!(scratch.workspace_manifest(), None);
+		assert_eq!(scratch.lock_path(), temp.path().join("scratch/nymph.lock"));
+	}
+
+	#[test]
+	fn virtual_manifests_and_invalid_members_are_reported() {
+		let temp = tempfile::tempdir().unwrap();
+		let manifest = temp.path().join(MANIFEST_FILE);
+		write(&manifest, "[workspace]\nmembers=['missing']\n");
+		assert!(matches!(
+			crate::discover(temp.path()),
+			Err(crate::DiscoverError::Manifest(
+				ManifestError::VirtualManifest { .. }
+			))
+		));
+		assert!(matches!(
+			WorkspaceRoot::load(&manifest),
+			Err(WorkspaceError::MissingMember { .. })
+		));
+
+		for member in ["../outside", "/abs", "a*/b", "**"] {
+			write(&manifest, &format!("[workspace]\nmembers=['{member}']\n"));
+			let error = WorkspaceRoot::load(&manifest).unwrap_err();
+			assert!(
+				matches!(
+					&error,
+					WorkspaceError::Manifest(error)
+						if matches!(**error, ManifestError::InvalidPath { field: "workspace.members", .. })
+				),
+				"{member}: {error}"
+			);
+		}
+
+		write(&manifest, "[workspace]\nmembers=['a', 'b']\n");
+		write(&temp.path().join("a/nymph.toml"), &package("same"));
+		write(&temp.path().join("b/nymph.toml"), &package("same"));
+		assert!(matches!(
+			WorkspaceRoot::load(&manifest),
+			Err(WorkspaceError::DuplicateMember { name, .. }) if name == "same"
+		));
+	}
+}
@@ -5,7 +5,7 @@ use std::{ops::Deref, sync::Arc};
 use nymph_ast::{Span, decl::Module};
 use rustc_hash::FxHashMap;
 
-use crate::{Annotations, CheckedFacts, DefinitionId, ModuleIdentity};
+use crate::{Annotations, CheckedFacts, DefinitionId, Deprecation, ModuleIdentity};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ImportReferenceTarget {
@@ -59,6 +59,9 @@ pub struct SemanticAnalysis {
 	/// standalone module has no project import universe and therefore leaves
 	/// this list empty.
 	pub import_references: Arc<[(Span, ImportReferenceTarget)]>,
+	/// Deprecated definitions of the dependency interfaces this module was
+	/// checked against, so tooling can mark their uses.
+	pub deprecations: Arc<FxHashMap<DefinitionId, Deprecation>>,
 }
 
 impl SemanticAnalysis {
@@ -173,6 +173,8 @@ pub struct MemberCompletion {
 	pub kind: MemberCompletionKind,
 	/// Fully instantiated field type or callable signature.
 	pub detail: String,
+	/// Stable identity of the offered member, when the checker selected one.
+	pub target: Option<DefinitionId>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -874,6 +876,9 @@ pub struct CheckedFacts {
 	/// Statically classified entry result. Absent outside entry mode and when
 	/// entry validation failed.
 	pub entry_root: Option<crate::EntryRootShape>,
+	/// The module's `test` declarations, in source order, that resolved to a
+	/// supported root result.
+	pub tests: Vec<crate::TestRoot>,
 	/// Resolved host marshalling ABI for each checked external-let declaration,
 	/// keyed by its binding span for consumption during HIR lowering.
 	pub external_value_marshals: FxHashMap<Span, MarshalKind>,
@@ -513,8 +513,10 @@ fn check_module_from_parts(
 	checker.finalize_named_generic_labels();
 	checker.check_external_value_linkage();
 	checker.check_external_func_kinds();
+	checker.check_host_binding_signatures();
 	checker.check_managed_fields();
 	checker.check_managed_child_captures();
+	checker.check_derives();
 	let entry_root = if entry == EntryMode::Entry {
 		// Runs after every body has been checked, so its diagnostics append
 		// after body-checking diagnostics rather than interleaving with them.
@@ -522,6 +524,7 @@ fn check_module_from_parts(
 	} else {
 		None
 	};
+	let tests = checker.check_tests();
 	// Every body-checking path (`check_func_body`, `check_let_body`,
 	// `check_method_body`, `check_interface_impl_members`) drains its own
 	// `pending_operators` entries before the next body's `param_bounds` are
@@ -889,6 +892,7 @@ fn check_module_from_parts(
 			annotations,
 			runtime_roles: checker.stable_runtime_roles.clone(),
 			entry_root,
+			tests,
 			external_value_marshals: checker.external_value_marshals,
 			interner: checker.interner,
 			semantic: CheckedSemantic {
@@ -1122,13 +1126,15 @@ fn for_impl_member_exprs(
 	}
 }
 
-fn for_declaration_exprs(
+pub(crate) fn for_declaration_exprs(
 	declaration: &nymph_ast::decl::Declaration,
 	f: &mut impl FnMut(&nymph_ast::expr::Expr),
 ) {
 	use nymph_ast::decl::Declaration;
 	match declaration {
-		Declaration::Let { value, .. } | Declaration::Func { body: value, .. } => f(value),
+		Declaration::Let { value, .. }
+		| Declaration::Func { body: value, .. }
+		| Declaration::Test { body: value, .. } => f(value),
 		Declaration::Struct {
 			fields,
 			members,
@@ -1331,6 +1337,7 @@ pub fn check_module_with_environment(
 		environment.imported.inherent.clone(),
 		environment.imported.external_abis.clone(),
 		environment.imported.effect_rows.clone(),
+		environment.imported.deprecations.clone(),
 		environment.contains_recovery,
 		environment.compiler_runtime_roles.clone(),
 		environment.runtime_role_provenance,
@@ -1366,6 +1373,7 @@ pub fn check_module_with_owned_environment(
 		external_abis,
 		definition_members: _,
 		effect_rows,
+		deprecations,
 	} = imported;
 	check_module_with_environment_parts(
 		module,
@@ -1378,6 +1386,7 @@ pub fn check_module_with_owned_environment(
 		inherent,
 		external_abis,
 		effect_rows,
+		deprecations,
 		contains_recovery,
 		compiler_runtime_roles,
 		runtime_role_provenance,
@@ -1397,6 +1406,7 @@ fn check_module_with_environment_parts(
 	imported_inherent: crate::members::InherentRegistry,
 	external_abis: FxHashMap<DefId, crate::ExternalAbi>,
 	declared_effects: FxHashMap<crate::DefinitionId, crate::EffectRow>,
+	deprecations: FxHashMap<crate::DefinitionId, crate::Deprecation>,
 	contains_recovery: bool,
 	compiler_runtime_roles: crate::CompilerRuntimeRoles,
 	runtime_role_provenance: crate::environment::RuntimeRoleProvenance,
@@ -1488,21 +1498,33 @@ fn check_module_with_environment_parts(
 				)
 			}),
 	);
-	let diagnostics: std::sync::Arc<[Diagnostic]> = checked.diags.into();
 	let facts = std::sync::Arc::new(checked.facts);
 	let annotations = std::sync::Arc::new(crate::ModuleAnnotations::from(facts.annotations.clone()));
+	let analysis = crate::SemanticAnalysis {
+		module,
+		checked: facts,
+		annotations,
+		declarations: std::sync::Arc::new(declarations),
+		import_references: std::sync::Arc::new([]),
+		deprecations: std::sync::Arc::new(deprecations),
+	};
+	let mut diagnostics = checked.diags;
+	diagnostics.extend(crate::query::deprecated_uses(&analysis).into_iter().map(
+		|(span, deprecation)| {
+			TypeError::DeprecatedUse {
+				name: deprecation.name.clone(),
+				note: deprecation.note.clone(),
+			}
+			.as_lint_diagnostic(span)
+		},
+	));
+	let diagnostics: std::sync::Arc<[Diagnostic]> = diagnostics.into();
 	let lowerable = !contains_recovery
 		&& !diagnostics
 			.iter()
 			.any(nymph_diagnostics::Diagnostic::is_error);
 	crate::SemanticCheckResult {
-		analysis: std::sync::Arc::new(crate::SemanticAnalysis {
-			module,
-			checked: facts,
-			annotations,
-			declarations: std::sync::Arc::new(declarations),
-			import_references: std::sync::Arc::new([]),
-		}),
+		analysis: std::sync::Arc::new(analysis),
 		diagnostics,
 		lowerable,
 	}
@@ -1560,6 +1582,20 @@ impl<'m> Checker<'m> {
 						},
 					)
 				}
+				// The registry owns an intrinsic's ABI, including whether the
+				// host hands back a task; a host binding's promise always is one.
+				Declaration::ExternalFunc(_, marker, meta)
+					if nymph_ast::decl::host_binding(marker).is_none()
+						&& meta.is_async != nymph_hir::linkage::is_task_marker(marker) =>
+				{
+					self.emit(
+						meta.name.1,
+						TypeError::ExternalTaskLinkageMismatch {
+							marker: marker.clone(),
+							is_async: meta.is_async,
+						},
+					)
+				}
 				Declaration::Struct { members, impls, .. } | Declaration::Enum { members, impls, .. } => {
 					check_members(self, members);
 					for impl_ in impls {
@@ -1601,7 +1637,9 @@ impl<'m> Checker<'m> {
 		span: Span,
 		ty: Option<Ty>,
 	) {
+		let host = nymph_ast::decl::host_binding(marker).is_some();
 		let linked = match nymph_hir::linkage::lookup_value(marker) {
+			_ if host => None,
 			Ok(linked) => Some(linked),
 			Err(nymph_hir::linkage::LinkageError::Missing { .. }) => {
 				self.emit(
@@ -1623,6 +1661,7 @@ impl<'m> Checker<'m> {
 			}
 		};
 		let marshal = ty.and_then(|ty| match self.interner.kind(ty) {
+			_ if host => self.host_marshal(ty, false),
 			nymph_hir::ty::TyKind::Int => Some(nymph_hir::hir::MarshalKind::Int),
 			nymph_hir::ty::TyKind::UInt => Some(nymph_hir::hir::MarshalKind::UInt),
 			nymph_hir::ty::TyKind::Float => Some(nymph_hir::hir::MarshalKind::Float),
@@ -1649,6 +1688,90 @@ impl<'m> Checker<'m> {
 		}
 	}
 
+	/// Reject a host binding whose signature mentions a type without a raw
+	/// JavaScript form. Marshalling is generated from these types, so anything
+	/// beyond the scalars, lists of them, and a `void` result has nothing to
+	/// convert to. An `async` binding's result is the value its promise
+	/// settles with.
+	fn check_host_binding_signatures(&mut self) {
+		use nymph_ast::decl::Declaration;
+		for decl in &self.module.members {
+			let Declaration::ExternalFunc(_, marker, meta) = decl else {
+				continue;
+			};
+			if nymph_ast::decl::host_binding(marker).is_none() {
+				continue;
+			}
+			let Some(sig) = self
+				.defs
+				.get(&meta.name.0)
+				.and_then(|def| self.sigs.funcs.get(&def))
+			else {
+				continue;
+			};
+			let params: Vec<_> = sig.params.iter().map(|param| param.ty).collect();
+			let ret = sig.ret;
+			// Only for naming the binding's own generics in diagnostics.
+			self
+				.params
+				.push(crate::lower::build_param_scope(&meta.generics));
+			for (param, ty) in meta.params.iter().zip(params) {
+				if !self.is_error_or_infer(ty) && self.host_marshal(ty, false).is_none() {
+					let ty = self.display(ty);
+					self.emit(
+						param.0.type_.1,
+						TypeError::ExternalHostTypeUnsupported { ty },
+					);
+				}
+			}
+			let void = matches!(self.interner.kind(ret), nymph_hir::ty::TyKind::Void);
+			if !void && !self.is_error_or_infer(ret) && self.host_marshal(ret, true).is_none() {
+				let span = meta.return_type.as_ref().map_or(meta.name.1, |ty| ty.1);
+				let ty = self.display(ret);
+				self.emit(span, TypeError::ExternalHostTypeUnsupported { ty });
+			}
+			self.params.pop();
+		}
+	}
+
+	/// How a value of `ty` crosses a host binding: a scalar as its raw value, a
+	/// list of scalars as an array, and an `async` binding's `Task` result as
+	/// a promise.
+	fn host_marshal(&self, ty: Ty, result: bool) -> Option<nymph_hir::hir::MarshalKind> {
+		use nymph_hir::hir::{MarshalKind, PromiseMarshal};
+		use nymph_hir::ty::TyKind;
+		if let Some(scalar) = self.host_scalar(ty) {
+			return Some(scalar.into());
+		}
+		match self.interner.kind(ty) {
+			TyKind::List(element) => self.host_scalar(*element).map(MarshalKind::Array),
+			TyKind::Task { output, .. } if result => {
+				let output = *output;
+				let settled = match self.interner.kind(output) {
+					TyKind::Void => PromiseMarshal::Void,
+					TyKind::List(element) => PromiseMarshal::Array(self.host_scalar(*element)?),
+					_ => PromiseMarshal::Scalar(self.host_scalar(output)?),
+				};
+				Some(MarshalKind::Promise(settled))
+			}
+			_ => None,
+		}
+	}
+
+	fn host_scalar(&self, ty: Ty) -> Option<nymph_hir::hir::ScalarMarshal> {
+		use nymph_hir::hir::ScalarMarshal;
+		use nymph_hir::ty::TyKind;
+		match self.interner.kind(ty) {
+			TyKind::Int => Some(ScalarMarshal::Int),
+			TyKind::UInt => Some(ScalarMarshal::UInt),
+			TyKind::Float => Some(ScalarMarshal::Float),
+			TyKind::Char => Some(ScalarMarshal::Char),
+			TyKind::String => Some(ScalarMarshal::String),
+			TyKind::Boolean => Some(ScalarMarshal::Boolean),
+			_ => None,
+		}
+	}
+
 	fn new(module: &'m Module, defs: DefMap, diags: Vec<Diagnostic>) -> Self {
 		Self {
 			module,
@@ -1701,16 +1824,7 @@ impl<'m> Checker<'m> {
 	// ── Diagnostics ──────────────────────────────────────────────────────────
 	/// Emit a typed [`TypeError`](crate::errors::TypeError), anchored at `span`.
 	pub(crate) fn emit(&mut self, span: Span, err: TypeError) {
-		use nymph_diagnostics::IntoDiagnostic;
-		let mut diagnostic = err.as_diagnostic(span);
-		match &err {
-			TypeError::ManagedFieldWithoutClose { .. } => diagnostic.code = "managed-field".into(),
-			TypeError::ManagedChildCapture { .. } => {
-				diagnostic.code = "managed-child-capture".into();
-			}
-			_ => {}
-		}
-		self.diags.push(diagnostic);
+		self.diags.push(err.as_lint_diagnostic(span));
 	}
 
 	// ── Annotations ──────────────────────────────────────────────────────────
@@ -312,7 +312,9 @@ pub(crate) fn build_def_map_on(
 
 	for (i, decl) in module.members.iter().enumerate() {
 		match decl {
-			Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) => {
+			Declaration::Func { meta, .. }
+			| Declaration::Test { meta, .. }
+			| Declaration::ExternalFunc(_, _, meta) => {
 				declare(&mut map, diags, &meta.name, DefKind::Func, i);
 			}
 			Declaration::Effect { name, .. } => {
@@ -492,12 +494,10 @@ pub struct ValueSig {
 #[derive(Debug, Clone)]
 pub enum NamespaceMemberSig {
 	Func {
-		#[allow(dead_code)]
 		target: Option<DefinitionId>,
 		sig: FuncSig,
 	},
 	Value {
-		#[allow(dead_code)]
 		target: Option<DefinitionId>,
 		ty: Ty,
 	},
@@ -0,0 +1,646 @@
+//! `derives` clauses: structural interface impls generated from a `struct`'s or
+//! `enum`'s fields.
+//!
+//! Expansion is syntactic and runs before any other pass. Each derived interface
+//! becomes an ordinary top-level `impl … for` appended to the module, so
+//! collection, checking, lowering and interface extraction all treat it exactly
+//! like a written impl — a downstream package sees it in the extracted interface.
+//! The impl copies the owner's generic parameters, adding the derived interface
+//! as a bound on each type parameter. Its members are generated source parsed by
+//! [`nymph_syntax::parse_generated`] at offsets past the end of the file, so no
+//! generated node shares a span with a written one; only the impl's header spans
+//! point back at the derive name.
+//!
+//! Whether every field's type implements the interface is left to the checker
+//! (see [`derived_owner`]), which reports a missing impl at the field instead of
+//! inside generated code.
+
+use std::fmt::Write as _;
+
+use ecow::EcoString;
+use nymph_ast::{
+	Ident, Span, Spanned,
+	decl::{Declaration, EnumVariant, Module, StructField},
+	expr::Expr,
+	ty::{GenericParam, GenericParamKind, Type},
+};
+
+use crate::check::Checker;
+use crate::errors::TypeError;
+use crate::ids::DefId;
+use crate::ty::{Ty, TyKind};
+
+/// The interfaces a `derives` clause can name.
+pub const DERIVABLE_INTERFACES: &[&str] = &[
+	"Equals",
+	"Hash",
+	"Display",
+	"Debug",
+	"Comparable",
+	"Default",
+];
+
+/// Append an impl for every derivable interface named by a `struct` or `enum`
+/// `derives` clause in `module`, parsed from `source`.
+///
+/// Unknown and repeated names are skipped, as are an enum that embeds another
+/// enum's variants and `Default` for an enum without variants; the checker
+/// reports each of them.
+pub fn expand_derives(module: &mut Module, source: &str) {
+	let mut next_id = next_node_id(module);
+	let mut base = source.len() + 1;
+	let mut generated = Vec::new();
+	for declaration in &module.members {
+		let Some(owner) = Owner::of(declaration) else {
+			continue;
+		};
+		let mut seen: Vec<&str> = Vec::new();
+		for derive in owner.derives {
+			let interface = derive.0.as_str();
+			if !DERIVABLE_INTERFACES.contains(&interface) || seen.contains(&interface) {
+				continue;
+			}
+			seen.push(interface);
+			let Some(text) = owner.impl_source(interface, source) else {
+				continue;
+			};
+			let parsed = nymph_syntax::parse_generated(&text, base, &mut next_id);
+			debug_assert!(
+				parsed.diagnostics.is_empty(),
+				"derived `{interface}` impl does not parse:\n{text}"
+			);
+			base += text.len() + 1;
+			for mut declaration in parsed.tree {
+				if let Declaration::ImplFor {
+					type_,
+					for_interface,
+					..
+				} = &mut declaration
+				{
+					type_.1 = derive.1;
+					for_interface.0.1 = derive.1;
+				}
+				generated.push(declaration);
+			}
+		}
+	}
+	module.members.extend(generated);
+}
+
+/// The `struct`/`enum` whose `derives` clause generated an impl whose
+/// interface is named by `interface`, if any.
+pub(crate) fn derived_owner<'a>(module: &'a Module, interface: &Ident) -> Option<&'a Declaration> {
+	module.members.iter().find(|declaration| {
+		Owner::of(declaration).is_some_and(|owner| {
+			owner
+				.derives
+				.iter()
+				.any(|derive| derive.1 == interface.1 && derive.0 == interface.0)
+		})
+	})
+}
+
+/// One past the largest node id in `module`, where generated nodes start.
+fn next_node_id(module: &Module) -> u32 {
+	fn visit(expr: &Expr, max: &mut u32) {
+		if expr.id != nymph_ast::NodeId::DUMMY {
+			*max = (*max).max(expr.id.0 + 1);
+		}
+		expr.for_each_child(|child| visit(child, max));
+	}
+	let mut max = 0;
+	for declaration in &module.members {
+		crate::check::for_declaration_exprs(declaration, &mut |expr| visit(expr, &mut max));
+	}
+	max
+}
+
+struct Owner<'a> {
+	name: &'a Ident,
+	generics: &'a [Spanned<GenericParam>],
+	shape: Shape<'a>,
+	derives: &'a [Ident],
+}
+
+enum Shape<'a> {
+	Struct(&'a [Spanned<StructField>]),
+	Enum(&'a [Spanned<EnumVariant>]),
+}
+
+impl<'a> Owner<'a> {
+	fn of(declaration: &'a Declaration) -> Option<Self> {
+		match declaration {
+			Declaration::Struct {
+				name,
+				generics,
+				fields,
+				derives,
+				..
+			} => Some(Self {
+				name,
+				generics,
+				shape: Shape::Struct(fields),
+				derives,
+			}),
+			Declaration::Enum {
+				name,
+				generics,
+				embeddings,
+				variants,
+				derives,
+				..
+			} if embeddings.is_empty() => Some(Self {
+				name,
+				generics,
+				shape: Shape::Enum(variants),
+				derives,
+			}),
+			_ => None,
+		}
+	}
+
+	/// `impl<T: Bound + Interface> Interface<Other = self> for Owner<T> { … }`.
+	fn impl_source(&self, interface: &str, source: &str) -> Option<String> {
+		if interface == "Default" && matches!(self.shape, Shape::Enum([])) {
+			return None;
+		}
+		let relational = matches!(interface, "Equals" | "Comparable");
+		let mut text = String::from("impl");
+		if !self.generics.is_empty() {
+			let params = self
+				.generics
+				.iter()
+				.map(|param| {
+					let GenericParam {
+						name,
+						kind,
+						constraint,
+						..
+					} = &param.0;
+					if *kind == GenericParamKind::Effect {
+						return format!("!{}", name.0);
+					}
+					let bound = if relational {
+						format!("{interface}<Other = {}>", name.0)
+					} else {
+						interface.to_string()
+					};
+					match constraint {
+						Some(constraint) => {
+							format!(
+								"{}: {} + {bound}",
+								name.0,
+								&source[span_range(constraint.1)]
+							)
+						}
+						None => format!("{}: {bound}", name.0),
+					}
+				})
+				.collect::<Vec<_>>();
+			let _ = write!(text, "<{}>", params.join(", "));
+		}
+		let _ = write!(text, " {interface}");
+		if relational {
+			text.push_str("<Other = self>");
+		}
+		let _ = write!(text, " for {}", self.name.0);
+		if !self.generics.is_empty() {
+			let args = self
+				.generics
+				.iter()
+				.map(|param| match param.0.kind {
+					GenericParamKind::Effect => format!("!{}", param.0.name.0),
+					GenericParamKind::Type => param.0.name.0.to_string(),
+				})
+				.collect::<Vec<_>>();
+			let _ = write!(text, "<{}>", args.join(", "));
+		}
+		let member = match interface {
+			"Equals" => format!("func equals(other: self): boolean = {}", self.equals_body()),
+			"Hash" => format!("func hash(): int = {}", self.hash_body()),
+			"Display" => format!("func display(): string = {}", self.display_body()),
+			"Debug" => format!("func debug(): string = {}", self.debug_body()),
+			"Comparable" => format!(
+				"func compare_to(other: self): Order = {}",
+				self.compare_body()
+			),
+			"Default" => format!("func default(): self = {}", self.default_body()),
+			_ => unreachable!("`{interface}` is not derivable"),
+		};
+		let _ = write!(text, " {{\n  {member}\n}}");
+		Some(text)
+	}
+
+	fn equals_body(&self) -> String {
+		match &self.shape {
+			Shape::Struct(fields) => conjunction(
+				fields
+					.iter()
+					.map(|field| format!("this.{0}.equals(other.{0})", field.0.name.0)),
+			),
+			Shape::Enum(variants) => self.match_pairs(variants, "false", |fields| {
+				conjunction((0..fields.len()).map(|index| format!("l{index}.equals(r{index})")))
+			}),
+		}
+	}
+
+	fn compare_body(&self) -> String {
+		match &self.shape {
+			Shape::Struct(fields) => lexicographic(
+				fields
+					.iter()
+					.map(|field| {
+						(
+							format!("this.{}", field.0.name.0),
+							format!("other.{}", field.0.name.0),
+						)
+					})
+					.collect(),
+			),
+			Shape::Enum(variants) => {
+				let index = |value: &str| {
+					let arms = variants
+						.iter()
+						.enumerate()
+						.map(|(index, variant)| {
+							format!("{} -> {index}", self.variant_pattern(&variant.0, None))
+						})
+						.collect::<Vec<_>>();
+					format!("match ({value}) {{ {} }}", arms.join(", "))
+				};
+				let fallback = format!(
+					"order_from_sign(compare_ints({}, {}))",
+					index("this"),
+					index("other")
+				);
+				self.match_pairs(variants, &fallback, |fields| {
+					lexicographic(
+						(0..fields.len())
+							.map(|index| (format!("l{index}"), format!("r{index}")))
+							.collect(),
+					)
+				})
+			}
+		}
+	}
+
+	fn hash_body(&self) -> String {
+		match &self.shape {
+			Shape::Struct(fields) => hash_list(
+				0,
+				fields
+					.iter()
+					.map(|field| format!("this.{}.hash()", field.0.name.0)),
+			),
+			Shape::Enum([]) => hash_list(0, std::iter::empty()),
+			Shape::Enum(variants) => {
+				let arms = variants.iter().enumerate().map(|(index, variant)| {
+					let fields = &variant.0.fields;
+					format!(
+						"{} -> {}",
+						self.variant_pattern(&variant.0, Some("f")),
+						hash_list(
+							index,
+							(0..fields.len()).map(|field| format!("f{field}.hash()"))
+						)
+					)
+				});
+				match_expr("this", arms)
+			}
+		}
+	}
+
+	fn display_body(&self) -> String {
+		self.render_body(|_, value| value)
+	}
+
+	/// Like `Display`, but each field renders through its own `debug`. Lists,
+	/// tuples and maps are interpolated instead: the runtime already renders
+	/// their elements with `Debug`.
+	fn debug_body(&self) -> String {
+		self.render_body(|ty, value| {
+			if is_collection(ty) {
+				value
+			} else {
+				format!("{value}.debug()")
+			}
+		})
+	}
+
+	/// A string rendering `Owner(field: …)`, or `Owner.Variant(field: …)` per
+	/// variant, with each field's text produced by `render`.
+	fn render_body(&self, render: impl Fn(&Type, String) -> String) -> String {
+		match &self.shape {
+			Shape::Struct(fields) => display_text(
+				&self.name.0,
+				fields.iter().map(|field| {
+					let value = format!("this.{}", field.0.name.0);
+					(&field.0.name.0, render(&field.0.type_.0, value))
+				}),
+			),
+			Shape::Enum([]) => "\"\"".to_string(),
+			Shape::Enum(variants) => {
+				let arms = variants.iter().map(|variant| {
+					let name = format!("{}.{}", self.name.0, variant.0.name.0);
+					let text = display_text(
+						&name,
+						variant.0.fields.iter().enumerate().map(|(index, field)| {
+							(
+								&field.0.name.0,
+								render(&field.0.type_.0, format!("f{index}")),
+							)
+						}),
+					);
+					format!("{} -> {text}", self.variant_pattern(&variant.0, Some("f")))
+				});
+				match_expr("this", arms)
+			}
+		}
+	}
+
+	/// Fields with a declared default keep it; the rest use their type's
+	/// `Default`. An enum defaults to its first variant.
+	fn default_body(&self) -> String {
+		let construct = |path: String, fields: &[Spanned<StructField>]| {
+			let values = fields
+				.iter()
+				.filter(|field| field.0.default.is_none())
+				.map(|field| format!("{} = default_value()", field.0.name.0))
+				.collect::<Vec<_>>();
+			format!("{path}({})", values.join(", "))
+		};
+		match &self.shape {
+			Shape::Struct(fields) => construct(self.name.0.to_string(), fields),
+			Shape::Enum(variants) => {
+				let variant = &variants[0].0;
+				let path = format!("{}.{}", self.name.0, variant.name.0);
+				if variant.fields.is_empty() {
+					path
+				} else {
+					construct(path, &variant.fields)
+				}
+			}
+		}
+	}
+
+	/// `match (#(this, other))` with one arm per variant pairing it with itself,
+	/// binding the left fields to `l0…` and the right ones to `r0…`, and
+	/// `fallback` for mismatched variants.
+	fn match_pairs(
+		&self,
+		variants: &[Spanned<EnumVariant>],
+		fallback: &str,
+		same: impl Fn(&[Spanned<StructField>]) -> String,
+	) -> String {
+		if variants.is_empty() {
+			return "true".to_string();
+		}
+		let mut arms = variants
+			.iter()
+			.map(|variant| {
+				format!(
+					"#({}, {}) -> {}",
+					self.variant_pattern(&variant.0, Some("l")),
+					self.variant_pattern(&variant.0, Some("r")),
+					same(&variant.0.fields)
+				)
+			})
+			.collect::<Vec<_>>();
+		if variants.len() > 1 {
+			arms.push(format!("_ -> {fallback}"));
+		}
+		match_expr("#(this, other)", arms.into_iter())
+	}
+
+	/// `Owner.Variant(field = prefix0, …)`, or `Owner.Variant(...)` ignoring
+	/// the fields when `prefix` is `None`.
+	fn variant_pattern(&self, variant: &EnumVariant, prefix: Option<&str>) -> String {
+		let path = format!("{}.{}", self.name.0, variant.name.0);
+		if variant.fields.is_empty() {
+			return path;
+		}
+		let Some(prefix) = prefix else {
+			return format!("{path}(...)");
+		};
+		let fields = variant
+			.fields
+			.iter()
+			.enumerate()
+			.map(|(index, field)| format!("{} = {prefix}{index}", field.0.name.0))
+			.collect::<Vec<_>>();
+		format!("{path}({})", fields.join(", "))
+	}
+}
+
+fn is_collection(ty: &Type) -> bool {
+	match ty {
+		Type::List(_) | Type::Tuple(_) | Type::Map(..) => true,
+		Type::Grouped(inner) => is_collection(&inner.0),
+		_ => false,
+	}
+}
+
+fn span_range(span: Span) -> std::ops::Range<usize> {
+	span.start..span.end
+}
+
+fn conjunction(terms: impl Iterator<Item = String>) -> String {
+	let terms = terms.collect::<Vec<_>>();
+	if terms.is_empty() {
+		"true".to_string()
+	} else {
+		terms.join(" && ")
+	}
+}
+
+/// Compare `pairs` in order, stopping at the first that is not `Equal`.
+fn lexicographic(pairs: Vec<(String, String)>) -> String {
+	pairs
+		.into_iter()
+		.rev()
+		.fold(None, |rest: Option<String>, (left, right)| {
+			let compare = format!("{left}.compare_to({right})");
+			Some(match rest {
+				None => compare,
+				Some(rest) => {
+					format!("match ({compare}) {{ Order.Equal -> {rest}, order -> order }}")
+				}
+			})
+		})
+		.unwrap_or_else(|| "Order.Equal".to_string())
+}
+
+fn hash_list(index: usize, hashes: impl Iterator<Item = String>) -> String {
+	let items = std::iter::once(index.to_string())
+		.chain(hashes)
+		.collect::<Vec<_>>();
+	format!("hash_primitive(#[{}])", items.join(", "))
+}
+
+/// A string literal rendering `name(field: value, …)`.
+fn display_text<'f>(name: &str, fields: impl Iterator<Item = (&'f EcoString, String)>) -> String {
+	let fields = fields
+		.map(|(field, value)| format!("{field}: ${{{value}}}"))
+		.collect::<Vec<_>>();
+	if fields.is_empty() {
+		format!("\"{name}\"")
+	} else {
+		format!("\"{name}({})\"", fields.join(", "))
+	}
+}
+
+fn match_expr(scrutinee: &str, arms: impl Iterator<Item = String>) -> String {
+	let arms = arms.collect::<Vec<_>>();
+	format!(
+		"match ({scrutinee}) {{\n    {},\n  }}",
+		arms.join(",\n    ")
+	)
+}
+
+impl Checker<'_> {
+	/// Report `derives` names that expansion skipped.
+	pub(crate) fn check_derives(&mut self) {
+		let module = self.module;
+		for declaration in &module.members {
+			let (owner, derives, embeds, has_variants) = match declaration {
+				Declaration::Struct { name, derives, .. } => (name, derives, false, true),
+				Declaration::Enum {
+					name,
+					derives,
+					embeddings,
+					variants,
+					..
+				} => (name, derives, !embeddings.is_empty(), !variants.is_empty()),
+				_ => continue,
+			};
+			for (index, derive) in derives.iter().enumerate() {
+				let name = derive.0.clone();
+				let error = if !DERIVABLE_INTERFACES.contains(&name.as_str()) {
+					TypeError::UnknownDerive { name }
+				} else if derives[..index].iter().any(|earlier| earlier.0 == name) {
+					TypeError::DuplicateDerive { name }
+				} else if embeds {
+					TypeError::DeriveOnEmbeddingEnum {
+						interface: name,
+						owner: owner.0.clone(),
+					}
+				} else if name == "Default" && !has_variants {
+					TypeError::DeriveDefaultWithoutVariants {
+						owner: owner.0.clone(),
+					}
+				} else {
+					continue;
+				};
+				self.emit(derive.1, error);
+			}
+		}
+	}
+
+	/// Whether every field of `owner` implements the derived `interface`, under
+	/// the bounds of the derived impl being checked. Each field that does not is
+	/// reported, and the impl's generated body is then left unchecked.
+	pub(crate) fn derived_fields_implement(
+		&mut self,
+		owner: &Declaration,
+		interface: &Ident,
+	) -> bool {
+		let Some(interface_def) = self
+			.defs
+			.get(&interface.0)
+			.filter(|&definition| self.is_interface(definition))
+		else {
+			return true;
+		};
+		let (owner_name, fields) = match owner {
+			Declaration::Struct { name, fields, .. } => {
+				let Some(signature) = self
+					.defs
+					.get(&name.0)
+					.and_then(|def| self.sigs.structs.get(&def))
+				else {
+					return true;
+				};
+				let fields = fields
+					.iter()
+					.zip(&signature.fields)
+					.map(|(field, (_, ty))| (field.0.name.0.clone(), field.0.type_.1, *ty))
+					.collect::<Vec<_>>();
+				(name, fields)
+			}
+			Declaration::Enum { name, variants, .. } => {
+				let Some(signature) = self
+					.defs
+					.get(&name.0)
+					.and_then(|def| self.sigs.enums.get(&def))
+				else {
+					return true;
+				};
+				let fields = variants
+					.iter()
+					.zip(&signature.variants)
+					.flat_map(|(variant, variant_signature)| {
+						variant
+							.0
+							.fields
+							.iter()
+							.zip(&variant_signature.fields)
+							.map(|(field, (_, ty))| (field.0.name.0.clone(), field.0.type_.1, *ty))
+					})
+					.collect::<Vec<_>>();
+				(name, fields)
+			}
+			_ => return true,
+		};
+		let mut implemented = true;
+		for (field, span, ty) in fields {
+			if self.derived_field_holds(ty, interface_def, &interface.0) {
+				continue;
+			}
+			implemented = false;
+			let ty = self.display(ty);
+			self.emit(
+				span,
+				TypeError::DeriveFieldNotImplemented {
+					interface: interface.0.clone(),
+					owner: owner_name.0.clone(),
+					field,
+					ty,
+				},
+			);
+		}
+		implemented
+	}
+
+	/// `Display` and `Debug` render lists, tuples and maps natively, so those
+	/// only need their elements to implement the interface.
+	fn derived_field_holds(&mut self, ty: Ty, interface: DefId, name: &str) -> bool {
+		let ty = self.shallow_resolve(ty);
+		let structural = matches!(name, "Display" | "Debug");
+		match self.interner.kind(ty).clone() {
+			TyKind::List(item) if structural => self.derived_field_holds(item, interface, name),
+			TyKind::Map(key, value) if structural => {
+				self.derived_field_holds(key, interface, name)
+					&& self.derived_field_holds(value, interface, name)
+			}
+			TyKind::Tuple(items) if structural => items
+				.into_iter()
+				.all(|item| self.derived_field_holds(item, interface, name)),
+			_ => {
+				let known = if matches!(name, "Equals" | "Comparable") {
+					vec![("Other".into(), ty)]
+				} else {
+					Vec::new()
+				};
+				let member = match name {
+					"Comparable" => "compare_to",
+					"Equals" => "equals",
+					"Hash" => "hash",
+					"Display" => "display",
+					"Debug" => "debug",
+					_ => "default",
+				};
+				self.holds_defining(ty, interface, member, &known)
+			}
+		}
+	}
+}
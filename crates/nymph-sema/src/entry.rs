@@ -3,9 +3,11 @@
 //! Entry mode (`check_module_entry`, see `check.rs`) requires the module to
 //! declare a top-level `func main` taking no parameters, declaring no generic
 //! parameters, and resolving to one of the supported root result shapes;
-//! library mode (`check_module`) never runs this pass. This stays separate
-//! from `check.rs` to keep that file from growing further.
+//! library mode (`check_module`) never runs this pass. `test` declarations
+//! share the same root shapes and are classified here in every mode. This
+//! stays separate from `check.rs` to keep that file from growing further.
 
+use ecow::EcoString;
 use nymph_ast::Span;
 use nymph_ast::decl::Declaration;
 
@@ -24,6 +26,16 @@ pub enum EntryRootShape {
 	TaskResult,
 }
 
+/// A `test` declaration whose body resolves to a supported root shape.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TestRoot {
+	/// The name written in the declaration's string literal.
+	pub name: EcoString,
+	/// The synthesized function the test body lowers to.
+	pub function: EcoString,
+	pub shape: EntryRootShape,
+}
+
 impl Checker<'_> {
 	/// Validate the module's entry point. Called once, in entry mode only,
 	/// after every body has been checked (see `check::check_module_impl`), so
@@ -67,7 +79,50 @@ impl Checker<'_> {
 			self.emit(first.span().to(last.span()), TypeError::MainHasParams);
 		}
 
-		let main_def = self
+		let shape = self.classify_member_root(member);
+		if shape.is_none() {
+			let span = meta
+				.return_type
+				.as_ref()
+				.map_or_else(|| meta.name.span(), |ret| ret.span());
+			self.emit(span, TypeError::MainNonVoidReturn);
+		}
+		shape
+	}
+
+	/// Classify every `test` declaration's result, reporting the ones the
+	/// runner could not judge. Like [`Self::check_entry_main`], this runs after
+	/// every body has been checked.
+	pub(crate) fn check_tests(&mut self) -> Vec<TestRoot> {
+		let tests: Vec<_> = self
+			.module
+			.members
+			.iter()
+			.enumerate()
+			.filter_map(|(member, decl)| match decl {
+				Declaration::Test { name, meta, .. } => {
+					Some((member, name.0.clone(), meta.name.clone()))
+				}
+				_ => None,
+			})
+			.collect();
+		let mut roots = Vec::with_capacity(tests.len());
+		for (member, name, function) in tests {
+			match self.classify_member_root(member) {
+				Some(shape) => roots.push(TestRoot {
+					name,
+					function: function.0,
+					shape,
+				}),
+				None => self.emit(function.1, TypeError::TestUnsupportedResult),
+			}
+		}
+		roots
+	}
+
+	/// The root shape of the function declared by top-level `member`.
+	fn classify_member_root(&mut self, member: usize) -> Option<EntryRootShape> {
+		let function = self
 			.defs
 			.defs
 			.iter()
@@ -77,17 +132,9 @@ impl Checker<'_> {
 				(definition.kind == DefKind::Func && self.defs.local_member(id) == Some(member))
 					.then_some(id)
 			});
-		let shape = main_def
-			.and_then(|main| self.sigs.funcs.get(&main).map(|signature| signature.ret))
-			.and_then(|root| self.classify_entry_root(root));
-		if shape.is_none() {
-			let span = meta
-				.return_type
-				.as_ref()
-				.map_or_else(|| meta.name.span(), |ret| ret.span());
-			self.emit(span, TypeError::MainNonVoidReturn);
-		}
-		shape
+		function
+			.and_then(|function| self.sigs.funcs.get(&function).map(|signature| signature.ret))
+			.and_then(|root| self.classify_entry_root(root))
 	}
 
 	fn classify_entry_root(&mut self, root: Ty) -> Option<EntryRootShape> {
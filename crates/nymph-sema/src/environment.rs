@@ -102,6 +102,8 @@ pub struct ImportedFacts {
 	/// Lossless member facts for namespaces, interfaces, and nominal types.
 	pub definition_members: FxHashMap<crate::DefId, Vec<OwnedMemberSig>>,
 	pub effect_rows: FxHashMap<DefinitionId, crate::EffectRow>,
+	/// Dependency definitions, members, fields and variants marked `@deprecated`.
+	pub deprecations: FxHashMap<DefinitionId, crate::Deprecation>,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -221,6 +223,8 @@ impl SemanticEnvironment {
 			}
 		}
 
+		imported.deprecations = module_deprecations(&modules);
+
 		// Pass B starts only now: every complete/recovered Known stable identity has a
 		// checker-local allocation, so conversion can never allocate on demand.
 		let definitions = Arc::new(definition_map(&imported.defs));
@@ -397,6 +401,104 @@ fn record_recovered_effects(
 	}
 }
 
+/// Every `@deprecated` definition, member, field and variant `modules` export,
+/// keyed by its stable identity.
+pub fn module_deprecations(
+	modules: &[Arc<ModuleEnvironment>],
+) -> FxHashMap<DefinitionId, crate::Deprecation> {
+	let mut deprecations = FxHashMap::default();
+	for module in modules {
+		match module.as_ref() {
+			ModuleEnvironment::Complete(interface) => {
+				for definition in &interface.exports {
+					record_deprecations(&mut deprecations, definition);
+				}
+				for implementation in &interface.implementations {
+					record_member_deprecations(&mut deprecations, &implementation.members);
+				}
+			}
+			ModuleEnvironment::Recovered(interface) => {
+				for definition in &interface.exports {
+					record_recovered_deprecations(&mut deprecations, definition);
+				}
+				for implementation in &interface.implementations {
+					record_member_deprecations(&mut deprecations, &implementation.members);
+				}
+			}
+		}
+	}
+	deprecations
+}
+
+fn record_deprecation(
+	deprecations: &mut FxHashMap<DefinitionId, crate::Deprecation>,
+	id: &DefinitionId,
+	name: &EcoString,
+	attributes: &[crate::AttributeShape],
+) {
+	if let Some(deprecation) = crate::Deprecation::from_attributes(name, attributes) {
+		deprecations.insert(id.clone(), deprecation);
+	}
+}
+
+fn record_member_deprecations<T, R, E>(
+	deprecations: &mut FxHashMap<DefinitionId, crate::Deprecation>,
+	members: &[crate::MemberShape<T, R, E>],
+) {
+	for member in members {
+		record_deprecation(deprecations, &member.id, &member.name, &member.attributes);
+	}
+}
+
+fn record_shape_deprecations<T>(
+	deprecations: &mut FxHashMap<DefinitionId, crate::Deprecation>,
+	fields: &[crate::FieldShape<T>],
+	variants: &[crate::VariantShape<T>],
+) {
+	for field in fields
+		.iter()
+		.chain(variants.iter().flat_map(|variant| &variant.fields))
+	{
+		record_deprecation(deprecations, &field.id, &field.name, &field.attributes);
+	}
+	for variant in variants {
+		record_deprecation(
+			deprecations,
+			&variant.id,
+			&variant.name,
+			&variant.attributes,
+		);
+	}
+}
+
+fn record_deprecations(
+	deprecations: &mut FxHashMap<DefinitionId, crate::Deprecation>,
+	definition: &ExportedDefinition,
+) {
+	record_deprecation(
+		deprecations,
+		&definition.id,
+		&definition.name,
+		&definition.attributes,
+	);
+	record_member_deprecations(deprecations, &definition.members);
+	record_shape_deprecations(deprecations, &definition.fields, &definition.variants);
+}
+
+fn record_recovered_deprecations(
+	deprecations: &mut FxHashMap<DefinitionId, crate::Deprecation>,
+	definition: &RecoveredExportedDefinition,
+) {
+	record_deprecation(
+		deprecations,
+		&definition.id,
+		&definition.name,
+		&definition.attributes,
+	);
+	record_member_deprecations(deprecations, &definition.members);
+	record_shape_deprecations(deprecations, &definition.fields, &definition.variants);
+}
+
 fn shape_kind(kind: DefinitionShapeKind) -> DefKind {
 	match kind {
 		DefinitionShapeKind::Function => DefKind::Func,
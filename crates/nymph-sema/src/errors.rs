@@ -484,6 +484,74 @@ pub enum TypeError {
 	RetiredEnumWrapper,
 	/// A destination enum qualified a source-owned variant pattern.
 	RetiredEnumWrapperPattern,
+	/// A `test` body resolves to something other than one of the root result
+	/// shapes `main` accepts, so the runner could not tell pass from fail.
+	TestUnsupportedResult,
+	/// A host binding's signature mentions a type with no raw JavaScript
+	/// form, so no marshalling can be generated for it.
+	ExternalHostTypeUnsupported {
+		ty: String,
+	},
+	/// An intrinsic external is `async` without returning a task, or returns
+	/// a task without being declared `async`.
+	ExternalTaskLinkageMismatch {
+		marker: EcoString,
+		is_async: bool,
+	},
+	/// A `derives` clause names an interface with no structural derivation.
+	UnknownDerive {
+		name: EcoString,
+	},
+	/// A `derives` clause names the same interface more than once.
+	DuplicateDerive {
+		name: EcoString,
+	},
+	/// A derived impl forwards to every field, so each field's type must
+	/// implement the derived interface too.
+	DeriveFieldNotImplemented {
+		interface: EcoString,
+		owner: EcoString,
+		field: EcoString,
+		ty: String,
+	},
+	/// An enum embedding another enum's variants cannot derive an interface:
+	/// its fields are declared elsewhere.
+	DeriveOnEmbeddingEnum {
+		interface: EcoString,
+		owner: EcoString,
+	},
+	/// A derived `Default` constructs an enum's first variant, so the enum
+	/// needs one.
+	DeriveDefaultWithoutVariants {
+		owner: EcoString,
+	},
+	/// A dependency definition marked `@deprecated` is used. **Warning.**
+	DeprecatedUse {
+		name: EcoString,
+		note: Option<EcoString>,
+	},
+}
+
+impl TypeError {
+	/// The `[lints]` name a configurable warning is reported under.
+	#[must_use]
+	pub fn lint(&self) -> Option<&'static str> {
+		match self {
+			TypeError::ManagedFieldWithoutClose { .. } => Some("managed-field"),
+			TypeError::ManagedChildCapture { .. } => Some("managed-child-capture"),
+			TypeError::DeprecatedUse { .. } => Some("deprecated"),
+			_ => None,
+		}
+	}
+
+	/// Assemble the diagnostic at `span`, coded by its lint name when it has one.
+	pub(crate) fn as_lint_diagnostic(&self, span: Span) -> nymph_diagnostics::Diagnostic {
+		let mut diagnostic = self.as_diagnostic(span);
+		if let Some(lint) = self.lint() {
+			diagnostic.code = lint.into();
+		}
+		diagnostic
+	}
 }
 
 impl IntoDiagnostic for TypeError {
@@ -744,6 +812,22 @@ impl IntoDiagnostic for TypeError {
 			E::RetiredEnumWrapperPattern => {
 				"embedded variants are matched through their qualified source enum".into()
 			}
+			E::ExternalHostTypeUnsupported { ty } => {
+				format!("`{ty}` has no raw JavaScript form an external binding can marshal").into()
+			}
+			E::ExternalTaskLinkageMismatch {
+				marker,
+				is_async: true,
+			} => format!("external marker `{marker}` does not return a task, so it cannot be `async`")
+				.into(),
+			E::ExternalTaskLinkageMismatch {
+				marker,
+				is_async: false,
+			} => format!("external marker `{marker}` returns a task, so it must be declared `async`")
+				.into(),
+			E::TestUnsupportedResult => {
+				"a test must produce `void`, `Option<void>`, `Result<void, E>`, or a `Task` producing one of those types".into()
+			}
 			E::PositionalStructField => "struct fields must be supplied by name (`field = value`)".into(),
 			E::InvalidStructSpread => "a struct clone/update requires exactly one leading source spread".into(),
 			E::DuplicateStructField { field } => format!("struct field `{field}` is supplied more than once").into(),
@@ -763,6 +847,25 @@ impl IntoDiagnostic for TypeError {
 			E::ManagedChildCapture { .. } => {
 				"spawned child may use this managed resource after its lexical cleanup".into()
 			}
+			E::UnknownDerive { name } => format!("`{name}` cannot be derived").into(),
+			E::DuplicateDerive { name } => format!("`{name}` is derived more than once").into(),
+			E::DeriveFieldNotImplemented {
+				interface,
+				owner,
+				field,
+				ty,
+			} => format!(
+				"cannot derive `{interface}` for `{owner}`: field `{field}` has type `{ty}`, which does not implement `{interface}`"
+			)
+			.into(),
+			E::DeriveOnEmbeddingEnum { interface, owner } => format!(
+				"cannot derive `{interface}` for `{owner}`, which embeds another enum's variants"
+			)
+			.into(),
+			E::DeriveDefaultWithoutVariants { owner } => {
+				format!("cannot derive `Default` for `{owner}`, which has no variants").into()
+			}
+			E::DeprecatedUse { name, .. } => format!("`{name}` is deprecated").into(),
 		}
 	}
 
@@ -770,7 +873,8 @@ impl IntoDiagnostic for TypeError {
 		match self {
 			TypeError::UnreachableArm
 			| TypeError::ManagedFieldWithoutClose { .. }
-			| TypeError::ManagedChildCapture { .. } => Severity::Warning,
+			| TypeError::ManagedChildCapture { .. }
+			| TypeError::DeprecatedUse { .. } => Severity::Warning,
 			_ => Severity::Error,
 		}
 	}
@@ -836,6 +940,23 @@ impl IntoDiagnostic for TypeError {
 			TypeError::MainNonVoidReturn => {
 				Some("use one of the supported resolved root result shapes".into())
 			}
+			TypeError::ExternalHostTypeUnsupported { .. } => Some(
+				"host bindings take and return `int`, `uint`, `float`, `char`, `string`, `boolean` \
+				 or a list of one of those; a result, including an `async` binding's, may also be `void`"
+					.into(),
+			),
+			TypeError::UnknownDerive { .. } => Some(
+				"the derivable interfaces are `Equals`, `Hash`, `Display`, `Debug`, `Comparable` and \
+				 `Default`; implement any other interface with an `impl` block"
+					.into(),
+			),
+			TypeError::DeriveFieldNotImplemented { interface, ty, .. } => Some(
+				format!("implement `{interface}` for `{ty}`, or write this `{interface}` impl by hand")
+					.into(),
+			),
+			TypeError::TestUnsupportedResult => Some(
+				"end the test body with a statement, or produce `None`/`Error` to fail it".into(),
+			),
 			TypeError::CastRequiresInto { .. } => Some(
 				"declare or import an `Into` interface and implement it for the source type, or cast \
 				 between built-in scalar types instead"
@@ -854,6 +975,7 @@ impl IntoDiagnostic for TypeError {
 			TypeError::IntLiteralOutOfRange { .. } => {
 				Some("use a `uint` literal suffix (`u`) for values up to `u64::MAX`".into())
 			}
+			TypeError::DeprecatedUse { note, .. } => note.clone(),
 			_ => None,
 		}
 	}
@@ -347,7 +347,7 @@ impl<'m> Checker<'m> {
 	fn check_func_body(&mut self, id: DefId, member: usize) {
 		let module = self.module;
 		let (meta, body) = match &module.members[member] {
-			Declaration::Func { meta, body, .. } => (meta, body),
+			Declaration::Func { meta, body, .. } | Declaration::Test { meta, body, .. } => (meta, body),
 			_ => return, // external funcs have no body
 		};
 		let sig = self.sigs.funcs[&id].clone();
@@ -2968,6 +2968,7 @@ impl<'m> Checker<'m> {
 						name: candidate_name,
 						kind: MemberCompletionKind::Function,
 						detail,
+						target: None,
 					});
 				}
 			}
@@ -2990,16 +2991,21 @@ impl<'m> Checker<'m> {
 				DefKind::Namespace => {
 					if let Some(namespace) = self.sigs.namespaces.get(&def).cloned() {
 						for (name, member) in namespace.members {
-							let (kind, detail) = match member {
-								NamespaceMemberSig::Func { sig, .. } => {
+							let (kind, detail, target) = match member {
+								NamespaceMemberSig::Func { sig, target } => {
 									let (ty, _) = self.namespace_func_type(&sig, span);
-									(MemberCompletionKind::Function, self.display(ty))
+									(MemberCompletionKind::Function, self.display(ty), target)
 								}
-								NamespaceMemberSig::Value { ty, .. } => {
-									(MemberCompletionKind::Value, self.display(ty))
+								NamespaceMemberSig::Value { ty, target } => {
+									(MemberCompletionKind::Value, self.display(ty), target)
 								}
 							};
-							out.push(MemberCompletion { name, kind, detail });
+							out.push(MemberCompletion {
+								name,
+								kind,
+								detail,
+								target,
+							});
 						}
 					}
 				}
@@ -3046,6 +3052,7 @@ impl<'m> Checker<'m> {
 								name: variant.name,
 								kind: MemberCompletionKind::Variant,
 								detail,
+								target: variant.target,
 							});
 						}
 					}
@@ -3066,13 +3073,13 @@ impl<'m> Checker<'m> {
 						let synthetic_bound_details = self.synthetic_bound_details.clone();
 						let annotations = self.annotations.clone();
 						let mut candidate = None;
-						if let Some((params, ret, _, _)) =
+						if let Some((params, ret, target, _)) =
 							self.resolve_namespaced_value_on(owner.unwrap_or(def), concrete_owner, &name, span)
 							&& self.diags.len() == diagnostics
 							&& !matches!(self.interner.kind(ret), TyKind::Error)
 						{
 							let ty = self.interner.mk_fn(params, ret);
-							candidate = Some(self.display(ty));
+							candidate = Some((self.display(ty), target));
 						}
 						self.diags.truncate(diagnostics);
 						self.table.rollback_to(checkpoint);
@@ -3082,11 +3089,12 @@ impl<'m> Checker<'m> {
 						self.synthetic_bounds = synthetic_bounds;
 						self.synthetic_bound_details = synthetic_bound_details;
 						self.annotations = annotations;
-						if let Some(detail) = candidate {
+						if let Some((detail, target)) = candidate {
 							out.push(MemberCompletion {
 								name,
 								kind: MemberCompletionKind::Function,
 								detail,
+								target,
 							});
 						}
 					}
@@ -3158,6 +3166,7 @@ impl<'m> Checker<'m> {
 					name,
 					kind: MemberCompletionKind::Field,
 					detail: self.display(ty),
+					target: metadata.target.clone(),
 				});
 			}
 		}
@@ -3199,6 +3208,7 @@ impl<'m> Checker<'m> {
 					name,
 					kind: MemberCompletionKind::Method,
 					detail,
+					target: method.target,
 				});
 			}
 			self.diags.truncate(diagnostics);
@@ -4647,7 +4657,7 @@ impl<'m> Checker<'m> {
 	}
 
 	/// Whether a (shallow-resolved) type is `Error` or an unbound inference variable.
-	fn is_error_or_infer(&self, ty: Ty) -> bool {
+	pub(crate) fn is_error_or_infer(&self, ty: Ty) -> bool {
 		matches!(self.interner.kind(ty), TyKind::Error | TyKind::Infer(_))
 	}
 
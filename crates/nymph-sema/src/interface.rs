@@ -7,7 +7,10 @@ use std::{
 };
 
 use ecow::EcoString;
-use nymph_ast::{Span, decl::Visibility};
+use nymph_ast::{
+	Span, Spanned,
+	decl::{Attribute, AttributeArgument, Visibility},
+};
 use nymph_hir::{
 	hir::MarshalKind,
 	ids::{DefId, InferVar, ParamIdx},
@@ -552,6 +555,62 @@ pub type SuperInterface = SuperInterfaceShape<InterfaceType>;
 pub type RecoveredSuperInterface =
 	SuperInterfaceShape<RecoveredInterfaceType, RecoveredDefinitionReference>;
 
+/// A source attribute as an interface records it: `@deprecated("use parse_all")`
+/// keeps its name and arguments but not its spans, so dependents, the linter,
+/// the docs generator and the LSP read it without the declaring source.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, salsa::SalsaValue)]
+pub struct AttributeShape {
+	pub name: EcoString,
+	pub arguments: Vec<AttributeArgument>,
+}
+
+impl AttributeShape {
+	pub fn from_source(attributes: &[Spanned<Attribute>]) -> Vec<Self> {
+		attributes
+			.iter()
+			.map(|attribute| Self {
+				name: attribute.0.name.0.clone(),
+				arguments: attribute
+					.0
+					.arguments
+					.iter()
+					.map(|argument| argument.0.clone())
+					.collect(),
+			})
+			.collect()
+	}
+
+	/// The first attribute called `name`.
+	pub fn find<'a>(attributes: &'a [Self], name: &str) -> Option<&'a Self> {
+		attributes.iter().find(|attribute| attribute.name == name)
+	}
+}
+
+/// A definition a library has phased out with `@deprecated`, keyed by its
+/// stable identity wherever a dependent needs to flag its uses.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, salsa::SalsaValue)]
+pub struct Deprecation {
+	/// The deprecated definition's declared name.
+	pub name: EcoString,
+	/// The marker's argument, conventionally naming the replacement.
+	pub note: Option<EcoString>,
+}
+
+impl Deprecation {
+	/// The deprecation `attributes` declare for the definition called `name`,
+	/// if any.
+	pub fn from_attributes(name: &EcoString, attributes: &[AttributeShape]) -> Option<Self> {
+		let marker = AttributeShape::find(attributes, "deprecated")?;
+		Some(Self {
+			name: name.clone(),
+			note: marker
+				.arguments
+				.first()
+				.map(|argument| argument.text().into()),
+		})
+	}
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash, salsa::SalsaValue)]
 pub struct ParameterShape<T> {
 	pub name: Option<EcoString>,
@@ -562,6 +621,7 @@ pub struct ParameterShape<T> {
 pub struct FieldShape<T> {
 	pub id: DefinitionId,
 	pub name: EcoString,
+	pub attributes: Vec<AttributeShape>,
 	pub visibility: Option<Visibility>,
 	pub ty: T,
 	pub has_default: bool,
@@ -573,12 +633,14 @@ pub struct FieldShape<T> {
 pub struct VariantShape<T> {
 	pub id: DefinitionId,
 	pub name: EcoString,
+	pub attributes: Vec<AttributeShape>,
 	pub fields: Vec<FieldShape<T>>,
 }
 #[derive(Clone, Debug, PartialEq, Eq, Hash, salsa::SalsaValue)]
 pub struct MemberShape<T, R = DefinitionId, E = EffectRow> {
 	pub id: DefinitionId,
 	pub name: EcoString,
+	pub attributes: Vec<AttributeShape>,
 	pub visibility: Option<Visibility>,
 	pub kind: MemberKind,
 	pub binders: Vec<GenericParameter>,
@@ -641,6 +703,9 @@ pub enum ExternalCallMode {
 	#[default]
 	Ordinary,
 	Cancellable,
+	/// A user-declared `external("module", "symbol")` binding, marshalled
+	/// through the raw host ABI in both directions.
+	Host,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, salsa::SalsaValue)]
@@ -732,6 +797,7 @@ pub enum DefinitionShapeKind {
 pub struct ExportedDefinition {
 	pub id: DefinitionId,
 	pub name: EcoString,
+	pub attributes: Vec<AttributeShape>,
 	pub visibility: Option<Visibility>,
 	pub kind: DefinitionShapeKind,
 	/// Callable/value modifier retained for function and let declarations.
@@ -1019,6 +1085,7 @@ pub enum SemanticAvailability {
 pub struct RecoveredExportedDefinition {
 	pub id: DefinitionId,
 	pub name: EcoString,
+	pub attributes: Vec<AttributeShape>,
 	pub visibility: Option<Visibility>,
 	pub kind: DefinitionShapeKind,
 	pub declaration_kind: Option<MemberKind>,
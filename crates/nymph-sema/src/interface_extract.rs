@@ -11,9 +11,9 @@ use nymph_ast::{
 };
 
 use crate::{
-	BinderScope, CanonicalizationContext, Checked, CheckedFacts, DeclarationCategory, DeclarationKey,
-	DefinitionId, DefinitionShapeKind, ExportedDefinition, ExportedImpl, ExternalAbi, FieldShape,
-	GenericParameter, GenericParameterId, HeaderBinder, HeaderParameterId, HeaderType,
+	AttributeShape, BinderScope, CanonicalizationContext, Checked, CheckedFacts, DeclarationCategory,
+	DeclarationKey, DefinitionId, DefinitionShapeKind, ExportedDefinition, ExportedImpl, ExternalAbi,
+	FieldShape, GenericParameter, GenericParameterId, HeaderBinder, HeaderParameterId, HeaderType,
 	ImplementationHeader, InterfaceConversionError, InterfaceType, MemberKind, MemberShape,
 	ModuleEnvironment, ModuleIdentity, ModuleInterface, ParameterShape, RecoveredExportedDefinition,
 	RecoveredExportedImpl, RecoveredInterfaceType, RecoveredModuleInterface, SemanticAvailability,
@@ -146,9 +146,9 @@ pub fn declared_headers(identity: ModuleIdentity, module: &Module) -> DeclaredHe
 
 fn declaration_identity(declaration: &Declaration) -> Option<(DeclarationCategory, &EcoString)> {
 	Some(match declaration {
-		Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) => {
-			(DeclarationCategory::Function, &meta.name.0)
-		}
+		Declaration::Func { meta, .. }
+		| Declaration::Test { meta, .. }
+		| Declaration::ExternalFunc(_, _, meta) => (DeclarationCategory::Function, &meta.name.0),
 		Declaration::Effect { name, .. } => (DeclarationCategory::Effect, &name.0),
 		Declaration::Let { meta, .. } | Declaration::ExternalLet(_, _, meta) => {
 			let Pattern::Binding { name, .. } = &meta.name.0 else {
@@ -228,7 +228,9 @@ pub fn top_level_declarations(
 				Declaration::TypeAlias {
 					visibility, meta, ..
 				} => (*visibility, meta.name.1),
-				Declaration::Effect { visibility, name } => (*visibility, name.1),
+				Declaration::Effect {
+					visibility, name, ..
+				} => (*visibility, name.1),
 				Declaration::Struct {
 					visibility, name, ..
 				}
@@ -241,7 +243,11 @@ pub fn top_level_declarations(
 				| Declaration::Namespace {
 					visibility, name, ..
 				} => (*visibility, name.1),
-				Declaration::Import { .. } | Declaration::Impl { .. } | Declaration::ImplFor { .. } => {
+				// A test's synthesized function cannot be named by source.
+				Declaration::Import { .. }
+				| Declaration::Impl { .. }
+				| Declaration::ImplFor { .. }
+				| Declaration::Test { .. } => {
 					return None;
 				}
 			};
@@ -285,6 +291,17 @@ fn external_function_abi_with_result(
 	explicit_arity: Option<usize>,
 	result: Option<nymph_hir::hir::BuiltinResult>,
 ) -> ExternalAbi {
+	if let Some(adapter) = host_adapter(marker) {
+		let callable = crate::ExternalCallable::Linked { adapter };
+		return ExternalAbi {
+			marker: marker.clone(),
+			audit: external_audit(&callable),
+			callable,
+			effects: crate::EffectRow::pure(),
+			call_mode: crate::ExternalCallMode::Host,
+			marshal: crate::ExternalMarshalPlan::default(),
+		};
+	}
 	let callable = match nymph_hir::linkage::resolve(marker, receiver_tag, explicit_arity, result) {
 		nymph_hir::linkage::ExternalCallable::Linked(linked) => crate::ExternalCallable::Linked {
 			adapter: crate::ExternalAdapterId {
@@ -306,6 +323,15 @@ fn external_function_abi_with_result(
 	}
 }
 
+/// The adapter a user-declared `external("module", "symbol")` marker names
+/// directly, bypassing the compiler's linkage registry.
+fn host_adapter(marker: &str) -> Option<crate::ExternalAdapterId> {
+	nymph_ast::decl::host_binding(marker).map(|(module, symbol)| crate::ExternalAdapterId {
+		module: module.into(),
+		symbol: symbol.into(),
+	})
+}
+
 fn external_audit(callable: &crate::ExternalCallable) -> crate::ExternalAudit {
 	use crate::{ExternalState, ExternalTransaction};
 	use nymph_hir::linkage::ExternalEffect;
@@ -436,14 +462,18 @@ pub(crate) fn external_value_abi(
 	marshal: Option<nymph_hir::hir::MarshalKind>,
 ) -> ExternalAbi {
 	let linked = nymph_hir::linkage::lookup_value(marker).ok();
-	let callable = linked.map_or(crate::ExternalCallable::Deferred, |linkage| {
-		crate::ExternalCallable::Linked {
-			adapter: crate::ExternalAdapterId {
-				module: linkage.linked.module.into(),
-				symbol: linkage.linked.symbol.into(),
-			},
-		}
-	});
+	let callable = if let Some(adapter) = host_adapter(marker) {
+		crate::ExternalCallable::Linked { adapter }
+	} else {
+		linked.map_or(crate::ExternalCallable::Deferred, |linkage| {
+			crate::ExternalCallable::Linked {
+				adapter: crate::ExternalAdapterId {
+					module: linkage.linked.module.into(),
+					symbol: linkage.linked.symbol.into(),
+				},
+			}
+		})
+	};
 	let audit = external_audit(&callable);
 	ExternalAbi {
 		marker: marker.clone(),
@@ -474,6 +504,34 @@ fn external_marshal(ty: &InterfaceType) -> Option<nymph_hir::hir::MarshalKind> {
 	}
 }
 
+/// How a value of `ty` crosses a host binding. Unlike the trusted stdlib ABI,
+/// lists convert to plain arrays and a `Task` wraps the promise the host
+/// returns.
+fn host_marshal(ty: &InterfaceType) -> Option<nymph_hir::hir::MarshalKind> {
+	use nymph_hir::hir::{MarshalKind, PromiseMarshal};
+	fn scalar(ty: &InterfaceType) -> Option<nymph_hir::hir::ScalarMarshal> {
+		use nymph_hir::hir::ScalarMarshal;
+		match ty {
+			InterfaceType::Int => Some(ScalarMarshal::Int),
+			InterfaceType::UInt => Some(ScalarMarshal::UInt),
+			InterfaceType::Float => Some(ScalarMarshal::Float),
+			InterfaceType::Char => Some(ScalarMarshal::Char),
+			InterfaceType::String => Some(ScalarMarshal::String),
+			InterfaceType::Boolean => Some(ScalarMarshal::Boolean),
+			_ => None,
+		}
+	}
+	match ty {
+		InterfaceType::List(element) => scalar(element).map(MarshalKind::Array),
+		InterfaceType::Task { output, .. } => Some(MarshalKind::Promise(match output.as_ref() {
+			InterfaceType::Void => PromiseMarshal::Void,
+			InterfaceType::List(element) => PromiseMarshal::Array(scalar(element)?),
+			output => PromiseMarshal::Scalar(scalar(output)?),
+		})),
+		_ => scalar(ty).map(MarshalKind::from),
+	}
+}
+
 fn empty_definition(
 	id: DefinitionId,
 	name: EcoString,
@@ -483,6 +541,7 @@ fn empty_definition(
 	ExportedDefinition {
 		id,
 		name,
+		attributes: Vec::new(),
 		visibility,
 		kind,
 		declaration_kind: None,
@@ -927,6 +986,7 @@ fn checked_member_shape(
 	Ok(MemberShape {
 		id,
 		name: meta.name.0.clone(),
+		attributes: AttributeShape::from_source(&meta.attributes),
 		visibility,
 		kind: match meta.kind {
 			FuncKind::Instance => MemberKind::Function,
@@ -1164,6 +1224,7 @@ fn member_shape(
 			name.clone(),
 		)),
 		name,
+		attributes: AttributeShape::from_source(member.attributes()),
 		visibility,
 		kind,
 		binders: Vec::new(),
@@ -1364,6 +1425,7 @@ fn namespace_members(
 				Ok(MemberShape {
 					id: target.clone().unwrap_or(id),
 					name: name.0.clone(),
+					attributes: AttributeShape::from_source(&meta.attributes),
 					visibility: *visibility,
 					kind: match meta.kind {
 						nymph_ast::decl::LetKind::Instance | nymph_ast::decl::LetKind::Use => MemberKind::Value,
@@ -2115,7 +2177,9 @@ fn collect_declaration_provenance(
 				.then_some(crate::DefId(index as u32))
 			});
 		match declaration {
-			Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) => {
+			Declaration::Func { meta, .. }
+			| Declaration::Test { meta, .. }
+			| Declaration::ExternalFunc(_, _, meta) => {
 				if let Some(target) = def.and_then(|def| checker.defs.stable(def)) {
 					source.declarations.insert(target.clone(), meta.name.1);
 				}
@@ -2267,6 +2331,7 @@ fn checker_snapshot(checker: &crate::check::Checker<'_>) -> Checked {
 			annotations: crate::Annotations::default(),
 			runtime_roles: checker.stable_runtime_roles.clone(),
 			entry_root: None,
+			tests: Vec::new(),
 			external_value_marshals: Default::default(),
 			interner: checker.interner.clone(),
 			semantic: crate::CheckedSemantic {
@@ -2345,10 +2410,15 @@ fn extract_definition(
 		Declaration::Effect { visibility, .. } => {
 			empty_definition(id, source_name, *visibility, DefinitionShapeKind::Effect)
 		}
-		Declaration::Func {
-			visibility, meta, ..
-		}
-		| Declaration::ExternalFunc(visibility, _, meta) => {
+		Declaration::Func { .. } | Declaration::Test { .. } | Declaration::ExternalFunc(..) => {
+			let (visibility, meta) = match declaration {
+				Declaration::Func {
+					visibility, meta, ..
+				}
+				| Declaration::ExternalFunc(visibility, _, meta) => (visibility, meta),
+				Declaration::Test { meta, .. } => (&Some(Visibility::Private), meta),
+				_ => unreachable!("matched a function declaration"),
+			};
 			if matches!(declaration, Declaration::ExternalFunc(..))
 				&& meta
 					.effects
@@ -2394,6 +2464,11 @@ fn extract_definition(
 			shape.runtime_owner = Some(shape.id.clone());
 			if let Declaration::ExternalFunc(_, symbol, _) = declaration {
 				let return_type = shape.return_type.as_ref().unwrap_or(&InterfaceType::Void);
+				let marshal = if host_adapter(symbol).is_some() {
+					host_marshal
+				} else {
+					external_marshal
+				};
 				shape.external = Some(external_function_contract(
 					external_function_abi_for_receiver(
 						symbol,
@@ -2405,8 +2480,8 @@ fn extract_definition(
 					shape
 						.parameters
 						.iter()
-						.map(|parameter| external_marshal(&parameter.ty)),
-					external_marshal(return_type),
+						.map(|parameter| marshal(&parameter.ty)),
+					marshal(return_type),
 				));
 			}
 			shape
@@ -2479,6 +2554,7 @@ fn extract_definition(
 					Ok(FieldShape {
 						id: field_id.clone(),
 						name: field.0.name.0.clone(),
+						attributes: AttributeShape::from_source(&field.0.attributes),
 						visibility: Some(field.0.visibility.unwrap_or(Visibility::Internal)),
 						ty: canonicalize_type(&checked.interner, *ty, &generic_context)?,
 						has_default: field.0.default.is_some(),
@@ -2563,6 +2639,7 @@ fn extract_definition(
 									field.0.name.0.clone(),
 								)),
 								name: field.0.name.0.clone(),
+								attributes: AttributeShape::from_source(&field.0.attributes),
 								visibility: Some(field.0.visibility.unwrap_or(Visibility::Internal)),
 								ty: canonicalize_type(&checked.interner, *ty, &generic_context)?,
 								has_default: field.0.default.is_some(),
@@ -2573,6 +2650,7 @@ fn extract_definition(
 					Ok(VariantShape {
 						id: variant_id,
 						name: variant.0.name.0.clone(),
+						attributes: AttributeShape::from_source(&variant.0.attributes),
 						fields,
 					})
 				})
@@ -2754,7 +2832,9 @@ fn extract_definition(
 		}
 	};
 	let source_generics: &[nymph_ast::Spanned<nymph_ast::ty::GenericParam>] = match declaration {
-		Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) => &meta.generics,
+		Declaration::Func { meta, .. }
+		| Declaration::Test { meta, .. }
+		| Declaration::ExternalFunc(_, _, meta) => &meta.generics,
 		Declaration::TypeAlias { meta, .. } => &meta.generics,
 		Declaration::Struct { generics, .. }
 		| Declaration::Enum { generics, .. }
@@ -2765,6 +2845,7 @@ fn extract_definition(
 		binder.kind = generic_parameter_kind(generic.0.kind);
 	}
 	result.declaration_kind = declaration_member_kind(declaration);
+	result.attributes = AttributeShape::from_source(declaration.attributes());
 	// External ABI metadata comes from checked linkage during lowering; stable
 	// ownership is already represented here.
 	result.binders.reserve(0);
@@ -2773,12 +2854,12 @@ fn extract_definition(
 
 fn declaration_member_kind(declaration: &Declaration) -> Option<MemberKind> {
 	match declaration {
-		Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) => {
-			Some(match meta.kind {
-				FuncKind::Instance => MemberKind::Function,
-				FuncKind::Namespace => MemberKind::StaticFunction,
-			})
-		}
+		Declaration::Func { meta, .. }
+		| Declaration::Test { meta, .. }
+		| Declaration::ExternalFunc(_, _, meta) => Some(match meta.kind {
+			FuncKind::Instance => MemberKind::Function,
+			FuncKind::Namespace => MemberKind::StaticFunction,
+		}),
 		Declaration::Let { meta, .. } | Declaration::ExternalLet(_, _, meta) => Some(match meta.kind {
 			nymph_ast::decl::LetKind::Instance | nymph_ast::decl::LetKind::Use => MemberKind::Value,
 			nymph_ast::decl::LetKind::Namespace => MemberKind::StaticValue,
@@ -3369,6 +3450,7 @@ pub fn extract_module_interface_from_facts_with_selection(
 			| Declaration::Namespace { visibility, .. }
 			| Declaration::ExternalFunc(visibility, ..)
 			| Declaration::ExternalLet(visibility, ..) => *visibility,
+			Declaration::Test { .. } => Some(Visibility::Private),
 			_ => None,
 		};
 		if facts.include_private_definitions || visible(visibility) {
@@ -3435,8 +3517,10 @@ pub fn namespace_summary(identity: ModuleIdentity, module: &Module) -> crate::Na
 				| Declaration::TypeAlias { visibility, .. }
 				| Declaration::Interface { visibility, .. }
 				| Declaration::Namespace { visibility, .. }
+				| Declaration::Effect { visibility, .. }
 				| Declaration::ExternalFunc(visibility, ..)
 				| Declaration::ExternalLet(visibility, ..) => *visibility,
+				Declaration::Test { .. } => Some(Visibility::Private),
 				_ => return None,
 			};
 			Some((member, source_name(name), visibility))
@@ -3870,6 +3954,7 @@ fn recover_func_member(
 	MemberShape {
 		id,
 		name: meta.name.0.clone(),
+		attributes: AttributeShape::from_source(&meta.attributes),
 		visibility,
 		kind: match meta.kind {
 			FuncKind::Instance => MemberKind::Function,
@@ -3911,6 +3996,7 @@ fn recover_value_member(
 			name.0.clone(),
 		)),
 		name: name.0.clone(),
+		attributes: AttributeShape::from_source(&meta.attributes),
 		visibility,
 		kind: match meta.kind {
 			nymph_ast::decl::LetKind::Instance | nymph_ast::decl::LetKind::Use => MemberKind::Value,
@@ -4064,6 +4150,7 @@ fn recover_implementations(
 				type_,
 				for_interface: (interface_name, source_arguments),
 				members,
+				..
 			} = declaration
 			else {
 				return None;
@@ -4253,6 +4340,7 @@ fn recover_implementations(
 			generics,
 			type_,
 			members,
+			..
 		} = declaration
 		else {
 			continue;
@@ -4511,6 +4599,7 @@ fn poison_definition(
 		| Declaration::Effect { visibility, .. }
 		| Declaration::ExternalFunc(visibility, ..)
 		| Declaration::ExternalLet(visibility, ..) => *visibility,
+		Declaration::Test { .. } => Some(Visibility::Private),
 		_ => None,
 	};
 	let (kind, binders, parameters, return_type, ty, fields, variants, members) = match declaration {
@@ -4524,7 +4613,9 @@ fn poison_definition(
 			Vec::new(),
 			Vec::new(),
 		),
-		Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) => (
+		Declaration::Func { meta, .. }
+		| Declaration::Test { meta, .. }
+		| Declaration::ExternalFunc(_, _, meta) => (
 			DefinitionShapeKind::Function,
 			poison_binders(id.clone(), &meta.generics),
 			meta
@@ -4585,6 +4676,7 @@ fn poison_definition(
 							field.0.name.0.clone(),
 						)),
 						name: field.0.name.0.clone(),
+						attributes: AttributeShape::from_source(&field.0.attributes),
 						visibility: Some(field.0.visibility.unwrap_or(Visibility::Internal)),
 						ty: recover_ast_type(
 							&field.0.type_.0,
@@ -4625,6 +4717,7 @@ fn poison_definition(
 					VariantShape {
 						id: id.clone(),
 						name: variant.0.name.0.clone(),
+						attributes: AttributeShape::from_source(&variant.0.attributes),
 						fields: variant
 							.0
 							.fields
@@ -4636,6 +4729,7 @@ fn poison_definition(
 									field.0.name.0.clone(),
 								)),
 								name: field.0.name.0.clone(),
+								attributes: AttributeShape::from_source(&field.0.attributes),
 								visibility: Some(field.0.visibility.unwrap_or(Visibility::Internal)),
 								ty: recover_ast_type(&field.0.type_.0, headers, &binders),
 								has_default: field.0.default.is_some(),
@@ -4735,6 +4829,7 @@ fn poison_definition(
 	let mut recovered = RecoveredExportedDefinition {
 		id,
 		name: name.clone(),
+		attributes: AttributeShape::from_source(declaration.attributes()),
 		visibility,
 		kind,
 		declaration_kind: declaration_member_kind(declaration),
@@ -4754,7 +4849,9 @@ fn poison_definition(
 		runtime_owner: None,
 	};
 	let generics: &[nymph_ast::Spanned<nymph_ast::ty::GenericParam>] = match declaration {
-		Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) => &meta.generics,
+		Declaration::Func { meta, .. }
+		| Declaration::Test { meta, .. }
+		| Declaration::ExternalFunc(_, _, meta) => &meta.generics,
 		Declaration::TypeAlias { meta, .. } => &meta.generics,
 		Declaration::Struct { generics, .. }
 		| Declaration::Enum { generics, .. }
@@ -4762,7 +4859,10 @@ fn poison_definition(
 		_ => &[],
 	};
 	recovered.constraints = recover_generic_constraints(generics, &recovered.binders, headers);
-	if let Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) = declaration {
+	if let Declaration::Func { meta, .. }
+	| Declaration::Test { meta, .. }
+	| Declaration::ExternalFunc(_, _, meta) = declaration
+	{
 		recovered.effects = match &meta.effects {
 			Some(row) if row.0.requests_inference() || row.0.contains_error() => {
 				crate::RecoveredEffectRow::Poison
@@ -5084,6 +5184,7 @@ impl From<ExportedDefinition> for RecoveredExportedDefinition {
 		Self {
 			id: value.id,
 			name: value.name,
+			attributes: value.attributes,
 			visibility: value.visibility,
 			kind: value.kind,
 			declaration_kind: value.declaration_kind,
@@ -5126,6 +5227,7 @@ impl From<ExportedDefinition> for RecoveredExportedDefinition {
 				.map(|f| FieldShape {
 					id: f.id,
 					name: f.name,
+					attributes: f.attributes,
 					visibility: f.visibility,
 					ty: RecoveredInterfaceType::Known(f.ty),
 					has_default: f.has_default,
@@ -5138,12 +5240,14 @@ impl From<ExportedDefinition> for RecoveredExportedDefinition {
 				.map(|v| VariantShape {
 					id: v.id,
 					name: v.name,
+					attributes: v.attributes,
 					fields: v
 						.fields
 						.into_iter()
 						.map(|f| FieldShape {
 							id: f.id,
 							name: f.name,
+							attributes: f.attributes,
 							visibility: f.visibility,
 							ty: RecoveredInterfaceType::Known(f.ty),
 							has_default: f.has_default,
@@ -5159,6 +5263,7 @@ impl From<ExportedDefinition> for RecoveredExportedDefinition {
 				.map(|m| MemberShape {
 					id: m.id,
 					name: m.name,
+					attributes: m.attributes,
 					visibility: m.visibility,
 					kind: m.kind,
 					binders: m.binders,
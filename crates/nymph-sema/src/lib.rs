@@ -20,6 +20,7 @@ mod anon_closure;
 mod check;
 mod coerce;
 mod def;
+mod derive;
 mod effects;
 mod entry;
 mod environment;
@@ -39,6 +40,7 @@ mod runtime;
 mod solve;
 mod stable_lowering;
 mod unify;
+mod unused;
 
 pub use analysis::{
 	DeclarationProvenance, ImportReferenceTarget, ModuleAnnotations, SemanticAnalysis,
@@ -56,8 +58,9 @@ pub use def::{
 	AliasSig, DefData, DefKind, DefMap, DefOrigin, FieldSigMetadata, NamespaceMemberSig,
 	NamespaceSig, OwnedMemberSig, Signatures, ValueSig,
 };
+pub use derive::{DERIVABLE_INTERFACES, expand_derives};
 pub use effects::*;
-pub use entry::EntryRootShape;
+pub use entry::{EntryRootShape, TestRoot};
 pub use environment::*;
 pub use errors::TypeError;
 pub use identity::{
@@ -75,12 +78,14 @@ pub use nymph_hir::ty::{self, GenericArgs, Interner, Ty, TyKind};
 pub use runtime::{
 	BodyNodeId, BuiltinDispatch, CheckedRuntimeBody, DispatchMaterialization, EnumShell,
 	ExpressionVariant, PatternNodeId, PatternVariant, RangeDecision, RangeEvidence, RangeOperation,
-	RangeProof, RuntimeAnnotations, RuntimeBodyKind, RuntimeDefinition, RuntimeExtractionError,
+	RangeProof, RuntimeAnnotations, RuntimeBodyKind, RuntimeBodySpans, RuntimeDefinition,
+	RuntimeExtractionError,
 	RuntimeIteration, RuntimePayload, RuntimePlacement, RuntimePropagationKind, StableBody,
 	StableCallArg, StableDispatch, StableExpr, StableExprKind, StableListItem,
 	StableListPatternEntry, StableMapEntry, StableMapPatternEntry, StableMatchArm, StableParameter,
 	StablePattern, StablePatternKind, StablePatternRange, StableRange, StableStatement,
 	StableStringPart, StableStringPatternPart, StableStructPatternField, StableVariantField,
-	StructShell, VariantExpressionMode, VariantPatternMode, runtime_definitions,
+	StructShell, VariantExpressionMode, VariantPatternMode, runtime_body_spans, runtime_definitions,
 };
 pub use stable_lowering::*;
+pub use unused::{UnusedBinding, unused_bindings};
@@ -303,7 +303,7 @@ impl Checker<'_> {
 				}
 				DefKind::Func => {
 					let (meta, has_body) = match &module.members[member] {
-						Declaration::Func { meta, .. } => (meta, true),
+						Declaration::Func { meta, .. } | Declaration::Test { meta, .. } => (meta, true),
 						Declaration::ExternalFunc(_, _, meta) => (meta, false),
 						_ => continue,
 					};
@@ -1017,6 +1017,12 @@ impl<'m> Checker<'m> {
 			self.param_bounds.clear();
 			self.param_bound_details.clear();
 			self.record_param_bounds(generics, 0);
+			if let Some(owner) = crate::derive::derived_owner(module, &for_interface.0)
+				&& !self.derived_fields_implement(owner, &for_interface.0)
+			{
+				self.pop_params();
+				continue;
+			}
 			let self_ty = self.lower_type(type_);
 			let bound_ty = self.shallow_resolve(self_ty);
 			if let crate::ty::TyKind::Param(param) = *self.interner.kind(bound_ty) {
@@ -109,6 +109,8 @@ pub enum ImportedNameKind {
 pub struct ImportedName {
 	pub name: String,
 	pub kind: ImportedNameKind,
+	/// Whether the named definition is marked `@deprecated`.
+	pub deprecated: bool,
 }
 
 /// Project resolved import bindings into immutable, editor-facing facts.
@@ -123,6 +125,7 @@ pub fn imported_names(
 	modules: &[Arc<ModuleEnvironment>],
 	module: &Module,
 ) -> Vec<ImportedName> {
+	let deprecations = crate::module_deprecations(modules);
 	let mut names = bindings
 		.iter()
 		.filter_map(|(name, binding)| {
@@ -157,6 +160,10 @@ pub fn imported_names(
 			Some(ImportedName {
 				name: name.to_string(),
 				kind,
+				deprecated: matches!(
+					binding,
+					ResolvedImportBinding::Definition(definition) if deprecations.contains_key(definition)
+				),
 			})
 		})
 		.collect::<Vec<_>>();
@@ -221,6 +228,9 @@ pub fn imported_names(
 			names.push(ImportedName {
 				name: variant,
 				kind: ImportedNameKind::Variant,
+				deprecated: candidates
+					.iter()
+					.any(|candidate| deprecations.contains_key(candidate)),
 			});
 		}
 	}
@@ -755,7 +765,7 @@ fn decl_generic_scope(decl: &Declaration, offset: usize) -> Option<Vec<EcoString
 		| Declaration::ExternalFunc(..)
 		| Declaration::TypeAlias { .. } => None,
 		Declaration::Let { value, .. } => covers(value.span, offset).then(Vec::new),
-		Declaration::Func { meta, body, .. } => {
+		Declaration::Func { meta, body, .. } | Declaration::Test { meta, body, .. } => {
 			covers(body.span, offset).then(|| names_of(&meta.generics))
 		}
 		Declaration::Struct {
@@ -937,7 +947,9 @@ fn collect_decl_exprs<'a>(decl: &'a Declaration, out: &mut Vec<&'a Expr>) {
 		| Declaration::ExternalLet(..)
 		| Declaration::ExternalFunc(..)
 		| Declaration::TypeAlias { .. } => {}
-		Declaration::Let { value, .. } | Declaration::Func { body: value, .. } => {
+		Declaration::Let { value, .. }
+		| Declaration::Func { body: value, .. }
+		| Declaration::Test { body: value, .. } => {
 			collect_expr(value, out);
 		}
 		Declaration::Struct {
@@ -1026,9 +1038,16 @@ fn collect_expr<'a>(expr: &'a Expr, out: &mut Vec<&'a Expr>) {
 	expr.for_each_child(|child| collect_expr(child, out));
 }
 
-/// Source spans of every privileged `echo` keyword in declaration order.
+/// One privileged `echo` observation: its keyword and the observed operand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EchoSite {
+	pub keyword: Span,
+	pub operand: Span,
+}
+
+/// Every privileged `echo` observation in declaration order.
 #[must_use]
-pub fn echo_sites(module: &Module) -> Vec<Span> {
+pub fn echo_sites(module: &Module) -> Vec<EchoSite> {
 	let mut expressions = Vec::new();
 	for declaration in &module.members {
 		collect_decl_exprs(declaration, &mut expressions);
@@ -1036,7 +1055,10 @@ pub fn echo_sites(module: &Module) -> Vec<Span> {
 	expressions
 		.into_iter()
 		.filter_map(|expression| match &expression.kind {
-			ExprKind::Echo { keyword, .. } => Some(*keyword),
+			ExprKind::Echo { keyword, operand } => Some(EchoSite {
+				keyword: *keyword,
+				operand: operand.span,
+			}),
 			_ => None,
 		})
 		.collect()
@@ -1152,6 +1174,26 @@ pub fn references_to(
 	occurrences
 }
 
+/// Return every use of a deprecated dependency definition, ordered by span.
+/// Only checker-recorded references count: spelling a deprecated name in an
+/// import list is not itself a use.
+#[must_use]
+pub fn deprecated_uses(analysis: &crate::SemanticAnalysis) -> Vec<(Span, &crate::Deprecation)> {
+	let mut uses = all_symbol_occurrences(analysis)
+		.into_iter()
+		.filter_map(|(identity, occurrence)| match identity {
+			SymbolIdentity::Definition(target) if !occurrence.is_declaration => analysis
+				.deprecations
+				.get(&target)
+				.map(|deprecation| (occurrence.span, deprecation)),
+			_ => None,
+		})
+		.collect::<Vec<_>>();
+	uses.sort_unstable_by_key(|(span, _)| (span.start, span.end));
+	uses.dedup_by_key(|(span, _)| *span);
+	uses
+}
+
 /// How a semantic occurrence must be rewritten during rename.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RenameReplacement {
@@ -1249,7 +1291,7 @@ fn uniquely_editable_occurrences(
 	Some(selected)
 }
 
-fn all_symbol_occurrences(
+pub(crate) fn all_symbol_occurrences(
 	analysis: &crate::SemanticAnalysis,
 ) -> Vec<(SymbolIdentity, ReferenceOccurrence)> {
 	let mut result = analysis
@@ -1690,13 +1732,15 @@ fn is_shadowed_by_local(module: &Module, target_id: NodeId) -> bool {
 		.any(|decl| matches!(walk_decl_for_binder(decl, target_id), Some(Some(_))))
 }
 
-/// The [`FuncDeclaration`] meta of a top-level `func`/`external func`
+/// The [`FuncDeclaration`] meta of a top-level `func`/`external func`/`test`
 /// declaration, for [`render_named_signature`]. `None` for any other
 /// declaration kind — a [`crate::def::DefKind::Func`] always indexes one of
-/// these two arms, so this only fails to find one if `member` is stale.
+/// these three arms, so this only fails to find one if `member` is stale.
 fn func_decl_meta(decl: &Declaration) -> Option<&FuncDeclaration> {
 	match decl {
-		Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) => Some(meta),
+		Declaration::Func { meta, .. }
+		| Declaration::Test { meta, .. }
+		| Declaration::ExternalFunc(_, _, meta) => Some(meta),
 		_ => None,
 	}
 }
@@ -1712,7 +1756,7 @@ fn walk_decl_for_binder(decl: &Declaration, target_id: NodeId) -> Option<Option<
 		| Declaration::ExternalLet(..)
 		| Declaration::ExternalFunc(..)
 		| Declaration::TypeAlias { .. } => None,
-		Declaration::Func { meta, body, .. } => {
+		Declaration::Func { meta, body, .. } | Declaration::Test { meta, body, .. } => {
 			let mut scopes = Vec::new();
 			for p in &meta.params {
 				pattern_bindings(&p.0.name.0, &mut scopes);
@@ -2072,7 +2116,9 @@ fn this_method_definition_at(module: &Module, offset: usize) -> Option<Span> {
 fn collect_decl_type_refs<'a>(decl: &'a Declaration, out: &mut Vec<(&'a Ident, Span)>) {
 	match decl {
 		Declaration::Effect { .. } => {}
-		Declaration::Func { meta, .. } | Declaration::ExternalFunc(_, _, meta) => {
+		Declaration::Func { meta, .. }
+		| Declaration::Test { meta, .. }
+		| Declaration::ExternalFunc(_, _, meta) => {
 			for p in &meta.params {
 				collect_type_refs(&p.0.type_, out);
 			}
@@ -2711,7 +2757,7 @@ fn has_unresolved_infer(interner: &Interner, ty: Ty) -> bool {
 /// declaration kind.
 fn func_decl_body(decl: &Declaration) -> Option<&Expr> {
 	match decl {
-		Declaration::Func { body, .. } => Some(body),
+		Declaration::Func { body, .. } | Declaration::Test { body, .. } => Some(body),
 		_ => None,
 	}
 }
@@ -3611,7 +3657,7 @@ fn collect_fallback_decl(
 			}
 			collect_fallback_exprs(value, checked, module, defs, params, out);
 		}
-		Declaration::Func { meta, body, .. } => {
+		Declaration::Func { meta, body, .. } | Declaration::Test { meta, body, .. } => {
 			let inferred_ret = inferred_return(body, checked, module, defs);
 			out.push((meta.name.1, render_named_signature(meta, inferred_ret)));
 			push_generic_param_candidates(&meta.generics, out);
@@ -3628,6 +3674,7 @@ fn collect_fallback_decl(
 			fields,
 			members,
 			impls,
+			..
 		} => {
 			out.push((
 				name.1,
@@ -3961,7 +4008,7 @@ fn collect_decl_scope_names(decl: &Declaration, offset: usize, out: &mut ScopeNa
 		| Declaration::ExternalLet(..)
 		| Declaration::ExternalFunc(..)
 		| Declaration::TypeAlias { .. } => {}
-		Declaration::Func { meta, body, .. } => {
+		Declaration::Func { meta, body, .. } | Declaration::Test { meta, body, .. } => {
 			if covers(meta.name.1.to(body.span), offset) {
 				let mut scopes = Vec::new();
 				for p in &meta.params {
@@ -5413,6 +5460,7 @@ mod member_completion_tests {
 				checked: facts,
 				declarations: Arc::default(),
 				import_references: Arc::default(),
+				deprecations: Arc::default(),
 			},
 			diagnostics,
 		)
@@ -5636,14 +5684,17 @@ mod imported_name_tests {
 				ImportedName {
 					name: "Shape".into(),
 					kind: ImportedNameKind::Struct,
+					deprecated: false,
 				},
 				ImportedName {
 					name: "dependency".into(),
 					kind: ImportedNameKind::ModuleNamespace,
+					deprecated: false,
 				},
 				ImportedName {
 					name: "renamed".into(),
 					kind: ImportedNameKind::Function,
+					deprecated: false,
 				},
 			]
 		);
@@ -94,7 +94,9 @@ fn bodies(module: &Module) -> Vec<&Expr> {
 	let mut result = Vec::new();
 	for declaration in &module.members {
 		match declaration {
-			Declaration::Let { value, .. } | Declaration::Func { body: value, .. } => result.push(value),
+			Declaration::Let { value, .. }
+			| Declaration::Func { body: value, .. }
+			| Declaration::Test { body: value, .. } => result.push(value),
 			Declaration::Struct {
 				members,
 				impls,
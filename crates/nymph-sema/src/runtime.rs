@@ -878,6 +878,36 @@ pub struct RuntimeDefinition {
 	pub payload: RuntimePayload,
 }
 
+/// Source positions of one runtime body, indexed by [`BodyNodeId`]. Kept apart
+/// from [`CheckedRuntimeBody`] so that moving code never invalidates the
+/// position-free artifacts; only source maps consume these.
+#[derive(Clone, Debug, PartialEq, Eq, salsa::SalsaValue)]
+pub struct RuntimeBodySpans {
+	pub definition: DefinitionId,
+	pub nodes: Arc<[Span]>,
+}
+
+#[derive(Default)]
+struct Extraction {
+	definitions: Vec<RuntimeDefinition>,
+	spans: Vec<RuntimeBodySpans>,
+}
+
+impl Extraction {
+	fn push(&mut self, definition: RuntimeDefinition) {
+		self.definitions.push(definition);
+	}
+
+	fn record_spans(&mut self, definition: &DefinitionId, body: &Expr) {
+		let mut nodes = Vec::new();
+		walk_expr(body, &mut nodes);
+		self.spans.push(RuntimeBodySpans {
+			definition: definition.clone(),
+			nodes: nodes.into_iter().map(|expr| expr.span).collect(),
+		});
+	}
+}
+
 /// Project top-level runtime artifacts directly from checker facts. Member and
 /// aggregate channels are represented by the schema and projected through
 /// stable definition identity; no name- or span-based lookup is used.
@@ -886,7 +916,25 @@ pub fn runtime_definitions(
 	checked: &crate::CheckedFacts,
 	interface: &crate::ModuleInterface,
 ) -> Result<Vec<RuntimeDefinition>, RuntimeExtractionError> {
-	let mut result = Vec::new();
+	extract(module, checked, interface).map(|extraction| extraction.definitions)
+}
+
+/// The source positions of every Nymph body [`runtime_definitions`] extracts
+/// from `module`, in the same order.
+pub fn runtime_body_spans(
+	module: &nymph_ast::decl::Module,
+	checked: &crate::CheckedFacts,
+	interface: &crate::ModuleInterface,
+) -> Result<Vec<RuntimeBodySpans>, RuntimeExtractionError> {
+	extract(module, checked, interface).map(|extraction| extraction.spans)
+}
+
+fn extract(
+	module: &nymph_ast::decl::Module,
+	checked: &crate::CheckedFacts,
+	interface: &crate::ModuleInterface,
+) -> Result<Extraction, RuntimeExtractionError> {
+	let mut result = Extraction::default();
 	let shapes = interface
 		.exports
 		.iter()
@@ -897,7 +945,8 @@ pub fn runtime_definitions(
 	};
 	for (declaration_index, declaration) in module.members.iter().enumerate() {
 		match declaration {
-			nymph_ast::decl::Declaration::Func { meta, body, .. } => {
+			nymph_ast::decl::Declaration::Func { meta, body, .. }
+			| nymph_ast::decl::Declaration::Test { meta, body, .. } => {
 				let definition = required_top_level(checked, &meta.name.0)?;
 				push_body(
 					&mut result,
@@ -1117,6 +1166,7 @@ pub fn runtime_definitions(
 				continue;
 			}
 			if result
+				.definitions
 				.iter()
 				.any(|artifact| artifact.definition == slot.member_id)
 			{
@@ -1188,7 +1238,7 @@ fn attached(member: &crate::MemberShape<InterfaceType>) -> RuntimePlacement {
 	}
 }
 fn push_external(
-	result: &mut Vec<RuntimeDefinition>,
+	result: &mut Extraction,
 	definition: DefinitionId,
 	placement: RuntimePlacement,
 	abi: Option<crate::ExternalAbi>,
@@ -1202,7 +1252,7 @@ fn push_external(
 	Ok(())
 }
 fn extract_members(
-	result: &mut Vec<RuntimeDefinition>,
+	result: &mut Extraction,
 	syntax: &[nymph_ast::Spanned<ImplMember>],
 	shapes: &[crate::MemberShape<InterfaceType>],
 	module_placed: bool,
@@ -1255,7 +1305,7 @@ fn required_implementation<'a>(
 }
 
 fn extract_implementation_members(
-	result: &mut Vec<RuntimeDefinition>,
+	result: &mut Extraction,
 	syntax: &[nymph_ast::Spanned<ImplMember>],
 	implementation: &crate::ExportedImpl,
 	checked: &crate::CheckedFacts,
@@ -1304,7 +1354,7 @@ fn extract_implementation_members(
 	Ok(())
 }
 fn push_value(
-	result: &mut Vec<RuntimeDefinition>,
+	result: &mut Extraction,
 	definition: DefinitionId,
 	placement: RuntimePlacement,
 	value: &Expr,
@@ -1325,7 +1375,7 @@ fn push_value(
 }
 
 fn push_body(
-	result: &mut Vec<RuntimeDefinition>,
+	result: &mut Extraction,
 	definition: DefinitionId,
 	placement: RuntimePlacement,
 	meta: &FuncDeclaration,
@@ -1350,7 +1400,7 @@ fn push_body(
 }
 
 fn push_canonical_body(
-	result: &mut Vec<RuntimeDefinition>,
+	result: &mut Extraction,
 	definition: DefinitionId,
 	placement: RuntimePlacement,
 	kind: RuntimeBodyKind,
@@ -1360,7 +1410,7 @@ fn push_canonical_body(
 	is_async: bool,
 	checked: &crate::CheckedFacts,
 ) -> Result<(), RuntimeExtractionError> {
-	let body = checked_runtime_body(
+	let checked_body = checked_runtime_body(
 		&definition,
 		kind,
 		immutable,
@@ -1369,11 +1419,12 @@ fn push_canonical_body(
 		is_async,
 		checked,
 	)?;
+	result.record_spans(&definition, body);
 	result.push(RuntimeDefinition {
 		source_owner: definition.module.clone(),
 		definition,
 		placement,
-		payload: RuntimePayload::NymphBody(body),
+		payload: RuntimePayload::NymphBody(checked_body),
 	});
 	Ok(())
 }
@@ -1914,7 +1965,8 @@ fn runtime_annotations(
 									..
 								}
 							)
-						}) || checked.annotations.variant_of(expression.id).is_some()
+						})
+						|| checked.annotations.variant_of(expression.id).is_some()
 				}
 				_ => checked.annotations.variant_of(expression.id).is_some(),
 			};
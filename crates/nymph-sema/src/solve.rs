@@ -308,6 +308,35 @@ impl Checker<'_> {
 		false
 	}
 
+	/// [`Self::holds`], counting only impls that define `member` themselves, so
+	/// an extension-only blanket impl (`impl<T> Comparable<Other = T> for T`
+	/// adding `minmax`) is not mistaken for an implementation.
+	pub(crate) fn holds_defining(
+		&mut self,
+		self_ty: Ty,
+		interface: DefId,
+		member: &str,
+		known: &[(EcoString, Ty)],
+	) -> bool {
+		let resolved = self.shallow_resolve(self_ty);
+		if matches!(self.interner.kind(resolved), TyKind::Param(_)) {
+			return self.holds(resolved, interface, known, 0);
+		}
+		let head = head_of(&self.interner, resolved);
+		for idx in self.impls.candidates(interface, head) {
+			if !self.impls.impls[idx].methods.contains_key(member) {
+				continue;
+			}
+			let snapshot = self.table.snapshot();
+			let matched = self.try_impl(idx, self_ty, known, 0);
+			self.table.rollback_to(snapshot);
+			if matched {
+				return true;
+			}
+		}
+		false
+	}
+
 	/// Trial: can impl `idx` satisfy the obligation `self_ty: interface<known>`?
 	/// Binds inference variables (the caller controls rollback).
 	fn try_impl(&mut self, idx: usize, self_ty: Ty, known: &[(EcoString, Ty)], depth: u32) -> bool {
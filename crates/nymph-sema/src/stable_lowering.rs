@@ -928,6 +928,7 @@ fn external_call_expr(
 				call_mode: match abi.call_mode {
 					crate::ExternalCallMode::Ordinary => nymph_hir::hir::ExternalCallMode::Ordinary,
 					crate::ExternalCallMode::Cancellable => nymph_hir::hir::ExternalCallMode::Cancellable,
+					crate::ExternalCallMode::Host => nymph_hir::hir::ExternalCallMode::Host,
 				},
 				argument_marshals,
 				return_marshal,
@@ -2254,6 +2255,7 @@ fn lower_body(
 		self_type,
 		implementation_slots,
 		receiver_binding: has_receiver.then(|| EcoString::from("$self")),
+		receiver_read: Cell::new(false),
 	};
 	if has_receiver {
 		lowerer.scopes.borrow_mut()[0].insert("this".into(), "$self".into());
@@ -2331,6 +2333,52 @@ fn lower_body(
 					},
 				});
 			}
+			// A member of a generic list or map impl that never reads `this`
+			// (`T.from_json(..)` with `T = #[U]`) can be reached through a type
+			// witness rather than a receiver tag, so it also lives on the shared
+			// prototype and projects its type arguments from that witness.
+			// Members reading `this` stay reachable by tag dispatch alone.
+			if has_receiver
+				&& !lowerer.receiver_read.get()
+				&& !exact_shellless_implementation
+				&& !blanket_implementation
+				&& let Some(binding) = self_type.and_then(|ty| match ty {
+					InterfaceType::List(_) => Some("NList"),
+					InterfaceType::Map(..) => Some("NMap"),
+					_ => None,
+				}) {
+				let hidden_params = (0..type_parameters.len())
+					.map(|index| EcoString::from(format!("$type${index}")))
+					.collect::<Vec<_>>();
+				let forwarded_args = std::iter::once(HirExpr::This)
+					.chain(
+						params
+							.iter()
+							.chain(&hidden_params)
+							.cloned()
+							.map(HirExpr::Local),
+					)
+					.collect();
+				return Ok(LoweredHirFragment::RuntimeTypeAttachment {
+					object: HirExpr::RuntimeTypeObject {
+						binding: binding.into(),
+						box_runtime: true,
+						is_enum: false,
+						arguments: vec![],
+					},
+					function: Some(function.clone()),
+					method: HirMethod {
+						name: context.member_name(&artifact.definition)?.as_str().into(),
+						params: params.into_iter().chain(hidden_params).collect(),
+						body: HirExpr::ActivationCall {
+							callee: Box::new(HirExpr::Local(emitted)),
+							args: forwarded_args,
+							mode: nymph_hir::hir::HirCallMode::Tail,
+							source: 0,
+						},
+					},
+				});
+			}
 			return Ok(LoweredHirFragment::TopLevelFunction(function));
 		}
 		match &artifact.placement {
@@ -2486,6 +2534,9 @@ struct StableBodyLowerer<'a, C> {
 	self_type: Option<&'a InterfaceType>,
 	implementation_slots: Option<&'a crate::ImplementationMemberCatalog>,
 	receiver_binding: Option<EcoString>,
+	/// Whether the body reads `this` as a value, rather than only projecting
+	/// type arguments from it.
+	receiver_read: Cell<bool>,
 }
 fn js_reserved_word(name: &str) -> bool {
 	matches!(
@@ -3502,11 +3553,14 @@ impl<C: StableLoweringContext> StableBodyLowerer<'_, C> {
 			StableExprKind::AnonymousParam(index) => {
 				HirExpr::Local(self.resolve(&crate::anon_closure::anon_param_name(index.unwrap_or(0))))
 			}
-			StableExprKind::This => self
-				.receiver_binding
-				.clone()
-				.map(HirExpr::Local)
-				.unwrap_or(HirExpr::This),
+			StableExprKind::This => {
+				self.receiver_read.set(true);
+				self
+					.receiver_binding
+					.clone()
+					.map(HirExpr::Local)
+					.unwrap_or(HirExpr::This)
+			}
 			StableExprKind::Grouped(inner) => {
 				let lowered = self.lower(inner)?;
 				if matches!(&lowered, HirExpr::Local(_) | HirExpr::Field { .. }) {
@@ -5431,6 +5485,7 @@ impl<C: StableLoweringContext> StableBodyLowerer<'_, C> {
 						call_mode: match abi.call_mode {
 							crate::ExternalCallMode::Ordinary => nymph_hir::hir::ExternalCallMode::Ordinary,
 							crate::ExternalCallMode::Cancellable => nymph_hir::hir::ExternalCallMode::Cancellable,
+							crate::ExternalCallMode::Host => nymph_hir::hir::ExternalCallMode::Host,
 						},
 					},
 					crate::ExternalCallable::Native(_) => {
@@ -0,0 +1,668 @@
+//! Unused-code findings over a checked module, for the project compiler's
+//! `unused-*` lints.
+//!
+//! Every finding is derived from the checker's recorded occurrences (the same
+//! ones rename and find-references use), never from spelling: a binding is
+//! unused when no checker-resolved reference names its declaration. Each
+//! finding carries the replacements of its machine-applicable fix, computed
+//! against the exact source the module was parsed from. Generated code, such as
+//! derived impls, lies past the end of that source and is never reported.
+
+use ecow::EcoString;
+use nymph_ast::{
+	Ident, Span, Spanned,
+	decl::{
+		Declaration, FuncDeclaration, ImplMember, InterfaceElement, InterfaceMember, LetKind,
+		Visibility,
+	},
+	expr::{Expr, ExprKind, Statement},
+	ty::GenericParam,
+};
+use nymph_diagnostics::TextReplacement;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+	DeclarationKey, DefinitionId, ImportReferenceTarget, ModuleIdentity, SemanticAnalysis, TyKind,
+	query::{ReferenceOccurrence, SymbolIdentity},
+};
+
+/// One declaration the module never reads, with the fix that removes it or
+/// marks it intentionally unused.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnusedBinding {
+	/// The `[lints]` name governing this finding.
+	pub lint: &'static str,
+	pub message: EcoString,
+	pub span: Span,
+	/// Title of the fix.
+	pub fix: EcoString,
+	/// Non-overlapping source replacements that apply the fix.
+	pub replacements: Vec<TextReplacement>,
+}
+
+/// Every unused variable, parameter, type parameter, private function and
+/// import of `analysis`, and every discarded `Result`, ordered by span.
+///
+/// `source` is the text the analyzed module was parsed from. Import findings
+/// need the compiler-installed [`SemanticAnalysis::import_references`]; a
+/// standalone analysis without them reports no imports.
+#[must_use]
+pub fn unused_bindings(analysis: &SemanticAnalysis, source: &str) -> Vec<UnusedBinding> {
+	let occurrences = crate::query::all_symbol_occurrences(analysis);
+	let mut sites = Sites::default();
+	for declaration in &analysis.module.members {
+		sites.declaration(declaration);
+	}
+	let mut found = Vec::new();
+	unused_locals(analysis, source, &occurrences, &sites, &mut found);
+	unused_type_parameters(source, &occurrences, &sites, &mut found);
+	unused_private_functions(source, &occurrences, &sites, &mut found);
+	unused_imports(analysis, source, &occurrences, &mut found);
+	unused_results(analysis, &sites, &mut found);
+	found.retain(|unused| unused.span.end <= source.len());
+	found.sort_by_key(|unused| (unused.span.start, unused.span.end));
+	found
+}
+
+/// Syntax the occurrence tables alone cannot classify.
+#[derive(Default)]
+struct Sites<'a> {
+	/// Parameter patterns of callables that choose their own signature.
+	parameters: Vec<Span>,
+	/// Patterns that must keep their bindings: parameters whose signature an
+	/// interface dictates, and `let use` bindings held for their cleanup.
+	exempt: Vec<Span>,
+	/// Generic parameter names of callables that choose their own signature.
+	type_parameters: Vec<Span>,
+	private_functions: Vec<PrivateFunction<'a>>,
+	blocks: Vec<&'a Expr>,
+}
+
+struct PrivateFunction<'a> {
+	name: &'a Ident,
+	/// Where the declaration's own syntax begins, attributes included.
+	start: usize,
+	body: &'a Expr,
+}
+
+impl<'a> Sites<'a> {
+	fn declaration(&mut self, declaration: &'a Declaration) {
+		match declaration {
+			Declaration::Func {
+				visibility,
+				meta,
+				body,
+			} => {
+				self.callable(meta, body, false);
+				if *visibility == Some(Visibility::Private) {
+					self.private_function(meta, None, body);
+				}
+			}
+			Declaration::Test { body, .. } => self.expr(body),
+			Declaration::Let { value, .. } => self.expr(value),
+			Declaration::Struct {
+				fields,
+				members,
+				impls,
+				..
+			} => {
+				for field in fields {
+					if let Some(default) = &field.0.default {
+						self.expr(default);
+					}
+				}
+				self.members(members, false);
+				for implementation in impls {
+					self.members(&implementation.0.members, true);
+				}
+			}
+			Declaration::Enum { members, impls, .. } => {
+				self.members(members, false);
+				for implementation in impls {
+					self.members(&implementation.0.members, true);
+				}
+			}
+			Declaration::Namespace { members, .. } | Declaration::Impl { members, .. } => {
+				self.members(members, false);
+			}
+			Declaration::ImplFor { members, .. } => self.members(members, true),
+			Declaration::Interface { members, .. } => {
+				for member in members {
+					match &member.0 {
+						InterfaceMember::Element(element) => match &element.0 {
+							InterfaceElement::Func {
+								meta,
+								body: Some(body),
+							} => self.callable(meta, body, true),
+							InterfaceElement::Let {
+								value: Some(value), ..
+							} => self.expr(value),
+							InterfaceElement::Func { .. } | InterfaceElement::Let { .. } => {}
+						},
+						InterfaceMember::Impl { members, .. } => self.members(members, true),
+					}
+				}
+			}
+			Declaration::Import { .. }
+			| Declaration::Effect { .. }
+			| Declaration::ExternalLet(..)
+			| Declaration::ExternalFunc(..)
+			| Declaration::TypeAlias { .. } => {}
+		}
+	}
+
+	/// Members of a type, namespace or impl body. `dictated` members implement
+	/// an interface, whose signature they cannot change.
+	fn members(&mut self, members: &'a [Spanned<ImplMember>], dictated: bool) {
+		for member in members {
+			match &member.0 {
+				ImplMember::Func {
+					visibility,
+					meta,
+					body,
+				} => {
+					self.callable(meta, body, dictated);
+					if !dictated && *visibility == Some(Visibility::Private) {
+						self.private_function(meta, Some(member.1), body);
+					}
+				}
+				ImplMember::Let { value, .. } => self.expr(value),
+				ImplMember::ExternalLet(..) | ImplMember::ExternalFunc(..) => {}
+			}
+		}
+	}
+
+	fn callable(&mut self, meta: &'a FuncDeclaration, body: &'a Expr, dictated: bool) {
+		let parameters = meta.params.iter().map(|parameter| parameter.0.name.1);
+		if dictated {
+			self.exempt.extend(parameters);
+		} else {
+			self.parameters.extend(parameters);
+			self.generics(&meta.generics);
+		}
+		self.expr(body);
+	}
+
+	fn generics(&mut self, generics: &[Spanned<GenericParam>]) {
+		self
+			.type_parameters
+			.extend(generics.iter().map(|generic| generic.0.name.1));
+	}
+
+	fn private_function(
+		&mut self,
+		meta: &'a FuncDeclaration,
+		member: Option<Span>,
+		body: &'a Expr,
+	) {
+		let start = meta
+			.attributes
+			.iter()
+			.map(|attribute| attribute.1.start)
+			.chain(member.map(|member| member.start))
+			.fold(meta.name.1.start, usize::min);
+		self.private_functions.push(PrivateFunction {
+			name: &meta.name,
+			start,
+			body,
+		});
+	}
+
+	fn expr(&mut self, expr: &'a Expr) {
+		match &expr.kind {
+			ExprKind::Closure {
+				params, generics, ..
+			} => {
+				self
+					.parameters
+					.extend(params.iter().map(|parameter| parameter.0.name.1));
+				self.generics(generics);
+			}
+			ExprKind::Block { body, .. } => {
+				self.blocks.push(expr);
+				for statement in body {
+					if let Statement::Let { meta, .. } = &statement.0
+						&& meta.kind == LetKind::Use
+					{
+						self.exempt.push(meta.name.1);
+					}
+				}
+			}
+			_ => {}
+		}
+		expr.for_each_child(|child| self.expr(child));
+	}
+}
+
+fn within(ranges: &[Span], span: Span) -> bool {
+	ranges
+		.iter()
+		.any(|range| range.start <= span.start && span.end <= range.end)
+}
+
+fn spelled(source: &str, span: Span) -> Option<&str> {
+	source.get(span.start..span.end)
+}
+
+fn underscore(name: &str) -> EcoString {
+	format!("Prefix `{name}` with an underscore").into()
+}
+
+fn unused_locals(
+	analysis: &SemanticAnalysis,
+	source: &str,
+	occurrences: &[(SymbolIdentity, ReferenceOccurrence)],
+	sites: &Sites<'_>,
+	found: &mut Vec<UnusedBinding>,
+) {
+	let mut written = FxHashMap::<Span, Vec<Span>>::default();
+	for (span, declaration) in analysis.annotations.local_declarations() {
+		written.entry(declaration).or_default().push(span);
+	}
+	let mut read = FxHashSet::default();
+	// A shorthand field pattern's name is also the field it selects.
+	let mut shorthand = FxHashSet::default();
+	for (identity, occurrence) in occurrences {
+		match identity {
+			SymbolIdentity::Local(declaration) if !occurrence.is_declaration => {
+				read.insert(*declaration);
+			}
+			SymbolIdentity::Local(_) => {}
+			_ if !occurrence.is_declaration => {
+				shorthand.insert(occurrence.span);
+			}
+			_ => {}
+		}
+	}
+	for (declaration, mut spans) in written {
+		if read.contains(&declaration) || within(&sites.exempt, declaration) {
+			continue;
+		}
+		let Some(name) = spelled(source, declaration).filter(|name| !name.starts_with('_')) else {
+			continue;
+		};
+		let (lint, kind) = if within(&sites.parameters, declaration) {
+			("unused-parameter", "parameter")
+		} else {
+			("unused-variable", "variable")
+		};
+		spans.sort_by_key(|span| span.start);
+		spans.dedup();
+		found.push(UnusedBinding {
+			lint,
+			message: format!("unused {kind} `{name}`").into(),
+			span: declaration,
+			fix: underscore(name),
+			replacements: spans
+				.into_iter()
+				.map(|span| {
+					if shorthand.contains(&span) {
+						TextReplacement::new(span, format!("{name} = _{name}"))
+					} else {
+						TextReplacement::new(Span::new(span.start, span.start), "_")
+					}
+				})
+				.collect(),
+		});
+	}
+}
+
+fn unused_type_parameters(
+	source: &str,
+	occurrences: &[(SymbolIdentity, ReferenceOccurrence)],
+	sites: &Sites<'_>,
+	found: &mut Vec<UnusedBinding>,
+) {
+	for &declaration in &sites.type_parameters {
+		let Some(identity) = occurrences.iter().find_map(|(identity, occurrence)| {
+			(occurrence.is_declaration
+				&& occurrence.span == declaration
+				&& matches!(identity, SymbolIdentity::Generic(_)))
+			.then_some(identity)
+		}) else {
+			continue;
+		};
+		if occurrences
+			.iter()
+			.any(|(other, occurrence)| !occurrence.is_declaration && other == identity)
+		{
+			continue;
+		}
+		let Some(name) = spelled(source, declaration).filter(|name| !name.starts_with('_')) else {
+			continue;
+		};
+		found.push(UnusedBinding {
+			lint: "unused-type-parameter",
+			message: format!("unused type parameter `{name}`").into(),
+			span: declaration,
+			fix: underscore(name),
+			replacements: vec![TextReplacement::new(
+				Span::new(declaration.start, declaration.start),
+				"_",
+			)],
+		});
+	}
+}
+
+fn unused_private_functions(
+	source: &str,
+	occurrences: &[(SymbolIdentity, ReferenceOccurrence)],
+	sites: &Sites<'_>,
+	found: &mut Vec<UnusedBinding>,
+) {
+	for function in &sites.private_functions {
+		let Some(identity) = occurrences.iter().find_map(|(identity, occurrence)| {
+			(occurrence.is_declaration
+				&& occurrence.span == function.name.1
+				&& matches!(identity, SymbolIdentity::Definition(_)))
+			.then_some(identity)
+		}) else {
+			continue;
+		};
+		let extent = Span::new(
+			keywords_before(source, function.start),
+			function.body.span.end,
+		);
+		// Recursion alone does not keep a function alive.
+		if occurrences.iter().any(|(other, occurrence)| {
+			!occurrence.is_declaration
+				&& other == identity
+				&& !(extent.start <= occurrence.span.start && occurrence.span.end <= extent.end)
+		}) {
+			continue;
+		}
+		let name = &function.name.0;
+		found.push(UnusedBinding {
+			lint: "unused-private-function",
+			message: format!("private function `{name}` is never used").into(),
+			span: function.name.1,
+			fix: format!("Remove `{name}`").into(),
+			replacements: vec![TextReplacement::new(whole_lines(source, extent), "")],
+		});
+	}
+}
+
+/// Step back from `start` over the declaration keywords written before it.
+fn keywords_before(source: &str, mut start: usize) -> usize {
+	const KEYWORDS: &[&str] = &[
+		"func",
+		"async",
+		"namespace",
+		"private",
+		"public",
+		"internal",
+	];
+	loop {
+		let before = source[..start].trim_end();
+		let Some(keyword) = KEYWORDS.iter().find(|keyword| {
+			before.ends_with(*keyword)
+				&& !before[..before.len() - keyword.len()]
+					.ends_with(|character: char| character.is_alphanumeric() || character == '_')
+		}) else {
+			return start;
+		};
+		start = before.len() - keyword.len();
+	}
+}
+
+/// Widen `span` to the whole lines it occupies, with their doc comments, when
+/// nothing else shares those lines. A blank line after them goes too when one
+/// already precedes them, so removal never leaves two blank lines in a row.
+fn whole_lines(source: &str, span: Span) -> Span {
+	let line_start = source[..span.start].rfind('\n').map_or(0, |newline| newline + 1);
+	let line_end = source[span.end..]
+		.find('\n')
+		.map_or(source.len(), |newline| span.end + newline + 1);
+	if !source[line_start..span.start].trim().is_empty()
+		|| !source[span.end..line_end].trim().is_empty()
+	{
+		return span;
+	}
+	let mut start = line_start;
+	while start > 0 {
+		let previous = source[..start - 1].rfind('\n').map_or(0, |newline| newline + 1);
+		if !source[previous..start].trim_start().starts_with("///") {
+			break;
+		}
+		start = previous;
+	}
+	let mut end = line_end;
+	let preceded_by_blank = start == 0
+		|| source[..start - 1]
+			.rfind('\n')
+			.map_or(&source[..start - 1], |newline| &source[newline + 1..start - 1])
+			.trim()
+			.is_empty();
+	if preceded_by_blank {
+		let next_end = source[end..]
+			.find('\n')
+			.map_or(source.len(), |newline| end + newline + 1);
+		if end < source.len() && source[end..next_end].trim().is_empty() {
+			end = next_end;
+		}
+	}
+	Span::new(start, end)
+}
+
+/// The top-level declaration a member, method body or materialized interface
+/// member belongs to.
+fn top_level(mut target: &DefinitionId) -> &DefinitionId {
+	loop {
+		target = match &target.key {
+			DeclarationKey::Member { owner, .. } | DeclarationKey::MethodBody { owner, .. } => owner,
+			DeclarationKey::MaterializedInterfaceMember {
+				interface_member, ..
+			} => interface_member,
+			_ => return target,
+		};
+	}
+}
+
+fn unused_imports(
+	analysis: &SemanticAnalysis,
+	source: &str,
+	occurrences: &[(SymbolIdentity, ReferenceOccurrence)],
+	found: &mut Vec<UnusedBinding>,
+) {
+	let written = analysis
+		.import_references
+		.iter()
+		.map(|(span, _)| *span)
+		.collect::<FxHashSet<_>>();
+	let mut used = FxHashSet::default();
+	let mut namespaces = FxHashSet::default();
+	for (identity, occurrence) in occurrences {
+		if occurrence.is_declaration || written.contains(&occurrence.span) {
+			continue;
+		}
+		match identity {
+			SymbolIdentity::Definition(target) => {
+				used.insert(top_level(target));
+			}
+			SymbolIdentity::Module(module) => {
+				namespaces.insert(module);
+			}
+			_ => {}
+		}
+	}
+	let target_at = |span: Span| {
+		analysis
+			.import_references
+			.iter()
+			.find_map(|(written, target)| (*written == span).then_some(target))
+	};
+	for declaration in &analysis.module.members {
+		let Declaration::Import {
+			path,
+			alias,
+			idents,
+			..
+		} = declaration
+		else {
+			continue;
+		};
+		let (Some(first), Some(last)) = (path.first(), path.last()) else {
+			continue;
+		};
+		let Some(keyword) = source[..first.1.start].rfind("import") else {
+			continue;
+		};
+		let namespace = match target_at(alias.as_ref().unwrap_or(last).1) {
+			Some(ImportReferenceTarget::Module(module)) => Some(module),
+			_ => None,
+		};
+		let head_end = alias.as_ref().map_or(last.1.end, |alias| alias.1.end);
+		let spelled_path = source[keyword + "import".len()..last.1.end].trim();
+		let Some(items) = idents else {
+			let Some(namespace) = namespace else {
+				continue;
+			};
+			if !namespace_used(namespace, &namespaces, &used, &[]) {
+				found.push(whole_import(
+					source,
+					spelled_path,
+					Span::new(keyword, head_end),
+				));
+			}
+			continue;
+		};
+		let items = items
+			.iter()
+			.map(|(name, alias)| {
+				let target = match target_at(name.1) {
+					Some(ImportReferenceTarget::Definition(target)) => Some(target),
+					_ => None,
+				};
+				let local = alias.as_ref().unwrap_or(name);
+				(Span::new(name.1.start, local.1.end), &local.0, target)
+			})
+			.collect::<Vec<_>>();
+		let unused = items
+			.iter()
+			.filter(|(_, _, target)| target.is_some_and(|target| !used.contains(target)))
+			.collect::<Vec<_>>();
+		if unused.is_empty() {
+			continue;
+		}
+		let Some(list_end) = items
+			.last()
+			.and_then(|(span, _, _)| source[span.end..].find(')').map(|close| span.end + close + 1))
+		else {
+			continue;
+		};
+		let selected = items
+			.iter()
+			.filter_map(|(_, _, target)| *target)
+			.collect::<Vec<_>>();
+		let every = unused.len() == items.len();
+		if every
+			&& !namespace.is_some_and(|namespace| {
+				namespace_used(namespace, &namespaces, &used, &selected)
+			}) {
+			found.push(whole_import(
+				source,
+				spelled_path,
+				Span::new(keyword, list_end),
+			));
+			continue;
+		}
+		let names = unused
+			.iter()
+			.map(|(_, name, _)| format!("`{name}`"))
+			.collect::<Vec<_>>()
+			.join(", ");
+		let replacement = if every {
+			TextReplacement::new(Span::new(head_end, list_end), "")
+		} else {
+			let open = source[..items[0].0.start].rfind('(').unwrap_or(items[0].0.start);
+			let kept = items
+				.iter()
+				.filter(|item| !unused.contains(item))
+				.map(|(span, _, _)| &source[span.start..span.end])
+				.collect::<Vec<_>>()
+				.join(", ");
+			TextReplacement::new(Span::new(open + 1, list_end - 1), kept)
+		};
+		found.push(UnusedBinding {
+			lint: "unused-import",
+			message: if unused.len() == 1 {
+				format!("unused import {names}")
+			} else {
+				format!("unused imports {names}")
+			}
+			.into(),
+			span: unused[0].0.to(unused[unused.len() - 1].0),
+			fix: if unused.len() == 1 {
+				format!("Remove {names}")
+			} else {
+				"Remove the unused imports".to_string()
+			}
+			.into(),
+			replacements: vec![replacement],
+		});
+	}
+}
+
+/// Whether anything outside its selected names reaches an imported module:
+/// its namespace, or a declaration of it that no `with` item selected.
+fn namespace_used(
+	namespace: &ModuleIdentity,
+	namespaces: &FxHashSet<&ModuleIdentity>,
+	used: &FxHashSet<&DefinitionId>,
+	selected: &[&DefinitionId],
+) -> bool {
+	namespaces.contains(namespace)
+		|| used
+			.iter()
+			.any(|target| target.module == *namespace && !selected.contains(target))
+}
+
+fn whole_import(source: &str, path: &str, extent: Span) -> UnusedBinding {
+	UnusedBinding {
+		lint: "unused-import",
+		message: format!("unused import `{path}`").into(),
+		span: extent,
+		fix: "Remove the unused import".into(),
+		replacements: vec![TextReplacement::new(whole_lines(source, extent), "")],
+	}
+}
+
+fn unused_results(analysis: &SemanticAnalysis, sites: &Sites<'_>, found: &mut Vec<UnusedBinding>) {
+	let Some(result) = &analysis.checked.runtime_roles.result else {
+		return;
+	};
+	let interner = &analysis.checked.interner;
+	let is_result = |expr: &Expr| {
+		analysis.annotations.get(expr.id).is_some_and(|info| {
+			matches!(interner.kind(info.ty), TyKind::Adt(definition, _)
+				if analysis.checked.semantic.stable_definition(*definition) == Some(&result.result))
+		})
+	};
+	for block in &sites.blocks {
+		let ExprKind::Block { body, .. } = &block.kind else {
+			continue;
+		};
+		// A block's final expression is its value unless the block is `void`.
+		let discards_tail = analysis
+			.annotations
+			.get(block.id)
+			.is_some_and(|info| matches!(interner.kind(info.ty), TyKind::Void));
+		for (index, statement) in body.iter().enumerate() {
+			let Statement::Expr(expr) = &statement.0 else {
+				continue;
+			};
+			if (index + 1 < body.len() || discards_tail) && is_result(expr) {
+				found.push(UnusedBinding {
+					lint: "unused-result",
+					message: "unused `Result` value".into(),
+					span: expr.span,
+					fix: "Bind the result to `_`".into(),
+					replacements: vec![TextReplacement::new(
+						Span::new(expr.span.start, expr.span.start),
+						"let _ = ",
+					)],
+				});
+			}
+		}
+	}
+}
@@ -77,6 +77,90 @@ fn external_let_linkage_errors_are_structured_diagnostics() {
 	}
 }
 
+#[test]
+fn host_bindings_accept_only_types_with_a_raw_abi() {
+	let parsed = parse_module(
+		"external(\"node:path\", \"basename\") func basename(path: string): string\n\
+		 external(\"node:util\", \"isDeepStrictEqual\") func same(a: float, b: int): boolean\n\
+		 external(\"node:os\", \"EOL\") let eol: string\n\
+		 external(\"node:fs\", \"readdirSync\") func list(path: string): #[string]\n\
+		 external(\"node:fs/promises\", \"readFile\") async func read(path: string, encoding: string): string\n\
+		 external(\"node:timers/promises\", \"setTimeout\") async func sleep(ms: float): void",
+		"test",
+	);
+	let checked = check_module(&parsed.tree);
+	assert!(checked.diags.is_empty(), "{:?}", checked.diags);
+
+	for (source, message) in [
+		(
+			"external(\"m\", \"f\") func f(rows: #[#[string]]): void",
+			"`#[#[string]]` has no raw JavaScript form",
+		),
+		(
+			"external(\"m\", \"f\") func f<T>(value: T): void",
+			"`T` has no raw JavaScript form",
+		),
+		(
+			"external(\"m\", \"f\") func f(value: void): void",
+			"`void` has no raw JavaScript form",
+		),
+		(
+			"external(\"node:os\", \"cpus\") let cpus: Option<string>",
+			"no raw host-value marshalling ABI",
+		),
+	] {
+		let parsed = parse_module(source, "test");
+		let checked = check_module(&parsed.tree);
+		assert!(
+			checked
+				.diags
+				.iter()
+				.any(|diag| diag.message.contains(message)),
+			"{source}: {:?}",
+			checked.diags
+		);
+	}
+}
+
+#[test]
+fn only_task_intrinsics_are_async() {
+	let parsed = parse_module(
+		"external(process_spawn) async func spawn(program: string): Result<int, string>",
+		"test",
+	);
+	let checked = check_module(&parsed.tree);
+	assert!(
+		!checked
+			.diags
+			.iter()
+			.any(|diag| diag.message.contains("task")),
+		"{:?}",
+		checked.diags
+	);
+
+	for (source, message) in [
+		(
+			"external(println) async func println(line: string): void",
+			"external marker `println` does not return a task, so it cannot be `async`",
+		),
+		(
+			"external(process_spawn) func spawn(program: string): Result<int, string>",
+			"external marker `process_spawn` returns a task, so it must be declared `async`",
+		),
+	] {
+		let parsed = parse_module(source, "test");
+		let checked = check_module(&parsed.tree);
+		assert!(
+			checked
+				.diags
+				.iter()
+				.any(|diag| diag.message.contains(message)),
+			"{source}: {:?}",
+			checked.diags
+		);
+	}
+}
+
 #[test]
 fn value_markers_are_rejected_for_external_functions_in_every_member_shape() {
 	for source in [
@@ -26,6 +26,7 @@ fn exported(effects: EffectRow) -> ExportedDefinition {
 	ExportedDefinition {
 		id: definition("run"),
 		name: "run".into(),
+		attributes: Vec::new(),
 		visibility: None,
 		kind: DefinitionShapeKind::Function,
 		declaration_kind: None,
@@ -4,9 +4,10 @@
 //! mode) never requires a `main` at all — every existing corpus/test caller of
 //! `check_module` stays unaffected. Entry mode requires a top-level `func
 //! main` taking no parameters, declaring no generics, and resolving to one of
-//! the six executable root shapes.
+//! the six executable root shapes. `test` declarations are held to the same
+//! shapes in both modes.
 
-use nymph_sema::{EntryRootShape, check_module, check_module_entry};
+use nymph_sema::{EntryRootShape, TestRoot, check_module, check_module_entry};
 use nymph_syntax::parse_module;
 
 /// Parse and check `source` in entry mode, returning the checker's error
@@ -260,3 +261,40 @@ fn params_and_non_void_return_are_both_reported_when_both_present() {
 		"expected a return-type error, got: {errors:?}"
 	);
 }
+
+#[test]
+fn library_mode_classifies_tests_and_rejects_unsupported_results() {
+	let source = format!(
+		"{ROOT_TYPES}\ntest \"unit\" {{}}\nasync test \"fallible\" {{ let result: Result<void, Good> = Ok(value = {{}})\n result }}\ntest \"value\" {{ 42 }}"
+	);
+	let parsed = parse_module(&source, "test");
+	assert!(parsed.diagnostics.is_empty(), "{:?}", parsed.diagnostics);
+	let checked = check_module(&parsed.tree);
+	let errors = checked
+		.diags
+		.iter()
+		.filter(|d| d.is_error())
+		.map(|d| d.message.to_string())
+		.collect::<Vec<_>>();
+	assert_eq!(
+		errors,
+		vec![
+			"a test must produce `void`, `Option<void>`, `Result<void, E>`, or a `Task` producing one of those types"
+		]
+	);
+	assert_eq!(
+		checked.tests,
+		vec![
+			TestRoot {
+				name: "unit".into(),
+				function: "test$0".into(),
+				shape: EntryRootShape::Void,
+			},
+			TestRoot {
+				name: "fallible".into(),
+				function: "test$1".into(),
+				shape: EntryRootShape::TaskResult,
+			},
+		]
+	);
+}
@@ -14,6 +14,7 @@ fn recovered(definition: ExportedDefinition) -> RecoveredExportedDefinition {
 	RecoveredExportedDefinition {
 		id: definition.id,
 		name: definition.name,
+		attributes: definition.attributes,
 		visibility: definition.visibility,
 		kind: definition.kind,
 		declaration_kind: definition.declaration_kind,
@@ -33,6 +34,7 @@ fn recovered(definition: ExportedDefinition) -> RecoveredExportedDefinition {
 			.map(|member| nymph_sema::RecoveredMemberShape {
 				id: member.id,
 				name: member.name,
+				attributes: member.attributes,
 				visibility: member.visibility,
 				kind: member.kind,
 				binders: member.binders,
@@ -82,6 +84,7 @@ fn exported(id: DefinitionId, name: &str) -> ExportedDefinition {
 	ExportedDefinition {
 		id,
 		name: name.into(),
+		attributes: Vec::new(),
 		visibility: None,
 		kind: DefinitionShapeKind::Struct,
 		declaration_kind: None,
@@ -123,6 +126,7 @@ fn member(
 			DeclarationKey::member(owner.clone(), DeclarationCategory::Method, name),
 		),
 		name: name.into(),
+		attributes: Vec::new(),
 		visibility: None,
 		kind,
 		binders: vec![],
@@ -539,6 +543,7 @@ fn recovery_taints_without_fabricating_poison_ids() {
 	let recovered = RecoveredExportedDefinition {
 		id: known.clone(),
 		name: "Known".into(),
+		attributes: Vec::new(),
 		visibility: None,
 		kind: DefinitionShapeKind::Struct,
 		declaration_kind: None,
@@ -608,6 +613,7 @@ fn pass_b_instantiates_owned_definition_facts_after_all_ids_exist() {
 			DeclarationKey::member(box_def.id.clone(), DeclarationCategory::Field, "value"),
 		),
 		name: "value".into(),
+		attributes: Vec::new(),
 		visibility: None,
 		ty: InterfaceType::Generic(generic.id.clone()),
 		has_default: false,
@@ -647,6 +653,7 @@ fn pass_b_instantiates_owned_definition_facts_after_all_ids_exist() {
 			DeclarationKey::member(namespace.id.clone(), DeclarationCategory::Function, "make"),
 		),
 		name: "make".into(),
+		attributes: Vec::new(),
 		visibility: None,
 		kind: MemberKind::Function,
 		binders: vec![],
@@ -701,6 +708,7 @@ fn recovered_poison_is_error_fact_and_environment_remains_tainted() {
 	let mut recovered = RecoveredExportedDefinition {
 		id: stable.clone(),
 		name: "value".into(),
+		attributes: Vec::new(),
 		visibility: None,
 		kind: DefinitionShapeKind::Let,
 		declaration_kind: None,
@@ -746,6 +754,7 @@ fn recovered_definitions_retain_every_independently_known_owned_fact() {
 	let mut alias = RecoveredExportedDefinition {
 		id: generic_owner.clone(),
 		name: "Alias".into(),
+		attributes: Vec::new(),
 		visibility: None,
 		kind: DefinitionShapeKind::TypeAlias,
 		declaration_kind: None,
@@ -782,6 +791,7 @@ fn recovered_definitions_retain_every_independently_known_owned_fact() {
 	let value = RecoveredExportedDefinition {
 		id: value_id.clone(),
 		name: "host_value".into(),
+		attributes: Vec::new(),
 		visibility: None,
 		kind: DefinitionShapeKind::Let,
 		declaration_kind: None,
@@ -824,6 +834,7 @@ fn recovered_definitions_retain_every_independently_known_owned_fact() {
 			definition: RecoveredExportedDefinition {
 				id: iface.clone(),
 				name: "Project".into(),
+				attributes: Vec::new(),
 				visibility: None,
 				kind: DefinitionShapeKind::Interface,
 				declaration_kind: None,
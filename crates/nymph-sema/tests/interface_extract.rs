@@ -1,12 +1,13 @@
 use std::sync::Arc;
 
+use nymph_ast::decl::AttributeArgument;
 use nymph_hir::{
 	hir::{BinOp, BuiltinResult, MarshalKind},
 	linkage::NativeExternal,
 };
 use nymph_sema::{
-	DeclarationCategory, DeclarationKey, DefinitionId, DefinitionShapeKind, EntryMode,
-	ExportedDefinition, InterfaceType, ModuleEnvironment, ModuleIdentity, ModuleInterface,
+	AttributeShape, DeclarationCategory, DeclarationKey, DefinitionId, DefinitionShapeKind,
+	EntryMode, ExportedDefinition, InterfaceType, ModuleEnvironment, ModuleIdentity, ModuleInterface,
 	ModuleOrigin, RecoveredDefinitionReference, RecoveredInterfaceType, SemanticAvailability,
 	SemanticEnvironment, check_module_with_environment, declared_headers, extract_module_interface,
 	extract_module_interface_from_facts_with_selection, extract_module_interface_with_facts,
@@ -671,6 +672,7 @@ fn extraction_preserves_transitive_imported_nominal_return_identity() {
 		exports: vec![ExportedDefinition {
 			id: answer.clone(),
 			name: "Answer".into(),
+			attributes: Vec::new(),
 			visibility: None,
 			kind: DefinitionShapeKind::Struct,
 			declaration_kind: None,
@@ -1251,6 +1253,67 @@ fn struct_field_visibility_and_default_presence_affect_complete_and_recovered_fi
 	);
 }
 
+#[test]
+fn attributes_reach_complete_and_recovered_interfaces_and_their_fingerprints() {
+	const SOURCE: &str = "@deprecated(\"use next\") public func legacy(): int = 1\n\
+		public struct Record(@deprecated public old: int) { @inline public func sum(): int = this.old }\n\
+		public enum Phase { @deprecated Old, New }";
+	let names = |attributes: &[AttributeShape]| {
+		attributes
+			.iter()
+			.map(|attribute| attribute.name.to_string())
+			.collect::<Vec<_>>()
+	};
+	let module = parse(SOURCE);
+	let checked = check(&module);
+	assert!(checked.diags.is_empty(), "{:?}", checked.diags);
+	let headers = declared_headers(identity(), &module);
+	let complete = extract_module_interface(identity(), &module, &checked, &headers).unwrap();
+	let legacy = &complete.exports[0];
+	assert_eq!(
+		legacy.attributes,
+		[AttributeShape {
+			name: "deprecated".into(),
+			arguments: vec![AttributeArgument::String("use next".into())],
+		}]
+	);
+	let record = &complete.exports[1];
+	assert!(record.attributes.is_empty());
+	assert_eq!(names(&record.fields[0].attributes), ["deprecated"]);
+	assert_eq!(names(&record.members[0].attributes), ["inline"]);
+	assert_eq!(
+		names(&complete.exports[2].variants[0].attributes),
+		["deprecated"]
+	);
+	assert!(complete.exports[2].variants[1].attributes.is_empty());
+
+	let plain = parse(&SOURCE.replace("@deprecated(\"use next\") ", ""));
+	let plain_checked = check(&plain);
+	let plain_headers = declared_headers(identity(), &plain);
+	let plain = extract_module_interface(identity(), &plain, &plain_checked, &plain_headers).unwrap();
+	assert_ne!(complete.fingerprint, plain.fingerprint);
+
+	let broken = parse(&format!(
+		"{SOURCE}\nfunc broken(): Missing = panic(\"broken\")"
+	));
+	let broken_checked = check(&broken);
+	let broken_headers = declared_headers(identity(), &broken);
+	let ModuleEnvironment::Recovered(recovered) =
+		recover_module_environment(identity(), &broken, &broken_checked, &broken_headers)
+	else {
+		panic!("expected recovered interface")
+	};
+	assert_eq!(names(&recovered.exports[0].attributes), ["deprecated"]);
+	assert_eq!(
+		names(&recovered.exports[1].fields[0].attributes),
+		["deprecated"]
+	);
+	assert_eq!(
+		names(&recovered.exports[1].members[0].attributes),
+		["inline"]
+	);
+}
+
 #[test]
 fn extraction_includes_inherent_nested_impl_method_facts_and_recursive_support() {
 	let module = parse(
@@ -201,6 +201,7 @@ fn complete_fixture() -> ModuleInterface {
 	let field = FieldShape {
 		id: definition("field"),
 		name: "field".into(),
+		attributes: Vec::new(),
 		visibility: Some(Visibility::Public),
 		ty: InterfaceType::Int,
 		has_default: true,
@@ -209,6 +210,7 @@ fn complete_fixture() -> ModuleInterface {
 	let member = MemberShape {
 		id: definition("member"),
 		name: "member".into(),
+		attributes: Vec::new(),
 		visibility: Some(Visibility::Internal),
 		kind: MemberKind::Function,
 		binders: vec![binder.clone()],
@@ -242,6 +244,7 @@ fn complete_fixture() -> ModuleInterface {
 	let exported = ExportedDefinition {
 		id: owner.clone(),
 		name: "Owner".into(),
+		attributes: Vec::new(),
 		visibility: Some(Visibility::Public),
 		kind: DefinitionShapeKind::Struct,
 		declaration_kind: None,
@@ -259,6 +262,7 @@ fn complete_fixture() -> ModuleInterface {
 		variants: vec![VariantShape {
 			id: definition("Variant"),
 			name: "Variant".into(),
+			attributes: Vec::new(),
 			fields: vec![field],
 		}],
 		enum_view_variants: vec![],
@@ -344,6 +348,7 @@ fn recovered_fixture() -> RecoveredModuleInterface {
 	let recovered = RecoveredExportedDefinition {
 		id: complete.id,
 		name: complete.name,
+		attributes: complete.attributes,
 		visibility: complete.visibility,
 		kind: complete.kind,
 		declaration_kind: complete.declaration_kind,
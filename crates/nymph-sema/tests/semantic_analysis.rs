@@ -32,6 +32,7 @@ fn semantic_analysis_owns_source_and_node_annotations_without_diagnostics() {
 		annotations,
 		declarations: Arc::default(),
 		import_references: Arc::default(),
+		deprecations: Arc::default(),
 	};
 	let cloned = analysis.clone();
 
@@ -67,6 +68,7 @@ fn environment_check_uses_imported_function_without_mutating_environment() {
 	let exported = ExportedDefinition {
 		id: function_id.clone(),
 		name: "answer".into(),
+		attributes: Vec::new(),
 		visibility: None,
 		kind: DefinitionShapeKind::Function,
 		declaration_kind: None,
@@ -230,6 +232,7 @@ fn struct_defaults_charge_fresh_construction_but_not_clone_updates() {
 				DeclarationKey::top_level(DeclarationCategory::Function, "answer"),
 			),
 			name: "answer".into(),
+			attributes: Vec::new(),
 			visibility: Some(Visibility::Public),
 			kind: DefinitionShapeKind::Function,
 			declaration_kind: None,
@@ -343,6 +346,7 @@ fn stable_definition_kind_resolves_namespace_and_inherent_function_members() {
 		checked: Arc::new(checked.facts),
 		declarations: Arc::default(),
 		import_references: Arc::default(),
+		deprecations: Arc::default(),
 	};
 
 	for expected in ["emit", "origin"] {
@@ -557,6 +561,7 @@ fn local_definition_overlays_imported_name_without_rechecking_imported_body() {
 	let imported = ExportedDefinition {
 		id: imported_id,
 		name: "answer".into(),
+		attributes: Vec::new(),
 		visibility: None,
 		kind: DefinitionShapeKind::Function,
 		declaration_kind: None,
@@ -632,6 +637,7 @@ fn recovered_dependency_poison_suppresses_cascades_without_hiding_independent_er
 		RecoveredExportedDefinition {
 			id,
 			name: name.into(),
+			attributes: Vec::new(),
 			visibility: None,
 			kind: DefinitionShapeKind::Function,
 			declaration_kind: None,
@@ -736,6 +742,7 @@ fn recovered_dependency_poison_suppresses_cascades_without_hiding_independent_er
 	let complete_function = |id: DefinitionId, name: &str| ExportedDefinition {
 		id,
 		name: name.into(),
+		attributes: Vec::new(),
 		visibility: None,
 		kind: DefinitionShapeKind::Function,
 		declaration_kind: None,
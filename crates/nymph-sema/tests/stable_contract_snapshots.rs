@@ -73,7 +73,7 @@ fn complete_interface_fingerprint_and_runtime_artifact_are_stable_snapshots() {
 	assert_eq!(first_interface, second_interface);
 	assert_eq!(first_runtime, second_runtime);
 	assert_eq!(first_interface.exports[0].name, "answer");
-	assert_eq!(first_interface.fingerprint, 1_079_886_840_365_780_136);
+	assert_eq!(first_interface.fingerprint, 1_870_545_758_680_216_616);
 	assert_eq!(
 		first_interface.fingerprint,
 		first_interface.structural_fingerprint()
@@ -112,7 +112,7 @@ fn recovered_interface_and_fingerprint_are_stable_snapshots() {
 	let second = recovered_snapshot(source);
 
 	assert_eq!(first, second);
-	assert_eq!(first.fingerprint, 5_588_967_939_573_553_042);
+	assert_eq!(first.fingerprint, 16_882_268_133_457_165_618);
 	assert_eq!(first.fingerprint, first.structural_fingerprint());
 	assert_eq!(
 		first
@@ -633,6 +633,7 @@ fn imported_external_reference_has_exact_stable_marshal_annotation() {
 		exports: vec![ExportedDefinition {
 			id: external.clone(),
 			name: "maximum".into(),
+			attributes: Vec::new(),
 			visibility: None,
 			kind: DefinitionShapeKind::Let,
 			declaration_kind: None,
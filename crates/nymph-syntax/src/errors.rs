@@ -84,10 +84,30 @@ pub enum ParseError {
 	ExpectedEffect { found: EcoString },
 	/// Effect rows require `!` on every composed atom.
 	ExpectedEffectAfterPlus { found: EcoString },
-	/// External functions cannot use the generated async execution-frame ABI.
+	/// An external function without a binding cannot use the async ABI.
 	AsyncExternalFunction,
 	/// Managed bindings are lexical locals, not module or type members.
 	ManagedLetOutsideLocal,
+	/// A test name interpolated an expression; test names are fixed strings.
+	InterpolationInTestName,
+	/// A test declaration carried a visibility modifier. Tests are always
+	/// private to their module.
+	VisibilityOnTest,
+	/// An `external("module", "symbol")` string interpolated an expression.
+	InterpolationInExternalBinding,
+	/// An external binding's module specifier was empty or could not be
+	/// written into an `import` statement.
+	InvalidExternalModule,
+	/// An external binding's symbol is not a JavaScript identifier.
+	InvalidExternalSymbol { symbol: EcoString },
+	/// A member was bound with `external("module", "symbol")`; only top-level
+	/// declarations bind host modules directly.
+	HostBindingInMember,
+	/// Attributes were written before an import, an enum embedding, a nested
+	/// `impl` or an expression statement, none of which carry them.
+	MisplacedAttributes,
+	/// An attribute's string argument interpolated an expression.
+	InterpolationInAttribute,
 }
 
 impl ParseError {
@@ -172,10 +192,31 @@ impl IntoDiagnostic for ParseError {
 				format!("expected an effect beginning with `!` after `+`, found {found}").into()
 			}
 			E::AsyncExternalFunction => {
-				"`external async func` is unsupported because external functions use the direct host ABI"
+				"`external async func` is unsupported without an `external(..)` binding; only a host binding or a task intrinsic may be `async`"
 					.into()
 			}
 			E::ManagedLetOutsideLocal => "`let use` is only allowed for lexical local bindings".into(),
+			E::InterpolationInTestName => "a test name cannot contain string interpolation".into(),
+			E::VisibilityOnTest => "a test is private to its module and takes no visibility".into(),
+			E::InterpolationInExternalBinding => {
+				"an external binding's module and symbol cannot contain string interpolation".into()
+			}
+			E::InvalidExternalModule => {
+				"an external binding needs a non-empty module specifier without quotes or line breaks"
+					.into()
+			}
+			E::InvalidExternalSymbol { symbol } => {
+				format!("`{symbol}` is not a JavaScript identifier an external can bind").into()
+			}
+			E::HostBindingInMember => {
+				"`external(\"module\", \"symbol\")` can only bind a top-level declaration".into()
+			}
+			E::MisplacedAttributes => {
+				"attributes can only be written before a declaration, member, field, variant or `let`".into()
+			}
+			E::InterpolationInAttribute => {
+				"an attribute's string argument cannot contain string interpolation".into()
+			}
 		}
 	}
 
@@ -198,6 +239,7 @@ impl IntoDiagnostic for ParseError {
 #[derive(Clone, Debug, PartialEq, Eq, ErrorCode)]
 #[error_code(0)]
 pub enum LexError {
+	/// A character other than the expected ones was found, or the input ended.
 	ExpectedFound {
 		found: Option<char>,
 		expected: Vec<EcoString>,
@@ -32,6 +32,10 @@ pub struct LexResult {
 	/// REPL clients use this typed signal instead of guessing from rendered
 	/// diagnostic text.
 	pub incomplete: bool,
+	/// For each token, whether a line break separates it from the token before.
+	/// The parser is otherwise layout-insensitive; this only tells a label's
+	/// `@` from an attribute that starts the next line.
+	pub line_breaks: Vec<bool>,
 }
 
 fn clean(s: &str) -> String {
@@ -75,10 +79,22 @@ pub fn lex(source: &str) -> LexResult {
 		})
 		.collect();
 
+	let line_breaks = tokens
+		.iter()
+		.scan(0, |end, token| {
+			let broken = source
+				.get(*end..token.1.start)
+				.is_some_and(|gap| gap.contains('\n'));
+			*end = token.1.end;
+			Some(broken)
+		})
+		.collect();
+
 	LexResult {
 		tokens,
 		diagnostics,
 		incomplete,
+		line_breaks,
 	}
 }
 
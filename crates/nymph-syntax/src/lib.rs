@@ -7,4 +7,4 @@ pub mod parser;
 
 pub use errors::{LexError, ParseError};
 pub use lexer::{LexResult, lex};
-pub use parser::{ParseResult, parse_expression, parse_module};
+pub use parser::{ParseResult, parse_expression, parse_generated, parse_module};
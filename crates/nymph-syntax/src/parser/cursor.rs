@@ -6,15 +6,19 @@ use nymph_ast::{Span, Spanned, token::Token};
 #[derive(Debug, Clone)]
 pub struct TokenCursor<'src> {
 	tokens: &'src [Spanned<Token>],
+	/// Parallel to `tokens`; see [`crate::LexResult::line_breaks`]. Empty for
+	/// an interpolation's tokens, which never need it.
+	line_breaks: &'src [bool],
 	pos: usize,
 	/// The end-of-input span, used when peeking past the last token.
 	eoi: Span,
 }
 
 impl<'src> TokenCursor<'src> {
-	pub fn new(tokens: &'src [Spanned<Token>], eoi: Span) -> Self {
+	pub fn new(tokens: &'src [Spanned<Token>], line_breaks: &'src [bool], eoi: Span) -> Self {
 		Self {
 			tokens,
+			line_breaks,
 			pos: 0,
 			eoi,
 		}
@@ -36,6 +40,11 @@ impl<'src> TokenCursor<'src> {
 		self.tokens.get(self.pos + n).map(|s| s.1)
 	}
 
+	/// Whether a line break precedes the token `n` ahead.
+	pub fn line_break_before(&self, n: usize) -> bool {
+		self.line_breaks.get(self.pos + n).copied().unwrap_or(false)
+	}
+
 	pub fn advance(&mut self) -> Option<&'src Spanned<Token>> {
 		let token = self.tokens.get(self.pos);
 		if token.is_some() {
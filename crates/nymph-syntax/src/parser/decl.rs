@@ -5,12 +5,13 @@ use ecow::EcoString;
 use nymph_ast::{
 	Ident, Spanned,
 	decl::{
-		Declaration, EnumEmbedding, EnumVariant, FuncDeclaration, FuncKind, FuncParam, ImplMember,
-		ImportRoot, InterfaceElement, InterfaceMember, LetDeclaration, LetKind, StructField,
-		StructImpl, TypeAliasDeclaration, Visibility,
+		Attribute, AttributeArgument, Declaration, EnumEmbedding, EnumVariant, FuncDeclaration,
+		FuncKind, FuncParam, ImplMember, ImportRoot, InterfaceElement, InterfaceMember, LetDeclaration,
+		LetKind, StructField, StructImpl, TypeAliasDeclaration, Visibility, host_binding,
+		host_binding_marker, test_function_name,
 	},
 	expr::{Expr, ExprKind},
-	token::Token,
+	token::{StrFragment, Token},
 	ty::{GenericArg, Type},
 };
 
@@ -46,11 +47,95 @@ impl Parser<'_> {
 	}
 
 	fn parse_declaration(&mut self) -> Option<Declaration> {
+		let attributes = self.parse_attributes();
 		if self.check(&Token::Import) {
+			self.reject_attributes(&attributes);
 			return Some(self.parse_import());
 		}
+		let mut declaration = self.parse_unattributed_declaration();
+		if let Some(slot) = declaration.as_mut().and_then(Declaration::attributes_mut) {
+			*slot = attributes;
+		}
+		declaration
+	}
+
+	/// `@name` or `@name(argument, …)`, any number of times.
+	pub(super) fn parse_attributes(&mut self) -> Vec<Spanned<Attribute>> {
+		let mut attributes = Vec::new();
+		while self.check(&Token::At) {
+			let start = self.position();
+			self.advance(); // `@`
+			let name = self.expect_ident();
+			let arguments = if self.eat(&Token::LParen).is_some() {
+				self.comma_separated(&Token::RParen, |p| p.parse_attribute_argument())
+			} else {
+				Vec::new()
+			};
+			attributes.push(Spanned(
+				Attribute { name, arguments },
+				self.span_from(start),
+			));
+		}
+		attributes
+	}
+
+	/// A plain string, or a name whose parts are joined by unspaced hyphens so
+	/// lint names like `unused-variable` read as one argument.
+	fn parse_attribute_argument(&mut self) -> Spanned<AttributeArgument> {
+		let start = self.position();
+		if matches!(self.peek(), Some(Token::Str(_))) {
+			let text = self.parse_plain_string(ParseError::InterpolationInAttribute);
+			return Spanned(AttributeArgument::String(text), self.span_from(start));
+		}
+		let Spanned(mut name, mut span) = self.expect_ident();
+		while self.check(&Token::Minus)
+			&& matches!(self.peek_nth(1), Some(Token::Identifier(_)))
+			&& self.current_span().start == span.end
+			&& self
+				.peek_nth_span(1)
+				.is_some_and(|next| next.start == span.end + 1)
+		{
+			self.advance(); // `-`
+			let part = self.expect_ident();
+			name.push('-');
+			name.push_str(&part.0);
+			span = span.to(part.1);
+		}
+		Spanned(AttributeArgument::Name(name), self.span_from(start))
+	}
+
+	/// How many tokens the attributes at the cursor span, so a caller can see
+	/// what they are attached to before parsing them.
+	fn attribute_tokens(&self) -> usize {
+		let mut offset = 0;
+		while self.peek_nth(offset) == Some(&Token::At) {
+			offset += 2; // `@name`
+			if self.peek_nth(offset) == Some(&Token::LParen) {
+				while !matches!(self.peek_nth(offset), Some(Token::RParen) | None) {
+					offset += 1;
+				}
+				offset += 1;
+			}
+		}
+		offset
+	}
+
+	/// Report attributes written where nothing can carry them.
+	pub(super) fn reject_attributes(&mut self, attributes: &[Spanned<Attribute>]) {
+		if let (Some(first), Some(last)) = (attributes.first(), attributes.last()) {
+			self.emit(first.1.to(last.1), ParseError::MisplacedAttributes);
+		}
+	}
+
+	fn parse_unattributed_declaration(&mut self) -> Option<Declaration> {
 		let visibility = self.parse_visibility();
+		if self.at_test_declaration(0) {
+			return Some(self.parse_test_decl(visibility, false));
+		}
 		match self.peek() {
+			Some(Token::Async) if self.at_test_declaration(1) => {
+				Some(self.parse_test_decl(visibility, true))
+			}
 			Some(Token::External) => Some(self.parse_external(visibility)),
 			Some(Token::Effect) => Some(self.parse_effect(visibility)),
 			Some(Token::Let) => Some(self.parse_let_decl(visibility)),
@@ -110,6 +195,7 @@ impl Parser<'_> {
 	fn parse_effect(&mut self, visibility: Option<Visibility>) -> Declaration {
 		self.advance(); // `effect`
 		Declaration::Effect {
+			attributes: Vec::new(),
 			visibility,
 			name: self.expect_ident(),
 		}
@@ -201,7 +287,15 @@ impl Parser<'_> {
 		};
 		self.expect(&Token::Eq);
 		let value = self.parse_expr();
-		(LetDeclaration { kind, name, type_ }, value)
+		(
+			LetDeclaration {
+				attributes: Vec::new(),
+				kind,
+				name,
+				type_,
+			},
+			value,
+		)
 	}
 
 	fn parse_let_decl(&mut self, visibility: Option<Visibility>) -> Declaration {
@@ -250,6 +344,7 @@ impl Parser<'_> {
 			(None, None)
 		};
 		FuncDeclaration {
+			attributes: Vec::new(),
 			kind,
 			is_async,
 			name,
@@ -292,23 +387,91 @@ impl Parser<'_> {
 		}
 	}
 
+	/// Whether the token `offset` ahead starts a `test "name"` declaration.
+	/// `test` stays an ordinary identifier everywhere else.
+	pub(super) fn at_test_declaration(&self, offset: usize) -> bool {
+		matches!(self.peek_nth(offset), Some(Token::Identifier(name)) if name == "test")
+			&& matches!(self.peek_nth(offset + 1), Some(Token::Str(_)))
+	}
+
+	/// `test "name" { ... }`: a block checked as the body of a private,
+	/// parameterless function with an inferred result.
+	fn parse_test_decl(&mut self, visibility: Option<Visibility>, is_async: bool) -> Declaration {
+		if visibility.is_some() {
+			self.emit(self.current_span(), ParseError::VisibilityOnTest);
+		}
+		if is_async {
+			self.advance(); // `async`
+		}
+		self.advance(); // `test`
+		let (fragments, span) = match self.advance() {
+			Some(Spanned(Token::Str(fragments), span)) => (fragments, *span),
+			_ => unreachable!("`at_test_declaration` checked the name literal"),
+		};
+		let mut name = EcoString::new();
+		for fragment in fragments {
+			match &fragment.0 {
+				StrFragment::Text(text) => name.push_str(text),
+				StrFragment::Escape(escape) => match escape.to_char() {
+					Some(char) => name.push(char),
+					None => name.push_str("${"),
+				},
+				StrFragment::Interpolation(_) => {
+					self.emit(fragment.1, ParseError::InterpolationInTestName);
+				}
+			}
+		}
+		let meta = FuncDeclaration {
+			attributes: Vec::new(),
+			name: Spanned(test_function_name(self.tests), span),
+			kind: FuncKind::Instance,
+			is_async,
+			generics: Vec::new(),
+			params: Vec::new(),
+			return_type: None,
+			effects: None,
+		};
+		self.tests += 1;
+		let body = if self.check(&Token::LBrace) {
+			self.parse_block()
+		} else {
+			self.expect(&Token::LBrace);
+			let span = self.current_span();
+			self.mk_expr(ExprKind::Tuple(Vec::new()), span)
+		};
+		Declaration::Test {
+			name: Spanned(name, span),
+			meta,
+			body,
+		}
+	}
+
 	fn parse_external(&mut self, visibility: Option<Visibility>) -> Declaration {
 		self.advance(); // `external`
 		let explicit_name = if self.eat(&Token::LParen).is_some() {
-			let name = self.expect_ident();
+			let name = if matches!(self.peek(), Some(Token::Str(_))) {
+				self.parse_host_binding()
+			} else {
+				self.expect_ident().0
+			};
 			self.expect(&Token::RParen);
-			Some(name.0)
+			Some(name)
 		} else {
 			None
 		};
 
 		if let Some(kind) = self.func_kind_here() {
 			// `external func`, `external mut func`, `external namespace func`.
-			if self.check(&Token::Async) {
+			// A host binding may be `async`: its promise is driven as a task.
+			// So may an explicitly named intrinsic; the checker only accepts
+			// one the linkage registry records as returning a task.
+			let may_be_async = explicit_name.is_some();
+			let is_async = self.check(&Token::Async) && may_be_async;
+			if self.check(&Token::Async) && !may_be_async {
 				let span = self.advance().expect("checked async token").1;
 				self.emit(span, ParseError::AsyncExternalFunction);
 			}
-			let meta = self.parse_func_signature(kind, false);
+			let meta = self.parse_func_signature(kind, is_async);
 			let js_name = explicit_name.unwrap_or_else(|| meta.name.0.clone());
 			Declaration::ExternalFunc(visibility, js_name, meta)
 		} else {
@@ -323,10 +486,87 @@ impl Parser<'_> {
 			};
 			let js_name = explicit_name
 				.unwrap_or_else(|| name.0.as_binding().map(|i| i.0.clone()).unwrap_or_default());
-			Declaration::ExternalLet(visibility, js_name, LetDeclaration { kind, name, type_ })
+			Declaration::ExternalLet(
+				visibility,
+				js_name,
+				LetDeclaration {
+					attributes: Vec::new(),
+					kind,
+					name,
+					type_,
+				},
+			)
 		}
 	}
 
+	/// `"module", "symbol"` inside `external(..)`: the module specifier and
+	/// export a declaration binds, recorded as a [`host_binding_marker`].
+	fn parse_host_binding(&mut self) -> EcoString {
+		let module_span = self.current_span();
+		let module = self.parse_host_binding_string();
+		if module.is_empty() || module.contains(['"', '\n', '\r']) {
+			self.emit(module_span, ParseError::InvalidExternalModule);
+		}
+		self.expect(&Token::Comma);
+		let symbol_span = self.current_span();
+		let symbol = self.parse_host_binding_string();
+		let mut chars = symbol.chars();
+		let identifier = chars
+			.next()
+			.is_some_and(|first| first.is_ascii_alphabetic() || matches!(first, '_' | '$'))
+			&& chars.all(|char| char.is_ascii_alphanumeric() || matches!(char, '_' | '$'));
+		if !identifier {
+			self.emit(
+				symbol_span,
+				ParseError::InvalidExternalSymbol {
+					symbol: symbol.clone(),
+				},
+			);
+		}
+		host_binding_marker(&module, &symbol)
+	}
+
+	/// One string literal of a host binding, with its escapes applied.
+	fn parse_host_binding_string(&mut self) -> EcoString {
+		self.parse_plain_string(ParseError::InterpolationInExternalBinding)
+	}
+
+	/// A string literal's text, reporting `interpolation` at each interpolated
+	/// expression.
+	fn parse_plain_string(&mut self, interpolation: ParseError) -> EcoString {
+		let fragments = match self.peek() {
+			Some(Token::Str(_)) => match self.advance() {
+				Some(Spanned(Token::Str(fragments), _)) => fragments,
+				_ => unreachable!("peeked a string literal"),
+			},
+			found => {
+				let found = found.map_or("end of input", Token::describe);
+				self.emit(
+					self.current_span(),
+					ParseError::ExpectedToken {
+						expected: "a string literal".into(),
+						found: found.into(),
+					},
+				);
+				return EcoString::new();
+			}
+		};
+		let mut text = EcoString::new();
+		for fragment in fragments {
+			match &fragment.0 {
+				StrFragment::Text(part) => text.push_str(part),
+				StrFragment::Escape(escape) => match escape.to_char() {
+					Some(char) => text.push(char),
+					None => text.push_str("${"),
+				},
+				StrFragment::Interpolation(_) => {
+					self.emit(fragment.1, interpolation.clone());
+				}
+			}
+		}
+		text
+	}
+
 	fn parse_type_alias(&mut self, visibility: Option<Visibility>) -> Declaration {
 		self.advance(); // `type`
 		let name = self.expect_ident();
@@ -335,7 +575,11 @@ impl Parser<'_> {
 		let value = self.parse_type();
 		Declaration::TypeAlias {
 			visibility,
-			meta: TypeAliasDeclaration { name, generics },
+			meta: TypeAliasDeclaration {
+				attributes: Vec::new(),
+				name,
+				generics,
+			},
 			value,
 		}
 	}
@@ -350,6 +594,7 @@ impl Parser<'_> {
 		} else {
 			Vec::new()
 		};
+		let derives = self.parse_derives();
 		let (members, impls) = if self.check(&Token::LBrace) {
 			self.advance();
 			let m = self.parse_inner_members();
@@ -359,17 +604,34 @@ impl Parser<'_> {
 			(Vec::new(), Vec::new())
 		};
 		Declaration::Struct {
+			attributes: Vec::new(),
 			visibility,
 			name,
 			generics,
 			fields,
 			members,
 			impls,
+			derives,
+		}
+	}
+
+	/// `derives Equals, Hash`: the interfaces whose impls are generated from the
+	/// declaration's fields. `derives` stays an ordinary identifier elsewhere.
+	fn parse_derives(&mut self) -> Vec<Ident> {
+		if !matches!(self.peek(), Some(Token::Identifier(name)) if name == "derives") {
+			return Vec::new();
+		}
+		self.advance(); // `derives`
+		let mut derives = vec![self.expect_ident()];
+		while self.eat(&Token::Comma).is_some() {
+			derives.push(self.expect_ident());
 		}
+		derives
 	}
 
 	fn parse_struct_field(&mut self) -> Spanned<StructField> {
 		let start = self.position();
+		let attributes = self.parse_attributes();
 		let visibility = self.parse_visibility();
 		let name = self.expect_ident();
 		self.expect(&Token::Colon);
@@ -381,6 +643,7 @@ impl Parser<'_> {
 		};
 		Spanned(
 			StructField {
+				attributes,
 				visibility,
 				name,
 				type_,
@@ -394,15 +657,21 @@ impl Parser<'_> {
 		self.advance(); // `enum`
 		let name = self.expect_ident();
 		let generics = self.parse_generic_params();
+		let derives = self.parse_derives();
 		self.expect(&Token::LBrace);
 
 		let mut embeddings = Vec::new();
 		let mut variants = Vec::new();
 		// Enum entries come first. `...Source` embeds a complete view;
 		// `Source.Variant` embeds one source-owned variant.
-		while self.check(&Token::DotDotDot) || matches!(self.peek(), Some(Token::Identifier(_))) {
+		while matches!(
+			self.peek_nth(self.attribute_tokens()),
+			Some(Token::DotDotDot | Token::Identifier(_))
+		) {
 			let start = self.position();
+			let attributes = self.parse_attributes();
 			if self.eat(&Token::DotDotDot).is_some() {
+				self.reject_attributes(&attributes);
 				let source = self.expect_ident();
 				embeddings.push(Spanned(
 					EnumEmbedding {
@@ -414,6 +683,7 @@ impl Parser<'_> {
 			} else {
 				let variant_name = self.expect_ident();
 				if self.eat(&Token::Dot).is_some() {
+					self.reject_attributes(&attributes);
 					let variant = self.expect_ident();
 					embeddings.push(Spanned(
 						EnumEmbedding {
@@ -431,6 +701,7 @@ impl Parser<'_> {
 					};
 					variants.push(Spanned(
 						EnumVariant {
+							attributes,
 							name: variant_name,
 							fields,
 						},
@@ -447,6 +718,7 @@ impl Parser<'_> {
 		self.expect(&Token::RBrace);
 
 		Declaration::Enum {
+			attributes: Vec::new(),
 			visibility,
 			name,
 			generics,
@@ -454,6 +726,7 @@ impl Parser<'_> {
 			variants,
 			members,
 			impls,
+			derives,
 		}
 	}
 
@@ -465,7 +738,9 @@ impl Parser<'_> {
 		let mut impls = Vec::new();
 		while !self.check(&Token::RBrace) && !self.at_end() {
 			let start = self.position();
-			if self.check(&Token::Impl) {
+			if self.peek_nth(self.attribute_tokens()) == Some(&Token::Impl) {
+				let attributes = self.parse_attributes();
+				self.reject_attributes(&attributes);
 				self.advance();
 				let generics = self.parse_generic_params();
 				let interface = self.parse_interface_ref();
@@ -508,9 +783,18 @@ impl Parser<'_> {
 
 	fn parse_impl_member(&mut self) -> Spanned<ImplMember> {
 		let start = self.position();
+		let attributes = self.parse_attributes();
 		let visibility = self.parse_visibility();
-		let member = if self.check(&Token::External) {
-			match self.parse_external(visibility) {
+		let mut member = if self.check(&Token::External) {
+			let external = self.current_span();
+			let member = self.parse_external(visibility);
+			if let Declaration::ExternalFunc(_, marker, _) | Declaration::ExternalLet(_, marker, _) =
+				&member
+				&& host_binding(marker).is_some()
+			{
+				self.emit(external, ParseError::HostBindingInMember);
+			}
+			match member {
 				Declaration::ExternalFunc(v, n, m) => ImplMember::ExternalFunc(v, n, m),
 				Declaration::ExternalLet(v, n, m) => ImplMember::ExternalLet(v, n, m),
 				_ => unreachable!(),
@@ -557,6 +841,7 @@ impl Parser<'_> {
 			ImplMember::Let {
 				visibility,
 				meta: LetDeclaration {
+					attributes: Vec::new(),
 					kind: LetKind::Instance,
 					name: Spanned(nymph_ast::expr::Pattern::Placeholder, span),
 					type_: None,
@@ -564,6 +849,14 @@ impl Parser<'_> {
 				value: self.mk_expr(ExprKind::Tuple(Vec::new()), span),
 			}
 		};
+		match &mut member {
+			ImplMember::Let { meta, .. } | ImplMember::ExternalLet(_, _, meta) => {
+				meta.attributes = attributes;
+			}
+			ImplMember::Func { meta, .. } | ImplMember::ExternalFunc(_, _, meta) => {
+				meta.attributes = attributes;
+			}
+		}
 		Spanned(member, self.span_from(start))
 	}
 
@@ -595,6 +888,7 @@ impl Parser<'_> {
 		}
 		self.expect(&Token::RBrace);
 		Declaration::Interface {
+			attributes: Vec::new(),
 			visibility,
 			name,
 			generics,
@@ -605,9 +899,11 @@ impl Parser<'_> {
 
 	fn parse_interface_member(&mut self) -> Option<Spanned<InterfaceMember>> {
 		let start = self.position();
+		let attributes = self.parse_attributes();
 		if let Some(kind) = self.func_kind_here() {
 			let is_async = self.check(&Token::Async);
-			let meta = self.parse_func_signature(kind, is_async);
+			let mut meta = self.parse_func_signature(kind, is_async);
+			meta.attributes = attributes;
 			let body = if self.eat(&Token::Eq).is_some() {
 				Some(self.parse_expr())
 			} else {
@@ -648,7 +944,12 @@ impl Parser<'_> {
 			Some(Spanned(
 				InterfaceMember::Element(Box::new(Spanned(
 					InterfaceElement::Let {
-						meta: LetDeclaration { kind, name, type_ },
+						meta: LetDeclaration {
+							attributes,
+							kind,
+							name,
+							type_,
+						},
 						value,
 					},
 					self.span_from(start),
@@ -656,6 +957,7 @@ impl Parser<'_> {
 				self.span_from(start),
 			))
 		} else if self.check(&Token::Impl) {
+			self.reject_attributes(&attributes);
 			self.advance();
 			let generics = self.parse_generic_params();
 			let interface = self.parse_interface_ref();
@@ -715,6 +1017,7 @@ impl Parser<'_> {
 			}
 		}
 		Declaration::Namespace {
+			attributes: Vec::new(),
 			visibility,
 			name,
 			members,
@@ -734,6 +1037,7 @@ impl Parser<'_> {
 			let members = self.parse_impl_members();
 			self.expect(&Token::RBrace);
 			Declaration::ImplFor {
+				attributes: Vec::new(),
 				visibility,
 				generics,
 				type_,
@@ -745,6 +1049,7 @@ impl Parser<'_> {
 			let members = self.parse_impl_members();
 			self.expect(&Token::RBrace);
 			Declaration::Impl {
+				attributes: Vec::new(),
 				visibility,
 				generics,
 				type_: first,
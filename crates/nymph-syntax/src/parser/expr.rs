@@ -443,7 +443,8 @@ impl Parser<'_> {
 			}
 			Token::Identifier(_) => {
 				// A labeled closure or block: `label@(params) -> body`, `label@{...}`.
-				if self.peek_nth(1) == Some(&Token::At) {
+				// An `@` on the next line starts an attribute instead.
+				if self.peek_nth(1) == Some(&Token::At) && !self.line_break_before(1) {
 					let label = self.expect_ident();
 					let at = self.expect(&Token::At).unwrap();
 					self.require_label_adjacency(label.1, at);
@@ -564,7 +565,7 @@ impl Parser<'_> {
 				}
 				StrFragment::Interpolation(tokens) => {
 					let eoi = Span::new(fragment.1.end, fragment.1.end);
-					let mut sub = Parser::new(tokens, eoi);
+					let mut sub = Parser::new(tokens, &[], eoi);
 					// Continue THIS parser's node-id counter through the sub-parser and read
 					// it back, so an interpolated expression's node ids never collide with the
 					// surrounding tree's. A fresh `Parser` restarts `next_id` at 0, so without
@@ -724,6 +725,10 @@ impl Parser<'_> {
 	}
 
 	fn parse_control_label(&mut self, keyword: Span) -> Option<Ident> {
+		if self.line_break_before(0) {
+			// `@` on the next line starts an attribute, not this label.
+			return None;
+		}
 		self.eat(&Token::At).map(|at| {
 			self.require_label_adjacency(keyword, at);
 			let label = self.expect_ident();
@@ -905,10 +910,13 @@ impl Parser<'_> {
 
 	fn parse_statement(&mut self) -> Spanned<Statement> {
 		let start = self.position();
+		let attributes = self.parse_attributes();
 		if self.check(&Token::Let) {
-			let (meta, value) = self.parse_let_binding();
+			let (mut meta, value) = self.parse_let_binding();
+			meta.attributes = attributes;
 			Spanned(Statement::Let { meta, value }, self.span_from(start))
 		} else {
+			self.reject_attributes(&attributes);
 			let expr = self.parse_expr();
 			Spanned(Statement::Expr(expr), self.span_from(start))
 		}
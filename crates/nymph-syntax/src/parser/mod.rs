@@ -16,9 +16,9 @@ use cursor::TokenCursor;
 use ecow::EcoString;
 use nymph_ast::{
 	Ident, NodeId, Span, Spanned,
-	decl::Module,
+	decl::{Declaration, Module},
 	expr::{Expr, ExprKind},
-	token::Token,
+	token::{StrFragment, Token},
 };
 use nymph_diagnostics::Diagnostic;
 
@@ -29,6 +29,8 @@ pub struct Parser<'src> {
 	diagnostics: Vec<Diagnostic>,
 	incomplete: bool,
 	next_id: u32,
+	/// Test declarations parsed so far, numbering their synthesized functions.
+	tests: usize,
 }
 
 /// The result of parsing: the (best-effort) tree plus every diagnostic encountered.
@@ -44,7 +46,7 @@ pub struct ParseResult<T> {
 pub fn parse_module(source: &str, module_path: impl Into<EcoString>) -> ParseResult<Module> {
 	let lexed = lex(source);
 	let eoi = Span::new(source.len(), source.len());
-	let mut parser = Parser::new(&lexed.tokens, eoi);
+	let mut parser = Parser::new(&lexed.tokens, &lexed.line_breaks, eoi);
 	let members = parser.parse_module_members();
 	let incomplete = lexed.incomplete
 		|| (!parser.diagnostics.is_empty() && lexed.diagnostics.is_empty() && parser.incomplete);
@@ -64,7 +66,7 @@ pub fn parse_module(source: &str, module_path: impl Into<EcoString>) -> ParseRes
 pub fn parse_expression(source: &str) -> ParseResult<Expr> {
 	let lexed = lex(source);
 	let eoi = Span::new(source.len(), source.len());
-	let mut parser = Parser::new(&lexed.tokens, eoi);
+	let mut parser = Parser::new(&lexed.tokens, &lexed.line_breaks, eoi);
 	let expr = parser.parse_expr();
 	if !parser.at_end() {
 		let span = parser.current_span();
@@ -81,13 +83,55 @@ pub fn parse_expression(source: &str) -> ParseResult<Expr> {
 	}
 }
 
+/// Parse declarations a compiler pass generated for a module, rather than read
+/// from its file. Every span is offset by `base`, which callers place past the
+/// end of that file so generated nodes never share a span with written ones,
+/// and node ids continue from `next_id`, which is left one past the last id used.
+pub fn parse_generated(
+	source: &str,
+	base: usize,
+	next_id: &mut u32,
+) -> ParseResult<Vec<Declaration>> {
+	let mut lexed = lex(source);
+	for token in &mut lexed.tokens {
+		offset_token(token, base);
+	}
+	let eoi = Span::new(base + source.len(), base + source.len());
+	let mut parser = Parser::new(&lexed.tokens, &lexed.line_breaks, eoi);
+	parser.next_id = *next_id;
+	let members = parser.parse_module_members();
+	*next_id = parser.next_id;
+	let mut diagnostics = lexed.diagnostics;
+	diagnostics.extend(parser.diagnostics);
+	ParseResult {
+		tree: members,
+		diagnostics,
+		incomplete: false,
+	}
+}
+
+fn offset_token(token: &mut Spanned<Token>, base: usize) {
+	token.1 = Span::new(token.1.start + base, token.1.end + base);
+	if let Token::Str(fragments) = &mut token.0 {
+		for fragment in fragments {
+			fragment.1 = Span::new(fragment.1.start + base, fragment.1.end + base);
+			if let StrFragment::Interpolation(tokens) = &mut fragment.0 {
+				for token in tokens {
+					offset_token(token, base);
+				}
+			}
+		}
+	}
+}
+
 impl<'src> Parser<'src> {
-	pub fn new(tokens: &'src [Spanned<Token>], eoi: Span) -> Self {
+	pub fn new(tokens: &'src [Spanned<Token>], line_breaks: &'src [bool], eoi: Span) -> Self {
 		Self {
-			cursor: TokenCursor::new(tokens, eoi),
+			cursor: TokenCursor::new(tokens, line_breaks, eoi),
 			diagnostics: Vec::new(),
 			incomplete: true,
 			next_id: 0,
+			tests: 0,
 		}
 	}
 
@@ -127,6 +171,10 @@ impl<'src> Parser<'src> {
 		self.cursor.peek_nth_span(n)
 	}
 
+	fn line_break_before(&self, n: usize) -> bool {
+		self.cursor.line_break_before(n)
+	}
+
 	fn span_from(&self, start: usize) -> Span {
 		self.cursor.span_from(start)
 	}
@@ -209,7 +257,8 @@ impl<'src> Parser<'src> {
 	/// Skip tokens until a likely declaration boundary, used to recover after an error.
 	fn recover_to_declaration(&mut self) {
 		while let Some(token) = self.peek() {
-			if matches!(
+			if self.at_test_declaration(0)
+				|| matches!(
 				token,
 				Token::Func
 					| Token::Async
@@ -226,7 +275,8 @@ impl<'src> Parser<'src> {
 					| Token::Private
 					| Token::External
 					| Token::Effect
-			) {
+					| Token::At
+				) {
 				break;
 			}
 			self.advance();
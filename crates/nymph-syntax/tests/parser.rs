@@ -2,8 +2,11 @@
 //! (lex → parse).
 
 use nymph_ast::{
-	Span,
-	decl::{Declaration, FuncDeclaration, FuncKind, ImplMember, LetKind},
+	Span, Spanned,
+	decl::{
+		Attribute, AttributeArgument, Declaration, FuncDeclaration, FuncKind, ImplMember,
+		InterfaceMember, LetKind,
+	},
 	expr::{
 		CallArg, Expr, ExprKind, ListItem, Pattern, RangeKind, RangePatternKind, Statement, StringPart,
 	},
@@ -1065,6 +1068,156 @@ fn struct_body_carries_destination_func_kinds_and_splits_out_impls() {
 	assert_eq!(impls.len(), 1, "the nested impl is not a flat member");
 }
 
+#[test]
+fn derives_clause_names_interfaces_after_fields_or_generics() {
+	let members = module_ok(
+		"struct Point(x: int, y: int) derives Equals, Hash { func sum(): int = this.x + this.y }\n\
+		 enum Shape<T> derives Debug { Empty }\n\
+		 func derives(derives: int): int = derives",
+	);
+	let names = |derives: &[nymph_ast::Ident]| {
+		derives
+			.iter()
+			.map(|derive| derive.0.to_string())
+			.collect::<Vec<_>>()
+	};
+	let Declaration::Struct {
+		derives,
+		members: body,
+		..
+	} = &members[0]
+	else {
+		panic!("expected a struct, got {:?}", members[0]);
+	};
+	assert_eq!(names(derives), ["Equals", "Hash"]);
+	assert_eq!(body.len(), 1);
+	let Declaration::Enum { derives, .. } = &members[1] else {
+		panic!("expected an enum, got {:?}", members[1]);
+	};
+	assert_eq!(names(derives), ["Debug"]);
+	assert!(matches!(&members[2], Declaration::Func { .. }));
+}
+
+#[test]
+fn attributes_attach_to_declarations_members_fields_and_variants() {
+	let members = module_ok(
+		"@deprecated(\"use next\") @inline public func legacy(): int = 1\n\
+		 @allow(unused-variable, managed-field)\n\
+		 struct Flagged(@deprecated x: int) { @inline func sum(): int = { @allow(unused-variable) let z = 1\n z } }\n\
+		 enum Phase { @deprecated Old, New }\n\
+		 interface Named { @deprecated func name(): string }\n\
+		 func labelled(): int = outer@{ 1 }",
+	);
+	let rendered = |attributes: &[Spanned<Attribute>]| {
+		attributes
+			.iter()
+			.map(|attribute| {
+				let arguments = attribute
+					.0
+					.arguments
+					.iter()
+					.map(|argument| match &argument.0 {
+						AttributeArgument::String(text) => format!("{text:?}"),
+						AttributeArgument::Name(name) => name.to_string(),
+					})
+					.collect::<Vec<_>>();
+				format!("{}({})", attribute.0.name.0, arguments.join(", "))
+			})
+			.collect::<Vec<_>>()
+	};
+	assert_eq!(
+		rendered(members[0].attributes()),
+		["deprecated(\"use next\")", "inline()"]
+	);
+	assert!(matches!(
+		&members[0],
+		Declaration::Func {
+			visibility: Some(_),
+			..
+		}
+	));
+	let Declaration::Struct {
+		attributes,
+		fields,
+		members: body,
+		..
+	} = &members[1]
+	else {
+		panic!("expected a struct, got {:?}", members[1]);
+	};
+	assert_eq!(
+		rendered(attributes),
+		["allow(unused-variable, managed-field)"]
+	);
+	assert_eq!(rendered(&fields[0].0.attributes), ["deprecated()"]);
+	assert_eq!(rendered(body[0].0.attributes()), ["inline()"]);
+	let ImplMember::Func { body, .. } = &body[0].0 else {
+		panic!("expected a method, got {:?}", body[0]);
+	};
+	let ExprKind::Block { body, .. } = &body.kind else {
+		panic!("expected a block body, got {body:?}");
+	};
+	let Statement::Let { meta, .. } = &body[0].0 else {
+		panic!("expected a local let, got {:?}", body[0]);
+	};
+	assert_eq!(rendered(&meta.attributes), ["allow(unused-variable)"]);
+	let Declaration::Enum { variants, .. } = &members[2] else {
+		panic!("expected an enum, got {:?}", members[2]);
+	};
+	assert_eq!(rendered(&variants[0].0.attributes), ["deprecated()"]);
+	assert!(variants[1].0.attributes.is_empty());
+	let Declaration::Interface { members: body, .. } = &members[3] else {
+		panic!("expected an interface, got {:?}", members[3]);
+	};
+	let InterfaceMember::Element(element) = &body[0].0 else {
+		panic!("expected an interface element, got {:?}", body[0]);
+	};
+	assert_eq!(rendered(element.0.attributes()), ["deprecated()"]);
+	assert!(members[4].attributes().is_empty());
+
+	// An attribute on the line after an expression body starts the next
+	// declaration instead of labelling the expression.
+	let members = module_ok("func first(): int = 1\n@inline func second(): int = 2");
+	assert_eq!(members.len(), 2);
+	assert_eq!(rendered(members[1].attributes()), ["inline()"]);
+}
+
+#[test]
+fn attributes_where_nothing_carries_them_are_rejected() {
+	for (source, message) in [
+		(
+			"@inline import std/io",
+			"attributes can only be written before a declaration",
+		),
+		(
+			"enum View { @deprecated ...Source }",
+			"attributes can only be written before a declaration",
+		),
+		(
+			"struct S { @inline impl Show { func show(): string = \"s\" } }",
+			"attributes can only be written before a declaration",
+		),
+		(
+			"func f(): int = { @inline 1 }",
+			"attributes can only be written before a declaration",
+		),
+		(
+			"@deprecated(\"use ${next}\") func f(): int = 1",
+			"an attribute's string argument cannot contain string interpolation",
+		),
+	] {
+		let parsed = parse_module(source, "test");
+		assert!(
+			parsed
+				.diagnostics
+				.iter()
+				.any(|diagnostic| diagnostic.message.contains(message)),
+			"{source}: {:?}",
+			parsed.diagnostics
+		);
+	}
+}
+
 #[test]
 fn top_level_namespace_block_still_parses() {
 	// A named `namespace Name { … }` remains a top-level declaration, holding
@@ -1130,6 +1283,58 @@ fn external_lets_default_and_preserve_explicit_markers() {
 	);
 }
 
+#[test]
+fn host_bindings_record_their_module_and_symbol() {
+	let members = module_ok(
+		"external(\"node:fs\", \"readFileSync\") func read(path: string): string\nexternal(\"node:path\", \"sep\") let separator: string",
+	);
+	let bindings: Vec<_> = members
+		.iter()
+		.map(|decl| match decl {
+			Declaration::ExternalFunc(_, marker, _) | Declaration::ExternalLet(_, marker, _) => {
+				nymph_ast::decl::host_binding(marker).expect("host binding marker")
+			}
+			other => panic!("expected an external, got {other:?}"),
+		})
+		.collect();
+	assert_eq!(
+		bindings,
+		[("node:fs", "readFileSync"), ("node:path", "sep")]
+	);
+}
+
+#[test]
+fn malformed_host_bindings_are_rejected() {
+	for (source, message) in [
+		(
+			"external(\"\", \"f\") func f(): void",
+			"non-empty module specifier",
+		),
+		(
+			"external(\"m\", \"not-an-ident\") func f(): void",
+			"is not a JavaScript identifier",
+		),
+		(
+			"external(\"m\", \"f${1}\") func f(): void",
+			"cannot contain string interpolation",
+		),
+		(
+			"struct S { external(\"m\", \"f\") func f(): void }",
+			"can only bind a top-level declaration",
+		),
+	] {
+		let parsed = parse_module(source, "test");
+		assert!(
+			parsed
+				.diagnostics
+				.iter()
+				.any(|diagnostic| diagnostic.message.contains(message)),
+			"{source}: {:?}",
+			parsed.diagnostics
+		);
+	}
+}
+
 #[test]
 fn namespace_let_is_a_static_member_binding() {
 	let members = module_ok(
@@ -1266,6 +1471,17 @@ fn malformed_async_declaration_recovers_at_the_next_declaration() {
 	}));
 }
 
+#[test]
+fn named_external_async_function_publishes_a_task_signature() {
+	let parsed = parse_module("external(process_spawn) async func spawn(): int", "test");
+	assert!(parsed.diagnostics.is_empty(), "{:?}", parsed.diagnostics);
+	let Declaration::ExternalFunc(_, marker, meta) = &parsed.tree.members[0] else {
+		panic!("expected external function");
+	};
+	assert_eq!(marker, "process_spawn");
+	assert!(meta.is_async);
+}
+
 #[test]
 fn external_async_function_is_rejected_without_publishing_a_task_signature() {
 	let parsed = parse_module(
@@ -1286,3 +1502,60 @@ fn external_async_function_is_rejected_without_publishing_a_task_signature() {
 		Declaration::Func { meta, .. } if meta.name.0 == "recovered"
 	));
 }
+
+#[test]
+fn test_declarations_synthesize_private_functions_and_keep_test_an_identifier() {
+	let members = module_ok(
+		"test \"adds \\\"two\\\"\" { 1 }\nasync test \"waits\" {}\nlet test = 1\nfunc uses() = test",
+	);
+	let Declaration::Test { name, meta, body } = &members[0] else {
+		panic!("expected a test declaration, got {:?}", members[0]);
+	};
+	assert_eq!(name.0, "adds \"two\"");
+	assert_eq!(meta.name.0, "test$0");
+	assert!(nymph_ast::decl::is_test_function_name(&meta.name.0));
+	assert!(!meta.is_async);
+	assert!(meta.params.is_empty() && meta.return_type.is_none());
+	assert!(matches!(body.kind, ExprKind::Block { .. }));
+	let Declaration::Test { name, meta, .. } = &members[1] else {
+		panic!("expected an async test declaration, got {:?}", members[1]);
+	};
+	assert_eq!((name.0.as_str(), meta.name.0.as_str()), ("waits", "test$1"));
+	assert!(meta.is_async);
+	assert!(matches!(&members[2], Declaration::Let { .. }));
+	assert!(matches!(&members[3], Declaration::Func { meta, .. } if meta.name.0 == "uses"));
+}
+
+#[test]
+fn test_declarations_reject_visibility_and_interpolated_names() {
+	let parsed = parse_module(
+		"public test \"exported\" {}\ntest \"value ${1}\" {}\nfunc recovered(): int = 1",
+		"test",
+	);
+	let messages = parsed
+		.diagnostics
+		.iter()
+		.map(|diagnostic| diagnostic.message.as_str())
+		.collect::<Vec<_>>();
+	assert!(
+		messages.contains(&"a test is private to its module and takes no visibility"),
+		"{messages:?}"
+	);
+	assert!(
+		messages.contains(&"a test name cannot contain string interpolation"),
+		"{messages:?}"
+	);
+	assert_eq!(
+		parsed
+			.tree
+			.members
+			.iter()
+			.filter(|member| matches!(member, Declaration::Test { .. }))
+			.count(),
+		2
+	);
+	assert!(matches!(
+		parsed.tree.members.last(),
+		Some(Declaration::Func { meta, .. }) if meta.name.0 == "recovered"
+	));
+}
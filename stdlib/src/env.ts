@@ -0,0 +1,15 @@
+import { NList, NMap, NString } from "std/box";
+import { Option } from "std/option";
+
+// `process.argv` starts with Node's own path and the script's.
+export const env_args = () => new NList(process.argv.slice(2).map((arg) => new NString(arg)));
+export const env_var = (name: NString) => {
+	const value = process.env[name.v];
+	return value === undefined ? Option.None : Option.Some({ value: new NString(value) });
+};
+export const env_vars = () =>
+	new NMap(
+		Object.entries(process.env)
+			.filter((entry): entry is [string, string] => entry[1] !== undefined)
+			.map(([name, value]) => [new NString(name), new NString(value)] as const),
+	);
@@ -0,0 +1,125 @@
+import { NBool, NFloat, NInt, NList, NString, NUint } from "std/box";
+import { Option } from "std/option";
+import { Result } from "std/result";
+
+interface NMetadata {
+	size: NUint;
+	is_file: NBool;
+	is_directory: NBool;
+	modified: NFloat;
+}
+
+interface StringDecoder {
+	write(bytes: Uint8Array): string;
+	end(): string;
+}
+
+// Only exports survive stripping, so every export reaches `node:fs` itself
+// and reports a failure as Node's error code, which the Nymph side turns
+// into an `IoError` carrying the path.
+export const fs_read_text = (path: NString) => {
+	try {
+		const fs = process.getBuiltinModule("node:fs");
+		return Result.Ok({ value: new NString(fs.readFileSync(path.v, "utf8")) });
+	} catch (error) {
+		return Result.Error({ error: new NString((error as { code?: string }).code ?? "EIO") });
+	}
+};
+export const fs_write_text = (path: NString, text: NString, flags: NString) => {
+	try {
+		const fs = process.getBuiltinModule("node:fs");
+		fs.writeFileSync(path.v, text.v, { flag: flags.v });
+		return Result.Ok({ value: undefined });
+	} catch (error) {
+		return Result.Error({ error: new NString((error as { code?: string }).code ?? "EIO") });
+	}
+};
+export const fs_read_dir = (path: NString) => {
+	try {
+		const fs = process.getBuiltinModule("node:fs");
+		const names = fs.readdirSync(path.v).sort();
+		return Result.Ok({ value: new NList(names.map((name) => new NString(name))) });
+	} catch (error) {
+		return Result.Error({ error: new NString((error as { code?: string }).code ?? "EIO") });
+	}
+};
+// `Metadata` is the compiler's own struct, so the result is a copy of the
+// received template with its fields replaced (as in js.ts).
+export const fs_metadata = (template: NMetadata, path: NString) => {
+	try {
+		const fs = process.getBuiltinModule("node:fs");
+		const stats = fs.statSync(path.v);
+		const fields = {
+			size: new NUint(BigInt(stats.size)),
+			is_file: new NBool(stats.isFile()),
+			is_directory: new NBool(stats.isDirectory()),
+			modified: new NFloat(stats.mtimeMs),
+		};
+		const descriptors = Object.getOwnPropertyDescriptors(template);
+		for (const [name, value] of Object.entries(fields)) {
+			descriptors[name as keyof NMetadata] = { ...descriptors[name as keyof NMetadata], value };
+		}
+		return Result.Ok({
+			value: Object.create(Object.getPrototypeOf(template), descriptors) as NMetadata,
+		});
+	} catch (error) {
+		return Result.Error({ error: new NString((error as { code?: string }).code ?? "EIO") });
+	}
+};
+
+// Open descriptors and their UTF-8 decoders live in one process-wide table,
+// so closing is idempotent and a character split across reads is kept
+// until the read that completes it.
+export const fs_open = (path: NString, flags: NString) => {
+	try {
+		const fs = process.getBuiltinModule("node:fs");
+		const { StringDecoder } = process.getBuiltinModule("node:string_decoder");
+		const descriptor = fs.openSync(path.v, flags.v);
+		const open = ((globalThis as Record<symbol, unknown>)[Symbol.for("nymph.fs.open")] ??=
+			new Map()) as Map<number, StringDecoder>;
+		open.set(descriptor, new StringDecoder("utf8"));
+		return Result.Ok({ value: new NInt(BigInt(descriptor)) });
+	} catch (error) {
+		return Result.Error({ error: new NString((error as { code?: string }).code ?? "EIO") });
+	}
+};
+export const fs_read_chunk = (descriptor: bigint, max: bigint) => {
+	const open = ((globalThis as Record<symbol, unknown>)[Symbol.for("nymph.fs.open")] ??=
+		new Map()) as Map<number, StringDecoder>;
+	const decoder = open.get(Number(descriptor));
+	if (decoder === undefined) return Result.Error({ error: new NString("EBADF") });
+	try {
+		const fs = process.getBuiltinModule("node:fs");
+		const bytes = new Uint8Array(Math.max(Number(max), 1));
+		for (;;) {
+			const read = fs.readSync(Number(descriptor), bytes, 0, bytes.length, null);
+			const text = read === 0 ? decoder.end() : decoder.write(bytes.subarray(0, read));
+			if (text !== "") return Result.Ok({ value: Option.Some({ value: new NString(text) }) });
+			if (read === 0) return Result.Ok({ value: Option.None });
+		}
+	} catch (error) {
+		return Result.Error({ error: new NString((error as { code?: string }).code ?? "EIO") });
+	}
+};
+export const fs_write_chunk = (descriptor: bigint, text: NString) => {
+	const open = ((globalThis as Record<symbol, unknown>)[Symbol.for("nymph.fs.open")] ??=
+		new Map()) as Map<number, StringDecoder>;
+	if (!open.has(Number(descriptor))) return Result.Error({ error: new NString("EBADF") });
+	try {
+		const fs = process.getBuiltinModule("node:fs");
+		fs.writeSync(Number(descriptor), text.v);
+		return Result.Ok({ value: undefined });
+	} catch (error) {
+		return Result.Error({ error: new NString((error as { code?: string }).code ?? "EIO") });
+	}
+};
+export const fs_close = (descriptor: bigint) => {
+	const open = ((globalThis as Record<symbol, unknown>)[Symbol.for("nymph.fs.open")] ??=
+		new Map()) as Map<number, StringDecoder>;
+	if (!open.delete(Number(descriptor))) return;
+	try {
+		process.getBuiltinModule("node:fs").closeSync(Number(descriptor));
+	} catch {
+		// Closing cannot fail from the program's point of view.
+	}
+};
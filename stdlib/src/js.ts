@@ -0,0 +1,91 @@
+import { NBool, NFloat, NList, NString } from "std/box";
+import { Option } from "std/option";
+
+interface NJsValue {
+	handle: unknown;
+}
+
+// A `JsValue` is an instance of the compiler's own struct, so every result is
+// a copy of a received one with its hidden `handle` replaced (as in set.ts).
+// Only exports survive stripping, so each export spells the copy out.
+export const js_global = (template: NJsValue) =>
+	Object.create(Object.getPrototypeOf(template), {
+		...Object.getOwnPropertyDescriptors(template),
+		handle: { ...Object.getOwnPropertyDescriptor(template, "handle"), value: globalThis },
+	}) as NJsValue;
+// `int` and `uint` arrive as BigInt; every other primitive arrives boxed.
+export const js_wrap = (template: NJsValue, value: unknown) => {
+	const handle = typeof value === "bigint" ? value : (value as { v: unknown }).v;
+	return Object.create(Object.getPrototypeOf(template), {
+		...Object.getOwnPropertyDescriptors(template),
+		handle: { ...Object.getOwnPropertyDescriptor(template, "handle"), value: handle },
+	}) as NJsValue;
+};
+
+export const js_is_null = ($_this: NJsValue) => new NBool($_this.handle == null);
+export const js_as_string = ($_this: NJsValue) =>
+	typeof $_this.handle === "string"
+		? Option.Some({ value: new NString($_this.handle) })
+		: Option.None;
+export const js_as_float = ($_this: NJsValue) =>
+	typeof $_this.handle === "number"
+		? Option.Some({ value: new NFloat($_this.handle) })
+		: Option.None;
+export const js_as_boolean = ($_this: NJsValue) =>
+	typeof $_this.handle === "boolean"
+		? Option.Some({ value: new NBool($_this.handle) })
+		: Option.None;
+export const js_get_field = ($_this: NJsValue, name: NString) => {
+	const target = $_this.handle;
+	if (target == null || !(name.v in Object(target))) return Option.None;
+	const handle = (target as Record<string, unknown>)[name.v];
+	return Option.Some({
+		value: Object.create(Object.getPrototypeOf($_this), {
+			...Object.getOwnPropertyDescriptors($_this),
+			handle: { ...Object.getOwnPropertyDescriptor($_this, "handle"), value: handle },
+		}) as NJsValue,
+	});
+};
+export const js_display = ($_this: NJsValue) => {
+	try {
+		return new NString(String($_this.handle));
+	} catch {
+		return new NString(Object.prototype.toString.call($_this.handle));
+	}
+};
+export const js_debug = ($_this: NJsValue) => {
+	const handle = $_this.handle;
+	switch (typeof handle) {
+		case "string":
+			return new NString(`JsValue(${JSON.stringify(handle)})`);
+		case "bigint":
+			return new NString(`JsValue(${handle}n)`);
+		case "object":
+		case "function":
+			return new NString(
+				handle === null ? "JsValue(null)" : `JsValue(${Object.prototype.toString.call(handle)})`,
+			);
+		default:
+			return new NString(`JsValue(${String(handle)})`);
+	}
+};
+
+// The outcome is itself a `JsValue` whose `threw` and `value` fields the
+// Nymph side reads back, so a thrown value never escapes as a defect.
+export const js_invoke = (callee: NJsValue, receiver: NJsValue, args: NList<NJsValue>) => {
+	let outcome;
+	try {
+		const value = Reflect.apply(
+			callee.handle as (...args: unknown[]) => unknown,
+			receiver.handle,
+			Array.from(args.v, (arg) => arg.handle),
+		);
+		outcome = { threw: false, value };
+	} catch (error) {
+		outcome = { threw: true, value: error };
+	}
+	return Object.create(Object.getPrototypeOf(callee), {
+		...Object.getOwnPropertyDescriptors(callee),
+		handle: { ...Object.getOwnPropertyDescriptor(callee, "handle"), value: outcome },
+	}) as NJsValue;
+};
@@ -0,0 +1,9 @@
+import { NFloat, NString } from "std/box";
+
+// The parser in json.nym has already checked the grammar, so these only
+// convert text it accepted, or values it is about to print.
+export const json_quote = (text: NString) => new NString(JSON.stringify(text.v));
+export const json_unescape = (raw: NString) => new NString(JSON.parse(`"${raw.v}"`));
+export const json_number_text = (value: NFloat) =>
+	new NString(Number.isFinite(value.v) ? JSON.stringify(value.v) : "null");
+export const json_parse_number = (text: NString) => new NFloat(Number(text.v));
@@ -1,3 +1,9 @@
-import { NBool } from "std/box";
+import { NBool, NString, nymphDebug, nymphDisplay, nymphHash } from "std/box";
 
 export const primitive_equals = ($_this: bigint, other: bigint) => new NBool($_this === other);
+
+// Structural on purpose: the `Display`/`Debug` protocol would dispatch back
+// into the primitive impls that call these.
+export const primitive_hash = (value: unknown) => nymphHash(value);
+export const primitive_display = ($_this: unknown) => new NString(nymphDisplay($_this));
+export const primitive_debug = ($_this: unknown) => new NString(nymphDebug($_this));
@@ -0,0 +1,78 @@
+import { NInt, NList, NMap, NString, NUint, nymphHostSignalTask } from "std/box";
+import { Option } from "std/option";
+import { Result } from "std/result";
+
+interface NFinished {
+	code: unknown;
+	signal: unknown;
+	stdout: NList<NUint>;
+	stderr: NList<NUint>;
+}
+
+// The child is spawned with the execution's abort signal, so cancelling the
+// task that awaits it kills the process. `Finished` is the compiler's own
+// struct, so the result is a copy of the received template (as in js.ts).
+export const process_spawn = (
+	template: NFinished,
+	program: NString,
+	args: NList<NString>,
+	env: NMap<NString, NString>,
+	cwd: NString,
+	stdio: NList<NString>,
+) =>
+	nymphHostSignalTask(
+		(signal: AbortSignal) =>
+			new Promise((resolve) => {
+				const { spawn } = process.getBuiltinModule("node:child_process");
+				const chunks: Record<"stdout" | "stderr", Uint8Array[]> = { stdout: [], stderr: [] };
+				const child = spawn(
+					program.v,
+					Array.from(args.v, (arg) => arg.v),
+					{
+						cwd: cwd.v === "" ? undefined : cwd.v,
+						env: {
+							...process.env,
+							...Object.fromEntries(Array.from(env, ([name, value]) => [name.v, value.v])),
+						},
+						stdio: Array.from(stdio.v, (mode) => mode.v) as ("inherit" | "pipe" | "ignore")[],
+						signal,
+						killSignal: "SIGKILL",
+					},
+				);
+				child.stdout?.on("data", (chunk: Uint8Array) => chunks.stdout.push(chunk));
+				child.stderr?.on("data", (chunk: Uint8Array) => chunks.stderr.push(chunk));
+				child.stdin?.end();
+				child.on("error", (error: Error & { code?: string }) => {
+					if (error.name !== "AbortError") {
+						resolve(Result.Error({ error: new NString(error.code ?? "EIO") }));
+					}
+				});
+				child.on("close", (code: number | null, signalName: string | null) => {
+					const fields = {
+						code: code === null ? Option.None : Option.Some({ value: new NInt(BigInt(code)) }),
+						signal:
+							signalName === null ? Option.None : Option.Some({ value: new NString(signalName) }),
+						stdout: new NList(
+							Array.from(Buffer.concat(chunks.stdout), (byte) => new NUint(BigInt(byte))),
+						),
+						stderr: new NList(
+							Array.from(Buffer.concat(chunks.stderr), (byte) => new NUint(BigInt(byte))),
+						),
+					};
+					const descriptors = Object.getOwnPropertyDescriptors(template);
+					for (const [name, value] of Object.entries(fields)) {
+						descriptors[name as keyof NFinished] = {
+							...descriptors[name as keyof NFinished],
+							value,
+						};
+					}
+					resolve(
+						Result.Ok({
+							value: Object.create(Object.getPrototypeOf(template), descriptors) as NFinished,
+						}),
+					);
+				});
+			}),
+	);
+export const process_decode = (bytes: NList<NUint>) =>
+	new NString(new TextDecoder().decode(Uint8Array.from(bytes.v, (byte) => Number(byte.v))));
@@ -0,0 +1,23 @@
+import { NString, nymphAssertionError, nymphEquals } from "std/box";
+
+export const assert_eq = (left: unknown, right: unknown) => {
+	if (!nymphEquals(left, right)) {
+		throw nymphAssertionError("left == right", [
+			["left", left],
+			["right", right],
+		]);
+	}
+};
+
+export const assert_ne = (left: unknown, right: unknown) => {
+	if (nymphEquals(left, right)) {
+		throw nymphAssertionError("left != right", [
+			["left", left],
+			["right", right],
+		]);
+	}
+};
+
+export const value_assertion_failed = (condition: NString, value: unknown): never => {
+	throw nymphAssertionError(condition.v, [["value", value]]);
+};
@@ -66,6 +66,14 @@ declare module "std/box" {
 
 	export function nymphStructuralValue<T>(value: T, identity: string, fields: string[]): T;
 	export function nymphProtocolDisplay(value: unknown): NString;
+	export function nymphDisplay(value: unknown): string;
+	export function nymphDebug(value: unknown): string;
+	export function nymphHash(value: unknown): bigint;
+	export function nymphEquals(left: unknown, right: unknown): boolean;
+	export function nymphAssertionError(
+		condition: string,
+		values: readonly (readonly [string, unknown])[],
+	): Error;
 	export function nymphTransactionBegin(): void;
 	export function nymphTransactionCommit(): void;
 	export function nymphTransactionRollback(): void;
@@ -149,6 +157,7 @@ declare module "std/box" {
 	): { cancel(): void; outcome: Promise<NymphTaskOutcome<T>> };
 	export function nymphRenderDefect(defect: unknown): string;
 	export function nymphRunTask<T>(task: NymphTask<T>): Promise<T>;
+	export function nymphHostSignalTask<T>(start: (signal: AbortSignal) => Promise<T>): NymphTask<T>;
 }
 
 declare module "std/option" {
@@ -166,3 +175,20 @@ declare module "std/option" {
 		const None: None;
 	}
 }
+
+declare module "std/result" {
+	export namespace Result {
+		interface Ok<T> {
+			readonly [tag: symbol]: unknown;
+			readonly value: T;
+		}
+
+		interface Error<E> {
+			readonly [tag: symbol]: unknown;
+			readonly error: E;
+		}
+
+		const Ok: <T>(fields: { value: T }) => Ok<T>;
+		const Error: <E>(fields: { error: E }) => Error<E>;
+	}
+}